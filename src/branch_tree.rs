@@ -8,6 +8,7 @@
 //! of building a stack upward from a base.
 
 use anyhow::Result;
+use std::collections::HashSet;
 
 use crate::git_gateway::GitGateway;
 use crate::ref_store::RefStore;
@@ -66,6 +67,39 @@ pub fn build_branch_tree(
     Ok(rows)
 }
 
+/// Build the branch tree scoped to the stack containing `current_branch`.
+///
+/// Same order and depths as `build_branch_tree`, keeping only the branches
+/// returned by `stack_branches`.
+pub fn build_stack_tree(
+    ref_store: &RefStore,
+    current_branch: &str,
+    gateway: &GitGateway,
+) -> Result<Vec<BranchDisplay>> {
+    let stack = stack_branches(ref_store, current_branch)?;
+    let rows = build_branch_tree(ref_store, current_branch, gateway)?;
+    Ok(rows.into_iter().filter(|row| stack.contains(&row.name)).collect())
+}
+
+/// Branches in the stack containing `branch`, plus trunk.
+///
+/// The stack is everything rooted at the trunk child `branch` descends from,
+/// so siblings within the stack are included. Trunk and untracked branches
+/// belong to no single stack; for them every tracked branch is returned.
+pub fn stack_branches(ref_store: &RefStore, branch: &str) -> Result<HashSet<String>> {
+    let trunk = ref_store.require_trunk()?;
+    let root = if branch != trunk && ref_store.is_tracked(branch)? {
+        // ancestors() runs trunk-first, so the stack root comes first
+        ref_store.ancestors(branch)?.swap_remove(0)
+    } else {
+        trunk.clone()
+    };
+
+    let mut stack: HashSet<String> = ref_store.collect_branches_dfs(&[root])?.into_iter().collect();
+    stack.insert(trunk);
+    Ok(stack)
+}
+
 fn build_tree_recursive(
     ref_store: &RefStore,
     branch: &str,
@@ -76,12 +110,7 @@ fn build_tree_recursive(
 ) -> Result<()> {
     let commit_time = gateway.get_commit_time_relative(branch).unwrap_or_default();
 
-    // Check if this branch needs restack (parent's tip is not an ancestor of this branch)
-    let needs_restack = if let Ok(Some(parent)) = ref_store.get_parent(branch) {
-        !gateway.is_ancestor(&parent, branch).unwrap_or(true)
-    } else {
-        false
-    };
+    let needs_restack = needs_restack(ref_store, gateway, branch);
 
     rows.push(BranchDisplay {
         name: branch.to_string(),
@@ -116,6 +145,31 @@ pub fn get_commit_info(gateway: &GitGateway, branch: &str) -> (String, String, S
 pub const MARKER_CURRENT: &str = "◉";
 /// Branch marker for non-current branches
 pub const MARKER_OTHER: &str = "◯";
+/// Branch marker for non-current branches that need restacking
+pub const MARKER_STALE: &str = "◍";
+
+/// Check whether a branch needs restacking onto its parent.
+///
+/// A branch is stale when its parent's tip is no longer an ancestor of it,
+/// i.e. the parent has moved on since the branch was last restacked.
+/// Untracked branches and branches whose ancestry can't be read are not flagged.
+pub fn needs_restack(ref_store: &RefStore, gateway: &GitGateway, branch: &str) -> bool {
+    match ref_store.get_parent(branch) {
+        Ok(Some(parent)) => !gateway.is_ancestor(&parent, branch).unwrap_or(true),
+        _ => false,
+    }
+}
+
+/// Pick the tree marker for a branch.
+pub fn branch_marker(is_current: bool, needs_restack: bool) -> &'static str {
+    if is_current {
+        MARKER_CURRENT
+    } else if needs_restack {
+        MARKER_STALE
+    } else {
+        MARKER_OTHER
+    }
+}
 
 /// Format tree indentation for a given depth.
 ///
@@ -215,6 +269,72 @@ mod tests {
     fn test_markers() {
         assert_eq!(MARKER_CURRENT, "◉");
         assert_eq!(MARKER_OTHER, "◯");
+        assert_eq!(MARKER_STALE, "◍");
+        assert_eq!(branch_marker(true, true), MARKER_CURRENT);
+        assert_eq!(branch_marker(false, true), MARKER_STALE);
+        assert_eq!(branch_marker(false, false), MARKER_OTHER);
+    }
+
+    #[test]
+    fn test_needs_restack_flags_descendant_when_parent_advances() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::from_path(dir.path())?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        // main -> parent -> child, all up to date
+        gateway.create_branch("parent")?;
+        ref_store.set_parent("parent", "main")?;
+        gateway.create_branch("child")?;
+        ref_store.set_parent("child", "parent")?;
+        assert!(!needs_restack(&ref_store, &gateway, "child"));
+
+        // Advance parent past the point child was built on
+        gateway.checkout_branch_worktree_safe("parent")?;
+        std::fs::write(dir.path().join("parent.txt"), "more")?;
+        gateway.stage_all()?;
+        gateway.commit("Advance parent")?;
+
+        assert!(needs_restack(&ref_store, &gateway, "child"));
+        assert!(!needs_restack(&ref_store, &gateway, "parent"));
+        assert!(!needs_restack(&ref_store, &gateway, "main"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_stack_tree_keeps_only_current_stack() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::from_path(dir.path())?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        // main -> a1 -> {a2, a3} and main -> b1
+        for (branch, parent) in [("a1", "main"), ("a2", "a1"), ("a3", "a1"), ("b1", "main")] {
+            gateway.create_branch(branch)?;
+            ref_store.set_parent(branch, parent)?;
+        }
+
+        let names = |rows: Vec<BranchDisplay>| rows.into_iter().map(|r| r.name).collect::<Vec<_>>();
+
+        // From inside stack a: the whole stack (including the sibling) and trunk, in tree order
+        let rows = build_stack_tree(&ref_store, "a2", &gateway)?;
+        assert_eq!(names(rows.clone()), vec!["a3", "a2", "a1", "main"]);
+        assert!(rows.iter().find(|r| r.name == "a2").unwrap().is_current);
+        assert_eq!(rows.iter().find(|r| r.name == "a1").unwrap().depth, 1);
+
+        assert_eq!(names(build_stack_tree(&ref_store, "b1", &gateway)?), vec!["b1", "main"]);
+
+        // Trunk is not in one stack, so everything shows
+        assert_eq!(build_stack_tree(&ref_store, "main", &gateway)?.len(), 5);
+
+        Ok(())
     }
 
     #[test]
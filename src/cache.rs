@@ -1,6 +1,6 @@
 //! Cache for non-critical metadata that can be regenerated.
 //!
-//! Stores PR URLs, base SHAs, and sync state in `.git/diamond/cache.json`.
+//! Stores PR URLs, PR status, base SHAs, and sync state in `.git/diamond/cache.json`.
 //! This data can be regenerated from the forge API or recomputed if lost.
 
 use anyhow::{Context, Result};
@@ -11,6 +11,7 @@ use std::fs::{self, File};
 use std::io::{BufReader, BufWriter};
 use std::path::PathBuf;
 
+use crate::forge::{CiStatus, PrFullInfo, PrInfo, PrState, ReviewState};
 use crate::program_name::program_name;
 use crate::state::find_git_root;
 
@@ -65,6 +66,175 @@ pub struct Cache {
     /// Commit SHA of trunk at last sync (merged from SyncState)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trunk_sha_at_sync: Option<String>,
+
+    /// Branches whose last `submit --stack` failed, for `submit --retry-failed`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_submits: Vec<String>,
+
+    /// PR status as last fetched from the forge, to spare repeated lookups
+    #[serde(default, skip_serializing_if = "PrStatusCache::is_empty")]
+    pub pr_status: PrStatusCache,
+}
+
+/// A branch's PR as last fetched from the forge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrStatusEntry {
+    pub number: u64,
+    pub url: String,
+    pub title: String,
+    pub state: PrState,
+    #[serde(default)]
+    pub is_draft: bool,
+    /// `None` when only the PR's existence was checked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review: Option<ReviewState>,
+    /// `None` when only the PR's existence was checked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ci: Option<CiStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mergeable: Option<bool>,
+    pub head_ref: String,
+    pub base_ref: String,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// PR status keyed by branch name
+///
+/// Entries older than the TTL are treated as missing. Only existing PRs are
+/// cached, so a branch whose PR was just created is always looked up.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct PrStatusCache {
+    entries: HashMap<String, PrStatusEntry>,
+    /// Whether this copy changed since it was loaded (see `Cache::save_to`)
+    #[serde(skip)]
+    modified: bool,
+    /// Whether this copy was cleared since it was loaded, which also drops
+    /// entries other commands saved in the meantime (see `Cache::save_to`)
+    #[serde(skip)]
+    cleared: bool,
+}
+
+impl PrStatusCache {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Entries sorted by branch name
+    pub fn entries(&self) -> Vec<(&String, &PrStatusEntry)> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by(|a, b| a.0.cmp(b.0));
+        entries
+    }
+
+    /// The cached entry for `branch` if it was fetched less than `ttl_secs` ago
+    fn fresh(&self, branch: &str, ttl_secs: u64) -> Option<&PrStatusEntry> {
+        let entry = self.entries.get(branch)?;
+        let age = Utc::now().signed_duration_since(entry.fetched_at).num_seconds();
+        u64::try_from(age).is_ok_and(|age| age < ttl_secs).then_some(entry)
+    }
+
+    pub fn contains(&self, branch: &str) -> bool {
+        self.entries.contains_key(branch)
+    }
+
+    /// Cached PR existence info, as `check_prs_exist` would return it
+    pub fn fresh_info(&self, branch: &str, ttl_secs: u64) -> Option<PrInfo> {
+        self.fresh(branch, ttl_secs).map(|entry| PrInfo {
+            number: entry.number,
+            url: entry.url.clone(),
+            head_ref: entry.head_ref.clone(),
+            base_ref: entry.base_ref.clone(),
+            state: entry.state,
+            title: entry.title.clone(),
+        })
+    }
+
+    /// Cached full PR info; `None` if review and CI status were never fetched
+    pub fn fresh_full_info(&self, branch: &str, ttl_secs: u64) -> Option<PrFullInfo> {
+        let entry = self.fresh(branch, ttl_secs)?;
+        Some(PrFullInfo {
+            number: entry.number,
+            url: entry.url.clone(),
+            title: entry.title.clone(),
+            state: entry.state,
+            is_draft: entry.is_draft,
+            review: entry.review?,
+            ci: entry.ci?,
+            head_ref: entry.head_ref.clone(),
+            base_ref: entry.base_ref.clone(),
+            mergeable: entry.mergeable,
+        })
+    }
+
+    /// Record a PR existence check
+    ///
+    /// Keeps review/CI status from an earlier full fetch of the same PR.
+    pub fn record_info(&mut self, branch: &str, info: &PrInfo) {
+        let previous = self.entries.remove(branch).filter(|entry| entry.number == info.number);
+        self.modified = true;
+        self.entries.insert(
+            branch.to_string(),
+            PrStatusEntry {
+                number: info.number,
+                url: info.url.clone(),
+                title: info.title.clone(),
+                state: info.state,
+                is_draft: previous.as_ref().is_some_and(|entry| entry.is_draft),
+                review: previous.as_ref().and_then(|entry| entry.review),
+                ci: previous.as_ref().and_then(|entry| entry.ci),
+                mergeable: previous.as_ref().and_then(|entry| entry.mergeable),
+                head_ref: info.head_ref.clone(),
+                base_ref: info.base_ref.clone(),
+                fetched_at: Utc::now(),
+            },
+        );
+    }
+
+    /// Record a full PR fetch
+    pub fn record_full_info(&mut self, branch: &str, info: &PrFullInfo) {
+        self.modified = true;
+        self.entries.insert(
+            branch.to_string(),
+            PrStatusEntry {
+                number: info.number,
+                url: info.url.clone(),
+                title: info.title.clone(),
+                state: info.state,
+                is_draft: info.is_draft,
+                review: Some(info.review),
+                ci: Some(info.ci),
+                mergeable: info.mergeable,
+                head_ref: info.head_ref.clone(),
+                base_ref: info.base_ref.clone(),
+                fetched_at: Utc::now(),
+            },
+        );
+    }
+
+    /// Forget the cached status of one branch
+    pub fn remove(&mut self, branch: &str) {
+        if self.entries.remove(branch).is_some() {
+            self.modified = true;
+        }
+    }
+
+    /// Forget all cached PR status, including what is on disk when saved
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.cleared = true;
+    }
+
+    fn rename(&mut self, old_name: &str, new_name: &str) {
+        if let Some(entry) = self.entries.remove(old_name) {
+            self.entries.insert(new_name.to_string(), entry);
+            self.modified = true;
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -103,7 +273,17 @@ impl Cache {
     ///
     /// Uses atomic write (write to temp file, then rename) to prevent
     /// data corruption if the write is interrupted.
+    ///
+    /// PR status is refreshed by the forge batch methods while commands hold
+    /// their own copy of the cache, so it is only written if this copy
+    /// changed or cleared it; otherwise what is on disk is kept.
     pub fn save_to(&self, path: &PathBuf) -> Result<()> {
+        let mut cache = self.clone();
+        let pr_status_changed = self.pr_status.modified || self.pr_status.cleared;
+        if !pr_status_changed && path.exists() {
+            cache.pr_status = Self::load_from(path).map(|c| c.pr_status).unwrap_or_default();
+        }
+
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent).context("Failed to create cache directory")?;
@@ -115,7 +295,7 @@ impl Cache {
         let file = File::create(&temp_path).context("Failed to create temporary cache file")?;
         let writer = BufWriter::new(file);
 
-        serde_json::to_writer_pretty(writer, self).context("Failed to write cache file")?;
+        serde_json::to_writer_pretty(writer, &cache).context("Failed to write cache file")?;
 
         // Atomic rename (on POSIX systems)
         fs::rename(&temp_path, path).context("Failed to finalize cache file")?;
@@ -124,7 +304,7 @@ impl Cache {
     }
 
     /// Get the cache file path
-    fn cache_path() -> Result<PathBuf> {
+    pub fn cache_path() -> Result<PathBuf> {
         let root = find_git_root()?;
         Ok(root.join(".git").join("diamond").join(CACHE_FILE))
     }
@@ -163,6 +343,7 @@ impl Cache {
     pub fn remove_branch(&mut self, branch: &str) {
         self.pr_urls.remove(branch);
         self.base_shas.remove(branch);
+        self.pr_status.remove(branch);
     }
 
     /// Check if the cache has any data for a branch
@@ -174,6 +355,19 @@ impl Cache {
     pub fn clear(&mut self) {
         self.pr_urls.clear();
         self.base_shas.clear();
+        self.pr_status.clear();
+    }
+
+    /// Forget all cached PR URLs and PR status
+    pub fn clear_prs(&mut self) {
+        self.pr_urls.clear();
+        self.pr_status.clear();
+    }
+
+    /// Forget when the last sync happened, as if the repo had never been synced
+    pub fn clear_sync(&mut self) {
+        self.last_sync_at = None;
+        self.trunk_sha_at_sync = None;
     }
 
     /// Rename a branch in the cache
@@ -184,6 +378,17 @@ impl Cache {
         if let Some(sha) = self.base_shas.remove(old_name) {
             self.base_shas.insert(new_name.to_string(), sha);
         }
+        self.pr_status.rename(old_name, new_name);
+    }
+
+    /// Replace the set of branches whose submit failed
+    pub fn set_failed_submits(&mut self, branches: Vec<String>) {
+        self.failed_submits = branches;
+    }
+
+    /// Branches whose last submit failed
+    pub fn failed_submits(&self) -> &[String] {
+        &self.failed_submits
     }
 
     // =========================================================================
@@ -434,6 +639,77 @@ mod tests {
         Ok(())
     }
 
+    fn sample_pr_info(number: u64) -> PrInfo {
+        PrInfo {
+            number,
+            url: format!("https://github.com/org/repo/pull/{}", number),
+            head_ref: "feature".to_string(),
+            base_ref: "main".to_string(),
+            state: PrState::Open,
+            title: "Feature".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_pr_status_expires_after_ttl() {
+        let mut cache = Cache::default();
+        cache.pr_status.record_info("feature", &sample_pr_info(42));
+
+        assert_eq!(cache.pr_status.fresh_info("feature", 60).map(|pr| pr.number), Some(42));
+        assert!(cache.pr_status.fresh_info("feature", 0).is_none());
+        // Existence checks carry no review/CI status
+        assert!(cache.pr_status.fresh_full_info("feature", 60).is_none());
+
+        cache.pr_status.entries.get_mut("feature").unwrap().fetched_at = Utc::now() - Duration::seconds(120);
+        assert!(cache.pr_status.fresh_info("feature", 60).is_none());
+        assert!(cache.pr_status.fresh_info("feature", 300).is_some());
+    }
+
+    #[test]
+    fn test_save_keeps_pr_status_written_by_others() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("cache.json");
+
+        // A command loads the cache, then a batch lookup records PR status
+        let mut held = Cache::load_from(&path)?;
+        let mut other = Cache::load_from(&path)?;
+        other.pr_status.record_info("feature", &sample_pr_info(42));
+        other.save_to(&path)?;
+
+        // Saving the older copy doesn't roll the PR status back
+        held.set_base_sha("feature", "abc123");
+        held.save_to(&path)?;
+        let loaded = Cache::load_from(&path)?;
+        assert!(loaded.pr_status.contains("feature"));
+        assert_eq!(loaded.get_base_sha("feature"), Some("abc123"));
+
+        // ...unless that copy changed it
+        let mut held = Cache::load_from(&path)?;
+        held.remove_branch("feature");
+        held.save_to(&path)?;
+        assert!(Cache::load_from(&path)?.pr_status.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear_drops_pr_status_saved_after_load() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("cache.json");
+
+        // Loaded before any PR status was recorded, so this copy holds none
+        let mut held = Cache::load_from(&path)?;
+        let mut other = Cache::load_from(&path)?;
+        other.pr_status.record_info("feature", &sample_pr_info(42));
+        other.save_to(&path)?;
+
+        held.clear();
+        held.save_to(&path)?;
+        assert!(Cache::load_from(&path)?.pr_status.is_empty());
+
+        Ok(())
+    }
+
     // =========================================================================
     // Cache Corruption Recovery Tests
     // =========================================================================
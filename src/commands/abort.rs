@@ -17,13 +17,27 @@ pub fn run() -> Result<()> {
 
     let state = state.unwrap();
 
-    // Abort any git rebase in progress
+    if state.autostash_conflicted {
+        return crate::commands::sync::abort_autostash_conflict();
+    }
+
+    // Abort any git rebase or merge in progress
     if gateway.rebase_in_progress()? {
         gateway.rebase_abort()?;
     }
+    if gateway.merge_in_progress()? {
+        gateway.merge_abort()?;
+    }
 
-    // For sync/restack operations, restore all branches from backups
-    if state.operation_type == OperationType::Sync || state.operation_type == OperationType::Restack {
+    // For sync/restack operations, restore all branches from backups. An insert
+    // restacks the displaced child and its descendants the same way (state files
+    // from before inserts took backups have no all_branches and nothing to restore).
+    let restores_backups = match state.operation_type {
+        OperationType::Sync | OperationType::Restack => true,
+        OperationType::Insert => !state.all_branches.is_empty(),
+        OperationType::Move => false,
+    };
+    if restores_backups {
         restore_branches_from_backups(&gateway, &state)?;
     }
 
@@ -37,7 +51,9 @@ pub fn run() -> Result<()> {
 
     // For insert operations, revert the child's parent back to original
     if state.operation_type == OperationType::Insert {
-        if let (Some(child), Some(original_parent)) = (state.current_branch.as_ref(), state.old_parent.as_ref()) {
+        // The child is first in all_branches; older state files only recorded it as current_branch
+        let child = state.all_branches.first().or(state.current_branch.as_ref());
+        if let (Some(child), Some(original_parent)) = (child, state.old_parent.as_ref()) {
             let ref_store = RefStore::new()?;
             ref_store.set_parent(child, original_parent)?;
             println!(
@@ -55,6 +71,10 @@ pub fn run() -> Result<()> {
     // Clear operation state
     OperationState::clear()?;
 
+    if state.autostash {
+        crate::commands::sync::restore_autostash(&gateway, &state.original_branch)?;
+    }
+
     // Capitalize the operation type for display
     let op_name = state.operation_type.to_string();
     let op_name_cap = op_name[..1].to_uppercase() + &op_name[1..];
@@ -10,7 +10,8 @@ use crate::git_gateway::GitGateway;
 ///
 /// This command uses git-absorb to automatically find which earlier commits
 /// should receive the currently staged changes, and amends them accordingly.
-/// If `all` is true, stage all changes before absorbing.
+/// If `all` is true, stage all changes before absorbing. Otherwise only the
+/// index is absorbed; unstaged changes stay in the working tree untouched.
 /// If `force` is true, skip confirmation prompts (not currently used, but reserved).
 pub fn run(all: bool, _force: bool) -> Result<()> {
     let dry_run = ExecutionContext::is_dry_run();
@@ -28,6 +29,18 @@ pub fn run(all: bool, _force: bool) -> Result<()> {
         return Ok(());
     }
 
+    // Without -a, unstaged edits are deliberately left out of the absorb
+    if !all {
+        let unstaged = gateway.get_unstaged_files()?;
+        if !unstaged.is_empty() {
+            println!(
+                "{} Leaving {} file(s) with unstaged changes untouched (use -a to include them)",
+                "ℹ".blue(),
+                unstaged.len()
+            );
+        }
+    }
+
     let current_branch = gateway.get_current_branch_name()?;
 
     if dry_run {
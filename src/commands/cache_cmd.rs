@@ -0,0 +1,224 @@
+//! Cache inspection and invalidation commands.
+//!
+//! Everything in `.git/diamond/cache.json` can be regenerated, so these are
+//! debugging aids: `inspect` shows what Diamond currently believes, `clear`
+//! throws away the parts that look wrong.
+
+use crate::cache::Cache;
+use crate::platform::DisplayPath;
+use anyhow::Result;
+use colored::Colorize;
+use std::collections::HashMap;
+
+/// Which parts of the cache `clear` removes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClearScope {
+    /// Cached PR URLs and PR status
+    Prs,
+    /// Last sync time and the trunk commit it synced to
+    Sync,
+    /// The whole cache
+    All,
+}
+
+/// Pretty-print the parsed cache
+pub fn inspect() -> Result<()> {
+    let path = Cache::cache_path()?;
+    let cache = Cache::load_from(&path)?;
+
+    let status = if path.exists() {
+        "exists".green()
+    } else {
+        "not found".dimmed()
+    };
+    println!("{} {} ({})", "Cache:".bold(), DisplayPath(&path), status);
+    println!();
+    for line in inspect_lines(&cache) {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+/// The cache contents, one section per field, with timestamps shown as relative times
+fn inspect_lines(cache: &Cache) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    let last_sync = match cache.last_sync_at {
+        Some(at) => format!("{} ({})", cache.sync_age_string(), at.format("%Y-%m-%d %H:%M:%S UTC")),
+        None => "never".dimmed().to_string(),
+    };
+    lines.push(format!("last_sync_at:      {}", last_sync));
+    let trunk_sha = match cache.trunk_sha_at_sync {
+        Some(ref sha) => sha.cyan().to_string(),
+        None => "(not set)".dimmed().to_string(),
+    };
+    lines.push(format!("trunk_sha_at_sync: {}", trunk_sha));
+
+    push_map_section(&mut lines, "pr_urls", &cache.pr_urls);
+
+    lines.push(format!("pr_status ({}):", cache.pr_status.len()));
+    let entries = cache.pr_status.entries();
+    let width = entries.iter().map(|(branch, _)| branch.len()).max().unwrap_or(0);
+    for (branch, entry) in entries {
+        let details = match (entry.review, entry.ci) {
+            (Some(review), Some(ci)) => format!(", review {}, ci {}", review, ci),
+            _ => String::new(),
+        };
+        lines.push(format!(
+            "  {:width$}  #{} {}{} (fetched {})",
+            branch,
+            entry.number,
+            entry.state,
+            details,
+            entry.fetched_at.format("%Y-%m-%d %H:%M:%S UTC"),
+            width = width
+        ));
+    }
+
+    push_map_section(&mut lines, "base_shas", &cache.base_shas);
+
+    lines.push(format!("failed_submits ({}):", cache.failed_submits().len()));
+    for branch in cache.failed_submits() {
+        lines.push(format!("  {}", branch.yellow()));
+    }
+
+    lines
+}
+
+/// A `name (count):` header followed by the entries sorted by branch
+fn push_map_section(lines: &mut Vec<String>, name: &str, map: &HashMap<String, String>) {
+    lines.push(format!("{} ({}):", name, map.len()));
+
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort();
+    let width = entries.iter().map(|(branch, _)| branch.len()).max().unwrap_or(0);
+    for (branch, value) in entries {
+        lines.push(format!("  {:width$}  {}", branch, value, width = width));
+    }
+}
+
+/// Remove the selected parts of the cache
+pub fn clear(scope: ClearScope) -> Result<()> {
+    let mut cache = Cache::load()?;
+
+    let what = match scope {
+        ClearScope::Prs => {
+            cache.clear_prs();
+            "cached PR URLs and status"
+        }
+        ClearScope::Sync => {
+            cache.clear_sync();
+            "sync state"
+        }
+        ClearScope::All => {
+            cache.clear();
+            cache.clear_sync();
+            cache.failed_submits.clear();
+            "the whole cache"
+        }
+    };
+    cache.save()?;
+
+    println!("{} Cleared {}", "✓".green().bold(), what);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::sync::SyncOptions;
+    use crate::git_gateway::Strategy;
+    use crate::ref_store::RefStore;
+    use crate::test_context::{init_test_repo, TestRepoContext};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn test_inspect_reports_sync_and_clear_sync_keeps_other_sections() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let main_commit = repo.head()?.peel_to_commit()?;
+        repo.branch("feature", &main_commit, false)?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feature", "main")?;
+
+        let mut cache = Cache::load()?;
+        cache.set_pr_url("feature", "https://github.com/test/repo/pull/7");
+        cache.save()?;
+
+        crate::commands::sync::run(SyncOptions {
+            no_cleanup: true,
+            restack: true,
+            strategy: Some(Strategy::Rebase),
+            no_tags: true,
+            ..Default::default()
+        })
+        .await?;
+
+        let cache = Cache::load()?;
+        assert!(cache.last_sync_at.is_some(), "sync should record last_sync_at");
+        let lines = inspect_lines(&cache);
+        assert!(
+            lines[0].starts_with("last_sync_at:") && lines[0].contains("just now"),
+            "got: {:?}",
+            lines
+        );
+        assert!(lines.iter().any(|l| l.contains("pull/7")), "got: {:?}", lines);
+
+        clear(ClearScope::Sync)?;
+
+        let cache = Cache::load()?;
+        assert!(cache.last_sync_at.is_none());
+        assert!(cache.trunk_sha_at_sync.is_none());
+        assert_eq!(cache.get_pr_url("feature"), Some("https://github.com/test/repo/pull/7"));
+        assert!(inspect_lines(&cache)[0].contains("never"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear_prs_and_all() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let mut cache = Cache::load()?;
+        cache.set_pr_url("feature", "https://github.com/test/repo/pull/7");
+        cache.set_base_sha("feature", "abc123");
+        cache.record_sync(Some("def456".to_string()));
+        let pr = crate::forge::PrInfo {
+            number: 7,
+            url: "https://github.com/test/repo/pull/7".to_string(),
+            head_ref: "feature".to_string(),
+            base_ref: "main".to_string(),
+            state: crate::forge::PrState::Open,
+            title: "Feature".to_string(),
+        };
+        cache.pr_status.record_info("feature", &pr);
+        cache.save()?;
+        assert!(inspect_lines(&Cache::load()?).iter().any(|l| l.contains("#7 open")));
+
+        clear(ClearScope::Prs)?;
+        let cache = Cache::load()?;
+        assert!(cache.pr_urls.is_empty());
+        assert!(cache.pr_status.is_empty());
+        assert_eq!(cache.get_base_sha("feature"), Some("abc123"));
+        assert!(cache.last_sync_at.is_some());
+
+        let mut cache = Cache::load()?;
+        cache.pr_status.record_info("feature", &pr);
+        cache.save()?;
+
+        clear(ClearScope::All)?;
+        let cache = Cache::load()?;
+        assert!(cache.base_shas.is_empty());
+        assert!(cache.pr_status.is_empty());
+        assert!(cache.last_sync_at.is_none());
+
+        Ok(())
+    }
+}
@@ -1,8 +1,17 @@
-use crate::branch_tree::{build_branch_tree, find_current_branch_index, format_indent, MARKER_CURRENT, MARKER_OTHER};
+use crate::branch_tree::{
+    build_branch_tree, build_stack_tree, find_current_branch_index, format_indent, BranchDisplay, MARKER_CURRENT,
+    MARKER_OTHER,
+};
+use crate::commands::track::infer_parent_candidates;
+use crate::config::{CheckoutSort, Config};
+use crate::forge::{get_async_forge, get_forge, AsyncForge, Forge};
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
-use anyhow::Result;
+use crate::stack_viz::print_breadcrumb;
+use crate::ui;
+use anyhow::{Context, Result};
+use colored::Colorize;
 use ratatui::{
     backend::CrosstermBackend,
     crossterm::{
@@ -16,22 +25,27 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Terminal,
 };
+use std::collections::HashMap;
 use std::io;
 
 /// Checkout a branch
 ///
 /// Flags:
 /// - `name`: Specific branch name to checkout
-/// - `trunk`: Go directly to trunk branch
-/// - `stack`: Show only current stack branches (TUI mode)
+/// - `trunk`: Go directly to trunk branch; `name`, if given, must match a configured trunk
+/// - `stack`: Show only the current stack's branches (and trunk) in the TUI
 /// - `all`: Show all trunks in selection (TUI mode)
 /// - `untracked`: Include untracked branches (TUI mode)
+/// - `track_on_checkout`: Track the branch if it is untracked (also enabled by `checkout.auto_track`)
+/// - `force`: Discard uncommitted changes that would otherwise block the checkout
 pub fn run(
     name: Option<String>,
     trunk: bool,
-    _stack: bool,     // TODO: implement stack filter for TUI
-    _all: bool,       // TODO: implement all-trunks filter for TUI
-    _untracked: bool, // TODO: implement untracked filter for TUI
+    stack: bool,
+    _all: bool, // TODO: implement all-trunks filter for TUI
+    untracked: bool,
+    track_on_checkout: bool,
+    force: bool,
 ) -> Result<()> {
     // Silent cleanup of orphaned refs before checkout
     let gateway = GitGateway::new()?;
@@ -40,25 +54,32 @@ pub fn run(
     }
 
     let ref_store = RefStore::new()?;
+    let config = Config::load().ok();
+    let auto_track = track_on_checkout || config.as_ref().is_some_and(|c| c.checkout.auto_track_enabled());
 
     // --trunk flag: go directly to trunk
     if trunk {
-        let trunk_branch = ref_store.require_trunk()?;
-        gateway.checkout_branch_worktree_safe(&trunk_branch)?;
+        let trunk_branch = resolve_trunk(&ref_store, name.as_deref())?;
+        gateway.checkout_branch_guarded(&trunk_branch, force)?;
         println!("Checked out trunk '{}'", trunk_branch);
+        print_breadcrumb(&ref_store, &trunk_branch);
         return Ok(());
     }
 
     // Non-interactive mode with explicit branch name
     if let Some(target) = name {
-        // Use safe checkout that respects uncommitted changes AND worktree conflicts
-        gateway.checkout_branch_worktree_safe(&target)?;
+        // Refuses if uncommitted changes would be overwritten or the branch is in another worktree
+        gateway.checkout_branch_guarded(&target, force)?;
 
         // Fetch diamond ref for this branch from remote (best effort)
         // This enables collaboration - we get the parent relationship from remote
         let _ = gateway.fetch_diamond_ref_for_branch(&target);
 
         println!("Checked out '{}'", target);
+        if auto_track {
+            track_if_untracked(&gateway, &ref_store, &target)?;
+        }
+        print_breadcrumb(&ref_store, &target);
         return Ok(());
     }
 
@@ -72,21 +93,181 @@ pub fn run(
 
     // Interactive TUI mode
     let current_branch = gateway.get_current_branch_name().unwrap_or_default();
-    let selected = run_tui(&ref_store, &current_branch, &gateway)?;
+    let sort = config.and_then(|c| c.checkout.sort).unwrap_or_default();
+    let selected = run_tui(&ref_store, &current_branch, &gateway, stack, untracked, sort)?;
 
     if let Some(target) = selected {
         println!("Selected: {}", target);
-        // Use safe checkout that respects uncommitted changes AND worktree conflicts
-        gateway.checkout_branch_worktree_safe(&target)?;
+        // Refuses if uncommitted changes would be overwritten or the branch is in another worktree
+        gateway.checkout_branch_guarded(&target, force)?;
 
         // Fetch diamond ref for this branch from remote (best effort)
         let _ = gateway.fetch_diamond_ref_for_branch(&target);
+
+        if auto_track {
+            track_if_untracked(&gateway, &ref_store, &target)?;
+        }
+        print_breadcrumb(&ref_store, &target);
+    }
+
+    Ok(())
+}
+
+/// Resolve `--trunk [NAME]` to a trunk branch
+///
+/// Without a name this is the configured trunk; a name must match it.
+fn resolve_trunk(ref_store: &RefStore, requested: Option<&str>) -> Result<String> {
+    let trunk = ref_store.require_trunk()?;
+    match requested {
+        Some(name) if name != trunk => {
+            anyhow::bail!("'{}' is not a trunk. The configured trunk is '{}'", name, trunk)
+        }
+        _ => Ok(trunk),
     }
+}
 
+/// Checkout the head branch of a PR by number
+///
+/// Unlike `get`, only the PR's own branch is fetched and checked out.
+pub fn run_pr(number: u64, force: bool) -> Result<()> {
+    let gateway = GitGateway::new()?;
+    let ref_store = RefStore::new()?;
+    let forge = get_forge(None)?;
+    checkout_pr(&gateway, &ref_store, forge.as_ref(), number, force)?;
     Ok(())
 }
 
-fn run_tui(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway) -> Result<Option<String>> {
+/// Checkout the next upstack branch (DFS from the current one) that has no PR yet
+pub async fn run_next_unsubmitted(force: bool) -> Result<()> {
+    let gateway = GitGateway::new()?;
+    let ref_store = RefStore::new()?;
+    let forge = get_async_forge(None)?;
+    let current = gateway.get_current_branch_name()?;
+
+    let Some(target) = next_unsubmitted(&ref_store, &current, forge.as_ref()).await? else {
+        println!(
+            "{} All submitted: every branch upstack of '{}' has a PR",
+            "✓".green().bold(),
+            current
+        );
+        return Ok(());
+    };
+
+    gateway.checkout_branch_guarded(&target, force)?;
+    println!("Checked out '{}' (no PR yet)", target);
+    print_breadcrumb(&ref_store, &target);
+    Ok(())
+}
+
+/// First branch upstack of `current`, in DFS order, without a PR
+///
+/// PR existence is checked for the whole upstack in one batch. A failed lookup
+/// is an error: a branch whose PR couldn't be checked isn't known to lack one.
+async fn next_unsubmitted(ref_store: &RefStore, current: &str, forge: &dyn AsyncForge) -> Result<Option<String>> {
+    let upstack = ref_store.descendants(current)?;
+    if upstack.is_empty() {
+        return Ok(None);
+    }
+
+    let prs: HashMap<String, _> = forge
+        .try_check_prs_exist(&upstack)
+        .await
+        .context("Failed to check which branches have PRs")?
+        .into_iter()
+        .collect();
+    Ok(upstack
+        .into_iter()
+        .find(|branch| prs.get(branch).is_none_or(|pr| pr.is_none())))
+}
+
+/// Resolve PR `number` to its head branch, fetch it if it isn't local, check it
+/// out, and track it with the PR base as parent. Returns the branch name.
+fn checkout_pr(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    forge: &dyn Forge,
+    number: u64,
+    force: bool,
+) -> Result<String> {
+    let pr = forge
+        .get_pr_info(&number.to_string())
+        .context(format!("Failed to look up PR #{}", number))?;
+    let branch = pr.head_ref;
+
+    if !gateway.branch_exists(&branch)? {
+        println!(
+            "{} Fetching '{}' from {}...",
+            "→".blue(),
+            branch.green(),
+            gateway.remote()
+        );
+        gateway.fetch_origin()?;
+        let remote_branch = format!("refs/remotes/{}/{}", gateway.remote(), branch);
+        gateway.create_branch_at_ref(&branch, &remote_branch).context(format!(
+            "PR #{} head branch '{}' was not found on {}",
+            number,
+            branch,
+            gateway.remote()
+        ))?;
+    }
+
+    gateway.checkout_branch_guarded(&branch, force)?;
+    let _ = gateway.fetch_diamond_ref_for_branch(&branch);
+    println!("Checked out '{}' (PR #{})", branch, number);
+
+    if let Some(trunk) = ref_store.get_trunk()? {
+        if branch != trunk && !ref_store.is_tracked(&branch)? {
+            // The PR base may be another branch of a stack we haven't fetched
+            let parent = if gateway.branch_exists(&pr.base_ref)? {
+                pr.base_ref
+            } else {
+                trunk
+            };
+            ref_store.set_parent(&branch, &parent)?;
+            println!("Now tracking '{}' with parent '{}'", branch, parent);
+        }
+    }
+
+    Ok(branch)
+}
+
+/// Track `branch` if it isn't tracked yet, inferring its parent.
+///
+/// The parent is the closest tracked branch (or trunk) in the branch's history.
+/// When several are equally close, asks in a TTY and otherwise prefers trunk.
+fn track_if_untracked(gateway: &GitGateway, ref_store: &RefStore, branch: &str) -> Result<()> {
+    let Some(trunk) = ref_store.get_trunk()? else {
+        return Ok(());
+    };
+    if branch == trunk || ref_store.is_tracked(branch)? {
+        return Ok(());
+    }
+
+    let candidates = infer_parent_candidates(gateway, ref_store, branch, &trunk)?;
+    let parent = match candidates.as_slice() {
+        [] => trunk,
+        [only] => only.clone(),
+        _ if std::io::IsTerminal::is_terminal(&std::io::stdin()) => {
+            let idx = ui::select(&format!("Select parent for '{}'", branch), &candidates)?;
+            candidates[idx].clone()
+        }
+        _ if candidates.contains(&trunk) => trunk,
+        _ => candidates[0].clone(),
+    };
+
+    ref_store.set_parent(branch, &parent)?;
+    println!("Now tracking '{}' with parent '{}'", branch, parent);
+    Ok(())
+}
+
+fn run_tui(
+    ref_store: &RefStore,
+    current_branch: &str,
+    gateway: &GitGateway,
+    stack: bool,
+    untracked: bool,
+    sort: CheckoutSort,
+) -> Result<Option<String>> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -94,7 +275,15 @@ fn run_tui(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway) ->
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let res = run_app(&mut terminal, ref_store, current_branch, gateway);
+    let res = run_app(
+        &mut terminal,
+        ref_store,
+        current_branch,
+        gateway,
+        stack,
+        untracked,
+        sort,
+    );
 
     // Restore terminal
     disable_raw_mode()?;
@@ -104,14 +293,122 @@ fn run_tui(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway) ->
     res
 }
 
+/// Order stack-ordered rows (as returned by `build_branch_tree`) for the checkout list
+///
+/// `last_commit` gives the tip commit time of a branch and is only consulted
+/// for `CheckoutSort::Recent`.
+fn order_checkout_rows(
+    rows: Vec<BranchDisplay>,
+    sort: CheckoutSort,
+    last_commit: impl Fn(&str) -> i64,
+) -> Vec<BranchDisplay> {
+    match sort {
+        CheckoutSort::Depth => rows,
+        CheckoutSort::Alpha => {
+            let mut rows = rows;
+            rows.sort_by(|a, b| a.name.cmp(&b.name));
+            for row in &mut rows {
+                row.depth = 0;
+            }
+            rows
+        }
+        CheckoutSort::Recent => {
+            // In stack order each stack is a contiguous run that ends with its
+            // base (the depth-1 child of trunk); trunk itself comes last
+            let mut stacks: Vec<Vec<BranchDisplay>> = Vec::new();
+            let mut stack = Vec::new();
+            let mut trunk = Vec::new();
+            for row in rows {
+                match row.depth {
+                    0 => trunk.push(row),
+                    1 => {
+                        stack.push(row);
+                        stacks.push(std::mem::take(&mut stack));
+                    }
+                    _ => stack.push(row),
+                }
+            }
+
+            // Stable sort: stacks committed to at the same time keep stack order
+            stacks.sort_by_key(|stack| {
+                std::cmp::Reverse(stack.iter().map(|row| last_commit(&row.name)).max().unwrap_or(i64::MIN))
+            });
+            stacks.into_iter().flatten().chain(trunk).collect()
+        }
+    }
+}
+
+/// Rows whose branch name contains `filter` (case-insensitive)
+fn filter_checkout_rows<'a>(rows: &'a [BranchDisplay], filter: &str) -> Vec<&'a BranchDisplay> {
+    let filter = filter.to_lowercase();
+    rows.iter()
+        .filter(|row| row.name.to_lowercase().contains(&filter))
+        .collect()
+}
+
+/// Display text for one entry of the checkout list (consistent with dm log)
+fn checkout_entry_label(branch: &BranchDisplay, is_untracked: bool) -> String {
+    let indent = format_indent(branch.depth);
+    let marker = if branch.is_current {
+        MARKER_CURRENT
+    } else {
+        MARKER_OTHER
+    };
+    let restack_indicator = if is_untracked {
+        " (untracked)"
+    } else if branch.needs_restack {
+        " (needs restack)"
+    } else {
+        ""
+    };
+
+    format!("{}{} {}{}", indent, marker, branch.name, restack_indicator)
+}
+
+/// Index of the current branch among the visible rows, or 0
+fn visible_current_index(visible: &[&BranchDisplay]) -> usize {
+    visible.iter().position(|b| b.is_current).unwrap_or(0)
+}
+
 fn run_app(
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
     ref_store: &RefStore,
     current_branch: &str,
     gateway: &GitGateway,
+    stack: bool,
+    untracked: bool,
+    sort: CheckoutSort,
 ) -> Result<Option<String>> {
     // Build tree view using shared branch_tree module (stack order: trunk at bottom)
-    let rows = build_branch_tree(ref_store, current_branch, gateway)?;
+    let rows = if stack {
+        build_stack_tree(ref_store, current_branch, gateway)?
+    } else {
+        build_branch_tree(ref_store, current_branch, gateway)?
+    };
+    let mut rows = order_checkout_rows(rows, sort, |name| {
+        gateway.get_commit_timestamp(name).unwrap_or_default()
+    });
+
+    // --untracked: list local branches Diamond doesn't know about below trunk
+    let mut untracked_names = std::collections::HashSet::new();
+    if untracked {
+        let trunk = ref_store.get_trunk()?;
+        let mut names = gateway.list_branches()?;
+        names.sort();
+        for name in names {
+            if trunk.as_deref() == Some(name.as_str()) || ref_store.is_tracked(&name)? {
+                continue;
+            }
+            rows.push(BranchDisplay {
+                commit_time: gateway.get_commit_time_relative(&name).unwrap_or_default(),
+                is_current: name == current_branch,
+                name: name.clone(),
+                depth: 0,
+                needs_restack: false,
+            });
+            untracked_names.insert(name);
+        }
+    }
 
     // Handle empty list
     if rows.is_empty() {
@@ -121,35 +418,35 @@ fn run_app(
         );
     }
 
+    // Inline filter: '/' starts typing, Esc clears it
+    let mut filter = String::new();
+    let mut filtering = false;
+
     let mut state = ListState::default();
     // Start with current branch selected (consistent with dm log)
     let current_idx = find_current_branch_index(&rows);
     state.select(Some(current_idx));
 
     loop {
+        let visible = filter_checkout_rows(&rows, &filter);
+
         terminal.draw(|f| {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
                 .split(f.area());
 
-            let items: Vec<ListItem> = rows
+            let items: Vec<ListItem> = visible
                 .iter()
                 .map(|branch| {
-                    // Build display line with tree indentation (consistent with dm log)
-                    let indent = format_indent(branch.depth);
-                    let marker = if branch.is_current {
-                        MARKER_CURRENT
-                    } else {
-                        MARKER_OTHER
-                    };
-                    let restack_indicator = if branch.needs_restack { " (needs restack)" } else { "" };
+                    let is_untracked = untracked_names.contains(&branch.name);
+                    let display = checkout_entry_label(branch, is_untracked);
 
-                    let display = format!("{}{} {}{}", indent, marker, branch.name, restack_indicator);
-
-                    // Style: current branch in green, needs restack in yellow
+                    // Style: current branch in green, needs restack in yellow, untracked dimmed
                     let style = if branch.is_current {
                         Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)
+                    } else if is_untracked {
+                        Style::default().fg(Color::DarkGray)
                     } else if branch.needs_restack {
                         Style::default().fg(Color::Yellow)
                     } else {
@@ -171,58 +468,76 @@ fn run_app(
                 .highlight_symbol("▶ ");
 
             f.render_stateful_widget(list, chunks[0], &mut state);
-            let help = Paragraph::new("Enter: Select | q: Quit | j/k: Navigate | g/G: Top/Bottom")
-                .block(Block::default().borders(Borders::ALL));
-            f.render_widget(help, chunks[1]);
+            let footer = if filtering || !filter.is_empty() {
+                Paragraph::new(Line::from(vec![
+                    Span::styled("Filter: ", Style::default().fg(Color::Cyan)),
+                    Span::raw(format!("{}{}", filter, if filtering { "▏" } else { "" })),
+                ]))
+            } else {
+                Paragraph::new("Enter: Select | q: Quit | j/k: Navigate | g/G: Top/Bottom | /: Filter")
+            };
+            f.render_widget(footer.block(Block::default().borders(Borders::ALL)), chunks[1]);
         })?;
 
         if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            // Typing into the filter box; arrows and Enter still work
+            if filtering {
                 match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
-                    KeyCode::Enter => {
-                        if let Some(i) = state.selected() {
-                            if i < rows.len() {
-                                return Ok(Some(rows[i].name.clone()));
-                            }
-                        }
-                    }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        let i = match state.selected() {
-                            Some(i) => {
-                                if i >= rows.len() - 1 {
-                                    0
-                                } else {
-                                    i + 1
-                                }
-                            }
-                            None => 0,
-                        };
-                        state.select(Some(i));
-                    }
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        let i = match state.selected() {
-                            Some(i) => {
-                                if i == 0 {
-                                    rows.len() - 1
-                                } else {
-                                    i - 1
-                                }
-                            }
-                            None => 0,
-                        };
-                        state.select(Some(i));
-                    }
-                    // Jump to top (consistent with dm log)
-                    KeyCode::Char('g') | KeyCode::Home => {
-                        state.select(Some(0));
+                    KeyCode::Esc => {
+                        filtering = false;
+                        filter.clear();
+                        state.select(Some(current_idx));
+                        continue;
                     }
-                    // Jump to bottom (consistent with dm log)
-                    KeyCode::Char('G') | KeyCode::End => {
-                        state.select(Some(rows.len().saturating_sub(1)));
+                    KeyCode::Backspace => {
+                        filter.pop();
                     }
+                    KeyCode::Char(c) => filter.push(c),
                     _ => {}
                 }
+                if matches!(key.code, KeyCode::Backspace | KeyCode::Char(_)) {
+                    let visible = filter_checkout_rows(&rows, &filter);
+                    state.select(Some(visible_current_index(&visible)));
+                    continue;
+                }
+            }
+
+            let len = visible.len();
+            match key.code {
+                KeyCode::Char('/') => filtering = true,
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+                KeyCode::Enter => {
+                    if let Some(branch) = state.selected().and_then(|i| visible.get(i)) {
+                        return Ok(Some(branch.name.clone()));
+                    }
+                }
+                KeyCode::Down | KeyCode::Char('j') if len > 0 => {
+                    let i = match state.selected() {
+                        Some(i) if i + 1 < len => i + 1,
+                        _ => 0,
+                    };
+                    state.select(Some(i));
+                }
+                KeyCode::Up | KeyCode::Char('k') if len > 0 => {
+                    let i = match state.selected() {
+                        Some(i) if i > 0 && i < len => i - 1,
+                        _ => len - 1,
+                    };
+                    state.select(Some(i));
+                }
+                // Jump to top (consistent with dm log)
+                KeyCode::Char('g') | KeyCode::Home => {
+                    state.select(Some(0));
+                }
+                // Jump to bottom (consistent with dm log)
+                KeyCode::Char('G') | KeyCode::End => {
+                    state.select(Some(len.saturating_sub(1)));
+                }
+                _ => {}
             }
         }
     }
@@ -254,13 +569,13 @@ mod tests {
         repo.branch("feature-2", &commit, false)?;
 
         // Checkout feature-1
-        run(Some("feature-1".to_string()), false, false, false, false)?;
+        run(Some("feature-1".to_string()), false, false, false, false, false, false)?;
 
         // Verify we're on feature-1
         assert_eq!(gateway.get_current_branch_name()?, "feature-1");
 
         // Checkout feature-2
-        run(Some("feature-2".to_string()), false, false, false, false)?;
+        run(Some("feature-2".to_string()), false, false, false, false, false, false)?;
 
         // Verify we're on feature-2
         assert_eq!(gateway.get_current_branch_name()?, "feature-2");
@@ -275,7 +590,15 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Try to checkout branch that doesn't exist
-        let result = run(Some("does-not-exist".to_string()), false, false, false, false);
+        let result = run(
+            Some("does-not-exist".to_string()),
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
         assert!(result.is_err());
 
         Ok(())
@@ -295,11 +618,11 @@ mod tests {
         repo.branch("feature", &commit, false)?;
 
         // Checkout once
-        run(Some("feature".to_string()), false, false, false, false)?;
+        run(Some("feature".to_string()), false, false, false, false, false, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "feature");
 
         // Checkout again - should work (idempotent)
-        run(Some("feature".to_string()), false, false, false, false)?;
+        run(Some("feature".to_string()), false, false, false, false, false, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "feature");
 
         Ok(())
@@ -312,7 +635,7 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Empty string should fail
-        let result = run(Some("".to_string()), false, false, false, false);
+        let result = run(Some("".to_string()), false, false, false, false, false, false);
         assert!(result.is_err());
 
         Ok(())
@@ -337,7 +660,7 @@ mod tests {
         gateway.checkout_branch_worktree_safe("feature")?;
 
         // Use --trunk flag to go back to trunk
-        run(None, true, false, false, false)?;
+        run(None, true, false, false, false, false, false)?;
 
         // Verify we're on trunk
         assert_eq!(gateway.get_current_branch_name()?, "main");
@@ -345,6 +668,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_checkout_trunk_flag_with_name() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        let commit = repo.head()?.peel_to_commit()?;
+        repo.branch("release", &commit, false)?;
+        repo.branch("feature", &commit, false)?;
+        gateway.checkout_branch_worktree_safe("feature")?;
+
+        // A branch that isn't a trunk is rejected, and we stay put
+        let err = run(Some("release".to_string()), true, false, false, false, false, false).unwrap_err();
+        assert!(err.to_string().contains("'release' is not a trunk"), "got: {}", err);
+        assert_eq!(gateway.get_current_branch_name()?, "feature");
+
+        // Naming the trunk checks it out
+        run(Some("main".to_string()), true, false, false, false, false, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+
+        Ok(())
+    }
+
     #[test]
     fn test_checkout_trunk_flag_fails_if_not_initialized() -> Result<()> {
         let dir = tempdir()?;
@@ -354,7 +704,7 @@ mod tests {
         // Don't initialize Diamond (no trunk set)
 
         // Try --trunk - should fail
-        let result = run(None, true, false, false, false);
+        let result = run(None, true, false, false, false, false, false);
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -411,7 +761,7 @@ mod tests {
         std::fs::write(&file_path, "dirty content")?;
 
         // Try to checkout feature - should fail due to dirty tree
-        let result = run(Some("feature".to_string()), false, false, false, false);
+        let result = run(Some("feature".to_string()), false, false, false, false, false, false);
         assert!(result.is_err(), "Checkout should fail with dirty tree");
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -423,6 +773,98 @@ mod tests {
         Ok(())
     }
 
+    /// main has tracked.txt and notes.txt; feature changes only tracked.txt
+    fn setup_feature_changing_tracked_file(dir: &std::path::Path, gateway: &GitGateway) -> Result<()> {
+        std::fs::write(dir.join("tracked.txt"), "original content")?;
+        std::fs::write(dir.join("notes.txt"), "notes")?;
+        gateway.stage_all()?;
+        gateway.commit("Add files")?;
+
+        gateway.create_branch("feature")?;
+        std::fs::write(dir.join("tracked.txt"), "feature content")?;
+        gateway.stage_all()?;
+        gateway.commit("Change tracked file")?;
+
+        gateway.checkout_branch_worktree_safe("main")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_checkout_refuses_and_lists_conflicting_files() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        setup_feature_changing_tracked_file(dir.path(), &gateway)?;
+
+        std::fs::write(dir.path().join("tracked.txt"), "dirty content")?;
+        std::fs::write(dir.path().join("notes.txt"), "dirty notes")?;
+
+        let result = run(Some("feature".to_string()), false, false, false, false, false, false);
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("would be overwritten"), "{}", err_msg);
+        assert!(
+            err_msg.contains("tracked.txt"),
+            "Conflicting file should be listed: {}",
+            err_msg
+        );
+        assert!(
+            !err_msg.contains("notes.txt"),
+            "Non-conflicting file should not be listed: {}",
+            err_msg
+        );
+        assert!(err_msg.contains("--force"), "{}", err_msg);
+
+        // Nothing was touched
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("tracked.txt"))?,
+            "dirty content"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checkout_carries_non_conflicting_changes() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        setup_feature_changing_tracked_file(dir.path(), &gateway)?;
+
+        std::fs::write(dir.path().join("notes.txt"), "dirty notes")?;
+
+        run(Some("feature".to_string()), false, false, false, false, false, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "feature");
+        assert_eq!(std::fs::read_to_string(dir.path().join("notes.txt"))?, "dirty notes");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checkout_force_discards_conflicting_changes() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        setup_feature_changing_tracked_file(dir.path(), &gateway)?;
+
+        std::fs::write(dir.path().join("tracked.txt"), "dirty content")?;
+
+        run(Some("feature".to_string()), false, false, false, false, false, true)?;
+        assert_eq!(gateway.get_current_branch_name()?, "feature");
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("tracked.txt"))?,
+            "feature content"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_checkout_preserves_untracked_files() -> Result<()> {
         let dir = tempdir()?;
@@ -454,7 +896,7 @@ mod tests {
         assert!(untracked_file.exists(), "Untracked file should exist before checkout");
 
         // Checkout main - this should NOT delete the untracked file
-        run(Some("main".to_string()), false, false, false, false)?;
+        run(Some("main".to_string()), false, false, false, false, false, false)?;
 
         // Verify we're on main
         assert_eq!(gateway.get_current_branch_name()?, "main");
@@ -497,7 +939,15 @@ mod tests {
         // Create test context after changing directory
         let _ctx = TestRepoContext::new(&main_path);
 
-        let result = run(Some("locked-branch".to_string()), false, false, false, false);
+        let result = run(
+            Some("locked-branch".to_string()),
+            false,
+            false,
+            false,
+            false,
+            false,
+            false,
+        );
 
         // Should fail with a clear, informative error
         assert!(
@@ -539,4 +989,205 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_checkout_track_on_checkout_infers_parent() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        // Tracked feature with its own commit, untracked topic built on top of it
+        gateway.create_branch("feature")?;
+        std::fs::write(dir.path().join("feature.txt"), "feature")?;
+        gateway.stage_all()?;
+        gateway.commit("Feature commit")?;
+        ref_store.set_parent("feature", "main")?;
+        let head = repo.head()?.peel_to_commit()?;
+        repo.branch("topic", &head, false)?;
+        gateway.checkout_branch_worktree_safe("main")?;
+
+        run(Some("topic".to_string()), false, false, false, false, true, false)?;
+
+        assert_eq!(gateway.get_current_branch_name()?, "topic");
+        assert_eq!(ref_store.get_parent("topic")?, Some("feature".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checkout_untracked_branch_stays_untracked_by_default() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        let head = repo.head()?.peel_to_commit()?;
+        repo.branch("topic", &head, false)?;
+
+        run(Some("topic".to_string()), false, false, false, false, false, false)?;
+
+        assert!(!ref_store.is_tracked("topic")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checkout_pr_fetches_head_branch_and_tracks_base() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        // The PR branch only exists on the remote
+        let remote_dir = tempdir()?;
+        git2::Repository::init_bare(remote_dir.path())?;
+        repo.remote("origin", remote_dir.path().to_str().unwrap())?;
+        let head = repo.head()?.peel_to_commit()?;
+        repo.branch("review-me", &head, false)?;
+        repo.find_remote("origin")?
+            .push(&["refs/heads/review-me:refs/heads/review-me"], None)?;
+        repo.find_branch("review-me", git2::BranchType::Local)?.delete()?;
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("get_pr_info:42", MockResponse::Success("review-me:main".to_string()));
+
+        let branch = checkout_pr(&gateway, &ref_store, &forge, 42, false)?;
+
+        assert_eq!(branch, "review-me");
+        assert_eq!(forge.get_call_count("get_pr_info:42"), 1);
+        assert_eq!(gateway.get_current_branch_name()?, "review-me");
+        assert_eq!(ref_store.get_parent("review-me")?, Some("main".to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_next_unsubmitted_skips_branches_with_prs() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        // main -> first -> second
+        let commit = repo.head()?.peel_to_commit()?;
+        for (branch, parent) in [("first", "main"), ("second", "first")] {
+            repo.branch(branch, &commit, false)?;
+            ref_store.set_parent(branch, parent)?;
+        }
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:first", MockResponse::Success("1".to_string()));
+
+        let next = next_unsubmitted(&ref_store, "main", &forge).await?;
+        assert_eq!(next, Some("second".to_string()));
+        assert_eq!(forge.get_call_count("pr_exists:second"), 1);
+
+        // Once everything has a PR there is nothing left to land on
+        forge.set_response("pr_exists:second", MockResponse::Success("2".to_string()));
+        assert_eq!(next_unsubmitted(&ref_store, "main", &forge).await?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_next_unsubmitted_fails_when_pr_lookup_fails() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        let commit = repo.head()?.peel_to_commit()?;
+        repo.branch("first", &commit, false)?;
+        ref_store.set_parent("first", "main")?;
+
+        // An unreachable forge must not look like "no PR yet"
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response(
+            "pr_exists:first",
+            MockResponse::Error("API rate limit exceeded".to_string()),
+        );
+
+        let err = next_unsubmitted(&ref_store, "main", &forge).await.unwrap_err();
+        assert!(
+            format!("{:#}", err).contains("API rate limit exceeded"),
+            "got: {:#}",
+            err
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checkout_list_orders_forked_stacks() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        // Two stacks off main; the first forks after its base
+        let commit = repo.head()?.peel_to_commit()?;
+        for (branch, parent) in [("a1", "main"), ("a2", "a1"), ("a3", "a1"), ("b1", "main")] {
+            repo.branch(branch, &commit, false)?;
+            ref_store.set_parent(branch, parent)?;
+        }
+
+        let rows = build_branch_tree(&ref_store, "a2", &gateway)?;
+        let labels = |sort: CheckoutSort| -> Vec<String> {
+            // a3 has the newest commit, so its stack is the most recent
+            let times = |name: &str| if name == "a3" { 200 } else { 100 };
+            order_checkout_rows(rows.clone(), sort, times)
+                .iter()
+                .map(|b| checkout_entry_label(b, false))
+                .collect()
+        };
+
+        assert_eq!(
+            labels(CheckoutSort::Depth),
+            vec!["│ ◯ b1", "│ │ ◯ a3", "│ │ ◉ a2", "│ ◯ a1", "◯ main"]
+        );
+
+        // Stacks move as a whole; trunk stays at the bottom
+        assert_eq!(
+            labels(CheckoutSort::Recent),
+            vec!["│ │ ◯ a3", "│ │ ◉ a2", "│ ◯ a1", "│ ◯ b1", "◯ main"]
+        );
+
+        assert_eq!(
+            labels(CheckoutSort::Alpha),
+            vec!["◯ a1", "◉ a2", "◯ a3", "◯ b1", "◯ main"]
+        );
+
+        // Filtering keeps stack order and indentation
+        let filtered: Vec<String> = filter_checkout_rows(&rows, "A")
+            .iter()
+            .map(|b| checkout_entry_label(b, false))
+            .collect();
+        assert_eq!(filtered, vec!["│ │ ◯ a3", "│ │ ◉ a2", "│ ◯ a1", "◯ main"]);
+
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 
 use crate::cache::Cache;
-use crate::forge::{get_forge, AsyncForge, PrInfo, PrState};
+use crate::forge::{get_forge, invalidate_pr_status, AsyncForge, PrInfo, PrState};
 #[cfg(test)]
 use crate::forge::Forge;
 use crate::git_gateway::{GitGateway, RebaseOutcome};
@@ -173,6 +173,9 @@ pub fn find_merged_branches(gateway: &GitGateway, ref_store: &RefStore, trunk: &
 ///
 /// This version uses batch API calls for better performance with many branches.
 pub async fn find_merged_prs_async(forge: &dyn AsyncForge, branches: &[String]) -> Vec<(String, PrInfo)> {
+    // A PR merged since it was cached must not be missed
+    invalidate_pr_status(branches);
+
     // Batch check all PRs in parallel
     let pr_results = forge.check_prs_exist(branches).await;
 
@@ -1,14 +1,93 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::CommandFactory;
 use clap_complete::{generate, Shell};
+use colored::Colorize;
 use std::io;
+use std::path::Path;
+
+use crate::platform::{completion_install_path, DisplayPath};
 
 /// Generate shell completion script for the specified shell
-pub fn run(shell: Shell) -> Result<()> {
+///
+/// With `install`, the script is written to the shell's per-user completion
+/// location (see `platform::completion_install_path`) instead of stdout.
+pub fn run(shell: Shell, install: bool) -> Result<()> {
     let mut cmd = crate::Cli::command();
     let bin_name = cmd.get_name().to_string();
 
-    generate(shell, &mut cmd, bin_name, &mut io::stdout());
+    if !install {
+        generate(shell, &mut cmd, bin_name, &mut io::stdout());
+        return Ok(());
+    }
+
+    let home = dirs::home_dir().context("Could not determine home directory")?;
+    let path = completion_install_path(shell, &bin_name, &home, |var| std::env::var(var).ok())
+        .with_context(|| format!("--install is not supported for {}; redirect the output instead", shell))?;
+
+    let mut script = Vec::new();
+    generate(shell, &mut cmd, bin_name.clone(), &mut script);
+
+    if shell == Shell::PowerShell {
+        install_powershell(&path, &bin_name, &script)?;
+    } else {
+        write_file(&path, &script)?;
+    }
+
+    println!(
+        "{} Installed {} completions to {}",
+        "✓".green().bold(),
+        shell,
+        DisplayPath(&path)
+    );
+    println!("  {}", reload_hint(shell, &path));
+
+    Ok(())
+}
+
+/// Write the script next to the profile and dot-source it from there
+///
+/// The profile is the user's own file, so it is only ever appended to.
+fn install_powershell(profile: &Path, bin_name: &str, script: &[u8]) -> Result<()> {
+    let script_path = profile.with_file_name(format!("{}-completion.ps1", bin_name));
+    write_file(&script_path, script)?;
+
+    let source_line = format!(". \"{}\"", script_path.display());
+    let existing = std::fs::read_to_string(profile).unwrap_or_default();
+    if !existing.lines().any(|line| line.trim() == source_line) {
+        let separator = if existing.is_empty() || existing.ends_with('\n') {
+            ""
+        } else {
+            "\n"
+        };
+        write_file(
+            profile,
+            format!("{}{}{}\n", existing, separator, source_line).as_bytes(),
+        )?;
+    }
 
     Ok(())
 }
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", DisplayPath(dir)))?;
+    }
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", DisplayPath(path)))
+}
+
+/// One-line instruction for picking up the new completions
+fn reload_hint(shell: Shell, path: &Path) -> String {
+    match shell {
+        Shell::Bash => format!("Reload your shell or run: source {}", DisplayPath(path)),
+        Shell::Zsh => {
+            let dir = path.parent().unwrap_or(path);
+            format!(
+                "Reload your shell (exec zsh); make sure ~/.zshrc has fpath=({} $fpath) before compinit",
+                DisplayPath(dir)
+            )
+        }
+        Shell::Fish => "Open a new fish session to load the completions".to_string(),
+        Shell::PowerShell => "Reload your profile: . $PROFILE".to_string(),
+        _ => "Reload your shell".to_string(),
+    }
+}
@@ -7,6 +7,134 @@ use crate::platform::DisplayPath;
 use anyhow::Result;
 use colored::Colorize;
 
+/// Type of value a config key accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueType {
+    Bool,
+    Int,
+    String,
+    List,
+    /// One of a fixed set of values
+    Enum(&'static [&'static str]),
+}
+
+impl ValueType {
+    fn describe(&self) -> String {
+        match self {
+            ValueType::Bool => "a boolean (true/false, yes/no, 1/0, on/off)".to_string(),
+            ValueType::Int => "a non-negative integer".to_string(),
+            ValueType::String => "a string".to_string(),
+            ValueType::List => "a space-separated list".to_string(),
+            ValueType::Enum(values) => format!("one of: {}", values.join(", ")),
+        }
+    }
+}
+
+/// Every known config key and the type of value it takes
+const CONFIG_SCHEMA: &[(&str, ValueType)] = &[
+    ("repo.remote", ValueType::String),
+    ("branch.format", ValueType::String),
+    ("branch.prefix", ValueType::String),
+    ("merge.ci_timeout_secs", ValueType::Int),
+    ("merge.proactive_rebase", ValueType::Bool),
+    ("merge.wait_for_ci", ValueType::Bool),
+    ("web.base_url", ValueType::String),
+    ("nav.wrap", ValueType::Bool),
+    ("nav.breadcrumb", ValueType::Bool),
+    ("checkout.auto_track", ValueType::Bool),
+    ("checkout.sort", ValueType::Enum(&["depth", "recent", "alpha"])),
+    ("log.orientation", ValueType::Enum(&["trunk-bottom", "trunk-top"])),
+    ("submit.template_path", ValueType::String),
+    ("submit.stack_viz", ValueType::Bool),
+    ("sync.autostash", ValueType::Bool),
+    ("sync.strategy", ValueType::Enum(&["rebase", "merge"])),
+    ("sync.fetch_tags", ValueType::Bool),
+    ("sync.default_flags", ValueType::List),
+    ("commit.require_conventional", ValueType::Bool),
+    ("ui.spinner", ValueType::Bool),
+    ("forge.gh_path", ValueType::String),
+    ("forge.glab_path", ValueType::String),
+    ("forge.pr_cache_ttl_secs", ValueType::Int),
+];
+
+/// Look up the type of a known config key
+fn key_type(key: &str) -> Option<ValueType> {
+    CONFIG_SCHEMA.iter().find(|(k, _)| *k == key).map(|(_, t)| *t)
+}
+
+/// Check that `value` has the type `key` expects
+fn validate_value(key: &str, value: &str) -> Result<()> {
+    let Some(value_type) = key_type(key) else {
+        return Err(unknown_key_error(key));
+    };
+
+    let valid = match value_type {
+        ValueType::Bool => parse_bool(value).is_ok(),
+        ValueType::Int => value.parse::<u64>().is_ok(),
+        ValueType::String | ValueType::List => true,
+        ValueType::Enum(values) => values.iter().any(|v| v.eq_ignore_ascii_case(value)),
+    };
+    if !valid {
+        anyhow::bail!(
+            "Invalid value '{}' for {}: expected {}",
+            value,
+            key,
+            value_type.describe()
+        );
+    }
+
+    Ok(())
+}
+
+/// Error for an unknown key, suggesting the closest known one
+fn unknown_key_error(key: &str) -> anyhow::Error {
+    let suggestion = closest_key(key)
+        .map(|k| format!(" Did you mean '{}'?", k))
+        .unwrap_or_default();
+    let available: String = CONFIG_SCHEMA.iter().map(|(k, _)| format!("\n  {}", k)).collect();
+    anyhow::anyhow!(
+        "Unknown config key: {}.{}\n\nAvailable keys:{}",
+        key,
+        suggestion,
+        available
+    )
+}
+
+/// Known key closest to `key`, if any is close enough to be a likely typo
+///
+/// A bare field name (e.g., "autostash") matches the key ending in it.
+fn closest_key(key: &str) -> Option<&'static str> {
+    let suffix = format!(".{}", key);
+    if let Some((k, _)) = CONFIG_SCHEMA.iter().find(|(k, _)| k.ends_with(&suffix)) {
+        return Some(k);
+    }
+
+    let max_distance = (key.chars().count() / 3).max(3);
+    CONFIG_SCHEMA
+        .iter()
+        .map(|(k, _)| (*k, edit_distance(key, k)))
+        .filter(|(_, distance)| *distance <= max_distance)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(k, _)| k)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+
+    prev[b.len()]
+}
+
 /// Parse a boolean value from string
 fn parse_bool(value: &str) -> Result<bool> {
     match value.to_lowercase().as_str() {
@@ -19,6 +147,11 @@ fn parse_bool(value: &str) -> Result<bool> {
     }
 }
 
+/// Split a space-separated list value into its items
+fn parse_list(value: &str) -> Vec<String> {
+    value.split_whitespace().map(str::to_string).collect()
+}
+
 /// Show current configuration from all sources
 pub fn show() -> Result<()> {
     let config = Config::load()?;
@@ -47,6 +180,87 @@ pub fn show() -> Result<()> {
     );
     println!("  wait_for_ci:       {}", config.merge.wait_for_ci.to_string().cyan());
 
+    println!();
+    println!("{}", "Web Configuration:".bold());
+    if let Some(ref base_url) = config.web.base_url {
+        println!("  base_url: {}", base_url.cyan());
+    } else {
+        println!("  base_url: {}", "(not set)".dimmed());
+    }
+
+    println!();
+    println!("{}", "Navigation Configuration:".bold());
+    println!("  wrap: {}", config.nav.wrap_enabled().to_string().cyan());
+    println!("  breadcrumb: {}", config.nav.breadcrumb_enabled().to_string().cyan());
+
+    println!();
+    println!("{}", "Checkout Configuration:".bold());
+    println!(
+        "  auto_track: {}",
+        config.checkout.auto_track_enabled().to_string().cyan()
+    );
+    println!(
+        "  sort: {}",
+        config.checkout.sort.unwrap_or_default().to_string().cyan()
+    );
+
+    println!();
+    println!("{}", "Log Configuration:".bold());
+    println!(
+        "  orientation: {}",
+        config.log.orientation.unwrap_or_default().to_string().cyan()
+    );
+
+    println!();
+    println!("{}", "Submit Configuration:".bold());
+    if let Some(ref path) = config.submit.template_path {
+        println!("  template_path: {}", path.cyan());
+    } else {
+        println!("  template_path: {}", "(not set)".dimmed());
+    }
+    println!("  stack_viz: {}", config.submit.stack_viz_enabled().to_string().cyan());
+
+    println!();
+    println!("{}", "Sync Configuration:".bold());
+    println!("  autostash: {}", config.sync.autostash_enabled().to_string().cyan());
+    println!(
+        "  strategy: {}",
+        config.sync.strategy.unwrap_or_default().to_string().cyan()
+    );
+    println!("  fetch_tags: {}", config.sync.fetch_tags_enabled().to_string().cyan());
+    match &config.sync.default_flags {
+        Some(flags) => println!("  default_flags: {}", flags.join(" ").cyan()),
+        None => println!("  default_flags: {}", "(not set)".dimmed()),
+    }
+
+    println!();
+    println!("{}", "Commit Configuration:".bold());
+    println!(
+        "  require_conventional: {}",
+        config.commit.require_conventional_enabled().to_string().cyan()
+    );
+
+    println!();
+    println!("{}", "UI Configuration:".bold());
+    println!("  spinner: {}", config.ui.spinner_enabled().to_string().cyan());
+
+    println!();
+    println!("{}", "Forge Configuration:".bold());
+    if let Some(ref path) = config.forge.gh_path {
+        println!("  gh_path: {}", path.cyan());
+    } else {
+        println!("  gh_path: {}", "(not set)".dimmed());
+    }
+    if let Some(ref path) = config.forge.glab_path {
+        println!("  glab_path: {}", path.cyan());
+    } else {
+        println!("  glab_path: {}", "(not set)".dimmed());
+    }
+    println!(
+        "  pr_cache_ttl_secs: {}",
+        config.forge.pr_cache_ttl_secs().to_string().cyan()
+    );
+
     println!();
     println!("{}", "Config file locations:".bold());
 
@@ -74,25 +288,74 @@ pub fn show() -> Result<()> {
     Ok(())
 }
 
+/// Effective value of a known key, or None if it is unset
+fn value_of(config: &Config, key: &str) -> Result<Option<String>> {
+    let value = match key {
+        "repo.remote" => Some(config.remote.clone()),
+        "branch.format" => Some(config.branch.format.clone()),
+        "branch.prefix" => config.branch.prefix.clone(),
+        "merge.ci_timeout_secs" => Some(config.merge.ci_timeout_secs.to_string()),
+        "merge.proactive_rebase" => Some(config.merge.proactive_rebase.to_string()),
+        "merge.wait_for_ci" => Some(config.merge.wait_for_ci.to_string()),
+        "nav.wrap" => Some(config.nav.wrap_enabled().to_string()),
+        "nav.breadcrumb" => Some(config.nav.breadcrumb_enabled().to_string()),
+        "checkout.auto_track" => Some(config.checkout.auto_track_enabled().to_string()),
+        "checkout.sort" => Some(config.checkout.sort.unwrap_or_default().to_string()),
+        "log.orientation" => Some(config.log.orientation.unwrap_or_default().to_string()),
+        "submit.template_path" => config.submit.template_path.clone(),
+        "submit.stack_viz" => Some(config.submit.stack_viz_enabled().to_string()),
+        "sync.autostash" => Some(config.sync.autostash_enabled().to_string()),
+        "sync.strategy" => Some(config.sync.strategy.unwrap_or_default().to_string()),
+        "sync.fetch_tags" => Some(config.sync.fetch_tags_enabled().to_string()),
+        "sync.default_flags" => config.sync.default_flags.as_ref().map(|flags| flags.join(" ")),
+        "commit.require_conventional" => Some(config.commit.require_conventional_enabled().to_string()),
+        "ui.spinner" => Some(config.ui.spinner_enabled().to_string()),
+        "web.base_url" => config.web.base_url.clone(),
+        "forge.gh_path" => config.forge.gh_path.clone(),
+        "forge.glab_path" => config.forge.glab_path.clone(),
+        "forge.pr_cache_ttl_secs" => Some(config.forge.pr_cache_ttl_secs().to_string()),
+        _ => return Err(unknown_key_error(key)),
+    };
+
+    Ok(value)
+}
+
 /// Get a specific configuration value
 pub fn get(key: &str) -> Result<()> {
     let config = Config::load()?;
 
-    match key {
-        "repo.remote" => println!("{}", config.remote),
-        "branch.format" => println!("{}", config.branch.format),
-        "branch.prefix" => {
-            if let Some(prefix) = config.branch.prefix {
-                println!("{}", prefix);
-            }
+    if let Some(value) = value_of(&config, key)? {
+        println!("{}", value);
+    }
+
+    Ok(())
+}
+
+/// Every known key with its effective value, in schema order
+///
+/// With `defaults_only`, keeps just the keys still at their default value.
+/// Unset keys have an empty value.
+fn list_entries(config: &Config, defaults_only: bool) -> Result<Vec<(&'static str, String)>> {
+    let defaults = Config::default();
+    let mut entries = Vec::new();
+
+    for (key, _) in CONFIG_SCHEMA {
+        let value = value_of(config, key)?;
+        if defaults_only && value != value_of(&defaults, key)? {
+            continue;
         }
-        "merge.ci_timeout_secs" => println!("{}", config.merge.ci_timeout_secs),
-        "merge.proactive_rebase" => println!("{}", config.merge.proactive_rebase),
-        "merge.wait_for_ci" => println!("{}", config.merge.wait_for_ci),
-        _ => anyhow::bail!(
-            "Unknown config key: {}\n\nAvailable keys:\n  repo.remote\n  branch.format\n  branch.prefix\n  merge.ci_timeout_secs\n  merge.proactive_rebase\n  merge.wait_for_ci",
-            key
-        ),
+        entries.push((*key, value.unwrap_or_default()));
+    }
+
+    Ok(entries)
+}
+
+/// List every known key as `key=value`, one per line
+pub fn list(defaults_only: bool) -> Result<()> {
+    let config = Config::load()?;
+
+    for (key, value) in list_entries(&config, defaults_only)? {
+        println!("{}={}", key, value);
     }
 
     Ok(())
@@ -100,6 +363,8 @@ pub fn get(key: &str) -> Result<()> {
 
 /// Set a configuration value
 pub fn set(key: &str, value: &str, local: bool) -> Result<()> {
+    validate_value(key, value)?;
+
     // repo.remote is a special case - it always goes in repo config (.diamond/config.toml)
     if key == "repo.remote" {
         return set_repo_remote(value);
@@ -155,9 +420,9 @@ fn set_user(key: &str, value: &str) -> Result<()> {
         "branch.format" => config.branch.format = value.to_string(),
         "branch.prefix" => config.branch.prefix = Some(value.to_string()),
         "merge.ci_timeout_secs" => {
-            config.merge.ci_timeout_secs = value.parse().map_err(|_| {
-                anyhow::anyhow!("Invalid value for ci_timeout_secs: expected a number")
-            })?;
+            config.merge.ci_timeout_secs = value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid value for ci_timeout_secs: expected a number"))?;
         }
         "merge.proactive_rebase" => {
             config.merge.proactive_rebase = parse_bool(value)?;
@@ -165,10 +430,30 @@ fn set_user(key: &str, value: &str) -> Result<()> {
         "merge.wait_for_ci" => {
             config.merge.wait_for_ci = parse_bool(value)?;
         }
-        _ => anyhow::bail!(
-            "Unknown config key: {}\n\nAvailable keys:\n  branch.format\n  branch.prefix\n  merge.ci_timeout_secs\n  merge.proactive_rebase\n  merge.wait_for_ci",
-            key
-        ),
+        "web.base_url" => config.web.base_url = Some(value.to_string()),
+        "nav.wrap" => config.nav.wrap = Some(parse_bool(value)?),
+        "nav.breadcrumb" => config.nav.breadcrumb = Some(parse_bool(value)?),
+        "checkout.auto_track" => config.checkout.auto_track = Some(parse_bool(value)?),
+        "checkout.sort" => config.checkout.sort = Some(value.parse().map_err(anyhow::Error::msg)?),
+        "log.orientation" => config.log.orientation = Some(value.parse().map_err(anyhow::Error::msg)?),
+        "submit.template_path" => config.submit.template_path = Some(value.to_string()),
+        "submit.stack_viz" => config.submit.stack_viz = Some(parse_bool(value)?),
+        "sync.autostash" => config.sync.autostash = Some(parse_bool(value)?),
+        "sync.strategy" => config.sync.strategy = Some(value.parse().map_err(anyhow::Error::msg)?),
+        "sync.fetch_tags" => config.sync.fetch_tags = Some(parse_bool(value)?),
+        "sync.default_flags" => config.sync.default_flags = Some(parse_list(value)),
+        "ui.spinner" => config.ui.spinner = Some(parse_bool(value)?),
+        "commit.require_conventional" => config.commit.require_conventional = Some(parse_bool(value)?),
+        "forge.gh_path" => config.forge.gh_path = Some(value.to_string()),
+        "forge.glab_path" => config.forge.glab_path = Some(value.to_string()),
+        "forge.pr_cache_ttl_secs" => {
+            config.forge.pr_cache_ttl_secs = Some(
+                value
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Invalid value for pr_cache_ttl_secs: expected a number"))?,
+            );
+        }
+        _ => return Err(unknown_key_error(key)),
     }
 
     // Save
@@ -196,9 +481,9 @@ fn set_local(key: &str, value: &str) -> Result<()> {
         "branch.format" => config.branch.format = value.to_string(),
         "branch.prefix" => config.branch.prefix = Some(value.to_string()),
         "merge.ci_timeout_secs" => {
-            config.merge.ci_timeout_secs = value.parse().map_err(|_| {
-                anyhow::anyhow!("Invalid value for ci_timeout_secs: expected a number")
-            })?;
+            config.merge.ci_timeout_secs = value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid value for ci_timeout_secs: expected a number"))?;
         }
         "merge.proactive_rebase" => {
             config.merge.proactive_rebase = parse_bool(value)?;
@@ -206,10 +491,30 @@ fn set_local(key: &str, value: &str) -> Result<()> {
         "merge.wait_for_ci" => {
             config.merge.wait_for_ci = parse_bool(value)?;
         }
-        _ => anyhow::bail!(
-            "Unknown config key: {}\n\nAvailable keys:\n  branch.format\n  branch.prefix\n  merge.ci_timeout_secs\n  merge.proactive_rebase\n  merge.wait_for_ci",
-            key
-        ),
+        "web.base_url" => config.web.base_url = Some(value.to_string()),
+        "nav.wrap" => config.nav.wrap = Some(parse_bool(value)?),
+        "nav.breadcrumb" => config.nav.breadcrumb = Some(parse_bool(value)?),
+        "checkout.auto_track" => config.checkout.auto_track = Some(parse_bool(value)?),
+        "checkout.sort" => config.checkout.sort = Some(value.parse().map_err(anyhow::Error::msg)?),
+        "log.orientation" => config.log.orientation = Some(value.parse().map_err(anyhow::Error::msg)?),
+        "submit.template_path" => config.submit.template_path = Some(value.to_string()),
+        "submit.stack_viz" => config.submit.stack_viz = Some(parse_bool(value)?),
+        "sync.autostash" => config.sync.autostash = Some(parse_bool(value)?),
+        "sync.strategy" => config.sync.strategy = Some(value.parse().map_err(anyhow::Error::msg)?),
+        "sync.fetch_tags" => config.sync.fetch_tags = Some(parse_bool(value)?),
+        "sync.default_flags" => config.sync.default_flags = Some(parse_list(value)),
+        "ui.spinner" => config.ui.spinner = Some(parse_bool(value)?),
+        "commit.require_conventional" => config.commit.require_conventional = Some(parse_bool(value)?),
+        "forge.gh_path" => config.forge.gh_path = Some(value.to_string()),
+        "forge.glab_path" => config.forge.glab_path = Some(value.to_string()),
+        "forge.pr_cache_ttl_secs" => {
+            config.forge.pr_cache_ttl_secs = Some(
+                value
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Invalid value for pr_cache_ttl_secs: expected a number"))?,
+            );
+        }
+        _ => return Err(unknown_key_error(key)),
     }
 
     // Save
@@ -247,7 +552,24 @@ fn unset_user(key: &str) -> Result<()> {
         "merge.ci_timeout_secs" => config.merge.ci_timeout_secs = MergeConfig::default().ci_timeout_secs,
         "merge.proactive_rebase" => config.merge.proactive_rebase = MergeConfig::default().proactive_rebase,
         "merge.wait_for_ci" => config.merge.wait_for_ci = MergeConfig::default().wait_for_ci,
-        _ => anyhow::bail!("Unknown config key: {}", key),
+        "web.base_url" => config.web.base_url = None,
+        "nav.wrap" => config.nav.wrap = None,
+        "nav.breadcrumb" => config.nav.breadcrumb = None,
+        "checkout.auto_track" => config.checkout.auto_track = None,
+        "checkout.sort" => config.checkout.sort = None,
+        "log.orientation" => config.log.orientation = None,
+        "submit.template_path" => config.submit.template_path = None,
+        "submit.stack_viz" => config.submit.stack_viz = None,
+        "sync.autostash" => config.sync.autostash = None,
+        "sync.strategy" => config.sync.strategy = None,
+        "sync.fetch_tags" => config.sync.fetch_tags = None,
+        "sync.default_flags" => config.sync.default_flags = None,
+        "ui.spinner" => config.ui.spinner = None,
+        "commit.require_conventional" => config.commit.require_conventional = None,
+        "forge.gh_path" => config.forge.gh_path = None,
+        "forge.glab_path" => config.forge.glab_path = None,
+        "forge.pr_cache_ttl_secs" => config.forge.pr_cache_ttl_secs = None,
+        _ => return Err(unknown_key_error(key)),
     }
 
     Config::save_user_config(&config)?;
@@ -273,7 +595,24 @@ fn unset_local(key: &str) -> Result<()> {
         "merge.ci_timeout_secs" => config.merge.ci_timeout_secs = MergeConfig::default().ci_timeout_secs,
         "merge.proactive_rebase" => config.merge.proactive_rebase = MergeConfig::default().proactive_rebase,
         "merge.wait_for_ci" => config.merge.wait_for_ci = MergeConfig::default().wait_for_ci,
-        _ => anyhow::bail!("Unknown config key: {}", key),
+        "web.base_url" => config.web.base_url = None,
+        "nav.wrap" => config.nav.wrap = None,
+        "nav.breadcrumb" => config.nav.breadcrumb = None,
+        "checkout.auto_track" => config.checkout.auto_track = None,
+        "checkout.sort" => config.checkout.sort = None,
+        "log.orientation" => config.log.orientation = None,
+        "submit.template_path" => config.submit.template_path = None,
+        "submit.stack_viz" => config.submit.stack_viz = None,
+        "sync.autostash" => config.sync.autostash = None,
+        "sync.strategy" => config.sync.strategy = None,
+        "sync.fetch_tags" => config.sync.fetch_tags = None,
+        "sync.default_flags" => config.sync.default_flags = None,
+        "ui.spinner" => config.ui.spinner = None,
+        "commit.require_conventional" => config.commit.require_conventional = None,
+        "forge.gh_path" => config.forge.gh_path = None,
+        "forge.glab_path" => config.forge.glab_path = None,
+        "forge.pr_cache_ttl_secs" => config.forge.pr_cache_ttl_secs = None,
+        _ => return Err(unknown_key_error(key)),
     }
 
     Config::save_local_config(&config)?;
@@ -286,10 +625,93 @@ fn unset_local(key: &str) -> Result<()> {
 mod tests {
     use super::*;
 
+    use tempfile::tempdir;
+
+    use crate::config::CheckoutSort;
+    use crate::test_context::{init_test_repo, TestRepoContext};
+
     #[test]
     fn test_show_runs_without_error() -> Result<()> {
         // Just verify it doesn't panic - actual output depends on system state
         // We can't easily test this without mocking the config paths
         Ok(())
     }
+
+    #[test]
+    fn test_set_rejects_type_mismatch() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let err = set("merge.ci_timeout_secs", "notanumber", true)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Invalid value 'notanumber' for merge.ci_timeout_secs"),
+            "{}",
+            err
+        );
+        assert!(err.contains("expected a non-negative integer"), "{}", err);
+
+        let err = set("nav.wrap", "sometimes", true).unwrap_err().to_string();
+        assert!(err.contains("expected a boolean"), "{}", err);
+
+        let err = set("checkout.sort", "newest", true).unwrap_err().to_string();
+        assert!(err.contains("expected one of: depth, recent, alpha"), "{}", err);
+        let err = set("log.orientation", "sideways", true).unwrap_err().to_string();
+        assert!(err.contains("expected one of: trunk-bottom, trunk-top"), "{}", err);
+
+        // Nothing was written
+        assert!(!Config::local_config_path()?.exists());
+
+        // Well-typed values are accepted
+        set("merge.ci_timeout_secs", "900", true)?;
+        assert_eq!(Config::load()?.merge.ci_timeout_secs, 900);
+        set("checkout.sort", "recent", true)?;
+        assert_eq!(Config::load()?.checkout.sort, Some(CheckoutSort::Recent));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_entries_covers_schema_and_filters_defaults() -> Result<()> {
+        let defaults = Config::default();
+        let all = list_entries(&defaults, false)?;
+        let keys: Vec<&str> = all.iter().map(|(k, _)| *k).collect();
+        let schema: Vec<&str> = CONFIG_SCHEMA.iter().map(|(k, _)| *k).collect();
+        assert_eq!(keys, schema);
+        assert_eq!(list_entries(&defaults, true)?.len(), all.len());
+
+        let mut config = Config::default();
+        config.nav.wrap = Some(true);
+        config.branch.prefix = Some("me/".to_string());
+        let at_defaults = list_entries(&config, true)?;
+        assert!(!at_defaults
+            .iter()
+            .any(|(k, _)| *k == "nav.wrap" || *k == "branch.prefix"));
+        assert!(list_entries(&config, false)?.contains(&("branch.prefix", "me/".to_string())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_unknown_key_suggests_closest() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let err = get("merge.wait_ci").unwrap_err().to_string();
+        assert!(err.contains("Did you mean 'merge.wait_for_ci'?"), "{}", err);
+
+        // A bare field name resolves to its section
+        let err = get("autostash").unwrap_err().to_string();
+        assert!(err.contains("Did you mean 'sync.autostash'?"), "{}", err);
+
+        // Nothing close: no suggestion, just the list
+        let err = get("completely.unrelated.setting").unwrap_err().to_string();
+        assert!(!err.contains("Did you mean"), "{}", err);
+        assert!(err.contains("Available keys:"), "{}", err);
+
+        Ok(())
+    }
 }
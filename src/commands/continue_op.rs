@@ -57,9 +57,15 @@ pub fn run() -> Result<()> {
     // Dispatch to appropriate handler based on operation type
     let ref_store = RefStore::new()?;
 
-    // If there's a rebase in progress, continue it first
-    if gateway.rebase_in_progress()? {
-        let result = gateway.rebase_continue()?;
+    // If there's a rebase (or a sync merge) in progress, continue it first
+    let in_progress = if gateway.rebase_in_progress()? {
+        Some(gateway.rebase_continue()?)
+    } else if gateway.merge_in_progress()? {
+        Some(gateway.merge_continue()?)
+    } else {
+        None
+    };
+    if let Some(result) = in_progress {
         if result.has_conflicts() {
             // Get parent branch for conflict context
             let current = state
@@ -95,20 +101,16 @@ pub fn run() -> Result<()> {
         }
         OperationType::Restack => crate::commands::restack::continue_restack_from_state(&mut state, &ref_store),
         OperationType::Move => crate::commands::move_cmd::continue_move_from_state(&mut state, &ref_store),
-        OperationType::Insert => continue_insert_from_state(&state, &gateway),
+        OperationType::Insert => continue_insert_from_state(&mut state, &ref_store, &gateway),
     }
 }
 
 /// Continue an insert operation after conflicts are resolved
-fn continue_insert_from_state(state: &OperationState, gateway: &GitGateway) -> Result<()> {
-    // The child branch has been rebased onto the new branch (original_branch)
-    // Just need to return to the new branch and clear state
-
-    // Return to the new branch (the one that was inserted)
-    gateway.checkout_branch_worktree_safe(&state.original_branch)?;
-
-    // Clear operation state
-    OperationState::clear()?;
+fn continue_insert_from_state(state: &mut OperationState, ref_store: &RefStore, gateway: &GitGateway) -> Result<()> {
+    // Restack whatever is left of the displaced child and its descendants
+    if !crate::commands::create::finish_insert(state, ref_store, gateway)? {
+        return Ok(());
+    }
 
     ui::success_bold(&format!(
         "Insert complete! '{}' is now between the parent and child",
@@ -264,12 +266,17 @@ mod tests {
         // Simulate being on child after a successful rebase
         gateway.checkout_branch_worktree_safe("child").unwrap();
 
+        let ref_store = RefStore::new().unwrap();
+        ref_store.set_trunk("main").unwrap();
+        ref_store.set_parent("new-branch", "main").unwrap();
+        ref_store.set_parent("child", "new-branch").unwrap();
+
         // Create insert operation state
-        let state = OperationState::new_insert("new-branch".to_string(), "child".to_string(), "main".to_string());
+        let mut state = OperationState::new_insert("new-branch".to_string(), "child".to_string(), "main".to_string());
         state.save().unwrap();
 
         // Continue should work (returns to new-branch and clears state)
-        let result = continue_insert_from_state(&state, &gateway);
+        let result = continue_insert_from_state(&mut state, &ref_store, &gateway);
         assert!(result.is_ok());
 
         // State should be cleared
@@ -1,6 +1,9 @@
 use crate::cache::Cache;
+use crate::commands::restack;
 use crate::config::Config;
+use crate::git_gateway::backup::new_backup_operation_id;
 use crate::git_gateway::GitGateway;
+use crate::operation_log::{Operation, OperationRecorder};
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 use crate::stack_viz::is_dangerous_branch_name;
@@ -8,6 +11,42 @@ use crate::state::{acquire_operation_lock, OperationState};
 use crate::worktree;
 use anyhow::{Context, Result};
 use colored::Colorize;
+use std::io::Read;
+use std::path::Path;
+
+/// Resolve the commit message from `-m`/`-F`, mirroring `git commit -F`
+///
+/// `-m -` reads the message from stdin and `-F <file>` from a file (`-F -` is
+/// also stdin). Trailing newlines are trimmed and an empty message is rejected.
+pub fn resolve_message(message: Option<String>, file: Option<&Path>) -> Result<Option<String>> {
+    resolve_message_from(message, file, std::io::stdin())
+}
+
+fn resolve_message_from(message: Option<String>, file: Option<&Path>, mut stdin: impl Read) -> Result<Option<String>> {
+    let raw = match (message, file) {
+        (Some(m), _) if m == "-" => {
+            let mut buf = String::new();
+            stdin
+                .read_to_string(&mut buf)
+                .context("Failed to read commit message from stdin")?;
+            buf
+        }
+        (Some(m), _) => return Ok(Some(m)),
+        (None, Some(path)) if path == Path::new("-") => {
+            return resolve_message_from(Some("-".to_string()), None, stdin)
+        }
+        (None, Some(path)) => {
+            std::fs::read_to_string(path).context(format!("Failed to read commit message from {}", path.display()))?
+        }
+        (None, None) => return Ok(None),
+    };
+
+    let message = raw.trim_end_matches(['\n', '\r']).to_string();
+    if message.trim().is_empty() {
+        anyhow::bail!("Aborting: the commit message is empty");
+    }
+    Ok(Some(message))
+}
 
 /// Slugify a commit message into a valid branch name component.
 /// Does NOT add date prefix - that's handled by Config::format_branch_name().
@@ -28,13 +67,46 @@ fn slugify_name(message: &str) -> String {
         .join("_")
 }
 
-pub fn run(
-    name: Option<String>,
-    all: bool,
-    update: bool,
-    message: Option<String>,
-    insert: Option<String>,
-) -> Result<()> {
+/// Options for [`run`], one per `create` flag
+#[derive(Debug, Default)]
+pub struct CreateOptions {
+    /// Name of the new branch (generated from `message` if not provided)
+    pub name: Option<String>,
+    /// Stage all changes
+    pub all: bool,
+    /// Stage only updates to already-tracked files
+    pub update: bool,
+    /// Commit message
+    pub message: Option<String>,
+    /// Insert between the current branch and this child (or its only child)
+    pub insert: Option<String>,
+    /// Create and track the branch without switching to it
+    pub no_switch: bool,
+    /// Commit an empty placeholder commit
+    pub empty: bool,
+    /// Check the original branch out again afterwards
+    pub switch_back: bool,
+}
+
+/// Create a new branch stacked on the current one
+///
+/// With `empty`, the branch gets an empty placeholder commit, using `message`
+/// or a generated "Placeholder for <branch>" message.
+///
+/// With `switch_back`, the new branch is created and committed to as usual,
+/// then the original branch is checked out again.
+pub fn run(options: CreateOptions) -> Result<()> {
+    let CreateOptions {
+        name,
+        all,
+        update,
+        message,
+        insert,
+        no_switch,
+        empty,
+        switch_back,
+    } = options;
+
     // Acquire operation lock to prevent race conditions with concurrent sync/restack.
     // This is especially important for --insert which modifies refs and rebases.
     let _lock = acquire_operation_lock()?;
@@ -47,6 +119,15 @@ pub fn run(
             branch: Default::default(),
             remote: "origin".to_string(),
             merge: Default::default(),
+            web: Default::default(),
+            nav: Default::default(),
+            checkout: Default::default(),
+            log: Default::default(),
+            submit: Default::default(),
+            sync: Default::default(),
+            commit: Default::default(),
+            ui: Default::default(),
+            forge: Default::default(),
         }
     });
 
@@ -55,19 +136,41 @@ pub fn run(
         anyhow::bail!("Cannot use both -a (all) and -u (update) flags together");
     }
 
+    // Staging, committing and inserting all act on the new branch, so they need the switch
+    if no_switch && (all || update || message.is_some() || insert.is_some() || empty) {
+        anyhow::bail!(
+            "Cannot use --no-switch with -a, -u, -m, --insert or --empty (they need the new branch checked out)"
+        );
+    }
+
+    if switch_back && (no_switch || insert.is_some()) {
+        anyhow::bail!("Cannot use --switch-back with --no-switch or --insert");
+    }
+
+    // Staged changes would make the placeholder commit non-empty
+    if empty && (all || update) {
+        anyhow::bail!("Cannot use --empty with -a or -u (the commit must stay empty)");
+    }
+
+    // Reject non-conforming messages before anything is created
+    if let Some(msg) = &message {
+        config.check_commit_message(msg)?;
+    }
+
     // 1. Determine raw branch name (explicit or auto-generated from message)
     let raw_name = match (&name, &message) {
         (Some(n), _) => n.clone(),
-        (None, Some(msg)) => slugify_name(msg),
+        // Only the subject line names the branch; a body (e.g. from -F) is ignored
+        (None, Some(msg)) => slugify_name(msg.lines().next().unwrap_or_default()),
         (None, None) => anyhow::bail!("Must provide either a branch name or a commit message to generate the name"),
     };
 
-    // 2. Apply branch name formatting from config (prefix, date, etc.)
-    // Only apply formatting when auto-generating from message, not for explicit names
+    // 2. Apply branch naming from config
+    // Explicit names only get the prefix; generated names are also formatted (date, etc.)
     let branch_name = if name.is_some() {
-        raw_name // Use explicit name as-is
+        config.with_branch_prefix(&raw_name)
     } else {
-        config.format_branch_name(&raw_name)
+        config.generated_branch_name(&raw_name)
     };
 
     // 3. Validate branch name is safe for PR descriptions
@@ -161,12 +264,20 @@ pub fn run(
         None
     };
 
-    // 7. Create and checkout the new branch
+    // 7. Create and checkout the new branch (or just create it at HEAD with --no-switch)
     println!("Creating branch '{}' from '{}'...", branch_name.green(), parent.blue());
-    gateway.create_branch(&branch_name)?;
+    if no_switch {
+        gateway.create_branch_at_head(&branch_name)?;
+    } else {
+        gateway.create_branch(&branch_name)?;
+    }
 
     // 8. Update Stack Metadata
     ref_store.set_parent(&branch_name, &parent)?;
+    OperationRecorder::new()?.record(Operation::BranchCreated {
+        branch: branch_name.clone(),
+        parent: Some(parent.clone()),
+    })?;
 
     // 9. Handle -a/-u and/or -m flags independently
     if all {
@@ -177,7 +288,11 @@ pub fn run(
         println!("Staged tracked file updates");
     }
 
-    if let Some(msg) = message {
+    if empty {
+        let msg = message.unwrap_or_else(|| format!("Placeholder for {}", branch_name));
+        gateway.commit_allow_empty(&msg)?;
+        println!("Committed (empty): {}", msg);
+    } else if let Some(msg) = message {
         gateway.commit(&msg)?;
         println!("Committed: {}", msg);
     }
@@ -190,44 +305,41 @@ pub fn run(
         cache.save()?;
     }
 
-    // 11. If inserting, re-parent the child and rebase it
+    // 11. If inserting, re-parent the child and restack it with its descendants
     if let Some(child) = insert_target {
+        let mut displaced = vec![child.clone()];
+        displaced.extend(ref_store.descendants(&child)?);
+
         // Check for worktree conflicts before rebasing
-        worktree::check_branches_for_worktree_conflicts(std::slice::from_ref(&child))?;
+        worktree::check_branches_for_worktree_conflicts(&displaced)?;
 
         println!("Inserting between '{}' and '{}'...", parent.blue(), child.green());
 
+        // Back up the displaced branches so `dm abort` can put them back
+        let backup_op = new_backup_operation_id()?;
+        for branch in &displaced {
+            gateway.create_operation_backup_ref(&backup_op, branch)?;
+        }
+
         // Save operation state BEFORE modifying anything.
         // This enables `dm abort` to rollback if the user runs `git rebase --abort`.
-        let insert_state = OperationState::new_insert(
+        let mut insert_state = OperationState::new_insert(
             branch_name.clone(),
             child.clone(),
             parent.clone(), // original parent of the child
         );
+        insert_state.remaining_branches = displaced.clone();
+        insert_state.all_branches = displaced;
         insert_state.save()?;
 
         // Update metadata: change child's parent to the new branch
         ref_store.reparent(&child, &branch_name)?;
 
-        // Rebase the child onto the new branch
-        let outcome = gateway.rebase_onto(&child, &branch_name)?;
-        if outcome.has_conflicts() {
-            println!(
-                "\n{} Conflicts while rebasing '{}' onto '{}'. Resolve and run '{} continue'.",
-                "!".yellow().bold(),
-                child,
-                branch_name,
-                program_name()
-            );
+        // Restack the child onto the new branch, then its descendants
+        if !finish_insert(&mut insert_state, &ref_store, &gateway)? {
             return Ok(());
         }
 
-        // Return to the new branch after rebasing
-        gateway.checkout_branch_worktree_safe(&branch_name)?;
-
-        // Clear operation state on success
-        OperationState::clear()?;
-
         println!(
             "{} Inserted '{}' between '{}' and '{}'",
             "✓".green().bold(),
@@ -235,6 +347,23 @@ pub fn run(
             parent,
             child
         );
+    } else if switch_back {
+        gateway.checkout_branch_worktree_safe(&parent)?;
+        println!(
+            "{} Created branch '{}' and switched back to '{}'",
+            "Success:".green().bold(),
+            branch_name,
+            parent
+        );
+        println!("Stack: {} -> {}", parent, branch_name);
+    } else if no_switch {
+        println!(
+            "{} Created branch '{}' (still on '{}')",
+            "Success:".green().bold(),
+            branch_name,
+            parent
+        );
+        println!("Stack: {} -> {}", parent, branch_name);
     } else {
         println!("{} Checked out branch '{}'", "Success:".green().bold(), branch_name);
         println!("Stack: {} -> {}", parent, branch_name);
@@ -243,10 +372,40 @@ pub fn run(
     Ok(())
 }
 
+/// Restack the branches displaced by `create --insert` and wrap up
+///
+/// Shared with `dm continue`. Returns `false` if a rebase stopped on
+/// conflicts; the state is saved and the user has been pointed at `dm continue`.
+pub(crate) fn finish_insert(state: &mut OperationState, ref_store: &RefStore, gateway: &GitGateway) -> Result<bool> {
+    // Every displaced branch has a parent, so the trunk fallback never applies
+    // (and `create` works before a trunk is configured)
+    let trunk = ref_store.get_trunk()?.unwrap_or_default();
+    if !restack::restack_remaining(state, ref_store, gateway, &trunk)? {
+        return Ok(false);
+    }
+
+    // Return to the new branch after rebasing
+    gateway.checkout_branch_worktree_safe(&state.original_branch)?;
+
+    // Clear operation state on success
+    OperationState::clear()?;
+
+    if !state.completed_branches.is_empty() {
+        println!(
+            "Restacked onto '{}': {}",
+            state.original_branch,
+            state.completed_branches.join(", ")
+        );
+    }
+
+    Ok(true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::cache::Cache;
+    use crate::config::LocalConfig;
     use crate::ref_store::RefStore;
     use anyhow::Result;
 
@@ -255,6 +414,27 @@ mod tests {
 
     use crate::test_context::{init_test_repo, TestRepoContext};
 
+    #[test]
+    fn test_resolve_message_trims_and_rejects_empty() -> Result<()> {
+        let stdin = "Subject\n\nBody line\n\n".as_bytes();
+        assert_eq!(
+            resolve_message_from(Some("-".to_string()), None, stdin)?,
+            Some("Subject\n\nBody line".to_string())
+        );
+
+        // A literal -m is passed through untouched, and stdin is not read
+        assert_eq!(
+            resolve_message_from(Some("Keep me\n".to_string()), None, "ignored".as_bytes())?,
+            Some("Keep me\n".to_string())
+        );
+        assert_eq!(resolve_message_from(None, None, "ignored".as_bytes())?, None);
+
+        let err = resolve_message_from(Some("-".to_string()), None, "\n\n".as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("empty"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_create_branch_success() -> Result<()> {
         let dir = tempdir()?;
@@ -264,7 +444,10 @@ mod tests {
         let gateway = GitGateway::new()?;
 
         // Create a new branch
-        run(Some("feature-1".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify branch exists in git
         assert!(gateway.branch_exists("feature-1")?);
@@ -286,10 +469,16 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Create first branch
-        run(Some("feature-1".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
 
         // Create second branch from first
-        run(Some("feature-2".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-2".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify parent relationship via refs
         let ref_store = RefStore::new()?;
@@ -303,6 +492,83 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_create_switch_back_returns_to_original_branch() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        run(CreateOptions {
+            name: Some("x".to_string()),
+            ..Default::default()
+        })?;
+        let x_sha = gateway.get_branch_sha("x")?;
+
+        std::fs::write(dir.path().join("quick.txt"), "quick fix")?;
+        run(CreateOptions {
+            name: Some("y".to_string()),
+            all: true,
+            message: Some("Quick fix".to_string()),
+            switch_back: true,
+            ..Default::default()
+        })?;
+
+        // Back on x, which is untouched
+        assert_eq!(gateway.get_current_branch_name()?, "x");
+        assert_eq!(gateway.get_branch_sha("x")?, x_sha);
+        assert!(!dir.path().join("quick.txt").exists());
+
+        // y is tracked on x and holds the commit
+        let ref_store = RefStore::new()?;
+        assert_eq!(ref_store.get_parent("y")?, Some("x".to_string()));
+        assert_eq!(gateway.get_commit_subject("y")?, "Quick fix");
+        assert_eq!(gateway.get_merge_base("y", "x")?, x_sha);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_no_switch_stays_on_current_branch() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let original = gateway.get_current_branch_name()?;
+
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            no_switch: true,
+            ..Default::default()
+        })?;
+
+        // Branch exists and is tracked, but we didn't move
+        assert_eq!(gateway.get_current_branch_name()?, original);
+        assert!(gateway.branch_exists("feature-1")?);
+        let ref_store = RefStore::new()?;
+        assert_eq!(ref_store.get_parent("feature-1")?, Some(original.clone()));
+
+        // base_sha records the branch's starting point (HEAD)
+        let cache = Cache::load()?;
+        assert_eq!(
+            cache.get_base_sha("feature-1").map(|s| s.to_string()),
+            Some(gateway.get_branch_sha(&original)?)
+        );
+
+        // Options that need the new branch checked out are rejected
+        let result = run(CreateOptions {
+            name: Some("feature-2".to_string()),
+            all: true,
+            no_switch: true,
+            ..Default::default()
+        });
+        assert!(result.unwrap_err().to_string().contains("--no-switch"));
+        assert!(!gateway.branch_exists("feature-2")?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_create_duplicate_branch_fails() -> Result<()> {
         let dir = tempdir()?;
@@ -310,10 +576,16 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Create a branch
-        run(Some("duplicate".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("duplicate".to_string()),
+            ..Default::default()
+        })?;
 
         // Try to create it again
-        let result = run(Some("duplicate".to_string()), false, false, None, None);
+        let result = run(CreateOptions {
+            name: Some("duplicate".to_string()),
+            ..Default::default()
+        });
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("already exists"));
 
@@ -332,7 +604,10 @@ mod tests {
         let initial_branch = gateway.get_current_branch_name()?;
 
         // Create feature from main/master
-        run(Some("feature".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify parent is initial branch via refs
         let ref_store = RefStore::new()?;
@@ -349,9 +624,18 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Create chain: main -> feature-1 -> feature-2 -> feature-3
-        run(Some("feature-1".to_string()), false, false, None, None)?;
-        run(Some("feature-2".to_string()), false, false, None, None)?;
-        run(Some("feature-3".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
+        run(CreateOptions {
+            name: Some("feature-2".to_string()),
+            ..Default::default()
+        })?;
+        run(CreateOptions {
+            name: Some("feature-3".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify full chain via refs
         let ref_store = RefStore::new()?;
@@ -375,7 +659,10 @@ mod tests {
         let gateway = GitGateway::new()?;
 
         // Create branch with special characters
-        run(Some("feature/sub-branch_v2".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature/sub-branch_v2".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify it was created
         assert!(gateway.branch_exists("feature/sub-branch_v2")?);
@@ -390,7 +677,10 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Create initial structure
-        run(Some("feature-1".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
 
         // Add metadata to feature-1 via cache
         let mut cache = Cache::load()?;
@@ -398,7 +688,10 @@ mod tests {
         cache.save()?;
 
         // Create child branch
-        run(Some("feature-2".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-2".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify original metadata preserved in cache
         let cache = Cache::load()?;
@@ -422,7 +715,11 @@ mod tests {
         fs::write(dir.path().join("test.txt"), "content")?;
 
         // Create with -a but no -m (should stage but not commit)
-        run(Some("feature".to_string()), true, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature".to_string()),
+            all: true,
+            ..Default::default()
+        })?;
 
         // Verify branch exists
         assert!(gateway.branch_exists("feature")?);
@@ -451,13 +748,11 @@ mod tests {
         fs::write(dir.path().join("unstaged.txt"), "unstaged")?;
 
         // Create with -m but no -a (should commit only staged files)
-        run(
-            Some("feature".to_string()),
-            false,
-            false,
-            Some("Test commit".to_string()),
-            None,
-        )?;
+        run(CreateOptions {
+            name: Some("feature".to_string()),
+            message: Some("Test commit".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify branch and commit
         assert_eq!(gateway.get_current_branch_name()?, "feature");
@@ -484,13 +779,12 @@ mod tests {
         fs::write(dir.path().join("test.txt"), "test content")?;
 
         // Create branch with -am
-        run(
-            Some("feature".to_string()),
-            true,
-            false,
-            Some("Test commit".to_string()),
-            None,
-        )?;
+        run(CreateOptions {
+            name: Some("feature".to_string()),
+            all: true,
+            message: Some("Test commit".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify we're on the feature branch
         assert_eq!(gateway.get_current_branch_name()?, "feature");
@@ -514,7 +808,11 @@ mod tests {
         fs::write(dir.path().join("test.txt"), "content")?;
 
         // Create branch with message but no name (should auto-generate)
-        run(None, true, false, Some("Add new feature".to_string()), None)?;
+        run(CreateOptions {
+            all: true,
+            message: Some("Add new feature".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify branch was created with slugified name (MM-DD-message_with_underscores)
         let current_branch = gateway.get_current_branch_name()?;
@@ -542,7 +840,10 @@ mod tests {
         let gateway = GitGateway::new()?;
 
         // Create branch with special characters in message
-        run(None, false, false, Some("Fix bug #123: URL parsing!".to_string()), None)?;
+        run(CreateOptions {
+            message: Some("Fix bug #123: URL parsing!".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify branch name is slugified (MM-DD-message_with_underscores)
         let current_branch = gateway.get_current_branch_name()?;
@@ -568,7 +869,7 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Create with neither name nor message should fail
-        let result = run(None, false, false, None, None);
+        let result = run(CreateOptions::default());
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("branch name"));
 
@@ -582,7 +883,10 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // URL injection attempt
-        let result = run(Some("branch](http://evil.com)".to_string()), false, false, None, None);
+        let result = run(CreateOptions {
+            name: Some("branch](http://evil.com)".to_string()),
+            ..Default::default()
+        });
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -592,18 +896,120 @@ mod tests {
         );
 
         // Code block injection
-        let result = run(Some("branch```code".to_string()), false, false, None, None);
+        let result = run(CreateOptions {
+            name: Some("branch```code".to_string()),
+            ..Default::default()
+        });
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("dangerous"));
 
         // HTML comment injection
-        let result = run(Some("branch<!--".to_string()), false, false, None, None);
+        let result = run(CreateOptions {
+            name: Some("branch<!--".to_string()),
+            ..Default::default()
+        });
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("dangerous"));
 
         Ok(())
     }
 
+    #[test]
+    fn test_create_autogenerated_name_gets_branch_prefix() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+
+        let mut config = LocalConfig::default();
+        config.branch.prefix = Some("alice-".to_string());
+        Config::save_local_config(&config)?;
+
+        fs::write(dir.path().join("test.txt"), "content")?;
+        run(CreateOptions {
+            all: true,
+            message: Some("Add login".to_string()),
+            ..Default::default()
+        })?;
+
+        let current_branch = gateway.get_current_branch_name()?;
+        assert!(
+            current_branch.starts_with("alice-") && current_branch.ends_with("-add_login"),
+            "Expected prefixed generated name, got: {}",
+            current_branch
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_rejects_non_conventional_message_when_required() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+
+        let mut config = LocalConfig::default();
+        config.commit.require_conventional = Some(true);
+        Config::save_local_config(&config)?;
+
+        fs::write(dir.path().join("test.txt"), "content")?;
+        let result = run(CreateOptions {
+            name: Some("feature".to_string()),
+            all: true,
+            message: Some("added stuff".to_string()),
+            ..Default::default()
+        });
+        assert!(result.unwrap_err().to_string().contains("conventional commit"));
+        assert!(!gateway.branch_exists("feature")?, "nothing should be created");
+
+        run(CreateOptions {
+            name: Some("feature".to_string()),
+            all: true,
+            message: Some("feat(x): y".to_string()),
+            ..Default::default()
+        })?;
+        assert_eq!(gateway.get_current_branch_name()?, "feature");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_explicit_name_with_prefix_not_doubled() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+
+        let mut config = LocalConfig::default();
+        config.branch.prefix = Some("alice-".to_string());
+        Config::save_local_config(&config)?;
+
+        // Name already carries the prefix
+        run(CreateOptions {
+            name: Some("alice-feature".to_string()),
+            ..Default::default()
+        })?;
+        assert_eq!(gateway.get_current_branch_name()?, "alice-feature");
+
+        // Name without the prefix gets it, including on the --insert path
+        gateway.checkout_branch_worktree_safe("main")?;
+        run(CreateOptions {
+            name: Some("middle".to_string()),
+            insert: Some("alice-feature".to_string()),
+            ..Default::default()
+        })?;
+        assert_eq!(gateway.get_current_branch_name()?, "alice-middle");
+
+        let ref_store = RefStore::new()?;
+        assert_eq!(ref_store.get_parent("alice-feature")?, Some("alice-middle".to_string()));
+
+        Ok(())
+    }
+
     #[test]
     fn test_create_insert_between_branches() -> Result<()> {
         let dir = tempdir()?;
@@ -613,7 +1019,10 @@ mod tests {
         let gateway = GitGateway::new()?;
 
         // Create initial stack: main -> feature-1
-        run(Some("feature-1".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
 
         // Make a commit on feature-1 so we have something to rebase
         fs::write(dir.path().join("feature1.txt"), "feature 1 content")?;
@@ -624,13 +1033,11 @@ mod tests {
         gateway.checkout_branch_worktree_safe("main")?;
 
         // Insert between main and feature-1
-        run(
-            Some("new-middle".to_string()),
-            false,
-            false,
-            None,
-            Some("feature-1".to_string()),
-        )?;
+        run(CreateOptions {
+            name: Some("new-middle".to_string()),
+            insert: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify the new structure via refs: main -> new-middle -> feature-1
         let ref_store = RefStore::new()?;
@@ -654,6 +1061,123 @@ mod tests {
         Ok(())
     }
 
+    /// main -> feature-1 -> feature-2, each with a commit; leaves `main` checked out
+    fn setup_two_branch_stack(dir: &Path, gateway: &GitGateway, feature1_file: &str) -> Result<()> {
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
+        fs::write(dir.join(feature1_file), "feature 1 content")?;
+        gateway.stage_all()?;
+        gateway.commit("Feature 1 commit")?;
+
+        run(CreateOptions {
+            name: Some("feature-2".to_string()),
+            ..Default::default()
+        })?;
+        fs::write(dir.join("feature2.txt"), "feature 2 content")?;
+        gateway.stage_all()?;
+        gateway.commit("Feature 2 commit")?;
+
+        gateway.checkout_branch_worktree_safe("main")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_insert_restacks_child_and_descendants() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let gateway = GitGateway::new()?;
+        setup_two_branch_stack(dir.path(), &gateway, "feature1.txt")?;
+
+        fs::write(dir.path().join("middle.txt"), "middle content")?;
+        run(CreateOptions {
+            name: Some("middle".to_string()),
+            all: true,
+            message: Some("Middle commit".to_string()),
+            insert: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
+
+        assert!(OperationState::load()?.is_none());
+        assert_eq!(gateway.get_current_branch_name()?, "middle");
+        assert!(gateway.is_branch_based_on("feature-1", "middle")?);
+        assert!(gateway.is_branch_based_on("feature-2", "feature-1")?);
+
+        // feature-2 still holds only its own commit, on top of the restacked feature-1
+        let feature2 = repo.revparse_single("feature-2")?.peel_to_commit()?;
+        let feature1 = repo.revparse_single("feature-1")?.peel_to_commit()?;
+        assert_eq!(feature2.parent_id(0)?, feature1.id());
+        assert_eq!(RefStore::new()?.get_parent("feature-2")?, Some("feature-1".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_insert_conflict_pauses_for_continue() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let gateway = GitGateway::new()?;
+        setup_two_branch_stack(dir.path(), &gateway, "shared.txt")?;
+
+        // The inserted branch touches the same file as feature-1
+        fs::write(dir.path().join("shared.txt"), "middle content")?;
+        run(CreateOptions {
+            name: Some("middle".to_string()),
+            all: true,
+            message: Some("Middle commit".to_string()),
+            insert: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
+
+        let state = OperationState::load()?.expect("insert should pause on the conflict");
+        assert_eq!(state.current_branch.as_deref(), Some("feature-1"));
+        assert_eq!(state.remaining_branches, vec!["feature-2"]);
+        assert_eq!(state.all_branches, vec!["feature-1", "feature-2"]);
+
+        // Keep feature-1's version
+        fs::write(dir.path().join("shared.txt"), "feature 1 content")?;
+        gateway.stage_all()?;
+        crate::commands::continue_op::run()?;
+
+        assert!(OperationState::load()?.is_none());
+        assert_eq!(gateway.get_current_branch_name()?, "middle");
+        assert!(gateway.is_branch_based_on("feature-1", "middle")?);
+        assert!(gateway.is_branch_based_on("feature-2", "feature-1")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_insert_abort_restores_displaced_branches() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let gateway = GitGateway::new()?;
+        setup_two_branch_stack(dir.path(), &gateway, "shared.txt")?;
+        let feature1_before = repo.revparse_single("feature-1")?.id();
+
+        fs::write(dir.path().join("shared.txt"), "middle content")?;
+        run(CreateOptions {
+            name: Some("middle".to_string()),
+            all: true,
+            message: Some("Middle commit".to_string()),
+            insert: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
+        assert!(OperationState::load()?.is_some());
+
+        crate::commands::abort::run()?;
+
+        assert!(OperationState::load()?.is_none());
+        assert_eq!(repo.revparse_single("feature-1")?.id(), feature1_before);
+        assert_eq!(RefStore::new()?.get_parent("feature-1")?, Some("main".to_string()));
+
+        Ok(())
+    }
+
     #[test]
     fn test_create_insert_nonexistent_child_fails() -> Result<()> {
         let dir = tempdir()?;
@@ -661,13 +1185,11 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Try to insert with a non-existent child
-        let result = run(
-            Some("new-branch".to_string()),
-            false,
-            false,
-            None,
-            Some("nonexistent".to_string()),
-        );
+        let result = run(CreateOptions {
+            name: Some("new-branch".to_string()),
+            insert: Some("nonexistent".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("does not exist"));
@@ -684,18 +1206,22 @@ mod tests {
         let gateway = GitGateway::new()?;
 
         // Create two branches from main
-        run(Some("feature-1".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
         gateway.checkout_branch_worktree_safe("main")?;
-        run(Some("feature-2".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-2".to_string()),
+            ..Default::default()
+        })?;
 
         // Try to insert between feature-2 (current) and feature-1 (not a child of feature-2)
-        let result = run(
-            Some("new-branch".to_string()),
-            false,
-            false,
-            None,
-            Some("feature-1".to_string()),
-        );
+        let result = run(CreateOptions {
+            name: Some("new-branch".to_string()),
+            insert: Some("feature-1".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -728,13 +1254,12 @@ mod tests {
         fs::write(dir.path().join("untracked.txt"), "new")?;
 
         // Create with -u flag (should only stage tracked file updates)
-        run(
-            Some("feature".to_string()),
-            false, // all
-            true,  // update
-            Some("Update tracked file".to_string()),
-            None,
-        )?;
+        run(CreateOptions {
+            name: Some("feature".to_string()),
+            update: true,
+            message: Some("Update tracked file".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify the commit only has tracked.txt changes
         let head = repo.head()?.peel_to_commit()?;
@@ -753,13 +1278,13 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Try to use both -a and -u
-        let result = run(
-            Some("feature".to_string()),
-            true, // all
-            true, // update
-            Some("Test".to_string()),
-            None,
-        );
+        let result = run(CreateOptions {
+            name: Some("feature".to_string()),
+            all: true,
+            update: true,
+            message: Some("Test".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -781,7 +1306,10 @@ mod tests {
         let gateway = GitGateway::new()?;
 
         // Create initial stack: main -> feature-1
-        run(Some("feature-1".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("feature-1".to_string()),
+            ..Default::default()
+        })?;
 
         // Make a commit on feature-1
         fs::write(dir.path().join("feature1.txt"), "feature 1 content")?;
@@ -793,13 +1321,11 @@ mod tests {
 
         // Insert with boolean --insert (auto-detect child)
         // This should insert between main and feature-1
-        run(
-            Some("new-middle".to_string()),
-            false,
-            false,
-            None,
-            Some("".to_string()), // Empty string indicates boolean flag usage
-        )?;
+        run(CreateOptions {
+            name: Some("new-middle".to_string()),
+            insert: Some("".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify the new structure: main -> new-middle -> feature-1
         let ref_store = RefStore::new()?;
@@ -821,13 +1347,11 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // On main with no children, --insert should fail
-        let result = run(
-            Some("new-branch".to_string()),
-            false,
-            false,
-            None,
-            Some("".to_string()), // Empty string indicates boolean flag usage
-        );
+        let result = run(CreateOptions {
+            name: Some("new-branch".to_string()),
+            insert: Some("".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -853,12 +1377,18 @@ mod tests {
         ref_store.set_trunk("main")?;
 
         // Create stack: main -> A -> B
-        run(Some("A".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("A".to_string()),
+            ..Default::default()
+        })?;
         fs::write(dir.path().join("a.txt"), "a")?;
         gateway.stage_all()?;
         gateway.commit("A commit")?;
 
-        run(Some("B".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("B".to_string()),
+            ..Default::default()
+        })?;
         fs::write(dir.path().join("b.txt"), "b")?;
         gateway.stage_all()?;
         gateway.commit("B commit")?;
@@ -873,7 +1403,10 @@ mod tests {
 
         // Try to create C from B (which has deleted parent A)
         // This should fail because B's parent (A) doesn't exist
-        let result = run(Some("C".to_string()), false, false, None, None);
+        let result = run(CreateOptions {
+            name: Some("C".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -899,7 +1432,10 @@ mod tests {
         ref_store.set_trunk("main")?;
 
         // Create from trunk should always succeed (no parent to validate)
-        let result = run(Some("A".to_string()), false, false, None, None);
+        let result = run(CreateOptions {
+            name: Some("A".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_ok());
 
@@ -919,16 +1455,70 @@ mod tests {
         ref_store.set_trunk("main")?;
 
         // Create A from main
-        run(Some("A".to_string()), false, false, None, None)?;
+        run(CreateOptions {
+            name: Some("A".to_string()),
+            ..Default::default()
+        })?;
         fs::write(dir.path().join("a.txt"), "a")?;
         gateway.stage_all()?;
         gateway.commit("A commit")?;
 
         // Create B from A should succeed (A's parent is trunk, which always exists)
-        let result = run(Some("B".to_string()), false, false, None, None);
+        let result = run(CreateOptions {
+            name: Some("B".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_ok());
 
         Ok(())
     }
+
+    #[test]
+    fn test_create_empty_adds_one_empty_commit() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        let main_sha = gateway.get_branch_sha("main")?;
+
+        run(CreateOptions {
+            name: Some("placeholder".to_string()),
+            empty: true,
+            ..Default::default()
+        })?;
+
+        // Exactly one new commit on top of main, with the generated message and no changes
+        assert_eq!(gateway.get_current_branch_name()?, "placeholder");
+        assert_eq!(gateway.get_commit_count_since("main")?, 1);
+        assert_eq!(
+            gateway.get_commit_subject("placeholder")?,
+            "Placeholder for placeholder"
+        );
+        let tip = gateway.get_branch_sha("placeholder")?;
+        assert_ne!(tip, main_sha);
+        assert_eq!(gateway.get_merge_base("placeholder", "main")?, main_sha);
+        let repo = git2::Repository::open(dir.path())?;
+        let tip_commit = repo.find_commit(git2::Oid::from_str(&tip)?)?;
+        assert_eq!(tip_commit.tree_id(), tip_commit.parent(0)?.tree_id());
+
+        // Tracked and cached like any other new branch
+        assert_eq!(ref_store.get_parent("placeholder")?, Some("main".to_string()));
+        assert_eq!(Cache::load()?.get_base_sha("placeholder"), Some(tip.as_str()));
+
+        // An explicit -m is used as-is
+        run(CreateOptions {
+            name: Some("second".to_string()),
+            message: Some("Scaffold API layer".to_string()),
+            empty: true,
+            ..Default::default()
+        })?;
+        assert_eq!(gateway.get_commit_subject("second")?, "Scaffold API layer");
+        assert_eq!(ref_store.get_parent("second")?, Some("placeholder".to_string()));
+
+        Ok(())
+    }
 }
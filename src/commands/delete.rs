@@ -1,4 +1,5 @@
 use crate::context::ExecutionContext;
+use crate::git_gateway::backup::new_backup_operation_id;
 use crate::git_gateway::{BackupRef, GitGateway};
 use crate::operation_log::{Operation, OperationRecorder};
 use crate::program_name::program_name;
@@ -13,6 +14,7 @@ use std::io::IsTerminal;
 ///
 /// If name is None, would show interactive TUI (not yet implemented).
 /// If reparent is true, children are re-parented to the grandparent AND rebased.
+/// If reparent is false and the branch has children, force is required since they'd be orphaned.
 /// If force is true, delete even if branch is not merged (skip confirmation).
 /// If upstack is true, delete branch and all descendants.
 /// If downstack is true, delete branch and all ancestors (except trunk).
@@ -80,6 +82,21 @@ pub fn run(name: Option<String>, reparent: bool, force: bool, upstack: bool, dow
         return delete_downstack(&gateway, &ref_store, &name, &current, force);
     }
 
+    // Refuse to silently orphan children when reparenting is turned off
+    if !reparent && !force {
+        let children: Vec<String> = ref_store.get_children(&name)?.into_iter().collect();
+        if !children.is_empty() {
+            anyhow::bail!(
+                "Deleting '{}' with --no-reparent would orphan {} {}: {}\n\
+                Drop --no-reparent to move them onto its parent, or use -f or --force to orphan them anyway.",
+                name,
+                children.len(),
+                if children.len() == 1 { "child" } else { "children" },
+                children.join(", ")
+            );
+        }
+    }
+
     // Check if branch is merged (if not force, require confirmation)
     if !force {
         let trunk = ref_store.require_trunk()?;
@@ -183,9 +200,10 @@ pub fn run(name: Option<String>, reparent: bool, force: bool, upstack: bool, dow
             let recorder = OperationRecorder::new()?;
             let mut backups: Vec<(String, BackupRef)> = Vec::new();
 
+            let backup_op = new_backup_operation_id()?;
             for child in &children {
                 if gateway.branch_exists(child)? {
-                    let backup = gateway.create_backup_ref(child)?;
+                    let backup = gateway.create_operation_backup_ref(&backup_op, child)?;
                     println!(
                         "  {} Backed up {} @ {}",
                         "✓".green(),
@@ -288,7 +306,7 @@ pub fn run(name: Option<String>, reparent: bool, force: bool, upstack: bool, dow
             }
 
             // PHASE 4: Delete from git (metadata already updated)
-            gateway.delete_branch(&name)?;
+            delete_git_branch(&gateway, &name)?;
             println!("{} Deleted branch: {}", "✓".green().bold(), name);
             return Ok(());
         }
@@ -298,12 +316,20 @@ pub fn run(name: Option<String>, reparent: bool, force: bool, upstack: bool, dow
     ref_store.remove_parent(&name)?;
 
     // Delete from git
-    gateway.delete_branch(&name)?;
+    delete_git_branch(&gateway, &name)?;
 
     println!("{} Deleted branch: {}", "✓".green().bold(), name);
     Ok(())
 }
 
+/// Delete a branch from git and record it in the operation log
+fn delete_git_branch(gateway: &GitGateway, branch: &str) -> Result<()> {
+    gateway.delete_branch(branch)?;
+    OperationRecorder::new()?.record(Operation::BranchDeleted {
+        branch: branch.to_string(),
+    })
+}
+
 /// Delete a branch and all its descendants (upstack deletion)
 fn delete_upstack(gateway: &GitGateway, ref_store: &RefStore, name: &str, current: &str, force: bool) -> Result<()> {
     // Collect all branches to delete (target + all descendants)
@@ -357,7 +383,7 @@ fn delete_upstack(gateway: &GitGateway, ref_store: &RefStore, name: &str, curren
     for branch in &branches_to_delete {
         ref_store.remove_parent(branch)?;
         if gateway.branch_exists(branch)? {
-            gateway.delete_branch(branch)?;
+            delete_git_branch(gateway, branch)?;
         }
     }
 
@@ -443,7 +469,7 @@ fn delete_downstack(gateway: &GitGateway, ref_store: &RefStore, name: &str, curr
     for branch in &branches_to_delete {
         ref_store.remove_parent(branch)?;
         if gateway.branch_exists(branch)? {
-            gateway.delete_branch(branch)?;
+            delete_git_branch(gateway, branch)?;
         }
     }
 
@@ -611,13 +637,48 @@ mod tests {
         run(Some("middle".to_string()), false, true, false, false)?;
 
         // Verify leaf is orphaned - parent ref still points to deleted branch
-        // (RefStore doesn't prevent this without --reparent)
+        // (RefStore doesn't prevent this with --no-reparent)
         assert_eq!(ref_store.get_parent("leaf")?, Some("middle".to_string()));
         assert!(!ref_store.is_tracked("middle")?);
 
         Ok(())
     }
 
+    #[test]
+    fn test_delete_without_reparent_refuses_to_orphan_children() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+
+        // Create branch hierarchy: main -> middle -> (left, right)
+        gateway.create_branch("middle")?;
+        gateway.create_branch("left")?;
+        gateway.checkout_branch_worktree_safe("middle")?;
+        gateway.create_branch("right")?;
+        gateway.checkout_branch_worktree_safe("main")?;
+
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("middle", "main")?;
+        ref_store.set_parent("left", "middle")?;
+        ref_store.set_parent("right", "middle")?;
+
+        let result = run(Some("middle".to_string()), false, false, false, false);
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("would orphan 2 children"), "unexpected error: {}", err);
+        assert!(err.contains("left") && err.contains("right"));
+
+        // Nothing was deleted or reparented
+        assert!(gateway.branch_exists("middle")?);
+        assert!(ref_store.is_tracked("middle")?);
+        assert_eq!(ref_store.get_parent("left")?, Some("middle".to_string()));
+        assert_eq!(ref_store.get_parent("right")?, Some("middle".to_string()));
+
+        Ok(())
+    }
+
     #[test]
     fn test_delete_upstack() -> Result<()> {
         let dir = tempdir()?;
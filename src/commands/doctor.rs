@@ -1,10 +1,11 @@
 use crate::cache::Cache;
+use crate::commands::export::StackMetadata;
 use crate::forge::get_forge;
-use crate::git_gateway::GitGateway;
+use crate::git_gateway::{BranchSyncState, GitGateway};
 use crate::program_name::program_name;
-use crate::ref_store::RefStore;
+use crate::ref_store::{RefStore, StackSnapshot};
 use crate::stack_viz::update_all_stack_visualizations;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
 use std::collections::HashSet;
 
@@ -21,10 +22,17 @@ pub enum DiagnosticError {
     TrackedBranchMissing(String),
     /// Parent ref contains corrupted data (CRITICAL-8 detection)
     CorruptedRef { branch: String, error: String },
+    /// Cached base SHA points at a commit that no longer exists
+    StaleBaseSha { branch: String, sha: String },
+    /// Frozen ref for a branch that no longer exists in git
+    StaleFrozenRef(String),
 }
 
 /// Run diagnostics on the stack metadata
-pub fn run(fix: bool, fix_viz: bool) -> Result<()> {
+///
+/// With `check_remote`, also reports tracked branches that differ from their
+/// remote-tracking refs. That report is informational and never fails the run.
+pub fn run(fix: bool, fix_viz: bool, check_remote: bool) -> Result<()> {
     // Handle --fix-viz first (independent of regular diagnostics)
     if fix_viz {
         return run_fix_viz();
@@ -35,7 +43,19 @@ pub fn run(fix: bool, fix_viz: bool) -> Result<()> {
     let ref_store = RefStore::new()?;
     let gateway = GitGateway::new()?;
 
-    let errors = validate_refs(&ref_store, &gateway)?;
+    let result = run_metadata_checks(&ref_store, &gateway, fix);
+
+    if check_remote {
+        println!();
+        print_remote_divergence(&remote_divergence(&ref_store, &gateway)?, gateway.remote());
+    }
+
+    result
+}
+
+/// Check the stack metadata, report issues, and repair them with `fix`
+fn run_metadata_checks(ref_store: &RefStore, gateway: &GitGateway, fix: bool) -> Result<()> {
+    let errors = validate_refs(ref_store, gateway)?;
 
     if errors.is_empty() {
         println!("{} All checks passed!", "✓".green().bold());
@@ -86,12 +106,29 @@ pub fn run(fix: bool, fix_viz: bool) -> Result<()> {
                 );
                 println!("   {}", error.dimmed());
             }
+            DiagnosticError::StaleBaseSha { branch, sha } => {
+                println!(
+                    "{}. {} Branch '{}' has a stale base SHA {} (commit no longer exists)",
+                    i + 1,
+                    "⚠".yellow(),
+                    branch.cyan(),
+                    sha.dimmed()
+                );
+            }
+            DiagnosticError::StaleFrozenRef(branch) => {
+                println!(
+                    "{}. {} Branch '{}' is marked frozen but doesn't exist in git",
+                    i + 1,
+                    "⚠".yellow(),
+                    branch.cyan()
+                );
+            }
         }
     }
 
     if fix {
         println!("\n{} Attempting automatic repair...", "🔧".blue());
-        let unfixed_count = attempt_fix(&ref_store, &gateway, &errors)?;
+        let unfixed_count = attempt_fix(ref_store, gateway, &errors)?;
 
         if unfixed_count > 0 {
             anyhow::bail!(
@@ -119,19 +156,23 @@ pub fn run(fix: bool, fix_viz: bool) -> Result<()> {
 fn validate_refs(ref_store: &RefStore, gateway: &GitGateway) -> Result<Vec<DiagnosticError>> {
     let mut errors = Vec::new();
 
+    // Read the whole graph once; every check below is answered from memory
+    let snapshot = ref_store.snapshot()?;
+    let cache = Cache::load().unwrap_or_default();
+
     // Check trunk exists
-    if let Some(trunk) = ref_store.get_trunk()? {
-        if !gateway.branch_exists(&trunk)? {
-            errors.push(DiagnosticError::MissingTrunk(trunk.clone()));
+    if let Some(trunk) = snapshot.trunk() {
+        if !gateway.branch_exists(trunk)? {
+            errors.push(DiagnosticError::MissingTrunk(trunk.to_string()));
         }
 
-        // Get all tracked branches - use list_tracked_branches to catch orphaned branches and cycles
+        // Get all tracked branches - use tracked_branches to catch orphaned branches and cycles
         // (collect_branches_dfs only finds branches reachable from trunk)
-        let all_branches = ref_store.list_tracked_branches()?;
+        let all_branches = snapshot.tracked_branches();
 
         for branch in &all_branches {
             // Skip trunk
-            if branch == &trunk {
+            if branch == trunk {
                 continue;
             }
 
@@ -141,57 +182,154 @@ fn validate_refs(ref_store: &RefStore, gateway: &GitGateway) -> Result<Vec<Diagn
                 continue;
             }
 
+            // Check the recorded base SHA still resolves to a commit (may be gc'd or rewritten)
+            if let Some(sha) = cache.get_base_sha(branch) {
+                if gateway.resolve_ref(&format!("{}^{{commit}}", sha)).is_err() {
+                    errors.push(DiagnosticError::StaleBaseSha {
+                        branch: branch.clone(),
+                        sha: sha.to_string(),
+                    });
+                }
+            }
+
+            // The snapshot reads blobs lossily; re-read strictly to catch non-UTF-8 content
+            if let Err(e) = ref_store.get_parent_unchecked(branch) {
+                errors.push(DiagnosticError::CorruptedRef {
+                    branch: branch.clone(),
+                    error: e.to_string(),
+                });
+                continue;
+            }
+
             // Check parent exists and is tracked
             // Use unchecked getter to allow inspection of corrupted refs
-            match ref_store.get_parent_unchecked(branch)? {
-                Some(parent) => {
-                    // Validate parent name first (CRITICAL-8 corruption detection)
-                    use crate::ref_store::validate_parent_name;
-                    if let Err(e) = validate_parent_name(&parent, branch) {
-                        errors.push(DiagnosticError::CorruptedRef {
+            if let Some(parent) = snapshot.parent_of_unchecked(branch) {
+                // Validate parent name first (CRITICAL-8 corruption detection)
+                use crate::ref_store::validate_parent_name;
+                if let Err(e) = validate_parent_name(parent, branch) {
+                    errors.push(DiagnosticError::CorruptedRef {
+                        branch: branch.clone(),
+                        error: e.to_string(),
+                    });
+                    continue; // Skip further validation for corrupted refs
+                }
+                if !gateway.is_valid_branch_name(parent) {
+                    errors.push(DiagnosticError::CorruptedRef {
+                        branch: branch.clone(),
+                        error: format!("Parent '{}' is not a valid branch name", parent),
+                    });
+                    continue;
+                }
+
+                // Parent name is valid, check if it's tracked and exists
+                if parent != trunk {
+                    // Check if parent is tracked
+                    let parent_is_tracked = snapshot.is_tracked(parent);
+                    if !parent_is_tracked {
+                        errors.push(DiagnosticError::OrphanedParent {
                             branch: branch.clone(),
-                            error: e.to_string(),
+                            parent: parent.to_string(),
                         });
-                        continue; // Skip further validation for corrupted refs
                     }
-
-                    // Parent name is valid, check if it's tracked and exists
-                    if parent != trunk {
-                        // Check if parent is tracked
-                        let parent_is_tracked = ref_store.is_tracked(&parent)? || parent == trunk;
-                        if !parent_is_tracked {
-                            errors.push(DiagnosticError::OrphanedParent {
-                                branch: branch.clone(),
-                                parent: parent.clone(),
-                            });
-                        }
-                        // Also check if parent exists in git
-                        if parent_is_tracked && !gateway.branch_exists(&parent)? {
-                            errors.push(DiagnosticError::OrphanedParent {
-                                branch: branch.clone(),
-                                parent,
-                            });
-                        }
+                    // Also check if parent exists in git
+                    if parent_is_tracked && !gateway.branch_exists(parent)? {
+                        errors.push(DiagnosticError::OrphanedParent {
+                            branch: branch.clone(),
+                            parent: parent.to_string(),
+                        });
                     }
                 }
-                None => {
-                    // Branch has no parent (could be untracked or trunk)
-                }
             }
         }
 
         // Check for cycles
-        if let Some(cycle) = detect_cycle(ref_store, &all_branches)? {
+        if let Some(cycle) = detect_cycle(&snapshot, &all_branches) {
             errors.push(DiagnosticError::Cycle(cycle));
         }
     }
 
+    // Frozen refs outlive their branches when those are deleted outside Diamond.
+    // Tracked branches already reported missing get their frozen ref removed with the tracking.
+    for branch in ref_store.list_frozen_branches()? {
+        let reported_missing = errors
+            .iter()
+            .any(|e| matches!(e, DiagnosticError::TrackedBranchMissing(missing) if *missing == branch));
+        if !reported_missing && !gateway.branch_exists(&branch)? {
+            errors.push(DiagnosticError::StaleFrozenRef(branch));
+        }
+    }
+
     Ok(errors)
 }
 
+/// Tracked branches whose local tip differs from their remote-tracking ref
+///
+/// Read-only and offline: compares against the refs from the last fetch.
+/// Trunk, branches without a remote counterpart and branches in sync are left out.
+fn remote_divergence(ref_store: &RefStore, gateway: &GitGateway) -> Result<Vec<(String, BranchSyncState)>> {
+    let trunk = ref_store.get_trunk()?;
+    let mut branches = ref_store.list_tracked_branches()?;
+    branches.sort();
+
+    let mut diverged = Vec::new();
+    for branch in branches {
+        if trunk.as_deref() == Some(branch.as_str()) || !gateway.branch_exists(&branch)? {
+            continue;
+        }
+        match gateway.check_remote_sync(&branch)? {
+            BranchSyncState::InSync | BranchSyncState::NoRemote => {}
+            state => diverged.push((branch, state)),
+        }
+    }
+
+    Ok(diverged)
+}
+
+/// Print the `--check-remote` report
+fn print_remote_divergence(diverged: &[(String, BranchSyncState)], remote: &str) {
+    if diverged.is_empty() {
+        println!(
+            "{} All tracked branches match {} (as of the last fetch)",
+            "✓".green().bold(),
+            remote
+        );
+        return;
+    }
+
+    println!(
+        "{} {} branch{} differ{} from {} (as of the last fetch):\n",
+        "⚠".yellow(),
+        diverged.len(),
+        if diverged.len() == 1 { "" } else { "es" },
+        if diverged.len() == 1 { "s" } else { "" },
+        remote
+    );
+    for (branch, state) in diverged {
+        let commits = |n: usize| format!("{} commit{}", n, if n == 1 { "" } else { "s" });
+        let detail = match state {
+            BranchSyncState::Ahead(n) => format!("{} ahead, needs a push ('{} submit')", commits(*n), program_name()),
+            BranchSyncState::Behind(n) => format!(
+                "{} behind, pull before submitting ('{} get')",
+                commits(*n),
+                program_name()
+            ),
+            BranchSyncState::Diverged {
+                local_ahead,
+                remote_ahead,
+            } => format!(
+                "diverged ({} local, {} remote), reconcile before submitting",
+                commits(*local_ahead),
+                commits(*remote_ahead)
+            ),
+            BranchSyncState::InSync | BranchSyncState::NoRemote => continue,
+        };
+        println!("   {}: {}", branch.cyan(), detail);
+    }
+}
+
 /// Detect cycles in parent relationships
-fn detect_cycle(ref_store: &RefStore, branches: &[String]) -> Result<Option<Vec<String>>> {
-    let trunk = ref_store.get_trunk()?.unwrap_or_default();
+fn detect_cycle(snapshot: &StackSnapshot, branches: &[String]) -> Option<Vec<String>> {
+    let trunk = snapshot.trunk().unwrap_or_default();
 
     for start in branches {
         let mut visited = HashSet::new();
@@ -208,7 +346,7 @@ fn detect_cycle(ref_store: &RefStore, branches: &[String]) -> Result<Option<Vec<
                 if let Some(pos) = path.iter().position(|b| b == &current) {
                     let mut cycle = path[pos..].to_vec();
                     cycle.push(current);
-                    return Ok(Some(cycle));
+                    return Some(cycle);
                 }
                 break;
             }
@@ -216,20 +354,16 @@ fn detect_cycle(ref_store: &RefStore, branches: &[String]) -> Result<Option<Vec<
             visited.insert(current.clone());
             path.push(current.clone());
 
-            // Use unchecked getter to skip corrupted refs when detecting cycles
-            match ref_store.get_parent_unchecked(&current) {
-                Ok(Some(parent)) => {
-                    // Skip validation - we just want to follow the chain
-                    // Corruption will be detected separately by validate_refs
-                    current = parent;
-                }
-                Ok(None) => break, // No parent, end of chain
-                Err(_) => break,   // Error reading ref, end of chain
+            // Skip validation - we just want to follow the chain
+            // Corruption will be detected separately by validate_refs
+            match snapshot.parent_of_unchecked(&current) {
+                Some(parent) => current = parent.to_string(),
+                None => break, // No parent, end of chain
             }
         }
     }
 
-    Ok(None)
+    None
 }
 
 /// Attempt to fix an orphaned parent relationship.
@@ -297,10 +431,33 @@ fn try_fix_orphaned_parent(
     Ok(None)
 }
 
+/// Replace a stale base SHA with the merge-base of the branch and its current parent.
+fn fix_stale_base_sha(ref_store: &RefStore, gateway: &GitGateway, branch: &str) -> Result<()> {
+    let mut cache = Cache::load()?;
+    match ref_store.get_parent(branch)? {
+        Some(parent) => {
+            let merge_base = gateway.get_merge_base(branch, &parent)?;
+            cache.set_base_sha(branch, &merge_base);
+        }
+        // Without a parent there is nothing to recompute from
+        None => cache.remove_base_sha(branch),
+    }
+    cache.save()
+}
+
 /// Attempt to fix issues. Returns the number of issues that could not be fixed.
+///
+/// Fixes are applied as a single transaction under the RefStore lock: if any
+/// repair fails, every change made during this run (metadata and cached base
+/// SHAs) is rolled back to the pre-fix snapshot and an error is returned.
 fn attempt_fix(ref_store: &RefStore, gateway: &GitGateway, errors: &[DiagnosticError]) -> Result<usize> {
+    let _lock = ref_store.lock()?;
+    let snapshot = StackMetadata::collect_unchecked(ref_store)?;
+    let cache_snapshot = Cache::load()?;
+
     let mut fixed_count = 0;
     let mut failed_count = 0;
+    let mut failure: Option<(String, anyhow::Error)> = None;
 
     let trunk = ref_store.get_trunk()?.unwrap_or_default();
 
@@ -322,7 +479,7 @@ fn attempt_fix(ref_store: &RefStore, gateway: &GitGateway, errors: &[DiagnosticE
                     }
                     Err(e) => {
                         println!("  {}: Failed to fix '{}': {}", "✗".red(), branch, e);
-                        failed_count += 1;
+                        failure = Some((branch.clone(), e));
                     }
                 }
             }
@@ -340,15 +497,46 @@ fn attempt_fix(ref_store: &RefStore, gateway: &GitGateway, errors: &[DiagnosticE
             DiagnosticError::TrackedBranchMissing(branch) => {
                 println!("  Fixing: Removing tracking for non-existent branch '{}'...", branch);
 
-                // Remove the parent ref for this branch
-                match ref_store.remove_parent(branch) {
+                // Remove the parent ref for this branch, along with a frozen ref it may have had
+                match ref_store
+                    .remove_parent(branch)
+                    .and_then(|()| ref_store.set_frozen(branch, false))
+                {
+                    Ok(()) => {
+                        fixed_count += 1;
+                        println!("  {} Fixed", "✓".green());
+                    }
+                    Err(e) => {
+                        println!("  {} Failed: {}", "✗".red(), e);
+                        failure = Some((branch.clone(), e));
+                    }
+                }
+            }
+            DiagnosticError::StaleBaseSha { branch, .. } => {
+                println!("  Fixing: Recomputing base SHA for '{}'...", branch);
+
+                match fix_stale_base_sha(ref_store, gateway, branch) {
                     Ok(()) => {
                         fixed_count += 1;
                         println!("  {} Fixed", "✓".green());
                     }
                     Err(e) => {
-                        failed_count += 1;
                         println!("  {} Failed: {}", "✗".red(), e);
+                        failure = Some((branch.clone(), e));
+                    }
+                }
+            }
+            DiagnosticError::StaleFrozenRef(branch) => {
+                println!("  Fixing: Removing frozen ref for non-existent branch '{}'...", branch);
+
+                match ref_store.set_frozen(branch, false) {
+                    Ok(()) => {
+                        fixed_count += 1;
+                        println!("  {} Fixed", "✓".green());
+                    }
+                    Err(e) => {
+                        println!("  {} Failed: {}", "✗".red(), e);
+                        failure = Some((branch.clone(), e));
                     }
                 }
             }
@@ -362,12 +550,43 @@ fn attempt_fix(ref_store: &RefStore, gateway: &GitGateway, errors: &[DiagnosticE
                         println!("  {} Fixed", "✓".green());
                     }
                     Err(e) => {
-                        failed_count += 1;
                         println!("  {} Failed: {}", "✗".red(), e);
+                        failure = Some((branch.clone(), e));
                     }
                 }
             }
         }
+
+        // Stop at the first failed repair; everything so far is rolled back below
+        if failure.is_some() {
+            break;
+        }
+    }
+
+    if let Some((branch, e)) = failure {
+        println!(
+            "\n{} Rolling back {} repair(s) from this run...",
+            "↩".yellow(),
+            fixed_count
+        );
+        let restored = snapshot
+            .restore(ref_store)
+            .context("Failed to roll back metadata changes from this run")?;
+        cache_snapshot.save().context("Failed to restore cache")?;
+
+        if restored.is_empty() {
+            println!("  No metadata changes needed rolling back");
+        }
+        for restored_branch in &restored {
+            println!("  {} Restored metadata for '{}'", "↩".dimmed(), restored_branch);
+        }
+
+        anyhow::bail!(
+            "Repair of '{}' failed: {}\nNo changes were kept. Resolve the issue manually and re-run '{} doctor --fix'.",
+            branch,
+            e,
+            program_name()
+        );
     }
 
     println!("\n{} Repair complete:", "📊".blue());
@@ -447,7 +666,7 @@ mod tests {
         ref_store.set_trunk("main")?;
 
         // Should pass doctor check
-        let result = run(false, false);
+        let result = run(false, false, false);
         assert!(result.is_ok());
 
         Ok(())
@@ -492,7 +711,7 @@ mod tests {
         ref_store.set_parent("non-existent", "main")?;
 
         // Doctor should return an error (exit code 1) when issues are found
-        let result = run(false, false);
+        let result = run(false, false, false);
         assert!(result.is_err(), "doctor should return error when issues are found");
 
         Ok(())
@@ -655,6 +874,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_doctor_detects_and_fixes_non_utf8_parent_ref() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        let gateway = GitGateway::new()?;
+        ref_store.set_trunk("main")?;
+
+        // Parent blob that isn't valid UTF-8 (the lossy read would hide it)
+        create_branch(&repo, "feature")?;
+        let bad_blob = repo.blob(b"ma\xffin")?;
+        repo.reference("refs/diamond/parent/feature", bad_blob, true, "corrupt")?;
+
+        let errors = validate_refs(&ref_store, &gateway)?;
+        assert_eq!(errors.len(), 1, "Should detect exactly one error: {:?}", errors);
+        match &errors[0] {
+            DiagnosticError::CorruptedRef { branch, error } => {
+                assert_eq!(branch, "feature");
+                assert!(error.contains("Invalid UTF-8"), "got: {}", error);
+            }
+            other => panic!("Expected CorruptedRef error, got: {:?}", other),
+        }
+
+        run(true, false, false)?;
+
+        assert!(!ref_store.is_tracked("feature")?, "Corrupt ref should be removed");
+        assert!(validate_refs(&ref_store, &gateway)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_detects_parent_that_is_not_a_branch_name() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        let gateway = GitGateway::new()?;
+        ref_store.set_trunk("main")?;
+
+        create_branch(&repo, "feature")?;
+        let bad_blob = repo.blob(b"main~1")?;
+        repo.reference("refs/diamond/parent/feature", bad_blob, true, "corrupt")?;
+
+        let errors = validate_refs(&ref_store, &gateway)?;
+        assert!(
+            matches!(&errors[..], [DiagnosticError::CorruptedRef { error, .. }] if error.contains("not a valid branch name")),
+            "got: {:?}",
+            errors
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_doctor_fix_removes_corrupted_refs() -> Result<()> {
         let dir = tempdir()?;
@@ -677,7 +953,7 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("Corrupted metadata"));
 
         // Run doctor --fix
-        run(true, false)?;
+        run(true, false, false)?;
 
         // Verify corruption is fixed (ref removed)
         assert!(ref_store.get_parent("feature")?.is_none(), "Ref should be removed");
@@ -717,4 +993,231 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_doctor_detects_and_fixes_stale_base_sha() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        let gateway = GitGateway::new()?;
+        ref_store.set_trunk("main")?;
+        create_branch(&repo, "feature")?;
+        ref_store.set_parent("feature", "main")?;
+
+        // Point the cached base SHA at a commit that doesn't exist
+        let bogus = "0123456789abcdef0123456789abcdef01234567";
+        let mut cache = Cache::load()?;
+        cache.set_base_sha("feature", bogus);
+        cache.save()?;
+
+        let errors = validate_refs(&ref_store, &gateway)?;
+        assert_eq!(errors.len(), 1, "Expected only the stale base SHA, got: {:?}", errors);
+        match &errors[0] {
+            DiagnosticError::StaleBaseSha { branch, sha } => {
+                assert_eq!(branch, "feature");
+                assert_eq!(sha, bogus);
+            }
+            other => panic!("Expected StaleBaseSha, got: {:?}", other),
+        }
+
+        let unfixed = attempt_fix(&ref_store, &gateway, &errors)?;
+        assert_eq!(unfixed, 0);
+
+        let expected = gateway.get_merge_base("feature", "main")?;
+        assert_eq!(Cache::load()?.get_base_sha("feature"), Some(expected.as_str()));
+        assert!(validate_refs(&ref_store, &gateway)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_detects_and_removes_stale_frozen_refs() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        let gateway = GitGateway::new()?;
+        ref_store.set_trunk("main")?;
+        create_branch(&repo, "feature")?;
+        create_branch(&repo, "gone")?;
+        ref_store.set_parent("feature", "main")?;
+        ref_store.set_frozen("feature", true)?;
+        ref_store.set_frozen("gone", true)?;
+
+        // An untracked branch deleted with plain git leaves its frozen ref behind
+        repo.find_branch("gone", git2::BranchType::Local)?.delete()?;
+
+        let errors = validate_refs(&ref_store, &gateway)?;
+        assert_eq!(errors.len(), 1, "Expected only the stale frozen ref, got: {:?}", errors);
+        assert!(matches!(&errors[0], DiagnosticError::StaleFrozenRef(branch) if branch == "gone"));
+
+        let unfixed = attempt_fix(&ref_store, &gateway, &errors)?;
+        assert_eq!(unfixed, 0);
+        assert_eq!(ref_store.list_frozen_branches()?, vec!["feature"]);
+        assert!(validate_refs(&ref_store, &gateway)?.is_empty());
+
+        // Refs already removed by clear_all aren't reported again
+        ref_store.set_frozen("gone", true)?;
+        ref_store.clear_all()?;
+        assert!(validate_refs(&ref_store, &gateway)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_reports_deleted_frozen_tracked_branch_once() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        let gateway = GitGateway::new()?;
+        ref_store.set_trunk("main")?;
+        create_branch(&repo, "feature")?;
+        ref_store.set_parent("feature", "main")?;
+        ref_store.set_frozen("feature", true)?;
+
+        repo.find_branch("feature", git2::BranchType::Local)?.delete()?;
+
+        let errors = validate_refs(&ref_store, &gateway)?;
+        assert_eq!(errors.len(), 1, "Expected only the missing branch, got: {:?}", errors);
+        assert!(matches!(&errors[0], DiagnosticError::TrackedBranchMissing(branch) if branch == "feature"));
+
+        // Untracking the branch removes its frozen ref too
+        let unfixed = attempt_fix(&ref_store, &gateway, &errors)?;
+        assert_eq!(unfixed, 0);
+        assert!(ref_store.list_frozen_branches()?.is_empty());
+        assert!(validate_refs(&ref_store, &gateway)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_fix_rolls_back_all_repairs_when_one_fails() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        let gateway = GitGateway::new()?;
+        ref_store.set_trunk("main")?;
+
+        // Fixable: tracked branch that no longer exists
+        create_branch(&repo, "gone")?;
+        ref_store.set_parent("gone", "main")?;
+        repo.find_branch("gone", git2::BranchType::Local)?.delete()?;
+
+        // Fixable: orphaned parent, reparented to trunk
+        create_branch(&repo, "feature-1")?;
+        create_branch(&repo, "deleted-parent")?;
+        ref_store.set_parent("feature-1", "deleted-parent")?;
+        repo.find_branch("deleted-parent", git2::BranchType::Local)?.delete()?;
+
+        // Fails: stale base SHA on a branch with no history in common with its parent,
+        // so the merge-base can't be recomputed
+        let sig = repo.signature()?;
+        let tree = repo.find_tree(repo.treebuilder(None)?.write()?)?;
+        let island = repo.commit(None, &sig, &sig, "Unrelated history", &tree, &[])?;
+        repo.branch("island", &repo.find_commit(island)?, false)?;
+        ref_store.set_parent("island", "main")?;
+        let bogus = "0123456789abcdef0123456789abcdef01234567";
+        let mut cache = Cache::load()?;
+        cache.set_base_sha("island", bogus);
+        cache.save()?;
+
+        let before = StackMetadata::collect_unchecked(&ref_store)?;
+        let errors = vec![
+            DiagnosticError::TrackedBranchMissing("gone".to_string()),
+            DiagnosticError::OrphanedParent {
+                branch: "feature-1".to_string(),
+                parent: "deleted-parent".to_string(),
+            },
+            DiagnosticError::StaleBaseSha {
+                branch: "island".to_string(),
+                sha: bogus.to_string(),
+            },
+        ];
+
+        let err = attempt_fix(&ref_store, &gateway, &errors).unwrap_err().to_string();
+        assert!(err.contains("island"), "error should name the failed repair: {}", err);
+
+        // The two repairs that succeeded before the failure were undone
+        assert_eq!(StackMetadata::collect_unchecked(&ref_store)?, before);
+        assert_eq!(ref_store.get_parent("gone")?, Some("main".to_string()));
+        assert_eq!(ref_store.get_parent("feature-1")?, Some("deleted-parent".to_string()));
+        assert_eq!(Cache::load()?.get_base_sha("island"), Some(bogus));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doctor_check_remote_reports_divergence_per_branch() -> Result<()> {
+        let dir = tempdir()?;
+        let remote_dir = tempdir()?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let repo = init_test_repo(dir.path())?;
+
+        let git = |args: &[&str]| -> Result<()> {
+            let status = std::process::Command::new("git")
+                .args(args)
+                .current_dir(dir.path())
+                .status()?;
+            anyhow::ensure!(status.success(), "git {:?} failed", args);
+            Ok(())
+        };
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        for branch in ["ahead", "behind", "diverged", "synced"] {
+            create_branch(&repo, branch)?;
+            ref_store.set_parent(branch, "main")?;
+        }
+        for branch in ["behind", "diverged"] {
+            git(&["checkout", "-q", branch])?;
+            git(&["commit", "-q", "--allow-empty", "-m", "pushed"])?;
+        }
+
+        git(&["init", "-q", "--bare", remote_dir.path().to_str().unwrap()])?;
+        git(&["remote", "add", "origin", remote_dir.path().to_str().unwrap()])?;
+        git(&["push", "-q", "origin", "main", "ahead", "behind", "diverged", "synced"])?;
+
+        // Local-only branch has no remote counterpart and is not reported
+        create_branch(&repo, "local-only")?;
+        ref_store.set_parent("local-only", "main")?;
+
+        git(&["checkout", "-q", "ahead"])?;
+        git(&["commit", "-q", "--allow-empty", "-m", "local 1"])?;
+        git(&["commit", "-q", "--allow-empty", "-m", "local 2"])?;
+        git(&["checkout", "-q", "main"])?;
+        git(&["branch", "-f", "behind", "main"])?;
+        git(&["branch", "-f", "diverged", "main"])?;
+        git(&["checkout", "-q", "diverged"])?;
+        git(&["commit", "-q", "--allow-empty", "-m", "rewritten"])?;
+        git(&["checkout", "-q", "main"])?;
+
+        let gateway = GitGateway::new()?;
+        let diverged = remote_divergence(&ref_store, &gateway)?;
+        assert_eq!(
+            diverged,
+            vec![
+                ("ahead".to_string(), BranchSyncState::Ahead(2)),
+                ("behind".to_string(), BranchSyncState::Behind(1)),
+                (
+                    "diverged".to_string(),
+                    BranchSyncState::Diverged {
+                        local_ahead: 1,
+                        remote_ahead: 1
+                    }
+                ),
+            ]
+        );
+
+        // The report is informational: doctor still succeeds on healthy metadata
+        assert!(run(false, false, true).is_ok());
+
+        Ok(())
+    }
 }
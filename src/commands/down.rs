@@ -1,12 +1,21 @@
+use crate::commands::top::find_stack_top;
+use crate::config::Config;
 use crate::git_gateway::GitGateway;
+use crate::program_name::program_name;
 use crate::ref_store::RefStore;
+use crate::stack_viz::print_breadcrumb;
 use anyhow::Result;
 
 /// Navigate to the parent branch (down the stack)
 ///
-/// If steps > 1, navigates multiple levels down.
-pub fn run(steps: usize) -> Result<()> {
-    if steps == 0 {
+/// If steps > 1, navigates multiple levels down, stopping at the bottom when
+/// there are fewer levels.
+/// If `to` is specified, navigates directly to that ancestor branch, matching
+/// a unique substring of an ancestor's name when there is no exact match.
+/// At trunk, stays put unless `nav.wrap` is enabled (and `no_wrap` isn't set),
+/// in which case a single step wraps around to the top of the stack.
+pub fn run(steps: usize, to: Option<String>, no_wrap: bool) -> Result<()> {
+    if steps == 0 && to.is_none() {
         return Ok(());
     }
 
@@ -16,36 +25,115 @@ pub fn run(steps: usize) -> Result<()> {
     // Silent cleanup of orphaned refs (handles branches deleted via git/IDE)
     if let Err(_e) = crate::validation::silent_cleanup_orphaned_refs(&gateway) {}
 
+    // Handle --to flag: navigate directly to a specific ancestor
+    if let Some(ref target) = to {
+        let current = gateway.get_current_branch_name()?;
+        let ancestor = resolve_ancestor(&ref_store, &current, target)?;
+        gateway.checkout_branch_worktree_safe(&ancestor)?;
+        println!("Switched to ancestor branch: {}", ancestor);
+        print_breadcrumb(&ref_store, &ancestor);
+        return Ok(());
+    }
+
+    let wrap = !no_wrap && Config::load().map(|c| c.nav.wrap_enabled()).unwrap_or(false);
+    let trunk = ref_store.get_trunk()?;
     let mut current = gateway.get_current_branch_name()?;
 
     for step in 0..steps {
         // Get parent from refs
-        let parent = ref_store.get_parent(&current)?.ok_or_else(|| {
-            if step == 0 {
-                anyhow::anyhow!("Already at stack root (no parent)")
-            } else {
-                anyhow::anyhow!("Reached stack root after {} step(s)", step)
+        let (target, wrapped) = match ref_store.get_parent(&current)? {
+            Some(parent) => (parent, false),
+            // Asked for more steps than there are levels: stop at the bottom
+            None if step > 0 => {
+                println!(
+                    "Reached bottom after {} step{}: {}",
+                    step,
+                    if step == 1 { "" } else { "s" },
+                    current
+                );
+                break;
             }
-        })?;
+            // Trunk is the bottom of every stack
+            None if trunk.as_deref() == Some(current.as_str()) => {
+                let top = find_stack_top(&ref_store, &current)?;
+                if wrap && steps == 1 && top != current {
+                    (top, true)
+                } else {
+                    println!("Already at bottom of stack: {}", current);
+                    return Ok(());
+                }
+            }
+            None => anyhow::bail!("Already at stack root (no parent)"),
+        };
 
-        // Checkout parent safely (fail if uncommitted changes)
-        gateway.checkout_branch_worktree_safe(&parent)?;
+        // Checkout target safely (fail if uncommitted changes)
+        gateway.checkout_branch_worktree_safe(&target)?;
 
         if steps == 1 {
-            println!("Switched to parent branch: {}", parent);
+            if wrapped {
+                println!("Wrapped to top of stack: {}", target);
+            } else {
+                println!("Switched to parent branch: {}", target);
+            }
         } else {
-            println!("Step {}: switched to {}", step + 1, parent);
+            println!("Step {}: switched to {}", step + 1, target);
         }
 
-        current = parent;
+        current = target;
     }
 
+    print_breadcrumb(&ref_store, &current);
     Ok(())
 }
 
+/// Resolve `target` to one of `from`'s ancestors
+///
+/// An exact name wins; otherwise `target` must be a (case-insensitive) substring
+/// of exactly one ancestor.
+fn resolve_ancestor(ref_store: &RefStore, from: &str, target: &str) -> Result<String> {
+    let mut ancestors = Vec::new();
+    let mut current = from.to_string();
+    while let Some(parent) = ref_store.get_parent(&current)? {
+        // Cycle detection
+        if parent == from || ancestors.contains(&parent) {
+            break;
+        }
+        ancestors.push(parent.clone());
+        current = parent;
+    }
+
+    if ancestors.iter().any(|a| a == target) {
+        return Ok(target.to_string());
+    }
+
+    let needle = target.to_lowercase();
+    let matches: Vec<&String> = ancestors
+        .iter()
+        .filter(|a| a.to_lowercase().contains(&needle))
+        .collect();
+
+    match matches.as_slice() {
+        [only] => Ok((*only).clone()),
+        [] => anyhow::bail!(
+            "Branch '{}' is not an ancestor of '{}'. Use '{} checkout {}' instead.",
+            target,
+            from,
+            program_name(),
+            target
+        ),
+        _ => anyhow::bail!(
+            "'{}' matches several ancestors of '{}':\n  {}",
+            target,
+            from,
+            matches.iter().map(|m| m.as_str()).collect::<Vec<_>>().join("\n  ")
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::LocalConfig;
     use crate::git_gateway::GitGateway;
     use git2::Repository;
     use tempfile::tempdir;
@@ -75,27 +163,27 @@ mod tests {
         gateway.create_branch("feature")?;
 
         // Now on feature, go down to main
-        run(1)?;
+        run(1, None, false)?;
 
         assert_eq!(gateway.get_current_branch_name()?, "main");
         Ok(())
     }
 
     #[test]
-    fn test_down_at_root_fails() -> Result<()> {
+    fn test_down_at_trunk_stays_put() -> Result<()> {
         let dir = tempdir()?;
         let _repo = init_test_repo_with_branch(dir.path(), "main")?;
         let _ctx = TestRepoContext::new(dir.path());
 
+        let gateway = GitGateway::new()?;
         let ref_store = RefStore::new()?;
 
         // Set up refs with main as root (no parent)
         ref_store.set_trunk("main")?;
 
-        // Try to go down from root - main has no parent
-        let result = run(1);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("root"));
+        // Going down from trunk is a friendly no-op
+        run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "main");
 
         Ok(())
     }
@@ -112,7 +200,7 @@ mod tests {
         gateway.create_branch("untracked")?;
 
         // Try to go down from untracked branch - it has no parent
-        let result = run(1);
+        let result = run(1, None, false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("root"));
 
@@ -144,18 +232,18 @@ mod tests {
         // Now on level3, navigate all the way down
         assert_eq!(gateway.get_current_branch_name()?, "level3");
 
-        run(1)?;
+        run(1, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "level2");
 
-        run(1)?;
+        run(1, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "level1");
 
-        run(1)?;
+        run(1, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "main");
 
-        // Can't go further
-        let result = run(1);
-        assert!(result.is_err());
+        // Already at the bottom: stays put
+        run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "main");
 
         Ok(())
     }
@@ -185,14 +273,14 @@ mod tests {
         // Now on level3, navigate down 2 steps
         assert_eq!(gateway.get_current_branch_name()?, "level3");
 
-        run(2)?;
+        run(2, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "level1");
 
         Ok(())
     }
 
     #[test]
-    fn test_down_too_many_steps_fails() -> Result<()> {
+    fn test_down_too_many_steps_clamps_to_trunk() -> Result<()> {
         let dir = tempdir()?;
         let _repo = init_test_repo_with_branch(dir.path(), "main")?;
         let _ctx = TestRepoContext::new(dir.path());
@@ -200,17 +288,16 @@ mod tests {
         let gateway = GitGateway::new()?;
         let ref_store = RefStore::new()?;
 
-        // Set up refs: main -> feature
+        // Set up refs: main -> level1 -> level2, currently on level2
+        gateway.create_branch("level1")?;
+        gateway.create_branch("level2")?;
         ref_store.set_trunk("main")?;
-        ref_store.set_parent("feature", "main")?;
-
-        // Create feature branch in git
-        gateway.create_branch("feature")?;
+        ref_store.set_parent("level1", "main")?;
+        ref_store.set_parent("level2", "level1")?;
 
-        // Try to go down 5 steps (only 1 parent exists)
-        let result = run(5);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Reached stack root"));
+        // Asking for more steps than there are levels stops at trunk
+        run(100, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "main");
 
         Ok(())
     }
@@ -230,7 +317,7 @@ mod tests {
         ref_store.set_parent("feature", "main")?;
 
         // Zero steps should be a no-op
-        run(0)?;
+        run(0, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "feature");
 
         Ok(())
@@ -265,7 +352,7 @@ mod tests {
         std::fs::write(dir.path().join("tracked.txt"), "modified")?;
 
         // Try to navigate down - should fail with uncommitted changes
-        let result = run(1);
+        let result = run(1, None, false);
         assert!(result.is_err(), "dm down should fail with uncommitted changes");
 
         let err_msg = result.unwrap_err().to_string();
@@ -280,4 +367,139 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_down_wraps_to_top_when_enabled() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+
+        // Set up refs: main -> level1 -> level2, currently on main
+        create_branch(&repo, "level1")?;
+        create_branch(&repo, "level2")?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("level1", "main")?;
+        ref_store.set_parent("level2", "level1")?;
+
+        let mut config = LocalConfig::default();
+        config.nav.wrap = Some(true);
+        Config::save_local_config(&config)?;
+
+        // --no-wrap overrides the config
+        run(1, None, true)?;
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+
+        // With wrap enabled, going down from trunk lands on the top
+        run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "level2");
+
+        // ...and going up from the top lands back on trunk
+        crate::commands::up::run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_down_multiple_steps_does_not_wrap() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+
+        // Set up refs: main -> level1 -> level2, currently on main
+        create_branch(&repo, "level1")?;
+        create_branch(&repo, "level2")?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("level1", "main")?;
+        ref_store.set_parent("level2", "level1")?;
+
+        let mut config = LocalConfig::default();
+        config.nav.wrap = Some(true);
+        Config::save_local_config(&config)?;
+
+        // Wrapping and walking back down would be surprising; stay at trunk instead
+        run(2, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+
+        Ok(())
+    }
+
+    fn setup_deep_stack(repo: &Repository) -> Result<()> {
+        // main -> auth-base -> auth-api -> auth-ui, plus a sibling stack main -> billing
+        for name in ["auth-base", "auth-api", "auth-ui", "billing"] {
+            create_branch(repo, name)?;
+        }
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("auth-base", "main")?;
+        ref_store.set_parent("auth-api", "auth-base")?;
+        ref_store.set_parent("auth-ui", "auth-api")?;
+        ref_store.set_parent("billing", "main")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_down_to_ancestor() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_deep_stack(&repo)?;
+
+        let gateway = GitGateway::new()?;
+        gateway.checkout_branch("auth-ui")?;
+
+        // Skips auth-api and lands directly on auth-base
+        run(1, Some("auth-base".to_string()), false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "auth-base");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_down_to_fuzzy_matches_ancestor() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_deep_stack(&repo)?;
+
+        let gateway = GitGateway::new()?;
+        gateway.checkout_branch("auth-ui")?;
+
+        run(1, Some("BASE".to_string()), false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "auth-base");
+
+        // "auth" matches both auth-base and auth-api
+        gateway.checkout_branch("auth-ui")?;
+        let err = run(1, Some("auth".to_string()), false).unwrap_err().to_string();
+        assert!(err.contains("several ancestors"), "Unexpected error: {}", err);
+        assert_eq!(gateway.get_current_branch_name()?, "auth-ui");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_down_to_non_ancestor_fails() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_deep_stack(&repo)?;
+
+        let gateway = GitGateway::new()?;
+        gateway.checkout_branch("auth-api")?;
+
+        // Neither a sibling stack nor a descendant is downstack
+        for target in ["billing", "auth-ui"] {
+            let err = run(1, Some(target.to_string()), false).unwrap_err().to_string();
+            assert!(err.contains("is not an ancestor"), "Unexpected error: {}", err);
+        }
+        assert_eq!(gateway.get_current_branch_name()?, "auth-api");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,160 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+use crate::ref_store::RefStore;
+
+/// Current version of the exported metadata format
+pub const METADATA_FORMAT_VERSION: u32 = 1;
+
+/// Portable snapshot of the stack metadata stored under `refs/diamond/`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct StackMetadata {
+    pub version: u32,
+    pub trunk: Option<String>,
+    /// Branch -> parent
+    pub parents: BTreeMap<String, String>,
+    pub frozen: Vec<String>,
+}
+
+impl StackMetadata {
+    /// Collect the metadata currently stored in the repository
+    pub fn collect(ref_store: &RefStore) -> Result<Self> {
+        let mut parents = BTreeMap::new();
+        for branch in ref_store.list_tracked_branches()? {
+            if let Some(parent) = ref_store.get_parent(&branch)? {
+                parents.insert(branch, parent);
+            }
+        }
+
+        Ok(Self {
+            version: METADATA_FORMAT_VERSION,
+            trunk: ref_store.get_trunk()?,
+            parents,
+            frozen: ref_store.list_frozen_branches()?,
+        })
+    }
+
+    /// Collect the metadata exactly as stored, without validating parent names
+    ///
+    /// Used to snapshot metadata that may be corrupted (e.g. before `dm doctor --fix`).
+    /// Parent refs that can't be read as text (e.g. non-UTF-8 blobs) are left out.
+    pub fn collect_unchecked(ref_store: &RefStore) -> Result<Self> {
+        let mut parents = BTreeMap::new();
+        for branch in ref_store.list_tracked_branches()? {
+            if let Ok(Some(parent)) = ref_store.get_parent_unchecked(&branch) {
+                parents.insert(branch, parent);
+            }
+        }
+
+        Ok(Self {
+            version: METADATA_FORMAT_VERSION,
+            trunk: ref_store.get_trunk()?,
+            parents,
+            frozen: ref_store.list_frozen_branches()?,
+        })
+    }
+
+    /// Put the repository's metadata back to exactly this snapshot
+    ///
+    /// Unlike `dm import`, values are written verbatim and tracking for
+    /// branches absent from the snapshot is removed. Returns the branches whose
+    /// metadata had to be changed. The caller is expected to hold the RefStore lock.
+    pub fn restore(&self, ref_store: &RefStore) -> Result<Vec<String>> {
+        let current = Self::collect_unchecked(ref_store)?;
+        let mut restored = BTreeSet::new();
+
+        for branch in current.parents.keys() {
+            if !self.parents.contains_key(branch) {
+                ref_store.remove_parent(branch)?;
+                restored.insert(branch.clone());
+            }
+        }
+        for (branch, parent) in &self.parents {
+            if current.parents.get(branch) != Some(parent) {
+                ref_store.set_parent_unchecked(branch, parent)?;
+                restored.insert(branch.clone());
+            }
+        }
+
+        for branch in current.frozen.iter().filter(|b| !self.frozen.contains(b)) {
+            ref_store.set_frozen(branch, false)?;
+            restored.insert(branch.clone());
+        }
+        for branch in self.frozen.iter().filter(|b| !current.frozen.contains(b)) {
+            ref_store.set_frozen(branch, true)?;
+            restored.insert(branch.clone());
+        }
+
+        if current.trunk != self.trunk {
+            if let Some(trunk) = &self.trunk {
+                ref_store.set_trunk(trunk)?;
+                restored.insert(trunk.clone());
+            }
+        }
+
+        Ok(restored.into_iter().collect())
+    }
+}
+
+/// Write the stack metadata to a JSON file
+pub fn run(file: &Path) -> Result<()> {
+    let ref_store = RefStore::new()?;
+    let metadata = StackMetadata::collect(&ref_store)?;
+
+    let json = serde_json::to_string_pretty(&metadata).context("Failed to serialize stack metadata")?;
+    std::fs::write(file, json + "\n").context(format!("Failed to write {}", file.display()))?;
+
+    println!(
+        "{} Exported {} tracked branch(es) to {}",
+        "✓".green(),
+        metadata.parents.len(),
+        file.display().to_string().cyan()
+    );
+    if !metadata.frozen.is_empty() {
+        println!(
+            "  {} {} frozen branch(es) included",
+            "→".dimmed(),
+            metadata.frozen.len()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    use crate::test_context::{init_test_repo, TestRepoContext};
+
+    #[test]
+    fn test_export_writes_parents_trunk_and_frozen() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let trunk = repo.head()?.shorthand().unwrap().to_string();
+        let head = repo.head()?.peel_to_commit()?;
+        repo.branch("feature", &head, false)?;
+
+        let ref_store = RefStore::from_path(dir.path())?;
+        ref_store.set_trunk(&trunk)?;
+        ref_store.set_parent("feature", &trunk)?;
+        ref_store.set_frozen("feature", true)?;
+
+        let _ctx = TestRepoContext::new(dir.path());
+        let file = dir.path().join("stack.json");
+        run(&file)?;
+
+        let metadata: StackMetadata = serde_json::from_str(&std::fs::read_to_string(&file)?)?;
+        assert_eq!(metadata.version, METADATA_FORMAT_VERSION);
+        assert_eq!(metadata.trunk.as_deref(), Some(trunk.as_str()));
+        assert_eq!(metadata.parents.get("feature"), Some(&trunk));
+        assert_eq!(metadata.frozen, vec!["feature".to_string()]);
+
+        Ok(())
+    }
+}
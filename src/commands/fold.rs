@@ -1,12 +1,36 @@
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::forge::{get_forge, Forge, PrState};
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 
 /// Fold the current branch into its parent
-pub fn run(keep_name: bool) -> Result<()> {
+///
+/// Refuses if the branch that disappears has an open PR, unless `close_pr` is set.
+pub fn run(keep_name: bool, close_pr: bool) -> Result<()> {
+    // PR lookups are best effort: without a usable forge, fold works offline as before
+    let forge = match get_forge(None) {
+        Ok(forge) => Some(forge),
+        Err(e) => {
+            warn_pr_check_skipped(&e);
+            None
+        }
+    };
+    run_with_forge(keep_name, close_pr, forge.as_deref())
+}
+
+/// Fold goes ahead without the open-PR check, so say why it was skipped
+fn warn_pr_check_skipped(error: &anyhow::Error) {
+    eprintln!(
+        "  {} Warning: Could not check for an open PR, folding without it: {}",
+        "!".yellow(),
+        error
+    );
+}
+
+fn run_with_forge(keep_name: bool, close_pr: bool, forge: Option<&dyn Forge>) -> Result<()> {
     let gateway = GitGateway::new()?;
     gateway.require_clean_working_tree("fold")?;
 
@@ -35,6 +59,34 @@ pub fn run(keep_name: bool) -> Result<()> {
         );
     }
 
+    // The branch that disappears: current, or parent when keeping current's name.
+    // Its open PR would be left dangling, so require an explicit decision.
+    let removed = if keep_name { &parent } else { &current };
+    let open_pr = forge
+        .and_then(|f| {
+            f.pr_exists(removed).unwrap_or_else(|e| {
+                warn_pr_check_skipped(&e);
+                None
+            })
+        })
+        .filter(|pr| pr.state == PrState::Open);
+    if let Some(ref pr) = open_pr {
+        if !close_pr {
+            anyhow::bail!(
+                "Branch '{}' has an open PR (#{}) that fold would orphan:\n  {}\n\n\
+                 Re-run with --close-pr to close it{}.",
+                removed,
+                pr.number,
+                pr.url,
+                if keep_name {
+                    ""
+                } else {
+                    ", or use --keep to keep this branch's name (and its PR)"
+                }
+            );
+        }
+    }
+
     println!("{} Folding {} into {}...", "→".blue(), current.green(), parent.green());
 
     // Get children of current branch
@@ -117,6 +169,15 @@ pub fn run(keep_name: bool) -> Result<()> {
         println!("  {} Folded and deleted branch {}", "✓".green(), current);
     }
 
+    // Close the orphaned PR only after the local fold succeeded
+    if let (Some(pr), Some(forge)) = (open_pr, forge) {
+        let comment = format!("Folded into '{}'", final_name);
+        match forge.close_pr(&pr.number.to_string(), Some(&comment)) {
+            Ok(()) => println!("  {} Closed PR #{} for {}", "✓".green(), pr.number, removed),
+            Err(e) => eprintln!("  {} Could not close PR #{}: {}", "⚠".yellow(), pr.number, e),
+        }
+    }
+
     // Warn about children that need restacking
     if !children.is_empty() {
         println!();
@@ -152,6 +213,8 @@ mod tests {
 
     use tempfile::tempdir;
 
+    use crate::forge::mock::{MockForge, MockResponse};
+    use crate::forge::ForgeType;
     use crate::test_context::{init_test_repo, TestRepoContext};
 
     fn create_commit(repo: &git2::Repository, message: &str, parent: &git2::Commit) -> Result<git2::Oid> {
@@ -190,7 +253,7 @@ mod tests {
         ref_store.set_parent("feature-b", "feature-a")?;
 
         // Fold feature-b into feature-a
-        run(false)?;
+        run(false, false)?;
 
         // Verify feature-b is deleted
         assert!(repo.find_branch("feature-b", git2::BranchType::Local).is_err());
@@ -232,7 +295,7 @@ mod tests {
         ref_store.set_parent("feature-b", "feature-a")?;
 
         // Fold with --keep flag
-        run(true)?;
+        run(true, false)?;
 
         // Verify feature-a no longer exists
         assert!(repo.find_branch("feature-a", git2::BranchType::Local).is_err());
@@ -265,7 +328,7 @@ mod tests {
         ref_store.set_trunk("main").unwrap();
         ref_store.set_parent("feature-a", "main").unwrap();
 
-        let result = run(false);
+        let result = run(false, false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Cannot fold into trunk"));
     }
@@ -278,8 +341,88 @@ mod tests {
 
         let _ref_store = RefStore::new().unwrap();
 
-        let result = run(false);
+        let result = run(false, false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not tracked"));
     }
+
+    /// Stack main -> feature-a -> feature-b, currently on feature-b
+    fn setup_fold_stack(repo: &git2::Repository) -> Result<()> {
+        let main_commit = repo.head()?.peel_to_commit()?;
+
+        repo.branch("feature-a", &main_commit, false)?;
+        repo.set_head("refs/heads/feature-a")?;
+        let commit_a = create_commit(repo, "Commit A", &main_commit)?;
+        let commit_a = repo.find_commit(commit_a)?;
+
+        repo.branch("feature-b", &commit_a, false)?;
+        repo.set_head("refs/heads/feature-b")?;
+        create_commit(repo, "Commit B", &commit_a)?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feature-a", "main")?;
+        ref_store.set_parent("feature-b", "feature-a")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_refuses_when_branch_has_open_pr() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_fold_stack(&repo)?;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature-b", MockResponse::Success("42".to_string()));
+
+        let result = run_with_forge(false, false, Some(&forge));
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("open PR (#42)"), "Unexpected error: {}", err);
+        assert!(err.contains("--close-pr"));
+
+        // Nothing changed
+        assert!(repo.find_branch("feature-b", git2::BranchType::Local).is_ok());
+        assert_eq!(RefStore::new()?.get_parent("feature-b")?, Some("feature-a".to_string()));
+        assert_eq!(forge.get_call_count("close_pr:42"), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_close_pr_closes_orphaned_pr() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_fold_stack(&repo)?;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature-b", MockResponse::Success("42".to_string()));
+
+        run_with_forge(false, true, Some(&forge))?;
+
+        assert!(repo.find_branch("feature-b", git2::BranchType::Local).is_err());
+        assert_eq!(forge.get_call_count("close_pr:42"), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_proceeds_when_pr_lookup_fails() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_fold_stack(&repo)?;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature-b", MockResponse::Error("rate limited".to_string()));
+
+        // The failed lookup is reported as a warning rather than blocking the fold
+        run_with_forge(false, false, Some(&forge))?;
+
+        assert_eq!(forge.get_call_count("pr_exists:feature-b"), 1);
+        assert!(repo.find_branch("feature-b", git2::BranchType::Local).is_err());
+
+        Ok(())
+    }
 }
@@ -6,7 +6,10 @@ use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 
 /// Freeze a branch to prevent local modifications
-pub fn run(branch: Option<String>) -> Result<()> {
+///
+/// With `stack`, its ancestors (except trunk) and descendants are frozen too;
+/// with `upstack`, just its descendants.
+pub fn run(branch: Option<String>, stack: bool, upstack: bool) -> Result<()> {
     let gateway = GitGateway::new()?;
     let ref_store = RefStore::new()?;
     let trunk = ref_store.require_trunk()?;
@@ -22,6 +25,16 @@ pub fn run(branch: Option<String>) -> Result<()> {
         anyhow::bail!("Cannot freeze trunk branch '{}'.", trunk);
     }
 
+    if stack || upstack {
+        let mut to_freeze = if stack {
+            ref_store.ancestors(&branch)?
+        } else {
+            vec![branch.clone()]
+        };
+        to_freeze.extend(ref_store.descendants(&branch)?);
+        return freeze_all(&ref_store, &to_freeze);
+    }
+
     // Check if already frozen
     if ref_store.is_frozen(&branch)? {
         println!("{} Branch '{}' is already frozen", "!".yellow(), branch);
@@ -43,6 +56,38 @@ pub fn run(branch: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Freeze several branches at once, holding the RefStore lock throughout
+fn freeze_all(ref_store: &RefStore, branches: &[String]) -> Result<()> {
+    let _lock = ref_store.lock()?;
+
+    let mut frozen = 0;
+    for b in branches {
+        if ref_store.is_frozen(b)? {
+            println!("{} '{}' is already frozen", "!".yellow(), b);
+            continue;
+        }
+        ref_store
+            .set_frozen(b, true)
+            .context(format!("Failed to freeze '{}'", b))?;
+        println!("{} Froze '{}'", "✓".green(), b.cyan());
+        frozen += 1;
+    }
+
+    println!(
+        "\n{} Froze {} branch{}",
+        "✓".green().bold(),
+        frozen,
+        if frozen == 1 { "" } else { "es" }
+    );
+    println!(
+        "  {} Use '{} unfreeze --upstack' to allow modifications again",
+        "→".dimmed(),
+        program_name()
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,7 +124,7 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Freeze with no argument (current branch)
-        run(None)?;
+        run(None, false, false)?;
 
         assert!(ref_store.is_frozen("feature")?);
 
@@ -102,7 +147,7 @@ mod tests {
 
         let _ctx = TestRepoContext::new(dir.path());
 
-        run(Some("feature".to_string()))?;
+        run(Some("feature".to_string()), false, false)?;
 
         assert!(ref_store.is_frozen("feature")?);
 
@@ -120,7 +165,7 @@ mod tests {
 
         let _ctx = TestRepoContext::new(dir.path());
 
-        let result = run(Some(trunk.clone()));
+        let result = run(Some(trunk.clone()), false, false);
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Cannot freeze trunk"));
@@ -147,10 +192,68 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Freeze again should succeed (idempotent)
-        run(Some("feature".to_string()))?;
+        run(Some("feature".to_string()), false, false)?;
 
         assert!(ref_store.is_frozen("feature")?);
 
         Ok(())
     }
+
+    #[test]
+    fn test_freeze_stack_freezes_ancestors_and_descendants() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let trunk = repo.head()?.shorthand().unwrap().to_string();
+
+        let ref_store = RefStore::from_path(dir.path())?;
+        ref_store.set_trunk(&trunk)?;
+
+        // trunk -> bottom -> middle -> top, with middle checked out
+        let head = repo.head()?.peel_to_commit()?;
+        repo.branch("bottom", &head, false)?;
+        repo.branch("top", &head, false)?;
+        create_and_checkout_branch(&repo, "middle")?;
+        ref_store.set_parent("bottom", &trunk)?;
+        ref_store.set_parent("middle", "bottom")?;
+        ref_store.set_parent("top", "middle")?;
+
+        let _ctx = TestRepoContext::new(dir.path());
+
+        run(None, true, false)?;
+
+        assert!(ref_store.is_frozen("bottom")?);
+        assert!(ref_store.is_frozen("middle")?);
+        assert!(ref_store.is_frozen("top")?);
+        assert!(!ref_store.is_frozen(&trunk)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_freeze_upstack_leaves_ancestors_alone() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let trunk = repo.head()?.shorthand().unwrap().to_string();
+
+        let ref_store = RefStore::from_path(dir.path())?;
+        ref_store.set_trunk(&trunk)?;
+
+        let head = repo.head()?.peel_to_commit()?;
+        repo.branch("bottom", &head, false)?;
+        repo.branch("top", &head, false)?;
+        create_and_checkout_branch(&repo, "middle")?;
+        ref_store.set_parent("bottom", &trunk)?;
+        ref_store.set_parent("middle", "bottom")?;
+        ref_store.set_parent("top", "middle")?;
+
+        let _ctx = TestRepoContext::new(dir.path());
+
+        run(None, false, true)?;
+
+        assert!(!ref_store.is_frozen("bottom")?);
+        assert!(ref_store.is_frozen("middle")?);
+        assert!(ref_store.is_frozen("top")?);
+
+        Ok(())
+    }
 }
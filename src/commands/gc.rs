@@ -1,17 +1,20 @@
 //! Garbage collection for Diamond refs.
 //!
-//! Cleans up old backup refs to prevent repository bloat.
+//! Cleans up old backup refs to prevent repository bloat, and optionally
+//! packs loose Diamond refs (`--repack`).
 
-use crate::git_gateway::backup::DEFAULT_BACKUP_TTL_DAYS;
-use crate::git_gateway::GitGateway;
+use crate::git_gateway::backup::{excess_backups, DEFAULT_BACKUP_TTL_DAYS};
+use crate::git_gateway::{GitGateway, RefFormat};
 use anyhow::Result;
 use colored::Colorize;
 
-/// Default number of backups to keep per branch
+/// Default number of backup operations to keep per branch
 const DEFAULT_KEEP_PER_BRANCH: usize = 10;
 
 /// Run garbage collection on Diamond refs
-pub fn run(max_age_days: Option<u64>, keep_per_branch: Option<usize>, dry_run: bool) -> Result<()> {
+///
+/// With `repack`, also packs refs afterwards and reports loose ref counts.
+pub fn run(max_age_days: Option<u64>, keep_per_branch: Option<usize>, dry_run: bool, repack: bool) -> Result<()> {
     let gateway = GitGateway::new()?;
 
     let max_age = max_age_days.unwrap_or(DEFAULT_BACKUP_TTL_DAYS);
@@ -19,13 +22,49 @@ pub fn run(max_age_days: Option<u64>, keep_per_branch: Option<usize>, dry_run: b
 
     if dry_run {
         println!("{} Dry run - showing what would be deleted:\n", "🔍".blue());
-        run_dry(gateway, max_age, keep)
+        run_dry(&gateway, max_age, keep)?;
+        if repack {
+            let loose = gateway.count_loose_diamond_refs()?;
+            println!(
+                "{} Would pack {} loose Diamond ref{}",
+                "ℹ".blue(),
+                loose,
+                if loose == 1 { "" } else { "s" }
+            );
+        }
+        Ok(())
     } else {
-        run_gc(gateway, max_age, keep)
+        run_gc(&gateway, max_age, keep)?;
+        if repack {
+            run_repack(&gateway)?;
+        }
+        Ok(())
     }
 }
 
-fn run_gc(gateway: GitGateway, max_age_days: u64, keep_per_branch: usize) -> Result<()> {
+/// Pack refs, reporting loose Diamond ref counts before and after
+fn run_repack(gateway: &GitGateway) -> Result<()> {
+    println!();
+    if gateway.ref_format() == RefFormat::Reftable {
+        gateway.pack_refs()?;
+        println!("{} Compacted reftable (no loose refs to pack)", "✓".green().bold());
+        return Ok(());
+    }
+
+    let before = gateway.count_loose_diamond_refs()?;
+    gateway.pack_refs()?;
+    let after = gateway.count_loose_diamond_refs()?;
+    println!(
+        "{} Packed refs: {} → {} loose Diamond ref{}",
+        "✓".green().bold(),
+        before,
+        after,
+        if after == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
+
+fn run_gc(gateway: &GitGateway, max_age_days: u64, keep_per_branch: usize) -> Result<()> {
     println!("{} Running garbage collection...\n", "🗑".blue());
 
     let (deleted_by_age, deleted_by_count) = gateway.gc(max_age_days, keep_per_branch)?;
@@ -46,7 +85,7 @@ fn run_gc(gateway: GitGateway, max_age_days: u64, keep_per_branch: usize) -> Res
         }
         if deleted_by_count > 0 {
             println!(
-                "  • {} exceeding {} per branch limit",
+                "  • {} exceeding {} operations per branch limit",
                 deleted_by_count, keep_per_branch
             );
         }
@@ -55,8 +94,7 @@ fn run_gc(gateway: GitGateway, max_age_days: u64, keep_per_branch: usize) -> Res
     Ok(())
 }
 
-fn run_dry(gateway: GitGateway, max_age_days: u64, keep_per_branch: usize) -> Result<()> {
-    use std::collections::HashMap;
+fn run_dry(gateway: &GitGateway, max_age_days: u64, keep_per_branch: usize) -> Result<()> {
     use std::time::{SystemTime, UNIX_EPOCH};
 
     let backups = gateway.list_backup_refs()?;
@@ -80,32 +118,21 @@ fn run_dry(gateway: GitGateway, max_age_days: u64, keep_per_branch: usize) -> Re
             let age_days = (now - backup.timestamp) / (24 * 60 * 60);
             println!("  {} {} ({} days old)", "×".red(), backup.ref_name.dimmed(), age_days);
         } else {
-            remaining_after_age.push(backup);
+            remaining_after_age.push(backup.clone());
         }
     }
 
-    // Count what would be deleted by count (from remaining)
-    let mut by_branch: HashMap<String, Vec<_>> = HashMap::new();
-    for backup in remaining_after_age {
-        by_branch.entry(backup.branch_name.clone()).or_default().push(backup);
-    }
-
-    let mut would_delete_by_count = 0;
-    for (_branch, branch_backups) in by_branch {
-        if branch_backups.len() > keep_per_branch {
-            let excess = branch_backups.len() - keep_per_branch;
-            would_delete_by_count += excess;
-            // Show excess refs (they're already sorted newest first)
-            for backup in branch_backups.iter().skip(keep_per_branch) {
-                println!(
-                    "  {} {} (excess for {})",
-                    "×".red(),
-                    backup.ref_name.dimmed(),
-                    backup.branch_name
-                );
-            }
-        }
+    // Count what would be deleted by count (from remaining, whole operations at a time)
+    let excess = excess_backups(&remaining_after_age, keep_per_branch);
+    for backup in &excess {
+        println!(
+            "  {} {} (excess for {})",
+            "×".red(),
+            backup.ref_name.dimmed(),
+            backup.branch_name
+        );
     }
+    let would_delete_by_count = excess.len();
 
     let total = would_delete_by_age + would_delete_by_count;
 
@@ -140,7 +167,7 @@ mod tests {
         init_test_repo(dir.path())?;
 
         // Should succeed with no backups
-        let result = run(None, None, false);
+        let result = run(None, None, false, false);
         assert!(result.is_ok());
 
         Ok(())
@@ -158,7 +185,7 @@ mod tests {
         gateway.create_backup_ref("feature")?;
 
         // Dry run should succeed and not delete anything
-        let result = run(None, None, true);
+        let result = run(None, None, true, false);
         assert!(result.is_ok());
 
         // Backup should still exist
@@ -168,6 +195,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_gc_repack_packs_loose_diamond_refs() -> Result<()> {
+        let dir = tempdir()?;
+        let _ctx = TestRepoContext::new(dir.path());
+        init_test_repo(dir.path())?;
+
+        let gateway = GitGateway::new()?;
+        if gateway.ref_format() != RefFormat::Files {
+            return Ok(());
+        }
+
+        let head = gateway.find_reference("refs/heads/main")?.unwrap();
+        for i in 0..50 {
+            gateway.create_reference(&format!("refs/diamond/parent/branch-{}", i), &head, true, "test")?;
+        }
+        assert!(gateway.count_loose_diamond_refs()? >= 50);
+
+        run(None, None, false, true)?;
+
+        // Refs are packed, not lost
+        assert_eq!(gateway.count_loose_diamond_refs()?, 0);
+        assert_eq!(gateway.list_references("refs/diamond/parent/*")?.len(), 50);
+
+        Ok(())
+    }
+
     #[test]
     fn test_gc_deletes_old_backups() -> Result<()> {
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -201,7 +254,7 @@ mod tests {
         assert_eq!(gateway.list_backup_refs()?.len(), 2);
 
         // Run gc with default settings (30 day max age)
-        let result = run(Some(30), None, false);
+        let result = run(Some(30), None, false, false);
         assert!(result.is_ok());
 
         // Only 1 backup should remain (the recent one)
@@ -237,7 +290,7 @@ mod tests {
         assert_eq!(gateway.list_backup_refs()?.len(), 5);
 
         // Run gc keeping only 2 per branch
-        let result = run(Some(365), Some(2), false); // Long max age so only count matters
+        let result = run(Some(365), Some(2), false, false); // Long max age so only count matters
         assert!(result.is_ok());
 
         // Only 2 backups should remain
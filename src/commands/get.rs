@@ -11,7 +11,20 @@ use crate::ref_store::RefStore;
 ///
 /// By default, downloaded branches are frozen to prevent accidental modification.
 /// Use `--unfrozen` to allow editing, or `dm unfreeze` later.
-pub fn run(pr_ref: String, force: bool, unfrozen: bool) -> Result<()> {
+///
+/// With `branch_prefix`, local branches are named `<prefix><head>` so they can't
+/// collide with your own; they still push to the PR's original head branch.
+pub fn run(pr_ref: String, force: bool, unfrozen: bool, branch_prefix: Option<String>) -> Result<()> {
+    if let Some(ref prefix) = branch_prefix {
+        // Parent refs can't name branches containing '/', so neither can the prefix
+        if prefix.contains('/') || prefix.contains("..") || prefix.chars().any(|c| c.is_control()) {
+            anyhow::bail!(
+                "Branch prefix '{}' is not allowed:\n  use letters, digits, '-' or '_' (e.g. 'alice-')",
+                prefix
+            );
+        }
+    }
+
     let gateway = GitGateway::new()?;
 
     // Get the forge
@@ -46,14 +59,14 @@ pub fn run(pr_ref: String, force: bool, unfrozen: bool) -> Result<()> {
     // Checkout and track each branch
     let ref_store = RefStore::new()?;
     let mut cache = Cache::load().unwrap_or_default();
-    let trunk = ref_store.get_trunk()?;
 
     // Collect branches that we'll freeze
     let mut branches_to_freeze = Vec::new();
 
+    let prefix = branch_prefix.unwrap_or_default();
     for pr in &chain {
-        checkout_and_track_pr(pr, &ref_store, &mut cache, trunk.as_deref(), &gateway, force)?;
-        branches_to_freeze.push(pr.head_ref.clone());
+        let branch = checkout_and_track_pr(pr, &chain, &prefix, &ref_store, &mut cache, &gateway, force)?;
+        branches_to_freeze.push(branch);
     }
 
     cache.save()?;
@@ -74,36 +87,56 @@ pub fn run(pr_ref: String, force: bool, unfrozen: bool) -> Result<()> {
 
     // Checkout the tip (last in chain, which is the original PR)
     // Use safe mode - fail if user has uncommitted changes
-    let tip = chain.last().unwrap();
-    gateway.checkout_branch_worktree_safe(&tip.head_ref)?;
+    let tip = branches_to_freeze.last().unwrap();
+    gateway.checkout_branch_worktree_safe(tip)?;
 
     println!();
     println!(
         "{} Downloaded stack. Now on '{}'{}",
         "✓".green().bold(),
-        tip.head_ref.green(),
+        tip.green(),
         if unfrozen { "" } else { " (frozen)" }
     );
 
     Ok(())
 }
 
+/// Local name for a PR's branch: the head ref under `prefix`
+fn local_branch_name(prefix: &str, head_ref: &str) -> String {
+    format!("{}{}", prefix, head_ref)
+}
+
 /// Checkout a branch from remote and track it, or sync if it already exists
+///
+/// Returns the local branch name. With a `prefix`, the local branch is
+/// `<prefix><head>`, its parent is prefixed too when the parent is part of
+/// `chain`, and pushes go to the original head ref.
 fn checkout_and_track_pr(
     pr: &PrInfo,
+    chain: &[PrInfo],
+    prefix: &str,
     ref_store: &RefStore,
     cache: &mut Cache,
-    trunk: Option<&str>,
     gateway: &GitGateway,
     force: bool,
-) -> Result<()> {
-    let branch = &pr.head_ref;
+) -> Result<String> {
+    let branch = &local_branch_name(prefix, &pr.head_ref);
 
     // Check if branch exists locally
     if !gateway.branch_exists(branch)? {
         // Create local tracking branch
         println!("  {} Creating local branch '{}'...", "→".blue(), branch.green());
-        create_tracking_branch(branch, gateway.remote())?;
+        create_tracking_branch(gateway, branch, &pr.head_ref)?;
+        if !prefix.is_empty() {
+            gateway.set_push_head(branch, &pr.head_ref)?;
+        }
+    } else if !prefix.is_empty() {
+        // Prefixed branches don't share a name with their remote branch
+        println!(
+            "  {} Branch '{}' already exists, leaving it as is",
+            "!".yellow(),
+            branch.yellow()
+        );
     } else {
         // Branch exists - sync it from remote
         match gateway.sync_branch_from_remote(branch, force)? {
@@ -148,24 +181,32 @@ fn checkout_and_track_pr(
         }
     }
 
-    // Register parent relationship (skip if parent is trunk)
-    if trunk != Some(&pr.base_ref) {
-        ref_store.set_parent(branch, &pr.base_ref)?;
+    // Parents downloaded in the same chain carry the prefix as well
+    let parent = if chain.iter().any(|p| p.head_ref == pr.base_ref) {
+        local_branch_name(prefix, &pr.base_ref)
     } else {
-        // Branch's parent is trunk, set it explicitly
-        ref_store.set_parent(branch, &pr.base_ref)?;
-    }
+        pr.base_ref.clone()
+    };
+
+    // Register parent relationship (trunk included)
+    ref_store.set_parent(branch, &parent)?;
 
     // Store PR URL in cache
     cache.set_pr_url(branch, &pr.url);
 
-    Ok(())
+    Ok(branch.clone())
 }
 
-/// Create a local branch tracking the remote
-fn create_tracking_branch(branch: &str, remote: &str) -> Result<()> {
+/// Create a local branch tracking the remote branch `remote_branch`
+fn create_tracking_branch(gateway: &GitGateway, branch: &str, remote_branch: &str) -> Result<()> {
     let status = std::process::Command::new("git")
-        .args(["checkout", "-b", branch, &format!("{}/{}", remote, branch)])
+        .args([
+            "checkout",
+            "-b",
+            branch,
+            &format!("{}/{}", gateway.remote(), remote_branch),
+        ])
+        .current_dir(gateway.workdir())
         .status()
         .context("Failed to run git checkout")?;
 
@@ -201,10 +242,20 @@ mod tests {
         ref_store.set_trunk("main").unwrap();
 
         // Try to get a PR - should fail because no origin remote
-        let result = run("123".to_string(), false, false);
+        let result = run("123".to_string(), false, false, None);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_get_rejects_branch_prefix_with_slash() {
+        let dir = tempdir().unwrap();
+        let _repo = init_test_repo(dir.path()).unwrap();
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let err = run("123".to_string(), false, false, Some("alice/".to_string())).unwrap_err();
+        assert!(err.to_string().contains("not allowed"), "got: {}", err);
+    }
+
     #[test]
     fn test_checkout_and_track_pr_registers_branch() -> Result<()> {
         let dir = tempdir()?;
@@ -395,7 +446,7 @@ mod tests {
         };
 
         // Call checkout_and_track_pr - should sync the branch
-        checkout_and_track_pr(&pr, &ref_store, &mut cache, Some("main"), &gateway, false)?;
+        checkout_and_track_pr(&pr, &[], "", &ref_store, &mut cache, &gateway, false)?;
 
         // Local branch should now have the remote commit
         let local_feature = local_repo.find_reference("refs/heads/feature-1")?;
@@ -482,7 +533,7 @@ mod tests {
         };
 
         // Call without force - should warn but not modify
-        checkout_and_track_pr(&pr, &ref_store, &mut cache, Some("main"), &gateway, false)?;
+        checkout_and_track_pr(&pr, &[], "", &ref_store, &mut cache, &gateway, false)?;
 
         // Local branch should NOT have changed (diverged without force)
         let local_after = local_repo.find_reference("refs/heads/feature-1")?.target().unwrap();
@@ -564,7 +615,7 @@ mod tests {
         };
 
         // Call WITH force - should overwrite local with remote
-        checkout_and_track_pr(&pr, &ref_store, &mut cache, Some("main"), &gateway, true)?;
+        checkout_and_track_pr(&pr, &[], "", &ref_store, &mut cache, &gateway, true)?;
 
         // Local branch should now match remote
         let local_feature = local_repo.find_reference("refs/heads/feature-1")?;
@@ -577,4 +628,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_with_branch_prefix_namespaces_chain() -> Result<()> {
+        let (local_dir, origin_dir) = setup_local_and_remote()?;
+        let _ctx = TestRepoContext::new(local_dir.path());
+
+        // Origin has the chain main -> feature-1 -> feature-2
+        let origin_repo = git2::Repository::open(origin_dir.path())?;
+        {
+            let head = origin_repo.head()?.peel_to_commit()?;
+            origin_repo.branch("feature-1", &head, false)?;
+            origin_repo.branch("feature-2", &head, false)?;
+        }
+
+        let gateway = GitGateway::new()?;
+        gateway.fetch_origin()?;
+
+        // A local branch of our own already uses one of the names
+        let local_repo = git2::Repository::open(local_dir.path())?;
+        create_branch(&local_repo, "feature-1")?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        let mut cache = Cache::load().unwrap_or_default();
+
+        let chain = vec![
+            PrInfo {
+                number: 1,
+                url: "https://github.com/test/repo/pull/1".to_string(),
+                head_ref: "feature-1".to_string(),
+                base_ref: "main".to_string(),
+                state: crate::forge::PrState::Open,
+                title: "First PR".to_string(),
+            },
+            PrInfo {
+                number: 2,
+                url: "https://github.com/test/repo/pull/2".to_string(),
+                head_ref: "feature-2".to_string(),
+                base_ref: "feature-1".to_string(),
+                state: crate::forge::PrState::Open,
+                title: "Second PR".to_string(),
+            },
+        ];
+
+        let mut local = Vec::new();
+        for pr in &chain {
+            local.push(checkout_and_track_pr(
+                pr, &chain, "alice-", &ref_store, &mut cache, &gateway, false,
+            )?);
+        }
+
+        assert_eq!(local, vec!["alice-feature-1", "alice-feature-2"]);
+        assert!(gateway.branch_exists("alice-feature-1")?);
+        assert!(gateway.branch_exists("alice-feature-2")?);
+
+        // Parents are mapped among the prefixed names; trunk stays as is
+        assert_eq!(ref_store.get_parent("alice-feature-1")?, Some("main".to_string()));
+        assert_eq!(
+            ref_store.get_parent("alice-feature-2")?,
+            Some("alice-feature-1".to_string())
+        );
+        // Our own branch is untouched
+        assert_eq!(ref_store.get_parent("feature-1")?, None);
+
+        // PR linkage and pushes still point at the original head refs
+        assert_eq!(
+            cache.get_pr_url("alice-feature-2"),
+            Some("https://github.com/test/repo/pull/2")
+        );
+        assert_eq!(gateway.push_head("alice-feature-2"), Some("feature-2".to_string()));
+        assert_eq!(gateway.push_head("feature-1"), None);
+
+        Ok(())
+    }
 }
@@ -5,17 +5,28 @@ use colored::Colorize;
 use crate::operation_log::{Operation, OperationLog};
 use crate::program_name::program_name;
 
-/// Show operation history
-pub fn run(count: Option<usize>) -> Result<()> {
+/// Show operation history, or undo a specific sync/restack by its ID
+///
+/// With `branch`, only operations that touched that branch are shown.
+pub fn run(count: Option<usize>, branch: Option<String>, undo: Option<String>, force: bool) -> Result<()> {
+    if let Some(id) = undo {
+        return crate::commands::undo::undo_operation_by_id(&id, force);
+    }
+
     let log = OperationLog::new()?;
     let limit = count.unwrap_or(20); // Default to last 20 entries
 
-    let entries = if limit > 0 {
-        log.read_last(limit)?
-    } else {
-        log.read_all()?
+    let entries = match &branch {
+        Some(branch) => log.read_for_branch(branch, limit)?,
+        None if limit > 0 => log.read_last(limit)?,
+        None => log.read_all()?,
     };
 
+    if let (Some(branch), true) = (&branch, entries.is_empty()) {
+        println!("{} No operations recorded for {}", "ℹ".blue(), branch.green());
+        return Ok(());
+    }
+
     if entries.is_empty() {
         let prog = program_name();
         println!("{} No operations recorded yet", "ℹ".blue());
@@ -26,8 +37,12 @@ pub fn run(count: Option<usize>) -> Result<()> {
     }
 
     println!(
-        "{} Operation History (last {}):\n",
+        "{} Operation History{} (last {}):\n",
         "📜".blue(),
+        branch
+            .as_ref()
+            .map(|b| format!(" for {}", b.green()))
+            .unwrap_or_default(),
         if limit > 0 {
             format!("{} entries", entries.len())
         } else {
@@ -96,7 +111,13 @@ pub fn run(count: Option<usize>) -> Result<()> {
             }
         };
 
-        println!("{} {} {}", timestamp.to_string().bright_black(), icon, description);
+        println!(
+            "{} {} {} {}",
+            timestamp.to_string().bright_black(),
+            entry.id().yellow(),
+            icon,
+            description
+        );
 
         if let Some(msg) = &entry.message {
             println!("    {}", msg.bright_black());
@@ -106,6 +127,14 @@ pub fn run(count: Option<usize>) -> Result<()> {
     println!();
     println!("{} To see all entries: dm history --all", "💡".blue());
     println!("{} To see last N entries: dm history --count N", "💡".blue());
+    println!(
+        "{} To see one branch's operations: dm history --branch <name>",
+        "💡".blue()
+    );
+    println!(
+        "{} To undo a specific sync/restack: dm history --undo <id>",
+        "💡".blue()
+    );
 
     Ok(())
 }
@@ -125,7 +154,7 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
         fs::create_dir_all(dir.path().join(".git").join("diamond"))?;
 
-        let result = run(Some(10));
+        let result = run(Some(10), None, None, false);
         assert!(result.is_ok());
 
         Ok(())
@@ -149,7 +178,7 @@ mod tests {
         }))?;
 
         // Should display without errors
-        let result = run(Some(10));
+        let result = run(Some(10), None, None, false);
         assert!(result.is_ok());
 
         Ok(())
@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+
+use crate::commands::export::{StackMetadata, METADATA_FORMAT_VERSION};
+use crate::git_gateway::GitGateway;
+use crate::program_name::program_name;
+use crate::ref_store::RefStore;
+
+/// Restore stack metadata previously written by `dm export`
+///
+/// Every branch referenced by the file (trunk, tracked branches, parents and
+/// frozen branches) must exist locally. With `force`, entries that reference
+/// missing branches are skipped instead. Metadata for branches not mentioned
+/// in the file is left untouched.
+pub fn run(file: &Path, force: bool) -> Result<()> {
+    let gateway = GitGateway::new()?;
+    let ref_store = RefStore::new()?;
+
+    let contents = std::fs::read_to_string(file).context(format!("Failed to read {}", file.display()))?;
+    let metadata: StackMetadata =
+        serde_json::from_str(&contents).context(format!("{} is not a valid stack metadata file", file.display()))?;
+
+    if metadata.version > METADATA_FORMAT_VERSION {
+        anyhow::bail!(
+            "{} uses metadata format version {}, but this version of {} only understands up to {}.",
+            file.display(),
+            metadata.version,
+            program_name(),
+            METADATA_FORMAT_VERSION
+        );
+    }
+
+    let mut missing: Vec<String> = Vec::new();
+    let mut note_missing = |branch: &str| -> Result<bool> {
+        if gateway.branch_exists(branch)? {
+            return Ok(true);
+        }
+        if !missing.iter().any(|m| m == branch) {
+            missing.push(branch.to_string());
+        }
+        Ok(false)
+    };
+
+    let trunk_ok = match &metadata.trunk {
+        Some(trunk) => note_missing(trunk)?,
+        None => false,
+    };
+    let mut parents = Vec::new();
+    for (branch, parent) in &metadata.parents {
+        let branch_ok = note_missing(branch)?;
+        let parent_ok = note_missing(parent)?;
+        if branch_ok && parent_ok {
+            parents.push((branch, parent));
+        }
+    }
+    let mut frozen = Vec::new();
+    for branch in &metadata.frozen {
+        if note_missing(branch)? {
+            frozen.push(branch);
+        }
+    }
+
+    if !missing.is_empty() && !force {
+        let list = missing
+            .iter()
+            .map(|b| format!("  - {}", b))
+            .collect::<Vec<_>>()
+            .join("\n");
+        anyhow::bail!(
+            "Cannot import: {} branch(es) referenced in {} do not exist locally:\n{}\n\n\
+             Fetch or create them first, or run '{} import --force' to skip them.",
+            missing.len(),
+            file.display(),
+            list,
+            program_name()
+        );
+    }
+
+    let _lock = ref_store.lock()?;
+
+    if let (true, Some(trunk)) = (trunk_ok, &metadata.trunk) {
+        ref_store.set_trunk(trunk)?;
+    }
+    for (branch, parent) in &parents {
+        ref_store
+            .set_parent(branch, parent)
+            .context(format!("Failed to restore parent of '{}'", branch))?;
+    }
+    for branch in &frozen {
+        ref_store.set_frozen(branch, true)?;
+    }
+
+    println!(
+        "{} Imported {} tracked branch(es) from {}",
+        "✓".green(),
+        parents.len(),
+        file.display().to_string().cyan()
+    );
+    if !frozen.is_empty() {
+        println!("  {} {} frozen branch(es) restored", "→".dimmed(), frozen.len());
+    }
+    if !missing.is_empty() {
+        println!(
+            "{} Skipped entries for {} missing branch(es): {}",
+            "!".yellow(),
+            missing.len(),
+            missing.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use git2::Repository;
+    use tempfile::tempdir;
+
+    use crate::test_context::{init_test_repo, TestRepoContext};
+
+    fn create_branch(repo: &Repository, name: &str) -> Result<()> {
+        let head = repo.head()?.peel_to_commit()?;
+        repo.branch(name, &head, false)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_clear_import_round_trip() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let trunk = repo.head()?.shorthand().unwrap().to_string();
+        create_branch(&repo, "feature-1")?;
+        create_branch(&repo, "feature-2")?;
+        create_branch(&repo, "feature-3")?;
+
+        let ref_store = RefStore::from_path(dir.path())?;
+        ref_store.set_trunk(&trunk)?;
+        ref_store.set_parent("feature-1", &trunk)?;
+        ref_store.set_parent("feature-2", "feature-1")?;
+        ref_store.set_parent("feature-3", &trunk)?;
+        ref_store.set_frozen("feature-2", true)?;
+
+        let _ctx = TestRepoContext::new(dir.path());
+        let file = dir.path().join("stack.json");
+        crate::commands::export::run(&file)?;
+
+        ref_store.clear_all()?;
+        assert!(ref_store.get_trunk()?.is_none());
+        assert!(ref_store.list_tracked_branches()?.is_empty());
+
+        run(&file, false)?;
+
+        assert_eq!(ref_store.get_trunk()?, Some(trunk.clone()));
+        assert_eq!(ref_store.get_parent("feature-1")?, Some(trunk.clone()));
+        assert_eq!(ref_store.get_parent("feature-2")?, Some("feature-1".to_string()));
+        assert_eq!(ref_store.get_parent("feature-3")?, Some(trunk.clone()));
+        assert!(ref_store.is_frozen("feature-2")?);
+        assert!(!ref_store.is_frozen("feature-1")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_missing_branch_requires_force() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let trunk = repo.head()?.shorthand().unwrap().to_string();
+        create_branch(&repo, "feature-1")?;
+
+        let mut metadata = StackMetadata {
+            version: METADATA_FORMAT_VERSION,
+            trunk: Some(trunk.clone()),
+            ..Default::default()
+        };
+        metadata.parents.insert("feature-1".to_string(), trunk.clone());
+        metadata.parents.insert("gone".to_string(), "feature-1".to_string());
+        let file = dir.path().join("stack.json");
+        std::fs::write(&file, serde_json::to_string(&metadata)?)?;
+
+        let _ctx = TestRepoContext::new(dir.path());
+        let ref_store = RefStore::from_path(dir.path())?;
+
+        let err = run(&file, false).unwrap_err().to_string();
+        assert!(err.contains("gone"), "error should list the missing branch: {}", err);
+        assert!(
+            ref_store.get_trunk()?.is_none(),
+            "nothing should be written without --force"
+        );
+
+        run(&file, true)?;
+        assert_eq!(ref_store.get_trunk()?, Some(trunk.clone()));
+        assert_eq!(ref_store.get_parent("feature-1")?, Some(trunk));
+        assert!(!ref_store.is_tracked("gone")?);
+
+        Ok(())
+    }
+}
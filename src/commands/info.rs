@@ -2,7 +2,9 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::cache::Cache;
-use crate::git_gateway::{BranchSyncState, GitGateway};
+use crate::forge::{get_forge, Forge};
+use crate::git_gateway::{format_relative_time, BranchSyncState, GitGateway};
+use crate::operation_log::OperationLog;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 
@@ -84,13 +86,33 @@ pub fn run_trunk(set: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Show the detected forge, the remote it talks to and the authenticated user
+pub fn run_whoami() -> Result<()> {
+    let gateway = GitGateway::new()?;
+    let forge = get_forge(None)?;
+    forge.check_auth()?;
+
+    let remote = gateway.remote();
+    let remote_display = match gateway.get_remote_url(remote) {
+        Ok(url) => format!("{} ({})", remote, url),
+        Err(_) => format!("{} {}", remote, "(not configured)".dimmed()),
+    };
+
+    println!("Forge:  {} ({})", forge.forge_type(), forge.cli_name());
+    println!("Remote: {}", remote_display);
+    println!("User:   {}", forge.current_user()?.green());
+    Ok(())
+}
+
 /// Show details about a branch including PR status
 ///
 /// Special subcommands (deprecated - use `dm parent`, `dm children`, `dm trunk`):
 /// - `dm info trunk` - print the trunk branch name
 /// - `dm info parent` - print the current branch's parent
 /// - `dm info children` - print the current branch's children
-pub fn run(branch: Option<String>) -> Result<()> {
+///
+/// With `remote`, also fetches the PR's state, review, CI and mergeability from the forge.
+pub fn run(branch: Option<String>, remote: bool) -> Result<()> {
     // Silent cleanup of orphaned refs before displaying info
     let gateway = GitGateway::new()?;
     if let Err(_e) = crate::validation::silent_cleanup_orphaned_refs(&gateway) {
@@ -247,9 +269,51 @@ pub fn run(branch: Option<String>) -> Result<()> {
         println!("  {}: {}", "Base SHA".bold(), sha.dimmed());
     }
 
+    // Last Diamond operation that modified the branch (from the operation log)
+    if let Ok(Some((kind, timestamp))) = OperationLog::new().and_then(|log| log.last_modified(&target)) {
+        let age = format_relative_time((chrono::Utc::now() - timestamp).num_seconds());
+        println!("  {}: {} ({})", "Last modified by".bold(), kind.cyan(), age.dimmed());
+    }
+
+    if remote {
+        let forge = get_forge(None)?;
+        println!();
+        for line in remote_pr_lines(&target, &cache, forge.as_ref())? {
+            println!("{}", line);
+        }
+    }
+
     Ok(())
 }
 
+/// Render the remote PR panel for `dm info --remote`
+///
+/// Uses the cached PR URL when available to skip the PR lookup.
+fn remote_pr_lines(branch: &str, cache: &Cache, forge: &dyn Forge) -> Result<Vec<String>> {
+    let pr_ref = match cache.get_pr_url(branch) {
+        Some(url) => url.to_string(),
+        None => match forge.pr_exists(branch)? {
+            Some(pr) => pr.url,
+            None => return Ok(vec![format!("  {}: {}", "Remote PR".bold(), "no PR".dimmed())]),
+        },
+    };
+
+    let pr = forge.get_pr_full_info(&pr_ref)?;
+    let mergeable = match pr.mergeable {
+        Some(true) => "yes".green().to_string(),
+        Some(false) => "no (conflicts)".red().to_string(),
+        None => "unknown".dimmed().to_string(),
+    };
+
+    Ok(vec![
+        format!("  {}: #{} {}", "Remote PR".bold(), pr.number, pr.title),
+        format!("    {}: {} {}", "State".bold(), pr.state_emoji(), pr.state_display()),
+        format!("    {}: {} {}", "Review".bold(), pr.review.emoji(), pr.review),
+        format!("    {}: {} {}", "CI".bold(), pr.ci.emoji(), pr.ci),
+        format!("    {}: {}", "Mergeable".bold(), mergeable),
+    ])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,7 +332,7 @@ mod tests {
         // Empty ref_store - branch not tracked
         let _ref_store = RefStore::new().unwrap();
 
-        let result = run(None);
+        let result = run(None, false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not tracked"));
     }
@@ -284,7 +348,7 @@ mod tests {
         ref_store.set_trunk("main").unwrap();
 
         // Should succeed for tracked branch
-        let result = run(Some("main".to_string()));
+        let result = run(Some("main".to_string()), false);
         assert!(result.is_ok());
     }
 
@@ -303,7 +367,7 @@ mod tests {
         cache.save().unwrap();
 
         // Should succeed
-        let result = run(Some("main".to_string()));
+        let result = run(Some("main".to_string()), false);
         assert!(result.is_ok());
     }
 
@@ -320,7 +384,7 @@ mod tests {
         ref_store.set_parent("feature-b", "main").unwrap();
 
         // Should succeed
-        let result = run(Some("main".to_string()));
+        let result = run(Some("main".to_string()), false);
         assert!(result.is_ok());
     }
 
@@ -334,7 +398,7 @@ mod tests {
         ref_store.set_trunk("main").unwrap();
 
         // Should succeed and print trunk name
-        let result = run(Some("trunk".to_string()));
+        let result = run(Some("trunk".to_string()), false);
         assert!(result.is_ok());
     }
 
@@ -347,7 +411,7 @@ mod tests {
         // Empty ref_store (no trunk)
         let _ref_store = RefStore::new().unwrap();
 
-        let result = run(Some("trunk".to_string()));
+        let result = run(Some("trunk".to_string()), false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No trunk"));
     }
@@ -366,7 +430,7 @@ mod tests {
         ref_store.set_parent("feature", "main").unwrap();
 
         // Should succeed and print parent name
-        let result = run(Some("parent".to_string()));
+        let result = run(Some("parent".to_string()), false);
         assert!(result.is_ok());
     }
 
@@ -383,7 +447,7 @@ mod tests {
         ref_store.set_parent("feature-b", "main").unwrap();
 
         // Should succeed and print children
-        let result = run(Some("children".to_string()));
+        let result = run(Some("children".to_string()), false);
         assert!(result.is_ok());
     }
 
@@ -401,7 +465,7 @@ mod tests {
         ref_store.set_parent("feature", "main").unwrap();
 
         // Feature has no children - should succeed
-        let result = run(Some("children".to_string()));
+        let result = run(Some("children".to_string()), false);
         assert!(result.is_ok());
     }
 
@@ -416,7 +480,7 @@ mod tests {
         ref_store.set_trunk("main").unwrap();
 
         // Info should succeed and include remote status (no remote = "not pushed")
-        let result = run(Some("main".to_string()));
+        let result = run(Some("main".to_string()), false);
         assert!(result.is_ok());
     }
 
@@ -449,4 +513,66 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("does not exist"));
     }
+
+    #[test]
+    fn test_info_remote_renders_pr_full_info() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::{CiStatus, ForgeType, PrFullInfo, PrState, ReviewState};
+
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let url = "https://github.com/org/repo/pull/42";
+        let mut cache = Cache::load()?;
+        cache.set_pr_url("feature", url);
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        let pr = PrFullInfo {
+            number: 42,
+            url: url.to_string(),
+            title: "Add widgets".to_string(),
+            state: PrState::Open,
+            is_draft: true,
+            review: ReviewState::ChangesRequested,
+            ci: CiStatus::Failure,
+            head_ref: "feature".to_string(),
+            base_ref: "main".to_string(),
+            mergeable: Some(false),
+        };
+        forge.set_response(
+            &format!("get_pr_full_info:{}", url),
+            MockResponse::Success(serde_json::to_string(&pr)?),
+        );
+
+        let panel = remote_pr_lines("feature", &cache, &forge)?.join("\n");
+        assert!(panel.contains("#42 Add widgets"), "{}", panel);
+        assert!(panel.contains("Draft"), "{}", panel);
+        assert!(panel.contains("changes_requested"), "{}", panel);
+        assert!(panel.contains("failure"), "{}", panel);
+        assert!(panel.contains("no (conflicts)"), "{}", panel);
+
+        // The cached URL is used directly, without looking the PR up by branch
+        assert_eq!(forge.get_call_count("pr_exists:feature"), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_info_remote_without_pr_shows_no_pr() -> Result<()> {
+        use crate::forge::mock::MockForge;
+        use crate::forge::ForgeType;
+
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        let panel = remote_pr_lines("feature", &Cache::default(), &forge)?.join("\n");
+
+        assert!(panel.contains("no PR"), "{}", panel);
+        assert_eq!(forge.get_call_count("pr_exists:feature"), 1);
+
+        Ok(())
+    }
 }
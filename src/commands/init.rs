@@ -1,23 +1,53 @@
 use crate::git_gateway::GitGateway;
+use crate::program_name::program_name;
 use crate::ref_store::RefStore;
-use anyhow::Result;
+use crate::ui;
+use anyhow::{Context, Result};
+
+/// Refs where Graphite stores per-branch metadata (JSON blobs)
+const GRAPHITE_METADATA_REF_PREFIX: &str = "refs/branch-metadata/";
+
+/// Graphite's repo config file inside the git directory
+const GRAPHITE_REPO_CONFIG: &str = ".graphite_repo_config";
+
+/// git-branchless keeps its event log and config under this git directory entry
+const BRANCHLESS_DIR: &str = "branchless";
+
+/// Repo-level config file written by spr (ejoffe/spr)
+const SPR_REPO_CONFIG: &str = ".spr.yml";
+
+/// Stack metadata imported from another stacking tool
+#[derive(Debug, Default)]
+struct AdoptedMetadata {
+    trunk: Option<String>,
+    /// (branch, parent) pairs, sorted by branch
+    parents: Vec<(String, String)>,
+}
 
 /// Initialize Diamond in a git repository
 ///
 /// If trunk is not specified, attempts to detect main or master branch.
 /// If reset is true, clears all existing tracking data first.
-pub fn run(trunk: Option<String>, reset: bool) -> Result<()> {
+/// If adopt is set (currently only "graphite"), imports that tool's trunk and
+/// branch parents, even when Diamond is already initialized.
+pub fn run(trunk: Option<String>, reset: bool, adopt: Option<String>) -> Result<()> {
     let gateway = GitGateway::new()?;
     let ref_store = RefStore::new()?;
 
+    let adopted = match adopt.as_deref() {
+        None => None,
+        Some("graphite") | Some("gt") => Some(read_graphite_metadata(&gateway)?),
+        Some(other) => anyhow::bail!("Unknown --adopt source '{}'. Supported: graphite", other),
+    };
+
     // Handle reset flag
     if reset {
         ref_store.clear_all()?;
         println!("Reinitializing Diamond...");
         println!("All branches have been untracked");
     } else {
-        // Check if already initialized (only when not resetting)
-        if ref_store.is_initialized()? {
+        // Check if already initialized (only when not resetting or adopting)
+        if ref_store.is_initialized()? && adopted.is_none() {
             let current_trunk = ref_store.require_trunk()?;
             println!("Diamond is already initialized with trunk: {}", current_trunk);
             return Ok(());
@@ -31,11 +61,23 @@ pub fn run(trunk: Option<String>, reset: bool) -> Result<()> {
             anyhow::bail!("Branch '{}' does not exist", name);
         }
         name
+    } else if let Some(name) = adopted.as_ref().and_then(|a| a.trunk.clone()) {
+        if !gateway.branch_exists(&name)? {
+            anyhow::bail!(
+                "Graphite trunk '{}' does not exist locally. Use --trunk to specify.",
+                name
+            );
+        }
+        name
     } else {
         // Auto-detect main or master
         detect_trunk(&gateway)?
     };
 
+    for warning in stack_tool_warnings(&gateway, adopted.is_some())? {
+        ui::warning(&warning);
+    }
+
     // Set trunk in refs
     ref_store.set_trunk(&trunk_name)?;
 
@@ -50,9 +92,128 @@ pub fn run(trunk: Option<String>, reset: bool) -> Result<()> {
         }
     }
 
+    if let Some(adopted) = adopted {
+        adopt_parents(&gateway, &ref_store, &trunk_name, &adopted.parents)?;
+    }
+
+    Ok(())
+}
+
+/// Read Graphite's trunk and branch-parent metadata
+///
+/// Parents come from `refs/branch-metadata/<branch>` blobs (`parentBranchName`),
+/// the trunk from `.git/.graphite_repo_config`.
+fn read_graphite_metadata(gateway: &GitGateway) -> Result<AdoptedMetadata> {
+    let mut metadata = AdoptedMetadata::default();
+
+    let config_path = gateway.git_dir().join(GRAPHITE_REPO_CONFIG);
+    if config_path.exists() {
+        let content = std::fs::read_to_string(&config_path).context("Failed to read Graphite repo config")?;
+        let config: serde_json::Value =
+            serde_json::from_str(&content).context("Failed to parse Graphite repo config")?;
+        metadata.trunk = config["trunk"]
+            .as_str()
+            .or_else(|| config["trunks"][0]["name"].as_str())
+            .map(|s| s.to_string());
+    }
+
+    let pattern = format!("{}*", GRAPHITE_METADATA_REF_PREFIX);
+    for (ref_name, oid) in gateway.list_references(&pattern)? {
+        let Some(branch) = ref_name.strip_prefix(GRAPHITE_METADATA_REF_PREFIX) else {
+            continue;
+        };
+        let blob = gateway.read_blob(&oid)?;
+        let Ok(json) = serde_json::from_slice::<serde_json::Value>(&blob) else {
+            continue;
+        };
+        if let Some(parent) = json["parentBranchName"].as_str() {
+            metadata.parents.push((branch.to_string(), parent.to_string()));
+        }
+    }
+
+    if metadata.trunk.is_none() && metadata.parents.is_empty() {
+        anyhow::bail!("No Graphite metadata found in this repository");
+    }
+
+    metadata.parents.sort();
+    Ok(metadata)
+}
+
+/// Write adopted parents as Diamond parent refs and print a summary
+///
+/// Branches (or parents) that no longer exist locally are skipped.
+fn adopt_parents(gateway: &GitGateway, ref_store: &RefStore, trunk: &str, parents: &[(String, String)]) -> Result<()> {
+    let mut adopted = 0;
+    let mut skipped = Vec::new();
+
+    for (branch, parent) in parents {
+        if branch == trunk {
+            continue;
+        }
+        if !gateway.branch_exists(branch)? || !gateway.branch_exists(parent)? {
+            skipped.push(branch.as_str());
+            continue;
+        }
+        if let Err(e) = ref_store.set_parent(branch, parent) {
+            eprintln!("Note: Could not adopt '{}': {}", branch, e);
+            skipped.push(branch.as_str());
+            continue;
+        }
+        adopted += 1;
+    }
+
+    println!("Adopted {} branch(es) from Graphite", adopted);
+    if !skipped.is_empty() {
+        println!("Skipped {} that no longer exist: {}", skipped.len(), skipped.join(", "));
+    }
+
     Ok(())
 }
 
+/// Warn about metadata left by other stacking tools
+///
+/// Two tools tracking parents for the same branches drift apart as soon as one
+/// of them restacks, so each one found gets a warning; init carries on regardless.
+/// Graphite is skipped when `adopting_graphite`, since its metadata is being imported.
+fn stack_tool_warnings(gateway: &GitGateway, adopting_graphite: bool) -> Result<Vec<String>> {
+    let mut warnings = Vec::new();
+
+    let has_graphite = gateway.git_dir().join(GRAPHITE_REPO_CONFIG).exists()
+        || !gateway
+            .list_references(&format!("{}*", GRAPHITE_METADATA_REF_PREFIX))?
+            .is_empty();
+    if has_graphite && !adopting_graphite {
+        warnings.push(format!(
+            "Graphite metadata found. Running both tools on the same stacks will conflict; \
+             run '{} init --adopt graphite' to import its stacks instead.",
+            program_name()
+        ));
+    }
+
+    if gateway.git_dir().join(BRANCHLESS_DIR).is_dir() {
+        warnings.push(
+            "git-branchless is set up in this repository. Its restacking does not update Diamond's \
+             parent tracking; use one tool per stack."
+                .to_string(),
+        );
+    }
+
+    let spr_config = std::process::Command::new("git")
+        .args(["config", "--get-regexp", r"^spr\."])
+        .current_dir(gateway.workdir())
+        .output()
+        .is_ok_and(|output| output.status.success());
+    if spr_config || gateway.workdir().join(SPR_REPO_CONFIG).exists() {
+        warnings.push(
+            "spr configuration found. spr manages its own PR stacks; submitting the same branches \
+             with both tools will create duplicate PRs."
+                .to_string(),
+        );
+    }
+
+    Ok(warnings)
+}
+
 /// Detect the trunk branch (main or master)
 fn detect_trunk(gateway: &GitGateway) -> Result<String> {
     let branches = gateway.list_branches()?;
@@ -81,7 +242,7 @@ mod tests {
         let _repo = init_test_repo_with_branch(dir.path(), "main")?;
         let _ctx = TestRepoContext::new(dir.path());
 
-        run(None, false)?;
+        run(None, false, None)?;
 
         let ref_store = RefStore::new()?;
         assert!(ref_store.is_initialized()?);
@@ -101,7 +262,7 @@ mod tests {
         let gateway = GitGateway::new()?;
         let branches = gateway.list_branches()?;
         if branches.contains(&"master".to_string()) {
-            run(None, false)?;
+            run(None, false, None)?;
 
             let ref_store = RefStore::new()?;
             assert!(ref_store.is_initialized()?);
@@ -117,7 +278,7 @@ mod tests {
         let _repo = init_test_repo_with_branch(dir.path(), "develop")?;
         let _ctx = TestRepoContext::new(dir.path());
 
-        run(Some("develop".to_string()), false)?;
+        run(Some("develop".to_string()), false, None)?;
 
         let ref_store = RefStore::new()?;
         assert!(ref_store.is_initialized()?);
@@ -132,7 +293,7 @@ mod tests {
         let _repo = init_test_repo(dir.path())?;
         let _ctx = TestRepoContext::new(dir.path());
 
-        let result = run(Some("nonexistent".to_string()), false);
+        let result = run(Some("nonexistent".to_string()), false, None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("does not exist"));
 
@@ -146,10 +307,10 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Initialize first time
-        run(None, false)?;
+        run(None, false, None)?;
 
         // Initialize second time - should not error
-        let result = run(None, false);
+        let result = run(None, false, None);
         assert!(result.is_ok());
 
         Ok(())
@@ -161,7 +322,7 @@ mod tests {
         let _repo = init_test_repo_with_branch(dir.path(), "main")?;
         let _ctx = TestRepoContext::new(dir.path());
 
-        run(None, false)?;
+        run(None, false, None)?;
 
         // Verify trunk is set via refs
         let ref_store = RefStore::new()?;
@@ -177,7 +338,7 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Initialize and create some tracked branches
-        run(None, false)?;
+        run(None, false, None)?;
 
         let ref_store = RefStore::new()?;
         let gateway = GitGateway::new()?;
@@ -188,7 +349,7 @@ mod tests {
         assert!(ref_store.is_tracked("feature-1")?);
 
         // Reset
-        run(None, true)?;
+        run(None, true, None)?;
 
         // Verify all tracking is cleared
         let ref_store = RefStore::new()?;
@@ -198,4 +359,100 @@ mod tests {
 
         Ok(())
     }
+
+    /// Write a Graphite `refs/branch-metadata/<branch>` blob
+    fn write_graphite_parent(gateway: &GitGateway, branch: &str, parent: &str) -> Result<()> {
+        let json = format!(r#"{{"parentBranchName":"{}","parentBranchRevision":"abc123"}}"#, parent);
+        let oid = gateway.create_blob(json.as_bytes())?;
+        gateway.create_reference(&format!("refs/branch-metadata/{}", branch), &oid, true, "graphite")?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_adopt_graphite_imports_parents() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo_with_branch(dir.path(), "develop")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        gateway.create_branch("feature-1")?;
+        gateway.create_branch("feature-2")?;
+        gateway.create_branch("side")?;
+
+        // Synthetic Graphite layout: develop -> feature-1 -> feature-2, develop -> side,
+        // plus metadata for a branch that has since been deleted
+        std::fs::write(
+            gateway.git_dir().join(".graphite_repo_config"),
+            r#"{"trunk":"develop"}"#,
+        )?;
+        write_graphite_parent(&gateway, "feature-1", "develop")?;
+        write_graphite_parent(&gateway, "feature-2", "feature-1")?;
+        write_graphite_parent(&gateway, "side", "develop")?;
+        write_graphite_parent(&gateway, "gone", "feature-1")?;
+
+        run(None, false, Some("graphite".to_string()))?;
+
+        let ref_store = RefStore::new()?;
+        assert_eq!(ref_store.get_trunk()?, Some("develop".to_string()));
+        assert_eq!(ref_store.get_parent("feature-1")?, Some("develop".to_string()));
+        assert_eq!(ref_store.get_parent("feature-2")?, Some("feature-1".to_string()));
+        assert_eq!(ref_store.get_parent("side")?, Some("develop".to_string()));
+        assert!(!ref_store.is_tracked("gone")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_adopt_unknown_source_fails() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let result = run(None, false, Some("sapling".to_string()));
+        assert!(result.unwrap_err().to_string().contains("Unknown --adopt source"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_warns_about_graphite_metadata_and_still_initializes() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        assert!(stack_tool_warnings(&gateway, false)?.is_empty());
+
+        std::fs::write(gateway.git_dir().join(".graphite_repo_config"), r#"{"trunk":"main"}"#)?;
+
+        let warnings = stack_tool_warnings(&gateway, false)?;
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("Graphite"), "got: {}", warnings[0]);
+        assert!(warnings[0].contains("--adopt graphite"), "got: {}", warnings[0]);
+        // Adopting is the fix, so no warning then
+        assert!(stack_tool_warnings(&gateway, true)?.is_empty());
+
+        run(None, false, None)?;
+        assert_eq!(RefStore::new()?.get_trunk()?, Some("main".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_warns_about_branchless_and_spr() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        std::fs::create_dir(gateway.git_dir().join("branchless"))?;
+        std::fs::write(dir.path().join(".spr.yml"), "githubRepoOwner: org\n")?;
+
+        let warnings = stack_tool_warnings(&gateway, false)?;
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("git-branchless"));
+        assert!(warnings[1].contains("spr"));
+
+        Ok(())
+    }
 }
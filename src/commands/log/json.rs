@@ -0,0 +1,113 @@
+//! JSON log output - machine-readable stack for editor plugins and dashboards.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::Cache;
+use crate::git_gateway::GitGateway;
+use crate::ref_store::RefStore;
+
+/// Bumped whenever a field is removed, renamed or changes meaning.
+/// Adding fields is not a breaking change.
+pub(crate) const SCHEMA_VERSION: u32 = 1;
+
+/// Top-level `dm log --json` document
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct LogJson {
+    pub schema_version: u32,
+    pub trunk: Option<String>,
+    pub current_branch: String,
+    /// Trunk first, then tracked branches depth-first (siblings sorted),
+    /// then untracked local branches
+    pub branches: Vec<BranchJson>,
+}
+
+/// One branch in the log
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct BranchJson {
+    pub name: String,
+    pub parent: Option<String>,
+    pub children: Vec<String>,
+    /// `None` when the branch is tracked but missing from git
+    pub sha: Option<String>,
+    pub subject: Option<String>,
+    pub tracked: bool,
+    pub frozen: bool,
+    pub current: bool,
+    /// From the PR URL cached by `submit`; no forge call is made
+    pub pr_number: Option<u64>,
+}
+
+/// Print the stack as a JSON document
+pub fn run_json(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway) -> Result<()> {
+    let cache = Cache::load().unwrap_or_default();
+    let log = build_log(ref_store, current_branch, gateway, &cache)?;
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&log).context("Failed to serialize log")?
+    );
+    Ok(())
+}
+
+/// Walk the stack from trunk and describe every branch
+pub(super) fn build_log(
+    ref_store: &RefStore,
+    current_branch: &str,
+    gateway: &GitGateway,
+    cache: &Cache,
+) -> Result<LogJson> {
+    // One scan of the parent refs answers every parent/child lookup below
+    let snapshot = ref_store.snapshot()?;
+    let trunk = snapshot.trunk().map(String::from);
+
+    let mut tracked = Vec::new();
+    if let Some(trunk) = &trunk {
+        tracked.push(trunk.clone());
+        tracked.extend(snapshot.descendants(trunk));
+    }
+
+    let mut untracked: Vec<String> = gateway
+        .list_branches()?
+        .into_iter()
+        .filter(|b| !tracked.contains(b))
+        .collect();
+    untracked.sort();
+
+    let mut branches = Vec::new();
+    for (name, is_tracked) in tracked
+        .iter()
+        .map(|b| (b, true))
+        .chain(untracked.iter().map(|b| (b, false)))
+    {
+        let children = snapshot.children_of(name).to_vec();
+
+        let sha = gateway.get_branch_sha(name).ok();
+        let subject = sha
+            .as_ref()
+            .and_then(|_| gateway.backend().get_commit_subject(name).ok());
+
+        branches.push(BranchJson {
+            name: name.clone(),
+            parent: snapshot.parent_of(name)?.map(String::from),
+            children,
+            sha,
+            subject,
+            tracked: is_tracked,
+            frozen: ref_store.is_frozen(name)?,
+            current: name == current_branch,
+            pr_number: cache.get_pr_url(name).and_then(pr_number_from_url),
+        });
+    }
+
+    Ok(LogJson {
+        schema_version: SCHEMA_VERSION,
+        trunk,
+        current_branch: current_branch.to_string(),
+        branches,
+    })
+}
+
+/// The trailing number of a PR/MR URL (".../pull/123", ".../merge_requests/45")
+fn pr_number_from_url(url: &str) -> Option<u64> {
+    url.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
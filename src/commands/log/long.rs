@@ -1,17 +1,94 @@
 //! Long log output - shows commits for each branch.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::branch_tree::{branch_marker, needs_restack};
+use crate::cache::Cache;
+use crate::config::LogOrientation;
+use crate::forge::{get_async_forge, AsyncForge, PrDiffStat};
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 
-use super::find_roots;
+use super::{find_roots, orient};
+
+/// Author names longer than this are truncated with an ellipsis
+const MAX_AUTHOR_WIDTH: usize = 16;
+
+/// Which optional columns the long view shows
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LongColumns {
+    pub author: bool,
+    pub time: bool,
+    /// Each PR's size, which takes a forge call
+    pub pr_size: bool,
+}
+
+/// One branch in the long view, before formatting
+#[derive(Debug, Clone, Default)]
+pub(super) struct LongRow {
+    /// Tree indentation for the branch's depth
+    pub indent: String,
+    pub branch: String,
+    pub is_current: bool,
+    pub needs_restack: bool,
+    /// Author of the branch's tip commit
+    pub author: String,
+    /// Relative time of the branch's tip commit (e.g., "2 hours ago")
+    pub time: String,
+    /// Size of the branch's PR (e.g., "+12 -3 (4 files)"), empty without a PR
+    pub diff_stat: String,
+    /// Short SHA and subject of the tip commit
+    pub commit_info: String,
+    /// For a "… (+K more)" row standing in for a collapsed subtree: K (0 for a branch row)
+    pub collapsed: usize,
+}
+
+impl LongRow {
+    fn marker(&self) -> &'static str {
+        branch_marker(self.is_current, self.needs_restack)
+    }
+
+    fn restack_suffix(&self) -> &'static str {
+        if self.needs_restack {
+            " (needs restack)"
+        } else {
+            ""
+        }
+    }
+
+    fn collapsed_label(&self) -> String {
+        format!("… (+{} more)", self.collapsed)
+    }
+
+    /// Display width of the tree/branch column
+    fn label_width(&self) -> usize {
+        if self.collapsed > 0 {
+            return self.indent.chars().count() + self.collapsed_label().chars().count();
+        }
+        self.indent.chars().count()
+            + self.marker().chars().count()
+            + 2
+            + self.branch.chars().count()
+            + self.restack_suffix().chars().count()
+    }
+}
 
 /// Long log output - shows commits for each branch
-/// Shows trunk at bottom, tips at top
-pub fn run_long(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway) -> Result<()> {
+/// Shows trunk at bottom, tips at top (or the reverse with `TrunkTop`)
+///
+/// With `depth`, deeper subtrees are collapsed as in the short view.
+pub async fn run_long(
+    ref_store: &RefStore,
+    current_branch: &str,
+    gateway: &GitGateway,
+    columns: LongColumns,
+    orientation: LogOrientation,
+    depth: Option<usize>,
+) -> Result<()> {
     let roots = find_roots(ref_store)?;
 
     if roots.is_empty() {
@@ -22,77 +99,165 @@ pub fn run_long(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway
         return Ok(());
     }
 
-    // Collect all lines, then reverse to show trunk at bottom
-    let mut lines: Vec<String> = Vec::new();
+    // Collect all rows trunk-first, then orient
+    let mut rows: Vec<LongRow> = Vec::new();
 
     for root in roots {
-        collect_long_tree(ref_store, &root, current_branch, gateway, 0, &mut lines)?;
+        collect_long_tree(ref_store, &root, current_branch, gateway, 0, depth, &mut rows)?;
     }
 
-    // Reverse so trunk is at bottom
-    lines.reverse();
+    orient(&mut rows, orientation);
+
+    if columns.pr_size {
+        fill_diff_stats(&mut rows).await;
+    }
 
-    for line in lines {
+    for line in format_long_rows(&rows, columns) {
         println!("{}", line);
     }
 
     Ok(())
 }
 
-fn collect_long_tree(
+pub(super) fn collect_long_tree(
     ref_store: &RefStore,
     branch: &str,
     current_branch: &str,
     gateway: &GitGateway,
     depth: usize,
-    lines: &mut Vec<String>,
+    max_depth: Option<usize>,
+    rows: &mut Vec<LongRow>,
 ) -> Result<()> {
-    let is_current = branch == current_branch;
-    let indent = "│ ".repeat(depth);
-    let marker = if is_current { "◉" } else { "◯" };
-
-    // Check if this branch needs restack (parent's tip is not an ancestor of this branch)
-    let needs_restack = if let Ok(Some(parent)) = ref_store.get_parent(branch) {
-        !gateway.is_ancestor(&parent, branch).unwrap_or(true)
-    } else {
-        false
-    };
+    let needs_restack = needs_restack(ref_store, gateway, branch);
 
-    let restack_suffix = if needs_restack {
-        " (needs restack)".yellow().to_string()
-    } else {
-        String::new()
-    };
+    rows.push(LongRow {
+        indent: "│ ".repeat(depth),
+        branch: branch.to_string(),
+        is_current: branch == current_branch,
+        needs_restack,
+        author: gateway
+            .get_commit_author(branch)
+            .map(|(name, _)| name)
+            .unwrap_or_default(),
+        time: gateway.get_commit_time_relative(branch).unwrap_or_default(),
+        commit_info: gateway.get_branch_commit_info(branch).unwrap_or_default(),
+        ..Default::default()
+    });
 
-    // Get commit info for this branch
-    let commit_info = gateway.get_branch_commit_info(branch).unwrap_or_default();
-
-    let line = if is_current {
-        format!(
-            "{}{}  {}{} {}",
-            indent,
-            marker.green().bold(),
-            branch.green().bold(),
-            restack_suffix,
-            commit_info.dimmed()
-        )
-    } else {
-        format!(
-            "{}{}  {}{} {}",
-            indent,
-            marker,
-            branch,
-            restack_suffix,
-            commit_info.dimmed()
-        )
-    };
-    lines.push(line);
+    if max_depth == Some(depth) {
+        let hidden = ref_store.descendants(branch)?.len();
+        if hidden > 0 {
+            rows.push(LongRow {
+                indent: "│ ".repeat(depth + 1),
+                collapsed: hidden,
+                ..Default::default()
+            });
+        }
+        return Ok(());
+    }
 
     let mut children: Vec<_> = ref_store.get_children(branch)?.into_iter().collect();
     children.sort();
     for child in children {
-        collect_long_tree(ref_store, &child, current_branch, gateway, depth + 1, lines)?;
+        collect_long_tree(ref_store, &child, current_branch, gateway, depth + 1, max_depth, rows)?;
     }
 
     Ok(())
 }
+
+/// Fill in the PR size of each row whose branch has a cached PR URL
+///
+/// Best-effort: without a forge or cached PR URLs the column stays empty.
+async fn fill_diff_stats(rows: &mut [LongRow]) {
+    let (Ok(forge), Ok(cache)) = (get_async_forge(None), Cache::load()) else {
+        return;
+    };
+    let pr_urls: Vec<(String, String)> = rows
+        .iter()
+        .filter_map(|row| {
+            cache
+                .get_pr_url(&row.branch)
+                .map(|url| (row.branch.clone(), url.to_string()))
+        })
+        .collect();
+    let stats = fetch_diff_stats(forge.as_ref(), &pr_urls).await;
+    for row in rows {
+        if let Some(stat) = stats.get(&row.branch) {
+            row.diff_stat = stat.to_string();
+        }
+    }
+}
+
+/// Fetch PR diff stats for (branch, pr_ref) pairs in one batch, keyed by branch
+///
+/// PRs whose stats can't be fetched are left out.
+pub(super) async fn fetch_diff_stats(
+    forge: &dyn AsyncForge,
+    pr_refs: &[(String, String)],
+) -> HashMap<String, PrDiffStat> {
+    let refs: Vec<String> = pr_refs.iter().map(|(_, pr_ref)| pr_ref.clone()).collect();
+    let by_ref: HashMap<String, PrDiffStat> = forge.get_prs_diff_stats(&refs).await.into_iter().collect();
+
+    pr_refs
+        .iter()
+        .filter_map(|(branch, pr_ref)| by_ref.get(pr_ref).map(|stat| (branch.clone(), *stat)))
+        .collect()
+}
+
+/// Truncate an author name to `MAX_AUTHOR_WIDTH` characters
+fn truncate_author(author: &str) -> String {
+    if author.chars().count() > MAX_AUTHOR_WIDTH {
+        let truncated: String = author.chars().take(MAX_AUTHOR_WIDTH - 1).collect();
+        format!("{}…", truncated)
+    } else {
+        author.to_string()
+    }
+}
+
+/// Format rows into aligned lines: tree/branch, author, time, PR size, then commit info
+///
+/// Padding is computed from the plain text so colors never skew alignment.
+pub(super) fn format_long_rows(rows: &[LongRow], columns: LongColumns) -> Vec<String> {
+    let label_width = rows.iter().map(LongRow::label_width).max().unwrap_or(0);
+    let authors: Vec<String> = rows.iter().map(|r| truncate_author(&r.author)).collect();
+    let author_width = authors.iter().map(|a| a.chars().count()).max().unwrap_or(0);
+    let time_width = rows.iter().map(|r| r.time.chars().count()).max().unwrap_or(0);
+    let diff_width = rows.iter().map(|r| r.diff_stat.chars().count()).max().unwrap_or(0);
+
+    rows.iter()
+        .zip(&authors)
+        .map(|(row, author)| {
+            if row.collapsed > 0 {
+                return format!("{}{}", row.indent, row.collapsed_label().dimmed());
+            }
+            let (marker, branch) = if row.is_current {
+                (row.marker().green().bold(), row.branch.green().bold())
+            } else if row.needs_restack {
+                (row.marker().yellow(), row.branch.yellow())
+            } else {
+                (row.marker().normal(), row.branch.normal())
+            };
+            let mut line = format!(
+                "{}{}  {}{}{}",
+                row.indent,
+                marker,
+                branch,
+                row.restack_suffix().yellow(),
+                " ".repeat(label_width - row.label_width())
+            );
+
+            if columns.author {
+                line.push_str(&format!("  {:<width$}", author, width = author_width));
+            }
+            if columns.time {
+                line.push_str(&format!("  {:<width$}", row.time, width = time_width));
+            }
+            if columns.pr_size && diff_width > 0 {
+                line.push_str(&format!("  {:<width$}", row.diff_stat, width = diff_width));
+            }
+            line.push_str(&format!("  {}", row.commit_info.dimmed()));
+
+            line.trim_end().to_string()
+        })
+        .collect()
+}
@@ -1,5 +1,6 @@
 //! Log command - display stack visualization.
 
+mod json;
 mod long;
 mod short;
 mod tui;
@@ -9,6 +10,7 @@ mod tests;
 
 use anyhow::Result;
 
+use crate::config::{Config, LogOrientation};
 use crate::git_gateway::GitGateway;
 use crate::ref_store::RefStore;
 
@@ -24,7 +26,24 @@ pub(crate) enum TuiAction {
     NavigateBottom,
 }
 
-pub fn run(mode: Option<String>) -> Result<()> {
+/// Show the stack log
+///
+/// `no_time` and `no_author` hide those columns in the long view. `pr_size` adds
+/// each PR's size to it, fetched from the forge.
+///
+/// The short and long views follow `log.orientation`; `reverse` flips it.
+/// `depth` limits them to that many levels above trunk.
+///
+/// `json` (or mode `json`) prints the whole stack as a versioned JSON document.
+pub async fn run(
+    mode: Option<String>,
+    json: bool,
+    no_time: bool,
+    no_author: bool,
+    pr_size: bool,
+    reverse: bool,
+    depth: Option<usize>,
+) -> Result<()> {
     // Silent cleanup of orphaned refs before displaying log
     let gateway = GitGateway::new()?;
     if let Err(_e) = crate::validation::silent_cleanup_orphaned_refs(&gateway) {
@@ -38,24 +57,64 @@ pub fn run(mode: Option<String>) -> Result<()> {
     // Get current branch
     let current_branch = gateway.get_current_branch_name()?;
 
+    let configured = Config::load().ok().and_then(|c| c.log.orientation).unwrap_or_default();
+    let orientation = if reverse { configured.reversed() } else { configured };
+
+    if json {
+        return json::run_json(&ref_store, &current_branch, &gateway);
+    }
+
     match mode.as_deref() {
-        Some("short") | Some("s") => short::run_short(&ref_store, &current_branch),
-        Some("long") | Some("l") => long::run_long(&ref_store, &current_branch, &gateway),
+        Some("json") => json::run_json(&ref_store, &current_branch, &gateway),
+        Some("short") | Some("s") => short::run_short(&ref_store, &current_branch, orientation, depth),
+        Some("long") | Some("l") => {
+            let columns = long::LongColumns {
+                author: !no_author,
+                time: !no_time,
+                pr_size,
+            };
+            long::run_long(&ref_store, &current_branch, &gateway, columns, orientation, depth).await
+        }
         Some(other) => {
-            anyhow::bail!("Unknown log mode '{}'. Use 'short' or 'long', or omit for TUI.", other)
+            anyhow::bail!(
+                "Unknown log mode '{}'. Use 'short', 'long' or 'json', or omit for TUI.",
+                other
+            )
         }
         None => {
             // Check if stdout is a TTY - if not, fall back to short mode
             if std::io::IsTerminal::is_terminal(&std::io::stdout()) {
-                tui::run_tui(&ref_store, &current_branch, &gateway)
+                tui::run_tui(&ref_store, &current_branch, &gateway, false)
             } else {
                 // Running in non-interactive environment (tests, pipes, etc.)
-                short::run_short(&ref_store, &current_branch)
+                short::run_short(&ref_store, &current_branch, orientation, depth)
             }
         }
     }
 }
 
+/// Open the interactive log browser scoped to the current stack
+///
+/// Used by `checkout --tui`: the same view and actions as `log`, limited to
+/// the branches of the current stack.
+pub fn run_stack_tui() -> Result<()> {
+    if !std::io::IsTerminal::is_terminal(&std::io::stdout()) {
+        anyhow::bail!("checkout --tui needs an interactive terminal");
+    }
+
+    let gateway = GitGateway::new()?;
+    let ref_store = RefStore::new()?;
+    let current_branch = gateway.get_current_branch_name()?;
+    tui::run_tui(&ref_store, &current_branch, &gateway, true)
+}
+
+/// Put rows collected trunk-first (DFS from trunk) into display order
+pub(crate) fn orient<T>(rows: &mut [T], orientation: LogOrientation) {
+    if orientation == LogOrientation::TrunkBottom {
+        rows.reverse();
+    }
+}
+
 pub(crate) fn find_roots(ref_store: &RefStore) -> Result<Vec<String>> {
     // In RefStore, the root is the trunk
     let trunk = ref_store.get_trunk()?;
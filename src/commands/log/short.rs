@@ -3,15 +3,25 @@
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::branch_tree::{branch_marker, needs_restack};
+use crate::config::LogOrientation;
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 
-use super::find_roots;
+use super::{find_roots, orient};
 
 /// Short log output - simple text tree
-/// Shows trunk at bottom, tips at top
-pub fn run_short(ref_store: &RefStore, current_branch: &str) -> Result<()> {
+/// Shows trunk at bottom, tips at top (or the reverse with `TrunkTop`)
+///
+/// With `depth`, branches more than `depth` levels above trunk are collapsed
+/// into a "… (+K more)" line per subtree.
+pub fn run_short(
+    ref_store: &RefStore,
+    current_branch: &str,
+    orientation: LogOrientation,
+    depth: Option<usize>,
+) -> Result<()> {
     let roots = find_roots(ref_store)?;
 
     if roots.is_empty() {
@@ -22,58 +32,79 @@ pub fn run_short(ref_store: &RefStore, current_branch: &str) -> Result<()> {
         return Ok(());
     }
 
+    for line in short_lines(ref_store, &roots, current_branch, orientation, depth)? {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+/// Render the short view's lines in display order
+pub(super) fn short_lines(
+    ref_store: &RefStore,
+    roots: &[String],
+    current_branch: &str,
+    orientation: LogOrientation,
+    depth: Option<usize>,
+) -> Result<Vec<String>> {
     let gateway = GitGateway::new()?;
 
-    // Collect all lines, then reverse to show trunk at bottom
+    // Collect all lines trunk-first, then orient
     // (is_current, marker, branch_name, needs_restack)
     let mut lines: Vec<(bool, String, String, bool)> = Vec::new();
 
     for root in roots {
-        collect_short_tree(ref_store, &root, current_branch, &mut lines, &gateway)?;
+        collect_short_tree(ref_store, root, current_branch, 0, depth, &mut lines, &gateway)?;
     }
 
-    // Reverse so trunk is at bottom
-    lines.reverse();
-
-    for (is_current, marker, branch, needs_restack) in lines {
-        let suffix = if needs_restack {
-            " (needs restack)".yellow().to_string()
-        } else {
-            String::new()
-        };
-        if is_current {
-            println!("{}  {}{}", marker.green().bold(), branch.green().bold(), suffix);
-        } else {
-            println!("{}  {}{}", marker, branch, suffix);
-        }
-    }
+    orient(&mut lines, orientation);
 
-    Ok(())
+    Ok(lines
+        .into_iter()
+        .map(|(is_current, marker, branch, needs_restack)| {
+            let suffix = if needs_restack {
+                " (needs restack)".yellow().to_string()
+            } else {
+                String::new()
+            };
+            if is_current {
+                format!("{}  {}{}", marker.green().bold(), branch.green().bold(), suffix)
+            } else if needs_restack {
+                format!("{}  {}{}", marker.yellow(), branch.yellow(), suffix)
+            } else {
+                format!("{}  {}{}", marker, branch, suffix)
+            }
+        })
+        .collect())
 }
 
 fn collect_short_tree(
     ref_store: &RefStore,
     branch: &str,
     current_branch: &str,
+    level: usize,
+    max_depth: Option<usize>,
     lines: &mut Vec<(bool, String, String, bool)>,
     gateway: &GitGateway,
 ) -> Result<()> {
     let is_current = branch == current_branch;
-    let marker = if is_current { "◉" } else { "◯" };
-
-    // Check if this branch needs restack (parent's tip is not an ancestor of this branch)
-    let needs_restack = if let Ok(Some(parent)) = ref_store.get_parent(branch) {
-        !gateway.is_ancestor(&parent, branch).unwrap_or(true)
-    } else {
-        false
-    };
+    let needs_restack = needs_restack(ref_store, gateway, branch);
+    let marker = branch_marker(is_current, needs_restack);
 
     lines.push((is_current, marker.to_string(), branch.to_string(), needs_restack));
 
+    if max_depth == Some(level) {
+        let hidden = ref_store.descendants(branch)?.len();
+        if hidden > 0 {
+            lines.push((false, " ".to_string(), format!("… (+{} more)", hidden), false));
+        }
+        return Ok(());
+    }
+
     let mut children: Vec<_> = ref_store.get_children(branch)?.into_iter().collect();
     children.sort();
     for child in children {
-        collect_short_tree(ref_store, &child, current_branch, lines, gateway)?;
+        collect_short_tree(ref_store, &child, current_branch, level + 1, max_depth, lines, gateway)?;
     }
     Ok(())
 }
@@ -308,3 +308,307 @@ fn test_build_branch_tree_untracked_current_branch() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Remove ANSI color codes so tests can check visible alignment
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[test]
+fn test_format_long_rows_aligns_columns_and_truncates_authors() {
+    use super::long::{format_long_rows, LongColumns, LongRow};
+
+    let rows = vec![
+        LongRow {
+            indent: "│ ".to_string(),
+            branch: "feature-with-long-name".to_string(),
+            is_current: true,
+            needs_restack: false,
+            author: "Maximiliana Featherstonehaugh".to_string(),
+            time: "2 hours ago".to_string(),
+            diff_stat: String::new(),
+            commit_info: "abc1234 Add feature".to_string(),
+            collapsed: 0,
+        },
+        LongRow {
+            indent: String::new(),
+            branch: "main".to_string(),
+            is_current: false,
+            needs_restack: false,
+            author: "Bo".to_string(),
+            time: "3 days ago".to_string(),
+            diff_stat: String::new(),
+            commit_info: "def5678 Initial".to_string(),
+            collapsed: 0,
+        },
+    ];
+
+    let lines: Vec<String> = format_long_rows(
+        &rows,
+        LongColumns {
+            author: true,
+            time: true,
+            pr_size: false,
+        },
+    )
+    .iter()
+    .map(|l| strip_ansi(l))
+    .collect();
+
+    // Long author names are truncated to 16 characters with an ellipsis
+    assert!(lines[0].contains("Maximiliana Fea…  "), "{}", lines[0]);
+    assert!(!lines[0].contains("Featherstonehaugh"));
+
+    // Author, time and commit columns start at the same offset on every row
+    let offset = |line: &str, needle: &str| line[..line.find(needle).unwrap()].chars().count();
+    for (top, bottom) in [
+        ("Maximiliana", "Bo "),
+        ("2 hours ago", "3 days ago"),
+        ("abc1234", "def5678"),
+    ] {
+        assert_eq!(offset(&lines[0], top), offset(&lines[1], bottom), "{:?}", lines);
+    }
+
+    // Columns can be hidden
+    let lines: Vec<String> = format_long_rows(
+        &rows,
+        LongColumns {
+            author: false,
+            time: false,
+            pr_size: false,
+        },
+    )
+    .iter()
+    .map(|l| strip_ansi(l))
+    .collect();
+    assert!(
+        !lines[1].contains("Bo") && !lines[1].contains("3 days ago"),
+        "{}",
+        lines[1]
+    );
+    assert!(lines[1].ends_with("def5678 Initial"));
+}
+
+#[tokio::test]
+async fn test_long_rows_show_pr_diff_stats_from_forge() {
+    use super::long::{fetch_diff_stats, format_long_rows, LongColumns, LongRow};
+    use crate::forge::mock::{MockForge, MockResponse};
+    use crate::forge::ForgeType;
+
+    let forge = MockForge::new(ForgeType::GitHub);
+    forge.set_response(
+        "get_pr_diff_stat:https://github.com/test/repo/pull/1",
+        MockResponse::Success(r#"{"files":4,"additions":120,"deletions":3}"#.to_string()),
+    );
+    forge.set_response(
+        "get_pr_diff_stat:https://github.com/test/repo/pull/2",
+        MockResponse::Success(r#"{"files":1,"additions":7,"deletions":45}"#.to_string()),
+    );
+
+    let stats = fetch_diff_stats(
+        &forge,
+        &[
+            (
+                "feature-a".to_string(),
+                "https://github.com/test/repo/pull/1".to_string(),
+            ),
+            (
+                "feature-b".to_string(),
+                "https://github.com/test/repo/pull/2".to_string(),
+            ),
+        ],
+    )
+    .await;
+
+    let rows: Vec<LongRow> = ["feature-b", "feature-a", "main"]
+        .iter()
+        .map(|branch| LongRow {
+            branch: branch.to_string(),
+            diff_stat: stats.get(*branch).map(|s| s.to_string()).unwrap_or_default(),
+            commit_info: format!("abc1234 {}", branch),
+            ..Default::default()
+        })
+        .collect();
+
+    let lines: Vec<String> = format_long_rows(
+        &rows,
+        LongColumns {
+            author: false,
+            time: false,
+            pr_size: true,
+        },
+    )
+    .iter()
+    .map(|l| strip_ansi(l))
+    .collect();
+
+    assert!(lines[0].contains("+7 -45 (1 file)"), "{}", lines[0]);
+    assert!(lines[1].contains("+120 -3 (4 files)"), "{}", lines[1]);
+
+    // Rows without a PR leave the column blank but keep the commit column aligned
+    let offset = |line: &str| line[..line.find("abc1234").unwrap()].chars().count();
+    assert_eq!(offset(&lines[0]), offset(&lines[2]), "{:?}", lines);
+    assert_eq!(offset(&lines[1]), offset(&lines[2]), "{:?}", lines);
+
+    // Without --pr-size the column is left out
+    let lines = format_long_rows(
+        &rows,
+        LongColumns {
+            author: false,
+            time: false,
+            pr_size: false,
+        },
+    );
+    assert!(lines.iter().all(|l| !l.contains("+7 -45")), "{:?}", lines);
+}
+
+#[test]
+fn test_short_lines_flip_with_orientation() -> anyhow::Result<()> {
+    use super::short::short_lines;
+
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+
+    // main -> first -> second
+    let commit = repo.head()?.peel_to_commit()?;
+    for (branch, parent) in [("first", "main"), ("second", "first")] {
+        repo.branch(branch, &commit, false)?;
+        ref_store.set_parent(branch, parent)?;
+    }
+
+    let roots = find_roots(&ref_store)?;
+    let branch_order = |orientation| -> anyhow::Result<Vec<String>> {
+        Ok(short_lines(&ref_store, &roots, "main", orientation, None)?
+            .iter()
+            .map(|line| line.split_whitespace().last().unwrap_or_default().to_string())
+            .collect())
+    };
+
+    assert_eq!(
+        branch_order(LogOrientation::TrunkBottom)?,
+        vec!["second", "first", "main"]
+    );
+    assert_eq!(branch_order(LogOrientation::TrunkTop)?, vec!["main", "first", "second"]);
+    assert_eq!(LogOrientation::TrunkBottom.reversed(), LogOrientation::TrunkTop);
+
+    Ok(())
+}
+
+#[test]
+fn test_depth_collapses_deep_stack() -> anyhow::Result<()> {
+    use super::long::{collect_long_tree, format_long_rows, LongColumns};
+    use super::short::short_lines;
+
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+
+    // main -> b1 -> b2 -> b3 -> b4 -> b5 -> b6
+    let commit = repo.head()?.peel_to_commit()?;
+    let mut parent = "main".to_string();
+    for i in 1..=6 {
+        let branch = format!("b{}", i);
+        repo.branch(&branch, &commit, false)?;
+        ref_store.set_parent(&branch, &parent)?;
+        parent = branch;
+    }
+
+    let roots = find_roots(&ref_store)?;
+    let lines: Vec<String> = short_lines(&ref_store, &roots, "main", LogOrientation::TrunkTop, Some(3))?
+        .iter()
+        .map(|l| strip_ansi(l))
+        .collect();
+    assert_eq!(lines.len(), 5, "{:?}", lines);
+    assert!(lines[3].ends_with("b3"), "{:?}", lines);
+    assert_eq!(lines[4], "   … (+3 more)");
+    assert!(!lines.iter().any(|l| l.contains("b4")));
+
+    let gateway = GitGateway::new()?;
+    let mut rows = Vec::new();
+    collect_long_tree(&ref_store, "main", "main", &gateway, 0, Some(3), &mut rows)?;
+    let lines: Vec<String> = format_long_rows(
+        &rows,
+        LongColumns {
+            author: false,
+            time: false,
+            pr_size: false,
+        },
+    )
+    .iter()
+    .map(|l| strip_ansi(l))
+    .collect();
+    assert_eq!(lines.len(), 5, "{:?}", lines);
+    assert_eq!(lines[4], "│ │ │ │ … (+3 more)");
+
+    Ok(())
+}
+
+#[test]
+fn test_json_log_round_trips_parent_relationships() -> anyhow::Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let head = repo.head()?.peel_to_commit()?;
+    repo.branch("f1", &head, false)?;
+    repo.branch("f2", &head, false)?;
+    repo.branch("side", &head, false)?;
+    repo.branch("loose", &head, false)?;
+
+    let gateway = GitGateway::from_path(dir.path())?;
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    ref_store.set_parent("f1", "main")?;
+    ref_store.set_parent("f2", "f1")?;
+    ref_store.set_parent("side", "main")?;
+    ref_store.set_frozen("f1", true)?;
+
+    let mut cache = crate::cache::Cache::default();
+    cache.set_pr_url("f2", "https://github.com/test/repo/pull/42");
+
+    let log = json::build_log(&ref_store, "f2", &gateway, &cache)?;
+    let text = serde_json::to_string(&log)?;
+    let parsed: json::LogJson = serde_json::from_str(&text)?;
+
+    assert_eq!(parsed.schema_version, json::SCHEMA_VERSION);
+    assert_eq!(parsed.trunk.as_deref(), Some("main"));
+    let names: Vec<&str> = parsed.branches.iter().map(|b| b.name.as_str()).collect();
+    assert_eq!(names, vec!["main", "f1", "f2", "side", "loose"]);
+
+    // Every tracked branch's parent comes back as recorded in the ref store
+    for branch in parsed.branches.iter().filter(|b| b.tracked) {
+        assert_eq!(branch.parent, ref_store.get_parent(&branch.name)?, "{}", branch.name);
+    }
+
+    let by_name = |name: &str| parsed.branches.iter().find(|b| b.name == name).unwrap();
+    assert_eq!(by_name("main").children, vec!["f1", "side"]);
+    assert!(by_name("f1").frozen);
+    assert!(by_name("f2").current);
+    assert_eq!(by_name("f2").pr_number, Some(42));
+    assert_eq!(by_name("f2").sha.as_deref(), Some(head.id().to_string().as_str()));
+    assert_eq!(by_name("f2").subject.as_deref(), Some("Initial commit"));
+    assert!(!by_name("loose").tracked);
+    assert_eq!(by_name("loose").parent, None);
+
+    Ok(())
+}
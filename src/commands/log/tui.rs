@@ -17,9 +17,7 @@ use ratatui::{
 use std::io;
 use std::process::Command;
 
-use crate::branch_tree::{
-    self, find_current_branch_index, format_indent, get_commit_info, BranchDisplay, MARKER_CURRENT, MARKER_OTHER,
-};
+use crate::branch_tree::{self, branch_marker, find_current_branch_index, format_indent, get_commit_info, BranchDisplay};
 use crate::cache::Cache;
 use crate::git_gateway::{BranchSyncState, GitGateway};
 use crate::program_name::program_name;
@@ -28,7 +26,9 @@ use crate::ref_store::RefStore;
 use super::TuiAction;
 
 /// TUI log output - interactive tree view with rich features
-pub fn run_tui(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway) -> Result<()> {
+///
+/// With `stack_only`, only the stack containing the current branch is shown.
+pub fn run_tui(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway, stack_only: bool) -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -37,7 +37,7 @@ pub fn run_tui(ref_store: &RefStore, current_branch: &str, gateway: &GitGateway)
     let mut terminal = Terminal::new(backend)?;
 
     // Run app
-    let res = run_app(&mut terminal, ref_store, current_branch, gateway);
+    let res = run_app(&mut terminal, ref_store, current_branch, gateway, stack_only);
 
     // Restore terminal
     disable_raw_mode()?;
@@ -52,9 +52,14 @@ fn run_app(
     ref_store: &RefStore,
     current_branch: &str,
     gateway: &GitGateway,
+    stack_only: bool,
 ) -> Result<()> {
     // Build tree view using shared branch_tree module
-    let rows = branch_tree::build_branch_tree(ref_store, current_branch, gateway)?;
+    let rows = if stack_only {
+        branch_tree::build_stack_tree(ref_store, current_branch, gateway)?
+    } else {
+        branch_tree::build_branch_tree(ref_store, current_branch, gateway)?
+    };
 
     // Handle empty list
     if rows.is_empty() {
@@ -254,11 +259,7 @@ fn render_branch_list(f: &mut ratatui::Frame, area: Rect, rows: &[BranchDisplay]
             let indent = format_indent(branch.depth);
 
             // Current branch marker (using shared constants)
-            let marker = if branch.is_current {
-                MARKER_CURRENT
-            } else {
-                MARKER_OTHER
-            };
+            let marker = branch_marker(branch.is_current, branch.needs_restack);
 
             // Needs restack indicator
             let restack_indicator = if branch.needs_restack { " (needs restack)" } else { "" };
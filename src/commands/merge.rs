@@ -2,25 +2,57 @@ use anyhow::Result;
 use colored::Colorize;
 
 use crate::cache::Cache;
+use crate::commands::cleanup::cleanup_merged_branches_for_sync_async;
 use crate::commands::sync;
 use crate::config::Config;
-use crate::forge::{get_forge, wait_for_ci, CiWaitConfig, CiWaitResult, Forge, MergeMethod, PrState};
+use crate::forge::{
+    get_async_forge, get_forge, wait_for_ci, CiStatus, CiWaitConfig, CiWaitResult, Forge, MergeMethod, PrFullInfo,
+    PrInfo, PrState, ReviewState,
+};
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 
+/// Options for [`run`], one per `merge` flag
+#[derive(Debug, Default)]
+pub struct MergeOptions {
+    /// How each PR is merged (squash, merge commit or rebase)
+    pub method: MergeMethod,
+    /// Show what would be merged without merging
+    pub dry_run: bool,
+    /// Skip the confirmation prompt
+    pub auto_confirm: bool,
+    /// Don't sync local branches after merging
+    pub no_sync: bool,
+    /// Skip waiting for CI (still proactively rebase)
+    pub no_wait: bool,
+    /// Skip proactive rebase and CI wait
+    pub fast_mode: bool,
+    /// Delete each merged PR's remote branch
+    pub auto_delete_remote: bool,
+    /// Keep local branches of merged PRs
+    pub keep: bool,
+}
+
 /// Merge PRs from the command line (merges entire downstack from trunk to current)
-pub async fn run(
-    method: MergeMethod,
-    dry_run: bool,
-    auto_confirm: bool,
-    no_sync: bool,
-    no_wait: bool,
-    fast_mode: bool,
-) -> Result<()> {
+///
+/// With `auto_delete_remote`, each merged PR's remote branch is deleted, and unless
+/// `keep` is set the local branch is removed too (children are reparented).
+/// `keep` also stops the follow-up sync from deleting merged local branches.
+pub async fn run(options: MergeOptions) -> Result<()> {
+    let MergeOptions {
+        method,
+        dry_run,
+        auto_confirm,
+        no_sync,
+        no_wait,
+        fast_mode,
+        auto_delete_remote,
+        keep,
+    } = options;
     let gateway = GitGateway::new()?;
     let ref_store = RefStore::new()?;
-    let cache = Cache::load().unwrap_or_default();
+    let mut cache = Cache::load().unwrap_or_default();
     let current = gateway.get_current_branch_name()?;
     let trunk = ref_store.require_trunk()?;
 
@@ -97,18 +129,26 @@ pub async fn run(
         if mergeable.len() == 1 { "" } else { "s" },
         method
     );
-    for (branch, url) in &mergeable {
-        println!("  • {} → {}", branch.green(), url.dimmed());
-    }
-    println!();
 
     if dry_run {
+        let plan = plan_merges(forge.as_ref(), &mergeable, proactive_rebase, do_wait_for_ci);
+        print_merge_plan(&plan);
+        println!();
         println!("{} Dry run - no PRs were merged", "[preview]".yellow().bold());
         return Ok(());
     }
 
+    for (branch, url) in &mergeable {
+        println!("  • {} → {}", branch.green(), url.dimmed());
+    }
+    println!();
+
     // Track how many PRs we actually merged (vs skipped because already merged)
     let mut actually_merged = 0;
+    // PRs merged by this run, for local cleanup with --auto-delete-remote
+    let mut merged_now: Vec<(String, PrInfo)> = Vec::new();
+    // Set when a PR fails to merge; reported once the PRs before it are cleaned up
+    let mut failure: Option<anyhow::Error> = None;
 
     // Merge PRs (from bottom of stack to top)
     for (i, (branch, url)) in mergeable.iter().enumerate() {
@@ -154,42 +194,11 @@ pub async fn run(
         // This ensures clean history and that CI passes before we try to merge.
         // Only needed for branches after the first one (first targets trunk directly).
         if proactive_rebase && i > 0 {
-            match proactive_rebase_for_merge(&gateway, forge.as_ref(), branch, &trunk) {
-                Ok(true) => {
-                    println!("  {} Rebased {} onto {}", "→".blue(), branch.cyan(), trunk.green());
-                }
-                Ok(false) => {
-                    // Already up to date, no rebase needed
-                }
-                Err(e) => {
-                    // Rebase failed with conflicts - abort entire operation
-                    eprintln!("  {} Rebase failed: {}", "✗".red(), e);
-                    anyhow::bail!(
-                        "Could not rebase {} onto {}. Resolve conflicts manually with:\n  {} sync",
-                        branch,
-                        trunk,
-                        program_name()
-                    );
-                }
-            }
-
-            // Wait for CI after rebase (if enabled)
-            if do_wait_for_ci {
-                match wait_for_ci(forge.as_ref(), &pr_number, branch, &ci_wait_config)? {
-                    CiWaitResult::Success | CiWaitResult::NoChecks => {
-                        println!("  {} CI passed for {}", "✓".green(), branch.cyan());
-                    }
-                    CiWaitResult::Failed => {
-                        anyhow::bail!("CI failed for PR #{}. Cannot merge until CI passes.", pr_number);
-                    }
-                    CiWaitResult::Timeout => {
-                        anyhow::bail!(
-                            "CI timeout for PR #{}. CI did not complete within {} seconds.\n\
-                             Use --no-wait to skip CI waiting, or increase timeout via:\n  {} config set merge.ci_timeout_secs <seconds>",
-                            pr_number, ci_wait_config.timeout_secs, program_name()
-                        );
-                    }
-                }
+            if let Err(e) =
+                rebase_and_wait_for_ci(&gateway, forge.as_ref(), branch, &trunk, &pr_number, &ci_wait_config)
+            {
+                failure = Some(e);
+                break;
             }
         }
 
@@ -240,7 +249,8 @@ pub async fn run(
                 // This is critical for squash merges: the child PR's original base branch
                 // was just squash-merged, so we need to point it at trunk instead.
                 // Only retarget if the next PR is still open (skip if already merged/closed).
-                match retarget_next_pr_if_open(forge.as_ref(), &mergeable, i, &trunk) {
+                let retarget = retarget_next_pr_if_open(forge.as_ref(), &mergeable, i, &trunk);
+                match &retarget {
                     Ok(Some(next_branch)) => {
                         println!("    Retargeted {} to {}", next_branch.cyan(), trunk.green());
                     }
@@ -261,6 +271,33 @@ pub async fn run(
                         }
                     }
                 }
+
+                // Delete the remote branch only after the next PR was retargeted,
+                // so the forge doesn't close it along with its base
+                match delete_remote_after_retarget(&gateway, branch, &retarget, auto_delete_remote) {
+                    Ok(true) => println!("    Deleted remote branch {}", branch.cyan()),
+                    Ok(false) if auto_delete_remote && retarget.is_err() => {
+                        eprintln!(
+                            "  {} Kept remote branch {} so the next PR isn't closed with it",
+                            "!".yellow(),
+                            branch
+                        );
+                    }
+                    Ok(false) => {}
+                    Err(e) => eprintln!("  {} Could not delete remote branch {}: {}", "!".yellow(), branch, e),
+                }
+
+                merged_now.push((
+                    branch.clone(),
+                    PrInfo {
+                        number: pr_number.parse().unwrap_or_default(),
+                        url: url.clone(),
+                        head_ref: branch.clone(),
+                        base_ref: trunk.clone(),
+                        state: PrState::Merged,
+                        title: String::new(),
+                    },
+                ));
             }
             Err(e) => {
                 eprintln!("  {} Failed to merge PR #{}: {}", "✗".red(), pr_number, e);
@@ -276,13 +313,16 @@ pub async fn run(
                 }
 
                 eprintln!("\n{} Stopping downstack merge. Remaining PRs not merged.", "!".yellow());
-                return Err(e);
+                failure = Some(e);
+                break;
             }
         }
     }
 
     if actually_merged == 0 {
-        println!("\n{} All PRs were already merged", "✓".green().bold());
+        if failure.is_none() {
+            println!("\n{} All PRs were already merged", "✓".green().bold());
+        }
     } else {
         println!(
             "\n{} Merged {} PR{}",
@@ -292,11 +332,39 @@ pub async fn run(
         );
     }
 
+    // Remove merged local branches now that their remote side is gone
+    if auto_delete_remote && !keep && !merged_now.is_empty() {
+        println!("\n{} Cleaning up merged local branches...", "→".blue());
+        if merged_now.iter().any(|(branch, _)| *branch == current) {
+            gateway.checkout_branch_worktree_safe(&trunk)?;
+        }
+        let async_forge = get_async_forge(None).ok();
+        cleanup_merged_branches_for_sync_async(
+            &gateway,
+            &ref_store,
+            &mut cache,
+            &trunk,
+            &merged_now,
+            async_forge.as_deref(),
+        )
+        .await?;
+    }
+
+    // A later PR failed to merge: the ones before it are merged and cleaned up
+    if let Some(e) = failure {
+        return Err(e);
+    }
+
     // Auto-sync to update local branches and clean up merged ones
     if !no_sync && !dry_run {
         println!("\n{} Syncing local branches...", "→".blue());
-        // Run sync with: continue=false, abort=false, force=false, no_cleanup=false, restack=true, verbose=false
-        if let Err(e) = sync::run(false, false, false, false, true, false).await {
+        if let Err(e) = sync::run(sync::SyncOptions {
+            no_cleanup: keep,
+            restack: true,
+            ..Default::default()
+        })
+        .await
+        {
             // Sync errors shouldn't fail the merge command since PRs are already merged
             eprintln!("  {} Sync encountered an issue: {}", "!".yellow(), e);
             eprintln!("  Run '{} sync' manually to complete cleanup.", program_name());
@@ -317,6 +385,211 @@ pub async fn run(
     Ok(())
 }
 
+/// What `merge` would do with one PR, as reported by `--dry-run`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PlannedAction {
+    /// Already merged or closed - the merge loop skips it
+    Skip(&'static str),
+    /// Merge right away
+    Merge,
+    /// Rebase onto trunk (and optionally wait for CI) before merging
+    RebaseThenMerge { wait_for_ci: bool },
+    /// The merge would stop at this PR
+    Blocked(&'static str),
+    /// An earlier PR blocks the merge, so this one is never attempted
+    NotReached,
+}
+
+impl std::fmt::Display for PlannedAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlannedAction::Skip(reason) => write!(f, "skip ({})", reason),
+            PlannedAction::Merge => write!(f, "merge"),
+            PlannedAction::RebaseThenMerge { wait_for_ci: true } => {
+                write!(f, "rebase onto trunk, wait for CI, then merge")
+            }
+            PlannedAction::RebaseThenMerge { wait_for_ci: false } => write!(f, "rebase onto trunk, then merge"),
+            PlannedAction::Blocked(reason) => write!(f, "blocked: {}", reason),
+            PlannedAction::NotReached => write!(f, "not reached (an earlier PR is blocked)"),
+        }
+    }
+}
+
+/// One PR in the `--dry-run` report
+struct MergePlanEntry {
+    branch: String,
+    pr_number: String,
+    /// None if the forge couldn't be queried
+    info: Option<PrFullInfo>,
+    action: PlannedAction,
+}
+
+/// Decide what `merge` would do with a PR at `position` in the downstack
+///
+/// Mirrors the merge loop: merged/closed PRs are skipped, PRs after the first
+/// are rebased onto trunk first when `proactive_rebase` is on, and PRs the
+/// forge would refuse (drafts, conflicts, failing CI, requested changes) stop
+/// the merge. Without forge info, the loop would still attempt the merge.
+fn plan_action(info: Option<&PrFullInfo>, position: usize, proactive_rebase: bool, wait_for_ci: bool) -> PlannedAction {
+    if let Some(pr) = info {
+        match pr.state {
+            PrState::Merged => return PlannedAction::Skip("already merged"),
+            PrState::Closed => return PlannedAction::Skip("closed"),
+            PrState::Open => {}
+        }
+        if pr.is_draft {
+            return PlannedAction::Blocked("PR is a draft");
+        }
+        if pr.mergeable == Some(false) {
+            return PlannedAction::Blocked("conflicts with its base");
+        }
+        if pr.ci == CiStatus::Failure {
+            return PlannedAction::Blocked("CI is failing");
+        }
+        if pr.review == ReviewState::ChangesRequested {
+            return PlannedAction::Blocked("changes requested");
+        }
+    }
+
+    if proactive_rebase && position > 0 {
+        PlannedAction::RebaseThenMerge { wait_for_ci }
+    } else {
+        PlannedAction::Merge
+    }
+}
+
+/// Build the `--dry-run` report for the PRs `merge` would process, in order
+fn plan_merges(
+    forge: &dyn Forge,
+    mergeable: &[(String, String)],
+    proactive_rebase: bool,
+    wait_for_ci: bool,
+) -> Vec<MergePlanEntry> {
+    let mut blocked = false;
+    mergeable
+        .iter()
+        .enumerate()
+        .map(|(i, (branch, url))| {
+            let pr_number = extract_pr_number(url).unwrap_or_else(|_| url.clone());
+            let info = forge.get_pr_full_info(&pr_number).ok();
+            let action = if blocked {
+                PlannedAction::NotReached
+            } else {
+                plan_action(info.as_ref(), i, proactive_rebase, wait_for_ci)
+            };
+            blocked |= matches!(action, PlannedAction::Blocked(_));
+            MergePlanEntry {
+                branch: branch.clone(),
+                pr_number,
+                info,
+                action,
+            }
+        })
+        .collect()
+}
+
+fn print_merge_plan(plan: &[MergePlanEntry]) {
+    for entry in plan {
+        println!("  • {} (PR #{})", entry.branch.green(), entry.pr_number);
+        match &entry.info {
+            Some(pr) => {
+                let mergeable = match pr.mergeable {
+                    Some(true) => "yes".green().to_string(),
+                    Some(false) => "no (conflicts)".red().to_string(),
+                    None => "unknown".dimmed().to_string(),
+                };
+                println!(
+                    "      state: {} · review: {} {} · CI: {} {} · mergeable: {}",
+                    pr.state_display(),
+                    pr.review.emoji(),
+                    pr.review,
+                    pr.ci.emoji(),
+                    pr.ci,
+                    mergeable
+                );
+            }
+            None => println!("      {}", "status unavailable".dimmed()),
+        }
+        let action = entry.action.to_string();
+        let action = match entry.action {
+            PlannedAction::Merge | PlannedAction::RebaseThenMerge { .. } => action.green(),
+            PlannedAction::Blocked(_) => action.red(),
+            PlannedAction::Skip(_) | PlannedAction::NotReached => action.dimmed(),
+        };
+        println!("      → {}", action);
+    }
+}
+
+/// Delete a merged PR's branch from the remote (`--auto-delete-remote`)
+///
+/// Does nothing unless the flag is set and retargeting the next PR succeeded:
+/// deleting the base of a PR that still points at it makes the forge close it.
+/// Returns whether the remote branch was deleted.
+fn delete_remote_after_retarget(
+    gateway: &GitGateway,
+    branch: &str,
+    retarget: &Result<Option<String>>,
+    auto_delete_remote: bool,
+) -> Result<bool> {
+    if !auto_delete_remote || retarget.is_err() {
+        return Ok(false);
+    }
+    gateway.delete_remote_branch(branch)?;
+    Ok(true)
+}
+
+/// Rebase a PR after the first onto trunk and wait for its CI (proactive mode)
+///
+/// This ensures clean history and that CI passes before we try to merge.
+fn rebase_and_wait_for_ci(
+    gateway: &GitGateway,
+    forge: &dyn Forge,
+    branch: &str,
+    trunk: &str,
+    pr_number: &str,
+    ci_wait_config: &CiWaitConfig,
+) -> Result<()> {
+    match proactive_rebase_for_merge(gateway, forge, branch, trunk) {
+        Ok(true) => {
+            println!("  {} Rebased {} onto {}", "→".blue(), branch.cyan(), trunk.green());
+        }
+        Ok(false) => {
+            // Already up to date, no rebase needed
+        }
+        Err(e) => {
+            // Rebase failed with conflicts - abort entire operation
+            eprintln!("  {} Rebase failed: {}", "✗".red(), e);
+            anyhow::bail!(
+                "Could not rebase {} onto {}. Resolve conflicts manually with:\n  {} sync",
+                branch,
+                trunk,
+                program_name()
+            );
+        }
+    }
+
+    // Wait for CI after rebase (if enabled)
+    if ci_wait_config.enabled {
+        match wait_for_ci(forge, pr_number, branch, ci_wait_config)? {
+            CiWaitResult::Success | CiWaitResult::NoChecks => {
+                println!("  {} CI passed for {}", "✓".green(), branch.cyan());
+            }
+            CiWaitResult::Failed => {
+                anyhow::bail!("CI failed for PR #{}. Cannot merge until CI passes.", pr_number);
+            }
+            CiWaitResult::Timeout => {
+                anyhow::bail!(
+                        "CI timeout for PR #{}. CI did not complete within {} seconds.\n\
+                         Use --no-wait to skip CI waiting, or increase timeout via:\n  {} config set merge.ci_timeout_secs <seconds>",
+                        pr_number, ci_wait_config.timeout_secs, program_name()
+                    );
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Proactively rebase a branch onto trunk before attempting to merge.
 ///
 /// This is used in "safe by default" mode to ensure clean history and that CI runs
@@ -586,6 +859,55 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_remote_branch_kept_when_retarget_fails() -> anyhow::Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let remote_dir = tempdir()?;
+        let remote_repo = git2::Repository::init_bare(remote_dir.path())?;
+        repo.remote("origin", remote_dir.path().to_str().unwrap())?;
+        for branch in ["a", "b", "unflagged"] {
+            create_branch(&repo, branch)?;
+            let refspec = format!("refs/heads/{0}:refs/heads/{0}", branch);
+            repo.find_remote("origin")?.push(&[refspec.as_str()], None)?;
+        }
+
+        let gateway = GitGateway::new()?;
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response(
+            "update_pr_base:b",
+            MockResponse::Error("Could not update base".to_string()),
+        );
+        let on_remote = |branch: &str| remote_repo.find_branch(branch, git2::BranchType::Local).is_ok();
+        let stack = vec![
+            ("a".to_string(), "https://github.com/test/repo/pull/1".to_string()),
+            ("b".to_string(), "https://github.com/test/repo/pull/2".to_string()),
+        ];
+
+        // Retargeting b failed: deleting a would close b's PR, so a stays on the remote
+        let retarget = retarget_next_pr_if_open(&forge, &stack, 0, "main");
+        assert!(retarget.is_err());
+        assert!(!delete_remote_after_retarget(&gateway, "a", &retarget, true)?);
+        assert!(on_remote("a"));
+
+        // Last PR in the stack: nothing to retarget, so it is deleted
+        let retarget = retarget_next_pr_if_open(&forge, &stack, 1, "main");
+        assert!(matches!(retarget, Ok(None)));
+        assert!(delete_remote_after_retarget(&gateway, "b", &retarget, true)?);
+        assert!(!on_remote("b"));
+
+        // Flag not set: remote branch kept
+        assert!(!delete_remote_after_retarget(&gateway, "unflagged", &Ok(None), false)?);
+        assert!(on_remote("unflagged"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_extract_pr_number() {
         assert_eq!(
@@ -605,6 +927,69 @@ mod tests {
         assert_eq!(MergeMethod::Rebase.as_str(), "rebase");
     }
 
+    #[test]
+    fn test_plan_merges_classifies_mixed_chain() -> anyhow::Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        let pr = |number: u64, state: PrState, review: ReviewState, ci: CiStatus, mergeable: Option<bool>| PrFullInfo {
+            number,
+            url: format!("https://github.com/org/repo/pull/{}", number),
+            title: format!("PR {}", number),
+            state,
+            is_draft: false,
+            review,
+            ci,
+            head_ref: String::new(),
+            base_ref: "main".to_string(),
+            mergeable,
+        };
+        let prs = [
+            pr(1, PrState::Merged, ReviewState::Approved, CiStatus::Success, None),
+            pr(2, PrState::Open, ReviewState::Approved, CiStatus::Success, Some(true)),
+            pr(3, PrState::Open, ReviewState::Pending, CiStatus::Pending, None),
+            pr(4, PrState::Open, ReviewState::Approved, CiStatus::Success, Some(false)),
+            pr(5, PrState::Open, ReviewState::Approved, CiStatus::Success, Some(true)),
+        ];
+        for info in &prs {
+            forge.set_response(
+                &format!("get_pr_full_info:{}", info.number),
+                MockResponse::Success(serde_json::to_string(info)?),
+            );
+        }
+        let chain: Vec<(String, String)> = prs
+            .iter()
+            .map(|info| (format!("b{}", info.number), info.url.clone()))
+            .collect();
+
+        let actions: Vec<PlannedAction> = plan_merges(&forge, &chain, true, true)
+            .into_iter()
+            .map(|entry| entry.action)
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                PlannedAction::Skip("already merged"),
+                PlannedAction::RebaseThenMerge { wait_for_ci: true },
+                PlannedAction::RebaseThenMerge { wait_for_ci: true },
+                PlannedAction::Blocked("conflicts with its base"),
+                PlannedAction::NotReached,
+            ]
+        );
+
+        // Without proactive rebase, open PRs are merged directly
+        let plan = plan_merges(&forge, &chain[1..3], false, false);
+        assert_eq!(plan[0].action, PlannedAction::Merge);
+        assert_eq!(plan[1].action, PlannedAction::Merge);
+        assert_eq!(plan[1].info.as_ref().map(|info| info.ci), Some(CiStatus::Pending));
+
+        // Nothing was merged
+        assert_eq!(forge.get_call_count("merge_pr:2"), 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_collect_downstack_returns_branches_in_merge_order() -> anyhow::Result<()> {
         let dir = tempdir()?;
@@ -885,6 +1270,7 @@ mod tests {
                 ci: CiStatus::None,
                 head_ref: "branch".to_string(),
                 base_ref: "main".to_string(),
+                mergeable: None,
             })
         }
         fn get_pr_body(&self, _pr_ref: &str) -> Result<String> {
@@ -1116,6 +1502,7 @@ mod tests {
                 ci: CiStatus::None,
                 head_ref: "branch".to_string(),
                 base_ref: "main".to_string(),
+                mergeable: None,
             })
         }
         fn get_pr_body(&self, _pr_ref: &str) -> Result<String> {
@@ -1549,6 +1936,7 @@ mod tests {
                 ci: CiStatus::None,
                 head_ref: "branch".to_string(),
                 base_ref: "main".to_string(),
+                mergeable: None,
             })
         }
         fn get_pr_body(&self, _pr_ref: &str) -> Result<String> {
@@ -18,23 +18,29 @@ pub mod up;
 // New commands
 pub mod abort;
 pub mod bottom;
+pub mod cache_cmd;
 pub mod cleanup;
 pub mod completion;
 pub mod config_cmd;
 pub mod continue_op;
 pub mod doctor;
+pub mod export;
 pub mod fold;
 pub mod freeze;
 pub mod gc;
 pub mod history;
+pub mod import;
 pub mod info;
 pub mod move_cmd;
 pub mod pr;
+pub mod rebase;
 pub mod rename;
 pub mod reorder;
 pub mod restack;
+pub mod setup_aliases;
 pub mod split;
 pub mod squash;
+pub mod status;
 pub mod top;
 pub mod undo;
 pub mod unfreeze;
@@ -1,34 +1,64 @@
 use crate::commands::restack;
+use crate::config::Config;
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 use anyhow::{Context, Result};
 
+/// Options for [`run`], one per `modify` flag
+#[derive(Debug, Default)]
+pub struct ModifyOptions {
+    /// Stage all changes, including new untracked files
+    pub all: bool,
+    /// Stage modifications and deletions of tracked files only
+    pub update: bool,
+    /// Commit message
+    pub message: Option<String>,
+    /// Create a new commit instead of amending
+    pub force_commit: bool,
+    /// Edit the commit message in an editor
+    pub edit: bool,
+    /// Reset the author of the commit to the current user
+    pub reset_author: bool,
+    /// Open an interactive rebase from the parent branch
+    pub interactive_rebase: bool,
+    /// Amend the changes into this downstack branch instead
+    pub into: Option<String>,
+    /// Create a `fixup!` commit targeting this commit instead of amending
+    pub fixup: Option<String>,
+    /// Immediately fold the fixup commit into its target
+    pub autosquash: bool,
+}
+
 /// Modify current branch (stage and commit/amend)
 ///
 /// Behavior:
-/// - If -a: stages all changes
-/// - If -u: stages only updates to already-tracked files (like git add -u)
+/// - If -a: stages all changes, including new untracked files and deletions
+/// - If -u/--tracked: stages modifications and deletions of tracked files only (like git add -u)
 /// - If -c: create new commit (uses -m message or opens editor)
 /// - If -e: open editor to edit commit message
 /// - If -m without -c: amend with new message
 /// - If --reset-author: reset the commit author to current user
 /// - If -i/--interactive-rebase: open interactive rebase from parent
 /// - If --into <branch>: amend changes into a downstack branch instead of current
+/// - If --fixup <commit>: create a `fixup!` commit for that commit (with --autosquash: fold it in)
 /// - If no -m and no -c: amend existing commit preserving its message
 ///
 /// After amending, automatically restacks any child branches
-#[allow(clippy::too_many_arguments)]
-pub fn run(
-    all: bool,
-    update: bool,
-    message: Option<String>,
-    force_commit: bool,
-    edit: bool,
-    reset_author: bool,
-    interactive_rebase: bool,
-    into: Option<String>,
-) -> Result<()> {
+pub fn run(options: ModifyOptions) -> Result<()> {
+    let ModifyOptions {
+        all,
+        update,
+        message,
+        force_commit,
+        edit,
+        reset_author,
+        interactive_rebase,
+        into,
+        fixup,
+        autosquash,
+    } = options;
+
     let gateway = GitGateway::new()?;
     let ref_store = RefStore::new()?;
 
@@ -40,6 +70,11 @@ pub fn run(
         anyhow::bail!("Cannot use both -a (all) and -u (update) flags together");
     }
 
+    // Enforce commit.require_conventional before touching anything
+    if let (Some(msg), Ok(config)) = (&message, Config::load()) {
+        config.check_commit_message(msg)?;
+    }
+
     let current_branch = gateway.get_current_branch_name()?;
 
     // Check if we're on trunk - cannot modify trunk directly
@@ -84,6 +119,11 @@ pub fn run(
         );
     }
 
+    // Handle --fixup: create a fixup! commit instead of amending
+    if let Some(ref target) = fixup {
+        return run_fixup(&gateway, &ref_store, &current_branch, target, all, update, autosquash);
+    }
+
     // Handle interactive rebase first (mutually exclusive with other operations)
     if interactive_rebase {
         let parent = ref_store
@@ -169,19 +209,7 @@ fn run_into(
     update: bool,
     message: Option<String>,
 ) -> Result<()> {
-    // Verify target branch exists
-    if !gateway.branch_exists(target_branch)? {
-        anyhow::bail!("Branch '{}' does not exist", target_branch);
-    }
-
-    // Verify target branch is in the downstack (is an ancestor of current branch)
-    if !is_in_downstack(ref_store, current_branch, target_branch)? {
-        anyhow::bail!(
-            "Branch '{}' is not in the downstack of '{}'. Can only modify ancestor branches.",
-            target_branch,
-            current_branch
-        );
-    }
+    validate_into_target(gateway, ref_store, current_branch, target_branch)?;
 
     // Check if target branch is frozen
     if ref_store.is_frozen(target_branch)? {
@@ -227,6 +255,125 @@ fn run_into(
     Ok(())
 }
 
+/// Check that `target_branch` is a valid `--into` target: a tracked, non-trunk
+/// ancestor of `current_branch` in the stack metadata
+fn validate_into_target(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    current_branch: &str,
+    target_branch: &str,
+) -> Result<()> {
+    if target_branch == current_branch {
+        anyhow::bail!(
+            "'{}' is the current branch; --into takes a downstack branch.\n  \
+             Run '{} modify' without --into to amend the current branch.",
+            target_branch,
+            program_name()
+        );
+    }
+
+    if !gateway.branch_exists(target_branch)? {
+        anyhow::bail!("Branch '{}' does not exist", target_branch);
+    }
+
+    if ref_store.get_trunk()?.as_deref() == Some(target_branch) {
+        anyhow::bail!(
+            "Cannot modify into the trunk branch '{}'. --into only amends stacked branches.",
+            target_branch
+        );
+    }
+
+    for branch in [current_branch, target_branch] {
+        if !ref_store.is_tracked(branch)? {
+            anyhow::bail!(
+                "Branch '{}' is not tracked by Diamond. Run '{} track {}' first.",
+                branch,
+                program_name(),
+                branch
+            );
+        }
+    }
+
+    if !is_in_downstack(ref_store, current_branch, target_branch)? {
+        let mut candidates = ref_store.ancestors(current_branch)?;
+        candidates.pop(); // the current branch itself
+        let hint = if candidates.is_empty() {
+            format!("'{}' has no downstack branches to modify.", current_branch)
+        } else {
+            format!("Downstack branches: {}", candidates.join(", "))
+        };
+        anyhow::bail!(
+            "Branch '{}' is not in the downstack of '{}'. Can only modify ancestor branches.\n  {}",
+            target_branch,
+            current_branch,
+            hint
+        );
+    }
+
+    Ok(())
+}
+
+/// Handle --fixup flag: create a `fixup!` commit targeting a commit in the current branch
+fn run_fixup(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    current_branch: &str,
+    target: &str,
+    all: bool,
+    update: bool,
+    autosquash: bool,
+) -> Result<()> {
+    let parent = ref_store
+        .get_parent(current_branch)?
+        .ok_or_else(|| anyhow::anyhow!("Branch '{}' has no parent. Cannot create fixup commit.", current_branch))?;
+
+    let target_oid = gateway
+        .resolve_ref(target)
+        .with_context(|| format!("Commit '{}' does not exist", target))?
+        .to_string();
+
+    // The target must be one of this branch's own commits so autosquash stays within the branch
+    if !gateway.is_ancestor(&target_oid, current_branch)? || gateway.is_ancestor(&target_oid, &parent)? {
+        anyhow::bail!(
+            "Commit '{}' is not part of branch '{}':\n  \
+             Only commits between '{}' and '{}' can be fixed up.",
+            target,
+            current_branch,
+            parent,
+            current_branch
+        );
+    }
+
+    // Stage changes based on flags
+    if all {
+        gateway.stage_all()?;
+        println!("Staged all changes");
+    } else if update {
+        gateway.stage_updates()?;
+        println!("Staged tracked file updates");
+    }
+
+    gateway.commit_fixup(&target_oid)?;
+    let short = gateway
+        .get_short_hash(&target_oid)
+        .unwrap_or_else(|_| target.to_string());
+    println!("Created fixup commit for {}", short);
+
+    if autosquash {
+        if gateway.rebase_autosquash(&parent)?.has_conflicts() {
+            anyhow::bail!(
+                "Autosquash stopped due to conflicts on '{}'.\n  \
+                 Resolve them and run 'git rebase --continue', or 'git rebase --abort' to cancel.",
+                current_branch
+            );
+        }
+        println!("Folded fixup into {}", short);
+        restack::restack_children(current_branch)?;
+    }
+
+    Ok(())
+}
+
 /// Check if target_branch is in the downstack (ancestors) of current_branch
 fn is_in_downstack(ref_store: &RefStore, current_branch: &str, target_branch: &str) -> Result<bool> {
     let trunk = ref_store.get_trunk()?;
@@ -292,7 +439,7 @@ mod tests {
         }
 
         // Modify without -a and without message (should amend)
-        run(false, false, None, false, false, false, false, None)?;
+        run(ModifyOptions::default())?;
 
         // Verify message was preserved
         let head = repo.head()?.peel_to_commit()?;
@@ -320,16 +467,11 @@ mod tests {
         fs::write(dir.path().join("test.txt"), "test content")?;
 
         // Modify with -am (should AMEND with new message, not create new commit)
-        run(
-            true,
-            false,
-            Some("Updated message".to_string()),
-            false,
-            false,
-            false,
-            false,
-            None,
-        )?;
+        run(ModifyOptions {
+            all: true,
+            message: Some("Updated message".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify commit was amended (same parent, different hash)
         let head = repo.head()?.peel_to_commit()?;
@@ -359,16 +501,12 @@ mod tests {
         fs::write(dir.path().join("test.txt"), "test content")?;
 
         // Modify with -c -m (should CREATE new commit)
-        run(
-            true,
-            false,
-            Some("Second commit".to_string()),
-            true,
-            false,
-            false,
-            false,
-            None,
-        )?;
+        run(ModifyOptions {
+            all: true,
+            message: Some("Second commit".to_string()),
+            force_commit: true,
+            ..Default::default()
+        })?;
 
         // Verify a new commit was created (2 commits total after Initial)
         let head = repo.head()?.peel_to_commit()?;
@@ -392,16 +530,11 @@ mod tests {
         fs::write(dir.path().join("file2.txt"), "content2")?;
 
         // Modify with -am
-        run(
-            true,
-            false,
-            Some("Multiple files".to_string()),
-            false,
-            false,
-            false,
-            false,
-            None,
-        )?;
+        run(ModifyOptions {
+            all: true,
+            message: Some("Multiple files".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify both files are in commit
         let head = repo.head()?.peel_to_commit()?;
@@ -431,16 +564,11 @@ mod tests {
 
         // Add more content and amend with -m (no -c)
         fs::write(dir.path().join("file2.txt"), "content2")?;
-        run(
-            true,
-            false,
-            Some("Amended message".to_string()),
-            false,
-            false,
-            false,
-            false,
-            None,
-        )?;
+        run(ModifyOptions {
+            all: true,
+            message: Some("Amended message".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify we AMENDED (not created new) - should still have same parent
         let head_after = repo.head()?.peel_to_commit()?;
@@ -469,7 +597,10 @@ mod tests {
         fs::write(dir.path().join("file2.txt"), "new content")?;
 
         // Modify with -a but no message (should amend and preserve message)
-        run(true, false, None, false, false, false, false, None)?;
+        run(ModifyOptions {
+            all: true,
+            ..Default::default()
+        })?;
 
         // Verify message was preserved
         let head = repo.head()?.peel_to_commit()?;
@@ -506,16 +637,11 @@ mod tests {
         fs::write(dir.path().join("file.txt"), "content")?;
 
         // Try to modify - should fail
-        let result = run(
-            true,
-            false,
-            Some("Should fail".to_string()),
-            false,
-            false,
-            false,
-            false,
-            None,
-        );
+        let result = run(ModifyOptions {
+            all: true,
+            message: Some("Should fail".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -557,20 +683,20 @@ mod tests {
 
         // We're on child, modify into parent
         fs::write(dir.path().join("fix.txt"), "fix for parent")?;
-        run(
-            true,
-            false,
-            Some("Fixed parent".to_string()),
-            false,
-            false,
-            false,
-            false,
-            Some("parent".to_string()),
-        )?;
+        run(ModifyOptions {
+            all: true,
+            message: Some("Fixed parent".to_string()),
+            into: Some("parent".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify we're back on child
         assert_eq!(gateway.get_current_branch_name()?, "child");
 
+        // Child was restacked onto the amended parent commit
+        assert!(gateway.is_ancestor("parent", "child")?);
+        assert!(dir.path().join("fix.txt").exists());
+
         // Verify parent commit was amended
         gateway.checkout_branch_worktree_safe("parent")?;
         let repo = git2::Repository::open(dir.path())?;
@@ -601,16 +727,11 @@ mod tests {
         ref_store.set_parent("feature", "main")?;
 
         // Try to modify into nonexistent branch
-        let result = run(
-            false,
-            false,
-            Some("Test".to_string()),
-            false,
-            false,
-            false,
-            false,
-            Some("nonexistent".to_string()),
-        );
+        let result = run(ModifyOptions {
+            message: Some("Test".to_string()),
+            into: Some("nonexistent".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("does not exist"));
@@ -639,16 +760,11 @@ mod tests {
         ref_store.set_parent("branch-b", "main")?;
 
         // From branch-b, try to modify into branch-a (not in downstack)
-        let result = run(
-            false,
-            false,
-            Some("Test".to_string()),
-            false,
-            false,
-            false,
-            false,
-            Some("branch-a".to_string()),
-        );
+        let result = run(ModifyOptions {
+            message: Some("Test".to_string()),
+            into: Some("branch-a".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -657,6 +773,42 @@ mod tests {
             "Expected error about downstack, got: {}",
             err_msg
         );
+        assert!(err_msg.contains("no downstack branches"), "got: {}", err_msg);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_modify_into_current_branch_or_trunk_fails() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        gateway.create_branch("feature")?;
+        ref_store.set_parent("feature", "main")?;
+
+        let into = |target: &str| {
+            run(ModifyOptions {
+                message: Some("Test".to_string()),
+                into: Some(target.to_string()),
+                ..Default::default()
+            })
+            .unwrap_err()
+            .to_string()
+        };
+
+        let err_msg = into("feature");
+        assert!(err_msg.contains("is the current branch"), "got: {}", err_msg);
+
+        let err_msg = into("main");
+        assert!(err_msg.contains("Cannot modify into the trunk"), "got: {}", err_msg);
+
+        // Nothing was switched or amended
+        assert_eq!(gateway.get_current_branch_name()?, "feature");
 
         Ok(())
     }
@@ -726,16 +878,12 @@ mod tests {
 
         // Try to modify into frozen parent
         fs::write(dir.path().join("fix.txt"), "fix")?;
-        let result = run(
-            true,
-            false,
-            Some("Fix".to_string()),
-            false,
-            false,
-            false,
-            false,
-            Some("parent".to_string()),
-        );
+        let result = run(ModifyOptions {
+            all: true,
+            message: Some("Fix".to_string()),
+            into: Some("parent".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -772,16 +920,11 @@ mod tests {
         fs::write(dir.path().join("untracked.txt"), "new")?;
 
         // Modify with -u flag (should only stage tracked file updates)
-        run(
-            false,
-            true,
-            Some("Update tracked".to_string()),
-            false,
-            false,
-            false,
-            false,
-            None,
-        )?;
+        run(ModifyOptions {
+            update: true,
+            message: Some("Update tracked".to_string()),
+            ..Default::default()
+        })?;
 
         // Verify the commit only has tracked.txt
         let head = repo.head()?.peel_to_commit()?;
@@ -800,16 +943,12 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Try to use both -a and -u
-        let result = run(
-            true, // all
-            true, // update
-            Some("Test".to_string()),
-            false,
-            false,
-            false,
-            false,
-            None,
-        );
+        let result = run(ModifyOptions {
+            all: true,
+            update: true,
+            message: Some("Test".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -837,16 +976,11 @@ mod tests {
         fs::write(dir.path().join("test.txt"), "content")?;
 
         // Try to modify on trunk - should fail
-        let result = run(
-            true,
-            false,
-            Some("Should fail".to_string()),
-            false,
-            false,
-            false,
-            false,
-            None,
-        );
+        let result = run(ModifyOptions {
+            all: true,
+            message: Some("Should fail".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
@@ -898,16 +1032,11 @@ mod tests {
 
         // Modify B - should succeed because modify auto-repairs orphaned metadata
         // The silent_cleanup_orphaned_refs call reparents B to trunk when A is missing
-        let result = run(
-            true,  // all
-            false, // update
-            Some("Update B".to_string()),
-            false, // force_commit
-            false, // edit
-            false, // reset_author
-            false, // interactive_rebase
-            None,  // into
-        );
+        let result = run(ModifyOptions {
+            all: true,
+            message: Some("Update B".to_string()),
+            ..Default::default()
+        });
 
         assert!(
             result.is_ok(),
@@ -957,19 +1086,170 @@ mod tests {
         fs::write(dir.path().join("change.txt"), "change")?;
 
         // Modify B - should succeed since A (parent) exists
-        let result = run(
-            true,  // all
-            false, // update
-            Some("Update B".to_string()),
-            false, // force_commit
-            false, // edit
-            false, // reset_author
-            false, // interactive_rebase
-            None,  // into
-        );
+        let result = run(ModifyOptions {
+            all: true,
+            message: Some("Update B".to_string()),
+            ..Default::default()
+        });
 
         assert!(result.is_ok());
 
         Ok(())
     }
+
+    fn setup_branch_with_two_commits(dir: &Path) -> Result<(git2::Repository, String)> {
+        let repo = init_test_repo_with_branch(dir, "main")?;
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+
+        gateway.create_branch("feature")?;
+        ref_store.set_parent("feature", "main")?;
+
+        fs::write(dir.join("a.txt"), "a1")?;
+        gateway.stage_all()?;
+        gateway.commit("Add a")?;
+        let target = repo.head()?.peel_to_commit()?.id().to_string();
+
+        fs::write(dir.join("b.txt"), "b1")?;
+        gateway.stage_all()?;
+        gateway.commit("Add b")?;
+
+        Ok((repo, target))
+    }
+
+    #[test]
+    fn test_modify_fixup_creates_fixup_commit() -> Result<()> {
+        let dir = tempdir()?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let (repo, target) = setup_branch_with_two_commits(dir.path())?;
+        let head_before = repo.head()?.peel_to_commit()?.id();
+
+        fs::write(dir.path().join("a.txt"), "a2")?;
+        run(ModifyOptions {
+            all: true,
+            fixup: Some(target.clone()),
+            ..Default::default()
+        })?;
+
+        let head = repo.head()?.peel_to_commit()?;
+        assert_eq!(head.summary(), Some("fixup! Add a"));
+        assert_eq!(
+            head.parent_id(0)?,
+            head_before,
+            "fixup should be a new commit, not an amend"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_modify_fixup_autosquash_folds_into_target() -> Result<()> {
+        let dir = tempdir()?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let (repo, target) = setup_branch_with_two_commits(dir.path())?;
+
+        fs::write(dir.path().join("a.txt"), "a2")?;
+        run(ModifyOptions {
+            all: true,
+            fixup: Some(target),
+            autosquash: true,
+            ..Default::default()
+        })?;
+
+        // Still two commits on the branch: "Add a" (now with a2) and "Add b"
+        let head = repo.head()?.peel_to_commit()?;
+        assert_eq!(head.summary(), Some("Add b"));
+        let folded = head.parent(0)?;
+        assert_eq!(folded.summary(), Some("Add a"));
+        let blob = folded
+            .tree()?
+            .get_path(Path::new("a.txt"))?
+            .to_object(&repo)?
+            .peel_to_blob()?;
+        assert_eq!(blob.content(), b"a2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_modify_fixup_rejects_commit_outside_branch() -> Result<()> {
+        let dir = tempdir()?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let (_repo, _target) = setup_branch_with_two_commits(dir.path())?;
+
+        fs::write(dir.path().join("a.txt"), "a2")?;
+        let result = run(ModifyOptions {
+            all: true,
+            fixup: Some("main".to_string()),
+            ..Default::default()
+        });
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("is not part of branch"));
+
+        Ok(())
+    }
+
+    /// Commit tracked.txt and gone.txt, then edit one, delete the other and add new.txt
+    fn setup_mixed_changes(dir: &Path) -> Result<git2::Repository> {
+        let repo = init_test_repo_with_branch(dir, "main")?;
+        let gateway = GitGateway::new()?;
+        fs::write(dir.join("tracked.txt"), "v1")?;
+        fs::write(dir.join("gone.txt"), "doomed")?;
+        gateway.stage_all()?;
+        gateway.commit("Base")?;
+
+        fs::write(dir.join("tracked.txt"), "v2")?;
+        fs::remove_file(dir.join("gone.txt"))?;
+        fs::write(dir.join("new.txt"), "brand new")?;
+        Ok(repo)
+    }
+
+    fn blob_content(repo: &git2::Repository, tree: &git2::Tree, name: &str) -> Result<String> {
+        let blob = repo.find_blob(tree.get_name(name).unwrap().id())?;
+        Ok(String::from_utf8(blob.content().to_vec())?)
+    }
+
+    #[test]
+    fn test_modify_update_stages_modifications_and_deletions_only() -> Result<()> {
+        let dir = tempdir()?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let repo = setup_mixed_changes(dir.path())?;
+
+        run(ModifyOptions {
+            update: true,
+            ..Default::default()
+        })?;
+
+        let tree = repo.head()?.peel_to_commit()?.tree()?;
+        assert_eq!(blob_content(&repo, &tree, "tracked.txt")?, "v2");
+        assert!(tree.get_name("gone.txt").is_none(), "deletion should be staged");
+        assert!(
+            tree.get_name("new.txt").is_none(),
+            "untracked file should not be staged"
+        );
+        assert!(repo.status_file(Path::new("new.txt"))?.is_wt_new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_modify_all_stages_untracked_files_and_deletions() -> Result<()> {
+        let dir = tempdir()?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let repo = setup_mixed_changes(dir.path())?;
+
+        run(ModifyOptions {
+            all: true,
+            ..Default::default()
+        })?;
+
+        let tree = repo.head()?.peel_to_commit()?.tree()?;
+        assert_eq!(blob_content(&repo, &tree, "tracked.txt")?, "v2");
+        assert!(tree.get_name("gone.txt").is_none(), "deletion should be staged");
+        assert_eq!(blob_content(&repo, &tree, "new.txt")?, "brand new");
+
+        Ok(())
+    }
 }
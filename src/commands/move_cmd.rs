@@ -17,6 +17,7 @@ use std::io;
 
 use crate::branch_tree::{build_branch_tree, format_indent, MARKER_CURRENT, MARKER_OTHER};
 use crate::forge::get_forge;
+use crate::git_gateway::backup::new_backup_operation_id;
 use crate::git_gateway::GitGateway;
 use crate::operation_log::{Operation, OperationRecorder};
 use crate::program_name::program_name;
@@ -29,6 +30,12 @@ use crate::worktree;
 /// If source is None, moves the current branch.
 /// If source is Some(branch), moves that branch instead.
 pub fn run(onto: Option<String>, source: Option<String>) -> Result<()> {
+    move_branch(onto, source, None)
+}
+
+/// Move a branch to a new parent, replaying its commits after `upstream`
+/// (defaults to the branch's current parent). Shared with `dm rebase`.
+pub(crate) fn move_branch(onto: Option<String>, source: Option<String>, upstream: Option<String>) -> Result<()> {
     let gateway = GitGateway::new()?;
     gateway.require_clean_for_rebase()?;
 
@@ -160,8 +167,9 @@ pub fn run(onto: Option<String>, source: Option<String>) -> Result<()> {
     // Create backup refs for all branches BEFORE starting
     println!("{} Creating backups...", "→".blue());
     let recorder = OperationRecorder::new()?;
+    let backup_op = new_backup_operation_id()?;
     for branch in &branches_to_rebase {
-        let backup = gateway.create_backup_ref(branch)?;
+        let backup = gateway.create_operation_backup_ref(&backup_op, branch)?;
         println!(
             "  {} Backed up {} @ {}",
             "✓".green(),
@@ -189,7 +197,8 @@ pub fn run(onto: Option<String>, source: Option<String>) -> Result<()> {
     // after metadata is updated but before rebase completes
     // Note: original_branch is where we return after the move - this should be `current`
     // (the branch we were on when starting the move), not `branch_to_move`
-    let state = OperationState::new_move(current.clone(), branches_to_rebase, target_parent.clone(), old_parent);
+    let mut state = OperationState::new_move(current.clone(), branches_to_rebase, target_parent.clone(), old_parent);
+    state.rebase_from = upstream;
     state.save()?;
 
     // METADATA-SECOND: Update metadata BEFORE rebasing (commit intent)
@@ -210,7 +219,6 @@ pub fn run(onto: Option<String>, source: Option<String>) -> Result<()> {
     }
 
     // Start rebasing to match git state to metadata
-    let mut state = state;
     continue_move_from_state(&mut state, &ref_store)
 }
 
@@ -258,14 +266,21 @@ pub fn continue_move_from_state(state: &mut OperationState, _ref_store: &RefStor
         .ok_or_else(|| anyhow::anyhow!("Move operation missing target parent"))?
         .clone();
 
-    // Get the old parent (saved before metadata was updated)
-    let old_parent = state
-        .old_parent
-        .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("Move operation missing old parent"))?
-        .clone();
+    // Get the old parent (saved before metadata was updated), unless `dm rebase`
+    // gave an explicit upstream to replay from
+    let old_parent = match &state.rebase_from {
+        Some(upstream) => upstream.clone(),
+        None => state
+            .old_parent
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Move operation missing old parent"))?
+            .clone(),
+    };
 
-    let first_branch = state.remaining_branches.first().cloned();
+    // The moved branch is the root of the subtree. After `dm continue` it is no
+    // longer in remaining_branches, and the next descendant must not be mistaken
+    // for it (that would replay the whole old stack onto the target).
+    let first_branch = state.all_branches.first().or(state.remaining_branches.first()).cloned();
 
     while !state.remaining_branches.is_empty() {
         let branch = state.remaining_branches.remove(0);
@@ -526,7 +541,7 @@ mod tests {
 
     use tempfile::tempdir;
 
-    use crate::test_context::{init_test_repo, TestRepoContext};
+    use crate::test_context::{commit_on_branch, create_branch_from, init_test_repo, TestRepoContext};
 
     fn create_branch(repo: &git2::Repository, name: &str) -> Result<()> {
         let head = repo.head()?.peel_to_commit()?;
@@ -534,6 +549,88 @@ mod tests {
         Ok(())
     }
 
+    /// main -> a1 -> a2 -> a3 and main -> b1, one commit per branch
+    ///
+    /// `a2` writes `a2_file`, so passing the file `b1` writes makes a2 conflict.
+    fn setup_two_stacks(repo: &git2::Repository, a2_file: &str) -> Result<RefStore> {
+        create_branch(repo, "a1")?;
+        commit_on_branch(repo, "a1", "a1.txt", "a1")?;
+        create_branch_from(repo, "a2", "a1")?;
+        commit_on_branch(repo, "a2", a2_file, "from a2")?;
+        create_branch_from(repo, "a3", "a2")?;
+        commit_on_branch(repo, "a3", "a3.txt", "a3")?;
+        create_branch(repo, "b1")?;
+        commit_on_branch(repo, "b1", "shared.txt", "from b1")?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("a1", "main")?;
+        ref_store.set_parent("a2", "a1")?;
+        ref_store.set_parent("a3", "a2")?;
+        ref_store.set_parent("b1", "main")?;
+        Ok(ref_store)
+    }
+
+    #[test]
+    fn test_move_carries_three_deep_subtree_onto_sibling_stack() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let ref_store = setup_two_stacks(&repo, "a2.txt")?;
+
+        run(Some("b1".to_string()), Some("a1".to_string()))?;
+
+        // Only the moved branch is reparented; its descendants keep their parents
+        assert_eq!(ref_store.get_parent("a1")?, Some("b1".to_string()));
+        assert_eq!(ref_store.get_parent("a2")?, Some("a1".to_string()));
+        assert_eq!(ref_store.get_parent("a3")?, Some("a2".to_string()));
+        assert_eq!(ref_store.descendants("b1")?, vec!["a1", "a2", "a3"]);
+
+        let gateway = GitGateway::new()?;
+        assert!(gateway.is_branch_based_on("a1", "b1")?);
+        assert!(gateway.is_branch_based_on("a2", "a1")?);
+        assert!(gateway.is_branch_based_on("a3", "a2")?);
+        // Each branch still carries exactly its own commit
+        let a3 = repo.revparse_single("a3")?.peel_to_commit()?;
+        assert_eq!(a3.parent_id(0)?, repo.revparse_single("a2")?.peel_to_commit()?.id());
+        assert!(OperationState::load()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_subtree_conflict_pauses_and_continue_finishes() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let ref_store = setup_two_stacks(&repo, "shared.txt")?;
+
+        run(Some("b1".to_string()), Some("a1".to_string()))?;
+
+        // a1 moved cleanly, a2 stopped on a conflict with a3 still queued
+        let state = OperationState::load()?.expect("move should pause with operation state");
+        assert_eq!(state.current_branch.as_deref(), Some("a2"));
+        assert_eq!(state.remaining_branches, vec!["a3"]);
+        assert_eq!(ref_store.get_parent("a1")?, Some("b1".to_string()));
+
+        std::fs::write(dir.path().join("shared.txt"), "resolved")?;
+        let status = std::process::Command::new("git")
+            .args(["add", "shared.txt"])
+            .current_dir(dir.path())
+            .status()?;
+        assert!(status.success());
+
+        crate::commands::continue_op::run()?;
+
+        assert!(OperationState::load()?.is_none());
+        let gateway = GitGateway::new()?;
+        assert!(gateway.is_branch_based_on("a2", "a1")?);
+        assert!(gateway.is_branch_based_on("a3", "a2")?);
+        assert_eq!(ref_store.get_parent("a3")?, Some("a2".to_string()));
+
+        Ok(())
+    }
+
     #[test]
     fn test_move_untracked_branch_fails() {
         let dir = tempdir().unwrap();
@@ -11,7 +11,9 @@ use crate::ref_store::RefStore;
 /// - Created a branch with the wrong name
 /// - Want to fold changes manually into another branch
 /// - Accidentally committed to the wrong branch
-pub fn run() -> Result<()> {
+///
+/// With `to`, the changes are applied onto that branch instead of the parent.
+pub fn run(to: Option<String>) -> Result<()> {
     let gateway = GitGateway::new()?;
     let ref_store = RefStore::new()?;
     let current = gateway.get_current_branch_name()?;
@@ -40,7 +42,7 @@ pub fn run() -> Result<()> {
         child_list.sort();
         anyhow::bail!(
             "Cannot pop branch '{}' - it has children: {}\n\
-            Use '{} delete --reparent {}' to delete and reparent children.",
+            Use '{} delete {}' to delete it and reparent its children.",
             current,
             child_list.join(", "),
             program_name(),
@@ -48,6 +50,10 @@ pub fn run() -> Result<()> {
         );
     }
 
+    if let Some(target) = to {
+        return pop_to(&gateway, &ref_store, &current, &parent, &target);
+    }
+
     // Get SHAs to determine if there are committed changes to preserve
     let current_sha = gateway.get_branch_sha(&current)?;
     let parent_sha = gateway.get_branch_sha(&parent)?;
@@ -93,6 +99,83 @@ pub fn run() -> Result<()> {
     Ok(())
 }
 
+/// Pop the current branch and apply its changes onto `target`
+///
+/// Everything the branch holds relative to its parent (commits, staged,
+/// unstaged and untracked files) is turned into a single patch and applied
+/// to the target's working tree with a three-way fallback, so overlapping
+/// edits end up as conflict markers rather than a failed pop.
+fn pop_to(gateway: &GitGateway, ref_store: &RefStore, current: &str, parent: &str, target: &str) -> Result<()> {
+    if target == current {
+        anyhow::bail!("Cannot pop '{}' onto itself", current);
+    }
+    if !gateway.branch_exists(target)? {
+        anyhow::bail!("Target branch '{}' does not exist", target);
+    }
+
+    let current_sha = gateway.get_branch_sha(current)?;
+    let base = gateway.get_merge_base(current, parent)?;
+
+    // Capture everything (including untracked files) relative to the fork point
+    gateway.stage_all()?;
+    let patch = gateway.get_staged_patch(&base)?;
+
+    // Keep uncommitted work in a stash until it has landed on the target
+    let stashed = gateway.stash_push(&format!("{} pop: changes from {}", program_name(), current))?;
+    if stashed {
+        println!("{} Stashed uncommitted changes", "→".blue());
+    }
+
+    gateway.checkout_branch_worktree_safe(target)?;
+    println!("{} Checked out {}", "→".blue(), target.green());
+
+    let clean = patch.is_empty() || gateway.apply_patch_3way(&patch)?;
+
+    gateway.delete_branch(current)?;
+    ref_store.remove_parent(current)?;
+    println!("{} Popped branch '{}'", "✓".green().bold(), current.yellow());
+
+    if !clean {
+        let conflicts = gateway.get_conflicted_files()?;
+        println!(
+            "\n{} Changes from '{}' conflict with '{}':",
+            "!".yellow().bold(),
+            current.yellow(),
+            target.green()
+        );
+        for file in &conflicts {
+            println!("  {} ({})", file.path, file.conflict_type);
+        }
+        let mut recovery = format!("The popped branch was at {}", &current_sha[..7.min(current_sha.len())]);
+        if stashed {
+            recovery.push_str("; its uncommitted changes are still in the stash (drop it once resolved)");
+        }
+        anyhow::bail!(
+            "Applied changes from '{}' to '{}' with conflicts.\n\
+            Resolve the conflict markers in the files above, then 'git add' them.\n\
+            {}.",
+            current,
+            target,
+            recovery
+        );
+    }
+
+    if stashed {
+        gateway.stash_drop()?;
+    }
+    if !patch.is_empty() {
+        println!("{} Applied changes to working tree", "✓".green());
+    }
+
+    println!(
+        "\nYou are now on '{}'. Changes from '{}' are preserved in your working tree.",
+        target.green(),
+        current.yellow()
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,7 +203,7 @@ mod tests {
         assert_eq!(gateway.get_current_branch_name()?, "feature");
 
         // Pop feature
-        run()?;
+        run(None)?;
 
         // Should be back on main
         assert_eq!(gateway.get_current_branch_name()?, "main");
@@ -150,7 +233,7 @@ mod tests {
         fs::write(dir.path().join("test.txt"), "hello world")?;
 
         // Pop feature
-        run()?;
+        run(None)?;
 
         // Should be back on main
         assert_eq!(gateway.get_current_branch_name()?, "main");
@@ -172,7 +255,7 @@ mod tests {
         ref_store.set_trunk("main")?;
 
         // Try to pop trunk
-        let result = run();
+        let result = run(None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Cannot pop trunk"));
 
@@ -197,7 +280,7 @@ mod tests {
         gateway.checkout_branch_worktree_safe("middle")?;
 
         // Try to pop middle (which has child "leaf")
-        let result = run();
+        let result = run(None);
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(err.contains("has children"));
@@ -220,7 +303,7 @@ mod tests {
         ref_store.set_trunk("main")?;
 
         // Try to pop untracked branch
-        let result = run();
+        let result = run(None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("has no parent"));
 
@@ -247,7 +330,7 @@ mod tests {
         gateway.commit("Add feature file")?;
 
         // Pop the feature branch
-        run()?;
+        run(None)?;
 
         // We should be on main
         assert_eq!(gateway.get_current_branch_name()?, "main");
@@ -1,20 +1,49 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
 
 use crate::cache::Cache;
-use crate::forge::get_forge;
+use crate::forge::{get_async_forge, get_forge, AsyncForge, Forge, PrOptions, PrState};
 use crate::git_gateway::GitGateway;
+use crate::platform;
 use crate::program_name::program_name;
+use crate::ref_store::RefStore;
 
 /// Open the PR for a branch in the browser
 ///
 /// If `branch` is None, uses the current branch.
 /// If `branch` is a number, treats it as a PR number.
 /// Otherwise, looks up the PR for the specified branch.
-pub fn run(branch: Option<String>) -> Result<()> {
+///
+/// With `url` or `copy_url`, prints the PR URL instead of opening a browser
+/// (`copy_url` also copies it to the clipboard when one is available).
+///
+/// With `draft`, creates a draft PR for the current branch instead (see
+/// [`create_draft_pr`]).
+pub fn run(branch: Option<String>, url: bool, copy_url: bool, draft: bool) -> Result<()> {
+    let forge = get_forge(None)?;
+
+    if draft {
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+        create_draft_pr(&gateway, &ref_store, forge.as_ref())?;
+        return Ok(());
+    }
+
+    if url || copy_url {
+        let pr_url = lookup_pr_url(branch, forge.as_ref())?;
+        println!("{}", pr_url);
+        if copy_url {
+            if platform::copy_to_clipboard(&pr_url) {
+                eprintln!("{} Copied PR URL to clipboard", "✓".green());
+            } else {
+                eprintln!("{} No clipboard available, printed URL only", "⚠".yellow());
+            }
+        }
+        return Ok(());
+    }
+
     let gateway = GitGateway::new()?;
     let cache = Cache::load().unwrap_or_default();
-    let forge = get_forge(None)?;
 
     // If a PR number is provided, open it directly via forge
     if let Some(ref arg) = branch {
@@ -51,6 +80,158 @@ pub fn run(branch: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Open every PR in the stack of `branch` (default: current branch) in the browser
+///
+/// PRs are opened bottom-to-top; branches without a PR are skipped and listed.
+pub async fn run_all(branch: Option<String>) -> Result<()> {
+    let gateway = GitGateway::new()?;
+    let ref_store = RefStore::new()?;
+    let forge = get_async_forge(None)?;
+
+    let branch = match branch {
+        Some(b) => b,
+        None => gateway.get_current_branch_name()?,
+    };
+
+    let opened = open_stack_prs(&ref_store, &branch, forge.as_ref(), |url| {
+        println!("{} Opening PR: {}", "→".blue(), url.cyan());
+        open_browser(url)
+    })
+    .await?;
+
+    if opened.is_empty() {
+        anyhow::bail!(
+            "No PRs found in the stack of '{}'. Run '{} submit --stack' first.",
+            branch,
+            program_name()
+        );
+    }
+    Ok(())
+}
+
+/// Open the PR of each branch in `branch`'s stack, bottom-to-top, via `open`
+///
+/// PR existence is checked for the whole stack in one batch; a failed lookup is
+/// an error rather than a branch without a PR. Returns the URLs that were
+/// opened, in order.
+async fn open_stack_prs(
+    ref_store: &RefStore,
+    branch: &str,
+    forge: &dyn AsyncForge,
+    mut open: impl FnMut(&str) -> Result<()>,
+) -> Result<Vec<String>> {
+    let trunk = ref_store.require_trunk()?;
+    if branch == trunk {
+        anyhow::bail!(
+            "'{}' is trunk and has no stack. Check out a stacked branch first.",
+            trunk
+        );
+    }
+
+    let mut stack = ref_store.ancestors(branch)?;
+    stack.extend(ref_store.descendants(branch)?);
+
+    let prs = forge
+        .try_check_prs_exist(&stack)
+        .await
+        .context("Failed to look up the stack's PRs")?;
+    let mut opened = Vec::new();
+    let mut without_pr = Vec::new();
+    for (branch, pr) in prs {
+        match pr {
+            Some(pr) => {
+                open(&pr.url)?;
+                opened.push(pr.url);
+            }
+            None => without_pr.push(branch),
+        }
+    }
+
+    if !without_pr.is_empty() {
+        println!("{} Skipped (no PR): {}", "ℹ".blue(), without_pr.join(", "));
+    }
+    Ok(opened)
+}
+
+/// Resolve the PR URL for a branch or PR number without opening anything
+fn lookup_pr_url(branch: Option<String>, forge: &dyn Forge) -> Result<String> {
+    if let Some(ref arg) = branch {
+        if arg.parse::<u64>().is_ok() {
+            return Ok(forge.get_pr_info(arg)?.url);
+        }
+    }
+
+    let target_branch = match branch {
+        Some(b) => b,
+        None => GitGateway::new()?.get_current_branch_name()?,
+    };
+
+    let cache = Cache::load().unwrap_or_default();
+    if let Some(url) = cache.get_pr_url(&target_branch) {
+        return Ok(url.to_string());
+    }
+
+    match forge.pr_exists(&target_branch)? {
+        Some(pr) => Ok(pr.url),
+        None => anyhow::bail!(
+            "No PR found for branch '{}'. Run '{} submit' first to create a PR.",
+            target_branch,
+            program_name()
+        ),
+    }
+}
+
+/// Push the current branch and open a draft PR against its parent
+///
+/// A lightweight alternative to `submit`: no stack validation, no ancestor
+/// pushes, and no stack visualization updates on other PRs. Does nothing if
+/// the branch already has an open PR. Returns the URL of the created PR.
+fn create_draft_pr(gateway: &GitGateway, ref_store: &RefStore, forge: &dyn Forge) -> Result<Option<String>> {
+    let trunk = ref_store.require_trunk()?;
+    let branch = gateway.get_current_branch_name()?;
+    if branch == trunk {
+        anyhow::bail!("Cannot create a PR for trunk branch '{}'.", trunk);
+    }
+
+    if let Some(pr) = forge.pr_exists(&branch)? {
+        if pr.state == PrState::Open {
+            println!(
+                "{} {} already has PR #{}: {}",
+                "ℹ".blue(),
+                branch.cyan(),
+                pr.number,
+                pr.url
+            );
+            return Ok(None);
+        }
+    }
+
+    let base = ref_store.get_parent(&branch)?.unwrap_or(trunk);
+
+    println!("{} Creating draft {} → {}...", "→".blue(), branch.green(), base.blue());
+    forge.push_branch(&branch, false)?;
+    if let Err(e) = gateway.push_diamond_ref(&branch) {
+        eprintln!("  {} Could not push diamond ref: {}", "!".yellow(), e);
+    }
+
+    // Same title rule as submit: tip commit subject, else the branch name
+    let title = gateway
+        .get_commit_subject(&branch)
+        .unwrap_or_else(|_| branch.replace(['-', '_'], " "));
+    let options = PrOptions {
+        draft: true,
+        ..Default::default()
+    };
+    let url = forge.create_pr(&branch, &base, &title, "", &options)?;
+
+    let mut cache = Cache::load().unwrap_or_default();
+    cache.set_pr_url(&branch, &url);
+    cache.save()?;
+
+    println!("{} Created draft PR: {}", "✓".green(), url.cyan());
+    Ok(Some(url))
+}
+
 fn open_browser(url: &str) -> Result<()> {
     #[cfg(target_os = "macos")]
     {
@@ -76,6 +257,8 @@ mod tests {
 
     use tempfile::tempdir;
 
+    use crate::forge::mock::{MockForge, MockResponse};
+    use crate::forge::ForgeType;
     use crate::test_context::{init_test_repo, TestRepoContext};
 
     #[test]
@@ -141,7 +324,7 @@ mod tests {
         cache.save().unwrap();
 
         // run() should fail because no remote is configured (forge requires remote)
-        let result = run(Some("nonexistent-branch".to_string()));
+        let result = run(Some("nonexistent-branch".to_string()), false, false, false);
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -171,4 +354,155 @@ mod tests {
         // because the URL is found in cache
         // Note: This test may open a browser window in non-headless environments
     }
+
+    #[test]
+    fn test_pr_url_prints_known_pr_without_opening_browser() {
+        let dir = tempdir().unwrap();
+        let _repo = init_test_repo(dir.path()).unwrap();
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let mut cache = Cache::load().unwrap_or_default();
+        cache.set_pr_url("feature", "https://github.com/org/repo/pull/321");
+        cache.save().unwrap();
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        let url = lookup_pr_url(Some("feature".to_string()), &forge).unwrap();
+
+        // Answered from the cache, without asking the forge
+        assert_eq!(url, "https://github.com/org/repo/pull/321");
+        assert_eq!(forge.get_call_count("pr_exists:feature"), 0);
+    }
+
+    #[test]
+    fn test_pr_url_falls_back_to_forge_lookup() {
+        let dir = tempdir().unwrap();
+        let _repo = init_test_repo(dir.path()).unwrap();
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        let missing = lookup_pr_url(Some("feature".to_string()), &forge);
+        assert!(missing.unwrap_err().to_string().contains("No PR found"));
+
+        forge.set_response("pr_exists:feature", MockResponse::Success("7".to_string()));
+        let url = lookup_pr_url(Some("feature".to_string()), &forge).unwrap();
+        assert!(url.ends_with("/7"), "Unexpected URL: {}", url);
+    }
+
+    #[test]
+    fn test_draft_creates_single_draft_pr_without_touching_siblings() {
+        let dir = tempdir().unwrap();
+        let repo = init_test_repo(dir.path()).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("feature-a", &head, false).unwrap();
+        repo.branch("feature-b", &head, false).unwrap();
+        repo.set_head("refs/heads/feature-b").unwrap();
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new().unwrap();
+        ref_store.set_trunk("main").unwrap();
+        ref_store.set_parent("feature-a", "main").unwrap();
+        ref_store.set_parent("feature-b", "feature-a").unwrap();
+
+        let gateway = GitGateway::new().unwrap();
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature-a", MockResponse::Success("1".to_string()));
+        forge.set_response(
+            "create_pr:feature-b",
+            MockResponse::Success("https://github.com/test/repo/pull/2".to_string()),
+        );
+
+        let url = create_draft_pr(&gateway, &ref_store, &forge).unwrap();
+
+        assert_eq!(url.as_deref(), Some("https://github.com/test/repo/pull/2"));
+        assert_eq!(forge.get_call_count("push_branch:feature-b"), 1);
+        assert_eq!(forge.get_call_count("create_pr:feature-b"), 1);
+        assert_eq!(forge.get_call_count("create_pr_draft:feature-b"), 1);
+        assert_eq!(forge.get_call_count("create_pr_base:feature-b:feature-a"), 1);
+        // No stack visualization: the sibling PR body is never read or rewritten
+        assert_eq!(forge.get_call_count("get_pr_body:1"), 0);
+        assert_eq!(forge.get_call_count("update_pr_body:1"), 0);
+        assert_eq!(forge.get_call_count("push_branch:feature-a"), 0);
+
+        // Running again once the PR exists is a no-op
+        forge.set_response("pr_exists:feature-b", MockResponse::Success("2".to_string()));
+        assert_eq!(create_draft_pr(&gateway, &ref_store, &forge).unwrap(), None);
+        assert_eq!(forge.get_call_count("create_pr:feature-b"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_open_stack_prs_opens_each_pr_bottom_to_top() {
+        let dir = tempdir().unwrap();
+        let repo = init_test_repo(dir.path()).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        for name in ["feature-a", "feature-b", "feature-c", "feature-d"] {
+            repo.branch(name, &head, false).unwrap();
+        }
+        let _ctx = TestRepoContext::new(dir.path());
+
+        // main -> a -> b (no PR) -> c -> d
+        let ref_store = RefStore::new().unwrap();
+        ref_store.set_trunk("main").unwrap();
+        ref_store.set_parent("feature-a", "main").unwrap();
+        ref_store.set_parent("feature-b", "feature-a").unwrap();
+        ref_store.set_parent("feature-c", "feature-b").unwrap();
+        ref_store.set_parent("feature-d", "feature-c").unwrap();
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature-a", MockResponse::Success("1".to_string()));
+        forge.set_response("pr_exists:feature-c", MockResponse::Success("3".to_string()));
+        forge.set_response("pr_exists:feature-d", MockResponse::Success("4".to_string()));
+
+        let mut seen = Vec::new();
+        let opened = open_stack_prs(&ref_store, "feature-c", &forge, |url| {
+            seen.push(url.to_string());
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let expected: Vec<String> = [1, 3, 4]
+            .iter()
+            .map(|n| format!("https://github.com/test/repo/pull/{}", n))
+            .collect();
+        assert_eq!(seen, expected);
+        assert_eq!(opened, expected);
+        // The whole stack, below and above the branch, is looked up exactly once
+        for branch in ["feature-a", "feature-b", "feature-c", "feature-d"] {
+            assert_eq!(forge.get_call_count(&format!("pr_exists:{}", branch)), 1, "{}", branch);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_stack_prs_fails_when_pr_lookup_fails() {
+        let dir = tempdir().unwrap();
+        let repo = init_test_repo(dir.path()).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("feature-a", &head, false).unwrap();
+        repo.branch("feature-b", &head, false).unwrap();
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new().unwrap();
+        ref_store.set_trunk("main").unwrap();
+        ref_store.set_parent("feature-a", "main").unwrap();
+        ref_store.set_parent("feature-b", "feature-a").unwrap();
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature-a", MockResponse::Success("1".to_string()));
+        forge.set_response(
+            "pr_exists:feature-b",
+            MockResponse::Error("gh: not logged in".to_string()),
+        );
+
+        let mut seen = Vec::new();
+        let err = open_stack_prs(&ref_store, "feature-b", &forge, |url| {
+            seen.push(url.to_string());
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+
+        // A forge failure is reported, not mistaken for "no PRs found"
+        assert!(format!("{:#}", err).contains("gh: not logged in"), "got: {:#}", err);
+        assert!(seen.is_empty());
+    }
 }
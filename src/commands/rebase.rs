@@ -0,0 +1,208 @@
+use anyhow::Result;
+
+use crate::commands::move_cmd;
+use crate::git_gateway::GitGateway;
+
+/// Replay a branch's commits after `from` onto `onto` (`git rebase --onto <onto> <from>`)
+///
+/// Unlike a plain git rebase this goes through the move machinery: backups are
+/// taken, the branch's parent is set to `onto`, and its descendants are restacked.
+/// If branch is None, rebases the current branch.
+pub fn run(onto: String, from: String, branch: Option<String>) -> Result<()> {
+    let gateway = GitGateway::new()?;
+
+    let target = match &branch {
+        Some(b) => b.clone(),
+        None => gateway.get_current_branch_name()?,
+    };
+
+    // Pin relative revspecs (`HEAD~1`, `feature~2`) now: the saved operation
+    // must still mean the same commit after a conflict moves HEAD or the branch
+    let Ok(from_sha) = gateway.resolve_ref(&from) else {
+        anyhow::bail!("Upstream '{}' does not resolve to a commit", from);
+    };
+    let from_sha = from_sha.to_string();
+    if gateway.branch_exists(&target)? && !gateway.is_ancestor(&from_sha, &target)? {
+        anyhow::bail!(
+            "Upstream '{}' is not an ancestor of '{}':\n  \
+             --from must point at the commit the replayed range starts after",
+            from,
+            target
+        );
+    }
+
+    move_cmd::move_branch(Some(onto), branch, Some(from_sha))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::Path;
+    use std::process::Command;
+    use tempfile::tempdir;
+
+    use crate::ref_store::RefStore;
+    use crate::state::OperationState;
+    use crate::test_context::{init_test_repo, TestRepoContext};
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = Command::new("git").args(args).current_dir(dir).output().unwrap();
+        assert!(output.status.success(), "git {:?} failed: {:?}", args, output);
+    }
+
+    fn commit_file(dir: &Path, name: &str) {
+        std::fs::write(dir.join(name), name).unwrap();
+        git(dir, &["add", name]);
+        git(dir, &["commit", "-m", name]);
+    }
+
+    #[test]
+    fn test_rebase_replays_range_and_restacks_descendants() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let path = dir.path();
+
+        // main -> feature (a.txt, b.txt) -> child (c.txt); main -> other (o.txt)
+        git(path, &["checkout", "-b", "other"]);
+        commit_file(path, "o.txt");
+        git(path, &["checkout", "main"]);
+        git(path, &["checkout", "-b", "feature"]);
+        commit_file(path, "a.txt");
+        commit_file(path, "b.txt");
+        git(path, &["checkout", "-b", "child"]);
+        commit_file(path, "c.txt");
+        git(path, &["checkout", "main"]);
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("other", "main")?;
+        ref_store.set_parent("feature", "main")?;
+        ref_store.set_parent("child", "feature")?;
+
+        // Replay only b.txt (the commit after feature~1) onto other
+        run(
+            "other".to_string(),
+            "feature~1".to_string(),
+            Some("feature".to_string()),
+        )?;
+
+        assert_eq!(ref_store.get_parent("feature")?, Some("other".to_string()));
+        assert_eq!(ref_store.get_parent("child")?, Some("feature".to_string()));
+
+        let gateway = GitGateway::new()?;
+        assert!(gateway.is_ancestor("other", "feature")?);
+        assert!(gateway.is_ancestor("feature", "child")?);
+
+        let feature_files = gateway.get_changed_files("other", "feature")?;
+        assert_eq!(feature_files, vec!["b.txt".to_string()]);
+        let child_files = gateway.get_changed_files("feature", "child")?;
+        assert_eq!(child_files, vec!["c.txt".to_string()]);
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rebase_relative_upstream_survives_conflict_and_continue() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let path = dir.path();
+
+        // main -> other (b.txt); main -> feature (a.txt, b.txt) -> child (c.txt)
+        git(path, &["checkout", "-b", "other"]);
+        std::fs::write(path.join("b.txt"), "other")?;
+        git(path, &["add", "b.txt"]);
+        git(path, &["commit", "-m", "other b"]);
+        git(path, &["checkout", "main"]);
+        git(path, &["checkout", "-b", "feature"]);
+        commit_file(path, "a.txt");
+        commit_file(path, "b.txt");
+        git(path, &["checkout", "-b", "child"]);
+        commit_file(path, "c.txt");
+        git(path, &["checkout", "feature"]);
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("other", "main")?;
+        ref_store.set_parent("feature", "main")?;
+        ref_store.set_parent("child", "feature")?;
+
+        let gateway = GitGateway::new()?;
+        let upstream = gateway.resolve_ref("HEAD~1")?.to_string();
+
+        // Replaying b.txt onto other conflicts; HEAD moves while it's paused
+        run("other".to_string(), "HEAD~1".to_string(), None)?;
+        let state = OperationState::load()?.expect("rebase should pause with operation state");
+        assert_eq!(state.rebase_from.as_deref(), Some(upstream.as_str()));
+
+        std::fs::write(path.join("b.txt"), "resolved")?;
+        git(path, &["add", "b.txt"]);
+        crate::commands::continue_op::run()?;
+
+        assert!(OperationState::load()?.is_none());
+        assert!(gateway.is_ancestor("other", "feature")?);
+        assert!(gateway.is_ancestor("feature", "child")?);
+        assert_eq!(
+            gateway.get_changed_files("other", "feature")?,
+            vec!["b.txt".to_string()]
+        );
+        assert_eq!(
+            gateway.get_changed_files("feature", "child")?,
+            vec!["c.txt".to_string()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rebase_onto_descendant_fails() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let main_commit = repo.head()?.peel_to_commit()?;
+        repo.branch("parent", &main_commit, false)?;
+        repo.branch("child", &main_commit, false)?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("parent", "main")?;
+        ref_store.set_parent("child", "parent")?;
+
+        let result = run("child".to_string(), "main".to_string(), Some("parent".to_string()));
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("cycle"), "Expected cycle error, got: {}", err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rebase_rejects_non_ancestor_upstream() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let path = dir.path();
+
+        git(path, &["checkout", "-b", "other"]);
+        commit_file(path, "o.txt");
+        git(path, &["checkout", "main"]);
+        git(path, &["checkout", "-b", "feature"]);
+        commit_file(path, "a.txt");
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("other", "main")?;
+        ref_store.set_parent("feature", "main")?;
+
+        let err = run("main".to_string(), "other".to_string(), None)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("not an ancestor"), "got: {}", err);
+        assert_eq!(ref_store.get_parent("feature")?, Some("main".to_string()));
+
+        Ok(())
+    }
+}
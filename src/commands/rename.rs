@@ -5,6 +5,7 @@ use std::io::IsTerminal;
 use crate::cache::Cache;
 use crate::forge::get_forge;
 use crate::git_gateway::{BranchSyncState, GitGateway};
+use crate::operation_log::{Operation, OperationRecorder};
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 use crate::ui;
@@ -68,6 +69,10 @@ pub fn run(new_name: Option<String>, local_only: bool, force: bool) -> Result<()
 
     // Update metadata
     update_metadata(&ref_store, &current, &new_name, trunk.as_deref())?;
+    OperationRecorder::new()?.record(Operation::BranchRenamed {
+        old_name: current.clone(),
+        new_name: new_name.clone(),
+    })?;
 
     println!(
         "{} Renamed '{}' → '{}' locally",
@@ -3,13 +3,17 @@ use colored::Colorize;
 use std::collections::HashSet;
 use std::io::{IsTerminal, Read, Write};
 
+use crate::git_gateway::backup::new_backup_operation_id;
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 use crate::state::acquire_operation_lock;
 
 /// Interactively reorder branches in the downstack
-pub fn run(file: Option<String>, preview: bool) -> Result<()> {
+///
+/// With `preview` and `json`, prints the current order as a JSON array that
+/// `--file` accepts back.
+pub fn run(file: Option<String>, preview: bool, json: bool) -> Result<()> {
     let gateway = GitGateway::new()?;
     let ref_store = RefStore::new()?;
     let trunk = ref_store.require_trunk()?;
@@ -24,6 +28,11 @@ pub fn run(file: Option<String>, preview: bool) -> Result<()> {
     // Collect downstack branches (from trunk to current, excluding trunk)
     let downstack = ref_store.ancestors(&current_branch)?;
 
+    if preview && json {
+        println!("{}", order_to_json(&downstack)?);
+        return Ok(());
+    }
+
     if downstack.is_empty() {
         println!("{} Nothing to reorder - only one branch in stack", "!".yellow());
         return Ok(());
@@ -69,10 +78,34 @@ pub fn run(file: Option<String>, preview: bool) -> Result<()> {
 }
 
 /// Read branch order from a file
+///
+/// Accepts a JSON array (detected by a `.json` extension or a leading `[`)
+/// or the newline-separated format used by the editor.
 fn read_order_from_file(path: &str) -> Result<Vec<String>> {
     let contents = std::fs::read_to_string(path).context(format!("Failed to read reorder file '{}'", path))?;
 
-    parse_order(&contents)
+    if path.ends_with(".json") || contents.trim_start().starts_with('[') {
+        parse_order_json(&contents).context(format!("Failed to parse reorder file '{}' as JSON", path))
+    } else {
+        parse_order(&contents)
+    }
+}
+
+/// Serialize a branch order (bottom to top) as a JSON array
+fn order_to_json(branches: &[String]) -> Result<String> {
+    serde_json::to_string_pretty(branches).context("Failed to serialize branch order")
+}
+
+/// Parse branch order from a JSON array of branch names
+fn parse_order_json(contents: &str) -> Result<Vec<String>> {
+    let branches: Vec<String> =
+        serde_json::from_str(contents).context("Expected a JSON array of branch names (bottom to top)")?;
+
+    if branches.is_empty() {
+        anyhow::bail!("No branches specified in reorder file");
+    }
+
+    Ok(branches)
 }
 
 /// Open editor to reorder branches
@@ -230,8 +263,9 @@ fn apply_new_order(
 
     // Create backups before rebasing
     println!("{} Creating backups...", "→".blue());
+    let backup_op = new_backup_operation_id()?;
     for (branch, _, _) in &new_parents {
-        let backup = gateway.create_backup_ref(branch)?;
+        let backup = gateway.create_operation_backup_ref(&backup_op, branch)?;
         println!(
             "  {} Backed up {} @ {}",
             "✓".green(),
@@ -378,10 +412,60 @@ mod tests {
         let ref_store = RefStore::new()?;
         ref_store.set_trunk("main")?;
 
-        let result = run(None, false);
+        let result = run(None, false, false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("trunk"));
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_order_json() -> Result<()> {
+        assert_eq!(parse_order_json(r#"["f1", "f2"]"#)?, vec!["f1", "f2"]);
+        assert!(parse_order_json("[]").is_err());
+        assert!(parse_order_json(r#"{"f1": "f2"}"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_preview_json_round_trips_through_file() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        create_branch(&repo, "f1")?;
+        create_branch(&repo, "f2")?;
+        create_branch(&repo, "f3")?;
+        repo.set_head("refs/heads/f3")?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("f1", "main")?;
+        ref_store.set_parent("f2", "f1")?;
+        ref_store.set_parent("f3", "f2")?;
+
+        // --preview --json emits the current parent chain as an array
+        let json = order_to_json(&ref_store.ancestors("f3")?)?;
+        let value: serde_json::Value = serde_json::from_str(&json)?;
+        assert_eq!(value, serde_json::json!(["f1", "f2", "f3"]));
+
+        // Unchanged JSON read back through --file is a no-op
+        let file = dir.path().join("order.json");
+        std::fs::write(&file, &json)?;
+        assert_eq!(read_order_from_file(file.to_str().unwrap())?, vec!["f1", "f2", "f3"]);
+        run(Some(file.to_str().unwrap().to_string()), false, false)?;
+        assert_eq!(ref_store.get_parent("f3")?, Some("f2".to_string()));
+
+        // A reordered array is applied; content sniffing works without the extension
+        let mut order: Vec<String> = serde_json::from_str(&json)?;
+        order.swap(1, 2);
+        let file = dir.path().join("order.txt");
+        std::fs::write(&file, serde_json::to_string(&order)?)?;
+        run(Some(file.to_str().unwrap().to_string()), false, false)?;
+
+        assert_eq!(ref_store.get_parent("f3")?, Some("f1".to_string()));
+        assert_eq!(ref_store.get_parent("f2")?, Some("f3".to_string()));
+
+        Ok(())
+    }
 }
@@ -1,15 +1,19 @@
 use anyhow::Result;
 use colored::Colorize;
+use std::collections::{HashMap, HashSet};
 use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
 
 use crate::cache::Cache;
 use crate::context::ExecutionContext;
-use crate::forge::{get_async_forge, ReviewState};
+use crate::forge::{get_async_forge, AsyncForge, ReviewState};
+use crate::git_gateway::backup::new_backup_operation_id;
+use crate::git_gateway::refs::Oid;
 use crate::git_gateway::GitGateway;
 use crate::operation_log::{Operation, OperationRecorder};
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
-use crate::state::{acquire_operation_lock, OperationState};
+use crate::state::{acquire_operation_lock, OperationState, OperationType};
 use crate::ui;
 use crate::validation::repair_orphaned_branches;
 use crate::worktree;
@@ -27,49 +31,110 @@ pub enum RestackScope {
     Downstack,
 }
 
+/// Handle `dm restack --continue` (delegates to the general continue logic)
+pub fn run_continue() -> Result<()> {
+    require_restack_in_progress("continue")?;
+    crate::commands::continue_op::run()
+}
+
+/// Handle `dm restack --abort` (delegates to the general abort logic)
+pub fn run_abort() -> Result<()> {
+    require_restack_in_progress("abort")?;
+    crate::commands::abort::run()
+}
+
+/// Refuse `restack --continue`/`--abort` unless the operation in progress is a restack
+fn require_restack_in_progress(action: &str) -> Result<()> {
+    match OperationState::load()? {
+        None => anyhow::bail!(
+            "No restack in progress to {}. Run '{} restack' to start one.",
+            action,
+            program_name()
+        ),
+        Some(state) if state.operation_type != OperationType::Restack => anyhow::bail!(
+            "A {} is in progress, not a restack. Use '{} {}' to {} any operation.",
+            state.operation_type,
+            program_name(),
+            action,
+            action
+        ),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Options for [`run`], one per `restack` flag
+#[derive(Debug, Default)]
+pub struct RestackOptions {
+    /// Branch to restack from (defaults to the current branch)
+    pub branch: Option<String>,
+    /// Only restack the branch itself
+    pub only: bool,
+    /// Restack the branch and its ancestors
+    pub downstack: bool,
+    /// Restack the branch and its descendants
+    pub upstack: bool,
+    /// Proceed even if external changes are detected
+    pub force: bool,
+    /// Skip branches whose PRs are approved
+    pub skip_approved: bool,
+    /// Report what would be rebased without changing anything
+    pub preview: bool,
+    /// Restack independent subtrees concurrently, up to this many at a time
+    pub jobs: usize,
+    /// Skip the backup and external change detection that sync already did
+    pub called_from_sync: bool,
+}
+
 /// Restack branches without fetching from remote
 /// This is useful after amending a parent branch and needing to update descendants
 ///
+/// When `preview` is true (or global `--dry-run` is set), reports which branches need
+/// rebasing and how many commits would be replayed, without changing anything.
+///
+/// When `jobs` is above 1, sibling subtrees are restacked concurrently in scratch
+/// worktrees (see `restack_in_worktrees`).
+///
 /// When `called_from_sync` is true, skips redundant work (backups, external change detection)
 /// since sync already performed these steps.
-pub async fn run(
-    branch: Option<String>,
-    only: bool,
-    downstack: bool,
-    upstack: bool,
-    force: bool,
-    skip_approved: bool,
-    called_from_sync: bool,
-) -> Result<()> {
+pub async fn run(options: RestackOptions) -> Result<()> {
     // Acquire exclusive lock to prevent concurrent Diamond operations
     let _lock = acquire_operation_lock()?;
 
     // Determine scope from flags
-    let scope = if only {
+    let scope = if options.only {
         RestackScope::Only
-    } else if downstack {
+    } else if options.downstack {
         RestackScope::Downstack
-    } else if upstack {
+    } else if options.upstack {
         RestackScope::Upstack
-    } else if branch.is_some() {
+    } else if options.branch.is_some() {
         // If branch is specified without other flags, default to upstack
         RestackScope::Upstack
     } else {
         RestackScope::All
     };
 
-    run_restack(branch, scope, force, skip_approved, called_from_sync).await
+    run_restack(scope, options).await
 }
 
-async fn run_restack(
-    target_branch: Option<String>,
-    scope: RestackScope,
-    force: bool,
-    skip_approved: bool,
-    called_from_sync: bool,
-) -> Result<()> {
+async fn run_restack(scope: RestackScope, options: RestackOptions) -> Result<()> {
+    let RestackOptions {
+        branch: target_branch,
+        force,
+        skip_approved,
+        preview,
+        jobs,
+        called_from_sync,
+        ..
+    } = options;
+
+    // Global --dry-run gets the same report as --preview
+    let preview = preview || ExecutionContext::is_dry_run();
+
     let gateway = GitGateway::new()?;
-    gateway.require_clean_for_rebase()?;
+    if !preview {
+        gateway.require_clean_for_rebase()?;
+    }
 
     let original_branch = gateway.get_current_branch_name()?;
     let ref_store = RefStore::new()?;
@@ -170,34 +235,18 @@ async fn run_restack(
     // Check for worktree conflicts before starting any rebase operations
     worktree::check_branches_for_worktree_conflicts(&branches_to_rebase)?;
 
-    // Check for approved PRs (only if not in dry-run and not forced)
+    // Check for approved PRs (only if not previewing and not forced)
     // Uses async for parallel PR status checks
-    if !ExecutionContext::is_dry_run() && !force {
+    if !preview && !force {
         let approved_branches = check_approved_prs_async(&branches_to_rebase).await;
 
         if !approved_branches.is_empty() {
             if skip_approved {
-                // Remove approved branches from the list
-                println!(
-                    "{} Skipping {} branch(es) with approved PRs:",
-                    "→".blue(),
-                    approved_branches.len().to_string().yellow()
-                );
-                for (branch, approval_count) in &approved_branches {
-                    println!(
-                        "  {} {} ({} approval{})",
-                        "↳".cyan(),
-                        branch.green(),
-                        approval_count,
-                        if *approval_count == 1 { "" } else { "s" }
-                    );
+                for line in skip_approved_branches(&mut branches_to_rebase, &approved_branches, &ref_store)? {
+                    println!("{}", line);
                 }
                 println!();
 
-                let approved_set: std::collections::HashSet<&String> =
-                    approved_branches.iter().map(|(b, _)| b).collect();
-                branches_to_rebase.retain(|b| !approved_set.contains(b));
-
                 if branches_to_rebase.is_empty() {
                     println!("{} No branches to restack (all have approved PRs)", "✓".green().bold());
                     return Ok(());
@@ -242,27 +291,18 @@ async fn run_restack(
         }
     }
 
-    // Detect external changes (skip in dry-run since we're not making changes)
+    // Detect external changes (skip in preview since we're not making changes)
     // Also skip when called from sync - sync already modified branches, so "external changes" are expected
-    if !ExecutionContext::is_dry_run() && !called_from_sync {
+    if !preview && !called_from_sync {
         let cache = Cache::load().unwrap_or_default();
         let external_changes = detect_external_changes_ref(&gateway, &ref_store, &cache, &branches_to_rebase)?;
         handle_external_changes_ref(&external_changes, force)?;
     }
 
-    // Handle dry-run mode
-    if ExecutionContext::is_dry_run() {
-        println!(
-            "{} Dry run - would restack {} branches:",
-            "[preview]".yellow().bold(),
-            branches_to_rebase.len().to_string().yellow()
-        );
-        for b in &branches_to_rebase {
-            let parent = ref_store.get_parent(b)?.unwrap_or_else(|| trunk.clone());
-            println!("  • {} onto {}", b.green(), parent.blue());
-        }
-        println!();
-        println!("{} No changes made (dry-run mode)", "✓".green().bold());
+    // Handle preview mode: report the plan without rebasing anything
+    if preview {
+        let plan = plan_restack(&gateway, &ref_store, &trunk, &branches_to_rebase)?;
+        print_restack_plan(&plan);
         return Ok(());
     }
 
@@ -281,8 +321,9 @@ async fn run_restack(
     let recorder = OperationRecorder::new()?;
     if !called_from_sync {
         println!("{} Creating backups...", "→".blue());
+        let backup_op = new_backup_operation_id()?;
         for branch in &branches_to_rebase {
-            let backup = gateway.create_backup_ref(branch)?;
+            let backup = gateway.create_operation_backup_ref(&backup_op, branch)?;
             println!(
                 "  {} Backed up {} @ {}",
                 "✓".green(),
@@ -309,8 +350,14 @@ async fn run_restack(
     let mut state = OperationState::new_restack(original_branch.clone(), branches_to_rebase.clone());
     state.save()?;
 
-    // Start rebasing
-    let result = continue_restack_from_state(&mut state, &ref_store);
+    // Start rebasing: independent subtrees in parallel first, then whatever is
+    // left (conflicted subtrees) one by one so conflicts stop as usual
+    let result = if jobs > 1 && branches_to_rebase.len() > 1 {
+        restack_in_worktrees(&gateway, &ref_store, &trunk, &mut state, jobs)
+            .and_then(|()| continue_restack_from_state(&mut state, &ref_store))
+    } else {
+        continue_restack_from_state(&mut state, &ref_store)
+    };
 
     // Log restack completion
     recorder.record(Operation::RestackCompleted {
@@ -321,16 +368,124 @@ async fn run_restack(
     result
 }
 
+/// Planned restack step for a single branch (reported by `--preview`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RestackPlanEntry {
+    branch: String,
+    onto: String,
+    /// Parent tip is not in the branch's history, or the parent itself will be restacked
+    needs_restack: bool,
+    /// Commits that would be replayed onto `onto`
+    commits: usize,
+}
+
+/// Work out which branches a restack would rebase, without touching anything.
+///
+/// `branches` must be parent-first so a restacked parent marks its children as well.
+fn plan_restack(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    trunk: &str,
+    branches: &[String],
+) -> Result<Vec<RestackPlanEntry>> {
+    let mut plan: Vec<RestackPlanEntry> = Vec::with_capacity(branches.len());
+
+    for branch in branches {
+        let onto = ref_store.get_parent(branch)?.unwrap_or_else(|| trunk.to_string());
+        let parent_moves = plan.iter().any(|e| e.branch == onto && e.needs_restack);
+        let needs_restack = parent_moves || !gateway.is_branch_based_on(branch, &onto)?;
+        let commits = gateway.get_commits_between(&onto, branch)?.len();
+
+        plan.push(RestackPlanEntry {
+            branch: branch.clone(),
+            onto,
+            needs_restack,
+            commits,
+        });
+    }
+
+    Ok(plan)
+}
+
+/// Print the restack plan produced by `plan_restack`
+fn print_restack_plan(plan: &[RestackPlanEntry]) {
+    println!(
+        "{} Restack preview for {} branch{}:",
+        "[preview]".yellow().bold(),
+        plan.len().to_string().yellow(),
+        if plan.len() == 1 { "" } else { "es" }
+    );
+    for entry in plan {
+        if entry.needs_restack {
+            println!(
+                "  • {} onto {}: {} ({} commit{} to replay)",
+                entry.branch.green(),
+                entry.onto.blue(),
+                "needs restack".yellow(),
+                entry.commits,
+                if entry.commits == 1 { "" } else { "s" }
+            );
+        } else {
+            println!(
+                "  • {} onto {}: {}",
+                entry.branch.green(),
+                entry.onto.blue(),
+                "up to date".dimmed()
+            );
+        }
+    }
+
+    let pending = plan.iter().filter(|e| e.needs_restack).count();
+    println!();
+    println!(
+        "{} {} branch{} would be restacked. No changes made (preview).",
+        "✓".green().bold(),
+        pending,
+        if pending == 1 { "" } else { "es" }
+    );
+}
+
 /// Continue restacking from saved state
 /// This is public so it can be called from the standalone continue command
 pub fn continue_restack_from_state(state: &mut OperationState, ref_store: &RefStore) -> Result<()> {
     let gateway = GitGateway::new()?;
-    let mut cache = Cache::load().unwrap_or_default();
     let trunk = ref_store.require_trunk()?;
 
     // Re-run repair in case state changed since crash
     repair_orphaned_branches(&gateway, ref_store, &trunk)?;
 
+    if !restack_remaining(state, ref_store, &gateway, &trunk)? {
+        return Ok(());
+    }
+
+    // All done - clean up
+    state.current_branch = None;
+    state.in_progress = false;
+    OperationState::clear()?;
+
+    // Return to original branch
+    gateway.checkout_branch_worktree_safe(&state.original_branch)?;
+
+    println!();
+    println!("{} Restack complete!", "✓".green().bold());
+    Ok(())
+}
+
+/// Rebase each of `state.remaining_branches` onto its parent, in order
+///
+/// Progress is checkpointed to `state` before every rebase and finished
+/// branches move to `state.completed_branches`. Returns `false` when a rebase
+/// stops on conflicts (the conflict message has been shown and the state is
+/// saved for `dm continue`), `true` once every branch is restacked. Clearing
+/// the state afterwards is up to the caller.
+pub(crate) fn restack_remaining(
+    state: &mut OperationState,
+    ref_store: &RefStore,
+    gateway: &GitGateway,
+    trunk: &str,
+) -> Result<bool> {
+    let mut cache = Cache::load().unwrap_or_default();
+
     // Calculate total branches for progress counter
     // We need to track how many branches were completed before we started
     // (in case this is a resume from conflict)
@@ -342,7 +497,7 @@ pub fn continue_restack_from_state(state: &mut OperationState, ref_store: &RefSt
         state.current_branch = Some(branch.clone());
 
         // Determine what to rebase onto
-        let onto = ref_store.get_parent(&branch)?.unwrap_or_else(|| trunk.clone());
+        let onto = ref_store.get_parent(&branch)?.unwrap_or_else(|| trunk.to_string());
 
         // Check if branch is already rebased onto target (crash recovery)
         if gateway.is_branch_based_on(&branch, &onto)? {
@@ -385,11 +540,11 @@ pub fn continue_restack_from_state(state: &mut OperationState, ref_store: &RefSt
                 &onto,
                 &state.remaining_branches,
                 ref_store,
-                &gateway,
+                gateway,
                 false, // initial conflict
             )?;
 
-            return Ok(());
+            return Ok(false);
         }
 
         println!("{}", "✓".green());
@@ -401,16 +556,181 @@ pub fn continue_restack_from_state(state: &mut OperationState, ref_store: &RefSt
         cache.save()?;
     }
 
-    // All done - clean up
-    state.current_branch = None;
-    state.in_progress = false;
-    OperationState::clear()?;
+    Ok(true)
+}
 
-    // Return to original branch
-    gateway.checkout_branch_worktree_safe(&state.original_branch)?;
+/// A branch being rebased in its own scratch worktree
+struct WorktreeJob {
+    branch: String,
+    onto: String,
+    onto_sha: String,
+    old_sha: String,
+    path: PathBuf,
+}
 
-    println!();
-    println!("{} Restack complete!", "✓".green().bold());
+/// Restack `state.remaining_branches` with up to `jobs` scratch worktrees at once
+///
+/// Once a branch is restacked its children are independent of each other, so
+/// every branch whose parent is done is rebased concurrently in a detached
+/// worktree and its ref moved on success. A branch that conflicts is aborted in
+/// its worktree without affecting its siblings; it and its descendants stay in
+/// `state.remaining_branches` for the sequential restack, which stops on the
+/// conflict as usual. `state` is saved after each batch.
+fn restack_in_worktrees(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    trunk: &str,
+    state: &mut OperationState,
+    jobs: usize,
+) -> Result<()> {
+    // The checked-out branch may be moved by ref, so step off it first
+    let detached = state.remaining_branches.contains(&state.original_branch);
+    if detached {
+        gateway.checkout_detached()?;
+    }
+
+    let result = restack_worktree_batches(gateway, ref_store, trunk, state, jobs);
+
+    // On success the sequential restack checks the original branch out again;
+    // on failure, don't leave the main worktree on a detached HEAD
+    if result.is_err() && detached {
+        if let Err(e) = gateway.checkout_branch_worktree_safe(&state.original_branch) {
+            eprintln!(
+                "  {} Warning: Failed to check '{}' out again: {}",
+                "!".yellow(),
+                state.original_branch,
+                e
+            );
+        }
+    }
+
+    result
+}
+
+/// The batches of [`restack_in_worktrees`], run while the original branch is stepped off
+fn restack_worktree_batches(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    trunk: &str,
+    state: &mut OperationState,
+    jobs: usize,
+) -> Result<()> {
+    let mut cache = Cache::load().unwrap_or_default();
+    let order = state.remaining_branches.clone();
+    let total = state.completed_branches.len() + order.len();
+    let mut parents = HashMap::new();
+    for branch in &order {
+        let parent = ref_store.get_parent(branch)?.unwrap_or_else(|| trunk.to_string());
+        parents.insert(branch.clone(), parent);
+    }
+
+    println!("{} Restacking with up to {} parallel worktrees", "→".blue(), jobs);
+    let mut pending = order.clone();
+    let mut blocked: HashSet<String> = HashSet::new();
+    while !pending.is_empty() {
+        let waiting: HashSet<String> = pending.iter().cloned().collect();
+        let (ready, rest): (Vec<String>, Vec<String>) =
+            pending.into_iter().partition(|b| !waiting.contains(&parents[b]));
+        pending = rest;
+        let (runnable, stuck): (Vec<String>, Vec<String>) =
+            ready.into_iter().partition(|b| !blocked.contains(&parents[b]));
+        blocked.extend(stuck);
+
+        for batch in runnable.chunks(jobs) {
+            let mut work = Vec::new();
+            for branch in batch {
+                let onto = parents[branch].clone();
+                if gateway.is_branch_based_on(branch, &onto)? {
+                    state.completed_branches.push(branch.clone());
+                    println!(
+                        "{} [{}/{}] {} already restacked on {}",
+                        "✓".green(),
+                        state.completed_branches.len(),
+                        total,
+                        branch,
+                        onto
+                    );
+                    continue;
+                }
+                let old_sha = gateway.get_branch_sha(branch)?;
+                let name = format!("restack-{}", work.len());
+                let path = worktree::create_diamond_worktree(&name, &old_sha)?;
+                work.push(WorktreeJob {
+                    branch: branch.clone(),
+                    onto_sha: gateway.get_branch_sha(&onto)?,
+                    onto,
+                    old_sha,
+                    path,
+                });
+            }
+
+            let results: Vec<Result<Option<String>>> = std::thread::scope(|scope| {
+                let handles: Vec<_> = work
+                    .iter()
+                    .map(|job| scope.spawn(|| worktree::rebase_in_worktree(&job.path, &job.onto_sha)))
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|h| {
+                        h.join()
+                            .unwrap_or_else(|_| Err(anyhow::anyhow!("Restack worker panicked")))
+                    })
+                    .collect()
+            });
+            for job in &work {
+                worktree::remove_diamond_worktree(&job.path);
+            }
+
+            for (job, result) in work.iter().zip(results) {
+                match result? {
+                    Some(new_sha) => {
+                        if gateway.get_branch_sha(&job.branch)? != job.old_sha {
+                            anyhow::bail!("Branch '{}' moved during restack; aborting", job.branch);
+                        }
+                        gateway.create_reference(
+                            &format!("refs/heads/{}", job.branch),
+                            &Oid::from_str(&new_sha)?,
+                            true,
+                            "diamond: restack",
+                        )?;
+                        cache.set_base_sha(&job.branch, &new_sha);
+                        state.completed_branches.push(job.branch.clone());
+                        println!(
+                            "{} [{}/{}] Restacked {} on {}",
+                            "✓".green(),
+                            state.completed_branches.len(),
+                            total,
+                            job.branch.green(),
+                            job.onto.blue()
+                        );
+                    }
+                    None => {
+                        println!(
+                            "{} {} conflicts with {}; restacking it and its descendants one by one",
+                            "⚠".yellow(),
+                            job.branch.yellow(),
+                            job.onto.blue()
+                        );
+                        blocked.insert(job.branch.clone());
+                    }
+                }
+            }
+
+            state.remaining_branches = order
+                .iter()
+                .filter(|b| !state.completed_branches.contains(b))
+                .cloned()
+                .collect();
+            state.save()?;
+            cache.save()?;
+        }
+    }
+
+    state.remaining_branches = order
+        .into_iter()
+        .filter(|b| !state.completed_branches.contains(b))
+        .collect();
+    state.save()?;
     Ok(())
 }
 
@@ -424,6 +744,11 @@ async fn check_approved_prs_async(branches: &[String]) -> Vec<(String, usize)> {
         Err(_) => return Vec::new(), // Can't check approvals without forge
     };
 
+    find_approved_prs(forge.as_ref(), branches).await
+}
+
+/// Branches among `branches` whose PR is approved, as (branch_name, approval_count)
+async fn find_approved_prs(forge: &dyn AsyncForge, branches: &[String]) -> Vec<(String, usize)> {
     // Batch fetch all PR info in parallel
     let pr_infos = forge.get_prs_full_info(branches).await;
 
@@ -435,6 +760,51 @@ async fn check_approved_prs_async(branches: &[String]) -> Vec<(String, usize)> {
         .collect()
 }
 
+/// Drop branches with approved PRs from `branches` (`--skip-approved`)
+///
+/// An approved branch is left exactly as it is so its approval survives. Its
+/// descendants are still restacked, onto the approved branch's current tip:
+/// that keeps them consistent with their parent without moving it.
+/// Returns the report lines describing what was skipped.
+fn skip_approved_branches(
+    branches: &mut Vec<String>,
+    approved: &[(String, usize)],
+    ref_store: &RefStore,
+) -> Result<Vec<String>> {
+    let approved_set: std::collections::HashSet<&String> = approved.iter().map(|(b, _)| b).collect();
+    branches.retain(|b| !approved_set.contains(b));
+
+    let mut lines = vec![format!(
+        "{} Skipping {} branch(es) with approved PRs:",
+        "→".blue(),
+        approved.len().to_string().yellow()
+    )];
+    for (branch, approval_count) in approved {
+        let descendants = ref_store
+            .collect_branches_dfs(std::slice::from_ref(branch))?
+            .into_iter()
+            .filter(|d| branches.contains(d))
+            .count();
+        let mut line = format!(
+            "  {} {} ({} approval{})",
+            "↳".cyan(),
+            branch.green(),
+            approval_count,
+            if *approval_count == 1 { "" } else { "s" }
+        );
+        if descendants > 0 {
+            line.push_str(&format!(
+                " - {} descendant{} restacked onto it as-is",
+                descendants,
+                if descendants == 1 { "" } else { "s" }
+            ));
+        }
+        lines.push(line);
+    }
+
+    Ok(lines)
+}
+
 /// Restack only the children of the specified branch
 /// Called after modify to auto-update descendants
 pub fn restack_children(parent_branch: &str) -> Result<()> {
@@ -574,10 +944,71 @@ mod tests {
 
     use tempfile::tempdir;
 
-    use crate::test_context::{init_test_repo, TestRepoContext};
+    use crate::test_context::{
+        assert_scratch_worktrees_removed, commit_on_branch, create_branch_from, init_test_repo, TestRepoContext,
+    };
 
     // === Async function logic tests ===
 
+    #[tokio::test]
+    async fn test_skip_approved_skips_middle_branch_and_keeps_descendants() -> anyhow::Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        let gateway = GitGateway::new()?;
+        for (branch, parent) in [("bottom", "main"), ("middle", "bottom"), ("top", "middle")] {
+            gateway.create_branch(branch)?;
+            ref_store.set_parent(branch, parent)?;
+        }
+
+        // Only the middle PR is approved
+        let forge = MockForge::new(ForgeType::GitHub);
+        for (branch, review) in [
+            ("bottom", ReviewState::Pending),
+            ("middle", ReviewState::Approved),
+            ("top", ReviewState::ChangesRequested),
+        ] {
+            let info = PrFullInfo {
+                number: 1,
+                url: format!("https://github.com/org/repo/pull/{}", branch),
+                title: branch.to_string(),
+                state: PrState::Open,
+                is_draft: false,
+                review,
+                ci: CiStatus::Success,
+                head_ref: branch.to_string(),
+                base_ref: "main".to_string(),
+                mergeable: None,
+            };
+            forge.set_response(
+                &format!("get_pr_full_info:{}", branch),
+                MockResponse::Success(serde_json::to_string(&info)?),
+            );
+        }
+
+        let mut branches = ref_store.collect_branches_dfs(&["bottom".to_string()])?;
+        let approved = find_approved_prs(&forge, &branches).await;
+        assert_eq!(approved, vec![("middle".to_string(), 1)]);
+
+        let report = skip_approved_branches(&mut branches, &approved, &ref_store)?;
+
+        // The approved branch is skipped; its descendant is still restacked onto it
+        assert_eq!(branches, vec!["bottom".to_string(), "top".to_string()]);
+        let report = report.join("\n");
+        assert!(report.contains("Skipping"), "{}", report);
+        assert!(report.contains("middle (1 approval)"), "{}", report);
+        assert!(report.contains("1 descendant restacked onto it as-is"), "{}", report);
+        assert!(!report.contains("bottom"), "{}", report);
+
+        Ok(())
+    }
+
     #[test]
     fn test_approved_pr_filtering_logic() {
         // Test the filtering logic used by check_approved_prs_async
@@ -594,6 +1025,7 @@ mod tests {
                 review: ReviewState::Approved,
                 ci: CiStatus::Success,
                 is_draft: false,
+                mergeable: None,
             },
             PrFullInfo {
                 number: 2,
@@ -605,6 +1037,7 @@ mod tests {
                 review: ReviewState::ChangesRequested,
                 ci: CiStatus::Success,
                 is_draft: false,
+                mergeable: None,
             },
             PrFullInfo {
                 number: 3,
@@ -616,6 +1049,7 @@ mod tests {
                 review: ReviewState::Pending,
                 ci: CiStatus::Success,
                 is_draft: false,
+                mergeable: None,
             },
         ];
 
@@ -656,6 +1090,7 @@ mod tests {
                 review: ReviewState::Pending,
                 ci: CiStatus::Success,
                 is_draft: false,
+                mergeable: None,
             },
             PrFullInfo {
                 number: 2,
@@ -667,6 +1102,7 @@ mod tests {
                 review: ReviewState::ChangesRequested,
                 ci: CiStatus::Success,
                 is_draft: false,
+                mergeable: None,
             },
         ];
 
@@ -688,7 +1124,11 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // No trunk set
-        let result = run(None, false, false, false, false, false, false).await;
+        let result = run(RestackOptions {
+            jobs: 1,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -708,7 +1148,11 @@ mod tests {
         let ref_store = RefStore::new().unwrap();
         ref_store.set_trunk("main").unwrap();
 
-        let result = run(None, false, false, false, false, false, false).await;
+        let result = run(RestackOptions {
+            jobs: 1,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_ok());
     }
 
@@ -756,7 +1200,14 @@ mod tests {
         ref_store.set_parent("missing-branch", "feature-1").unwrap();
 
         // Try to restack - should auto-clean the stale ref for missing-branch
-        let result = run_restack(None, RestackScope::All, false, false, false).await;
+        let result = run_restack(
+            RestackScope::All,
+            RestackOptions {
+                jobs: 1,
+                ..Default::default()
+            },
+        )
+        .await;
         assert!(result.is_ok(), "Restack should succeed after cleaning up stale refs");
 
         // Verify the stale ref was cleaned up
@@ -791,7 +1242,12 @@ mod tests {
         repo.set_head("refs/heads/feature-1").unwrap();
 
         // Try restack with --only flag - should restack only feature-1
-        let result = run(None, true, false, false, false, false, false).await;
+        let result = run(RestackOptions {
+            only: true,
+            jobs: 1,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_ok());
     }
 
@@ -805,8 +1261,188 @@ mod tests {
         ref_store.set_trunk("main").unwrap();
 
         // Try to restack trunk with --only should fail
-        let result = run(Some("main".to_string()), true, false, false, false, false, false).await;
+        let result = run(RestackOptions {
+            branch: Some("main".to_string()),
+            only: true,
+            jobs: 1,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Cannot restack trunk"));
     }
+
+    #[tokio::test]
+    async fn test_restack_preview_reports_plan_without_changes() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        // Stack: main -> a -> b -> c, one commit per branch (c has two)
+        create_branch(&repo, "a")?;
+        commit_on_branch(&repo, "a", "a1.txt", "a1.txt")?;
+        create_branch_from(&repo, "b", "a")?;
+        commit_on_branch(&repo, "b", "b1.txt", "b1.txt")?;
+        create_branch_from(&repo, "c", "b")?;
+        commit_on_branch(&repo, "c", "c1.txt", "c1.txt")?;
+        commit_on_branch(&repo, "c", "c2.txt", "c2.txt")?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("a", "main")?;
+        ref_store.set_parent("b", "a")?;
+        ref_store.set_parent("c", "b")?;
+
+        // Advance the parent: b and c now need restacking
+        commit_on_branch(&repo, "a", "a2.txt", "a2.txt")?;
+
+        let gateway = GitGateway::new()?;
+        let branches = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let plan = plan_restack(&gateway, &ref_store, "main", &branches)?;
+
+        let summary: Vec<(&str, bool, usize)> = plan
+            .iter()
+            .map(|e| (e.branch.as_str(), e.needs_restack, e.commits))
+            .collect();
+        assert_eq!(summary, vec![("a", false, 2), ("b", true, 1), ("c", true, 2)]);
+
+        // Running with --preview must not move any branch
+        let before: Vec<String> = branches
+            .iter()
+            .map(|b| gateway.get_branch_sha(b))
+            .collect::<Result<_>>()?;
+        run(RestackOptions {
+            preview: true,
+            jobs: 1,
+            ..Default::default()
+        })
+        .await?;
+        let after: Vec<String> = branches
+            .iter()
+            .map(|b| gateway.get_branch_sha(b))
+            .collect::<Result<_>>()?;
+        assert_eq!(before, after);
+
+        Ok(())
+    }
+
+    /// main -> a -> {b, c}, each with one commit; callers then advance a
+    fn setup_forked_stack(repo: &git2::Repository) -> Result<RefStore> {
+        create_branch(repo, "a")?;
+        commit_on_branch(repo, "a", "a1.txt", "a1.txt")?;
+        create_branch_from(repo, "b", "a")?;
+        commit_on_branch(repo, "b", "b1.txt", "b1.txt")?;
+        create_branch_from(repo, "c", "a")?;
+        commit_on_branch(repo, "c", "shared.txt", "from c")?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("a", "main")?;
+        ref_store.set_parent("b", "a")?;
+        ref_store.set_parent("c", "a")?;
+        Ok(ref_store)
+    }
+
+    fn checkout(repo: &git2::Repository, branch: &str) -> Result<()> {
+        let obj = repo.revparse_single(branch)?;
+        repo.checkout_tree(&obj, Some(git2::build::CheckoutBuilder::new().force()))?;
+        repo.set_head(&format!("refs/heads/{}", branch))?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_restack_jobs_restacks_sibling_subtrees_in_worktrees() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_forked_stack(&repo)?;
+        commit_on_branch(&repo, "a", "a2.txt", "a2.txt")?;
+        checkout(&repo, "b")?;
+
+        run(RestackOptions {
+            force: true,
+            jobs: 2,
+            ..Default::default()
+        })
+        .await?;
+
+        let gateway = GitGateway::new()?;
+        assert!(gateway.is_branch_based_on("b", "a")?);
+        assert!(gateway.is_branch_based_on("c", "a")?);
+        assert_eq!(gateway.get_changed_files("a", "b")?, vec!["b1.txt".to_string()]);
+        assert_eq!(gateway.get_changed_files("a", "c")?, vec!["shared.txt".to_string()]);
+
+        // Back on the user's branch, with its working tree matching the rebased tip
+        assert_eq!(gateway.get_current_branch_name()?, "b");
+        assert!(dir.path().join("a2.txt").exists());
+        assert!(!gateway.has_uncommitted_changes()?);
+        assert!(OperationState::load()?.is_none());
+        assert_scratch_worktrees_removed(dir.path());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_restack_jobs_conflict_in_one_sibling_leaves_other_restacked() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_forked_stack(&repo)?;
+        commit_on_branch(&repo, "a", "shared.txt", "from a")?;
+        checkout(&repo, "main")?;
+
+        run(RestackOptions {
+            force: true,
+            jobs: 2,
+            ..Default::default()
+        })
+        .await?;
+
+        let gateway = GitGateway::new()?;
+        assert!(gateway.is_branch_based_on("b", "a")?);
+        assert!(!gateway.is_branch_based_on("c", "a")?);
+
+        // The conflicting sibling goes through the regular conflict flow
+        assert!(gateway.rebase_in_progress()?);
+        let state = OperationState::load()?.expect("restack state should be saved");
+        assert_eq!(state.current_branch.as_deref(), Some("c"));
+        assert!(state.completed_branches.contains(&"b".to_string()));
+        assert_scratch_worktrees_removed(dir.path());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_restack_jobs_failure_checks_original_branch_out_again() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_forked_stack(&repo)?;
+        commit_on_branch(&repo, "a", "a2.txt", "a2.txt")?;
+        checkout(&repo, "b")?;
+
+        // Occupy the first scratch worktree's path so creating it fails mid-batch
+        let blocker = repo
+            .path()
+            .join("diamond")
+            .join("worktrees")
+            .join(std::process::id().to_string())
+            .join("restack-0");
+        std::fs::create_dir_all(&blocker)?;
+        std::fs::write(blocker.join("occupied"), "")?;
+
+        let result = run(RestackOptions {
+            force: true,
+            jobs: 2,
+            ..Default::default()
+        })
+        .await;
+        assert!(result.is_err(), "worktree creation should have failed");
+
+        // Not left on the detached HEAD the batches ran from
+        let gateway = GitGateway::new()?;
+        assert_eq!(gateway.get_current_branch_name()?, "b");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,173 @@
+//! Setup-aliases command - map Diamond verbs to git aliases and shell abbreviations.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::process::Command;
+
+use crate::program_name::program_name;
+
+/// Diamond verbs exposed as git aliases.
+///
+/// Verbs that collide with built-in git commands (checkout, log, merge, ...) are left
+/// out on purpose: git ignores aliases that shadow built-ins.
+const ALIAS_VERBS: &[&str] = &[
+    "create", "modify", "submit", "sync", "restack", "up", "down", "top", "bottom", "fold",
+];
+
+/// Shell flavour used for abbreviation syntax
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShellKind {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl ShellKind {
+    /// Detect the shell from a `$SHELL`-style path (e.g. "/usr/bin/fish")
+    pub fn from_shell_path(path: &str) -> Option<Self> {
+        match std::path::Path::new(path).file_name()?.to_str()? {
+            "bash" => Some(ShellKind::Bash),
+            "zsh" => Some(ShellKind::Zsh),
+            "fish" => Some(ShellKind::Fish),
+            _ => None,
+        }
+    }
+
+    /// Detect the user's shell from `$SHELL`
+    pub fn detect() -> Option<Self> {
+        std::env::var("SHELL").ok().and_then(|s| Self::from_shell_path(&s))
+    }
+}
+
+/// Generate `[alias]` entries mapping each verb to the Diamond binary (e.g. `sync = !dm sync`)
+pub fn git_alias_lines(program: &str) -> Vec<String> {
+    ALIAS_VERBS
+        .iter()
+        .map(|verb| format!("{} = !{} {}", verb, program, verb))
+        .collect()
+}
+
+/// Generate shell abbreviations like `gsync` -> `dm sync` in the given shell's syntax
+pub fn shell_abbreviation_lines(program: &str, shell: ShellKind) -> Vec<String> {
+    ALIAS_VERBS
+        .iter()
+        .map(|verb| match shell {
+            ShellKind::Fish => format!("abbr -a g{} '{} {}'", verb, program, verb),
+            ShellKind::Bash | ShellKind::Zsh => format!("alias g{}='{} {}'", verb, program, verb),
+        })
+        .collect()
+}
+
+/// Print (or install, with `install`) git aliases for common Diamond verbs.
+///
+/// With `shell`, also prints abbreviations for the detected shell. Abbreviations
+/// are never written to rc files; copy them in yourself.
+pub fn run(install: bool, shell: bool) -> Result<()> {
+    let program = program_name();
+
+    if install {
+        install_git_aliases(program)?;
+    } else {
+        println!("# Add to ~/.gitconfig (or run '{} setup-aliases --install'):", program);
+        println!("[alias]");
+        for line in git_alias_lines(program) {
+            println!("\t{}", line);
+        }
+    }
+
+    if shell {
+        println!();
+        match ShellKind::detect() {
+            Some(kind) => {
+                println!("# Add to your shell config:");
+                for line in shell_abbreviation_lines(program, kind) {
+                    println!("{}", line);
+                }
+            }
+            None => println!(
+                "{} Could not detect your shell from $SHELL (supported: bash, zsh, fish)",
+                "⚠".yellow()
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the aliases to the global git config, leaving conflicting user aliases alone
+fn install_git_aliases(program: &str) -> Result<()> {
+    let mut installed = 0;
+
+    for verb in ALIAS_VERBS {
+        let key = format!("alias.{}", verb);
+        let value = format!("!{} {}", program, verb);
+
+        let existing = Command::new("git")
+            .args(["config", "--global", "--get", &key])
+            .output()
+            .context("Failed to run git config")?;
+        let existing = String::from_utf8_lossy(&existing.stdout).trim().to_string();
+
+        if existing == value {
+            continue;
+        }
+        if !existing.is_empty() {
+            println!(
+                "{} Skipping git {}: already aliased to '{}'",
+                "⚠".yellow(),
+                verb,
+                existing
+            );
+            continue;
+        }
+
+        let status = Command::new("git")
+            .args(["config", "--global", &key, &value])
+            .status()
+            .context("Failed to run git config")?;
+        if !status.success() {
+            anyhow::bail!("Failed to set git alias '{}'", verb);
+        }
+        installed += 1;
+    }
+
+    println!(
+        "{} Installed {} git alias{} (e.g. 'git sync' runs '{} sync')",
+        "✓".green().bold(),
+        installed,
+        if installed == 1 { "" } else { "es" },
+        program
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_git_alias_lines_use_program_name() {
+        let lines = git_alias_lines("sc");
+        assert_eq!(lines.len(), ALIAS_VERBS.len());
+        assert!(lines.contains(&"sync = !sc sync".to_string()));
+        assert!(lines.contains(&"submit = !sc submit".to_string()));
+        assert!(lines.iter().all(|l| !l.starts_with("checkout ")));
+    }
+
+    #[test]
+    fn test_shell_abbreviation_lines_per_shell() {
+        let fish = shell_abbreviation_lines("dm", ShellKind::Fish);
+        assert!(fish.contains(&"abbr -a gsync 'dm sync'".to_string()));
+
+        let zsh = shell_abbreviation_lines("dm", ShellKind::Zsh);
+        assert!(zsh.contains(&"alias gsync='dm sync'".to_string()));
+    }
+
+    #[test]
+    fn test_shell_kind_from_shell_path() {
+        assert_eq!(ShellKind::from_shell_path("/usr/bin/fish"), Some(ShellKind::Fish));
+        assert_eq!(ShellKind::from_shell_path("/bin/zsh"), Some(ShellKind::Zsh));
+        assert_eq!(ShellKind::from_shell_path("/bin/bash"), Some(ShellKind::Bash));
+        assert_eq!(ShellKind::from_shell_path("/bin/tcsh"), None);
+    }
+}
@@ -2,25 +2,30 @@ use anyhow::Result;
 use colored::Colorize;
 use std::io::IsTerminal;
 
+use crate::config::Config;
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 
 /// Split the current branch into multiple branches
 ///
-/// Supports three modes:
+/// Supports four modes:
 /// 1. --by-commit: Split each commit into its own branch
 /// 2. --by-file <pathspecs>: Extract files into a new parent branch
-/// 3. Legacy: dm split <new-branch> <commit> - split at a specific commit
+/// 3. --by-size <lines>: Pack the branch's changes into stacked branches of at most N changed lines
+/// 4. Legacy: dm split <new-branch> <commit> - split at a specific commit
 pub fn run(
     new_branch: Option<String>,
     commit: Option<String>,
     by_commit: bool,
     by_file: Option<Vec<String>>,
     by_hunk: bool,
+    by_size: Option<usize>,
 ) -> Result<()> {
     // Dispatch to the appropriate mode
-    if by_hunk {
+    if let Some(max_lines) = by_size {
+        run_by_size(max_lines)
+    } else if by_hunk {
         run_by_hunk()
     } else if by_commit {
         run_by_commit()
@@ -49,6 +54,10 @@ fn show_usage() -> Result<()> {
         "  {} split --by-file <files> Extract files into a new parent branch",
         prog
     );
+    println!(
+        "  {} split --by-size <lines> Pack changes into branches of at most N lines",
+        prog
+    );
     println!("  {} split <branch> <commit> Split at a specific commit", prog);
     println!();
     println!("Examples:");
@@ -59,12 +68,24 @@ fn show_usage() -> Result<()> {
     println!("  {} split --by-file '*.test.ts' 'test/**'", prog);
     println!("    Extracts test files into a new parent branch");
     println!();
+    println!("  {} split --by-size 400", prog);
+    println!("    Creates: main -> feature-part1 -> feature-part2 -> feature");
+    println!("    (each adding or removing at most 400 lines, files kept whole when they fit)");
+    println!();
     println!("  {} split feature-part2 HEAD~2", prog);
     println!("    Splits current branch at 2 commits ago");
 
     Ok(())
 }
 
+/// Apply the configured `branch.prefix` to a new branch name
+fn with_prefix(name: String) -> String {
+    match Config::load() {
+        Ok(config) => config.with_branch_prefix(&name),
+        Err(_) => name,
+    }
+}
+
 /// Split by commit - creates a branch for each commit
 fn run_by_commit() -> Result<()> {
     let gateway = GitGateway::new()?;
@@ -129,7 +150,7 @@ fn run_by_commit() -> Result<()> {
             current_branch.clone()
         } else {
             // Generate names for intermediate branches
-            format!("{}-part{}", base_name, i + 1)
+            with_prefix(format!("{}-part{}", base_name, i + 1))
         };
 
         // Check if branch exists (skip original branch name)
@@ -290,7 +311,7 @@ fn run_by_file(patterns: Vec<String>) -> Result<()> {
     }
 
     // Generate name for the new branch
-    let new_branch_name = format!("{}-extracted", current_branch);
+    let new_branch_name = with_prefix(format!("{}-extracted", current_branch));
     if gateway.branch_exists(&new_branch_name)? {
         anyhow::bail!(
             "Branch '{}' already exists. Please rename or delete it first.",
@@ -364,6 +385,305 @@ fn run_by_file(patterns: Vec<String>) -> Result<()> {
     Ok(())
 }
 
+/// Split by size - packs the branch's diff into stacked branches of at most `max_lines`
+///
+/// Files stay whole unless a file alone is over the limit, in which case its
+/// hunks are packed separately. A single hunk over the limit gets a branch of
+/// its own. The original branch keeps its name and ends up on top of the new
+/// `<branch>-partN` branches as one commit, with its tree unchanged. Its
+/// commits are squashed in the process, so it is backed up first.
+fn run_by_size(max_lines: usize) -> Result<()> {
+    if max_lines == 0 {
+        anyhow::bail!("--by-size needs a line count greater than 0");
+    }
+
+    let gateway = GitGateway::new()?;
+    let ref_store = RefStore::new()?;
+    let current_branch = gateway.get_current_branch_name()?;
+    let trunk = ref_store.get_trunk()?;
+
+    // Verify current branch is tracked
+    let parent = ref_store.get_parent(&current_branch)?;
+    if parent.is_none() && trunk.as_ref() != Some(&current_branch) {
+        anyhow::bail!(
+            "Branch '{}' is not tracked. Run '{} track' first.",
+            current_branch,
+            program_name()
+        );
+    }
+
+    // Cannot split trunk
+    if trunk.as_ref() == Some(&current_branch) {
+        anyhow::bail!("Cannot split trunk branch '{}'", current_branch);
+    }
+
+    let parent_branch = parent
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("Branch '{}' has no parent", current_branch))?;
+
+    if gateway.has_uncommitted_changes()? {
+        anyhow::bail!("Cannot split with uncommitted changes. Commit or stash them first.");
+    }
+
+    let parent_tip = gateway.resolve_ref(parent_branch)?.to_string();
+    let patch = gateway.get_diff_patch(&parent_tip, "HEAD")?;
+    let files = parse_file_patches(&patch);
+    let groups = pack_by_size(&files, max_lines);
+
+    if groups.len() < 2 {
+        let total: usize = files.iter().map(|f| f.lines).sum();
+        println!(
+            "{} {} changed lines fit in one branch of {} - nothing to split",
+            "ℹ".blue(),
+            total,
+            max_lines
+        );
+        return Ok(());
+    }
+
+    let part_names: Vec<String> = (1..groups.len())
+        .map(|i| with_prefix(format!("{}-part{}", current_branch, i)))
+        .collect();
+    for name in &part_names {
+        if gateway.branch_exists(name)? {
+            anyhow::bail!("Branch '{}' already exists. Please rename or delete it first.", name);
+        }
+    }
+
+    let subject = gateway.backend().get_commit_subject("HEAD")?;
+    let total = groups.len();
+
+    // The original branch's commits are squashed into its last part
+    let backup = gateway.create_backup_ref(&current_branch)?;
+    println!(
+        "{} Backed up {} @ {} (its commits are squashed into the last part)",
+        "✓".green(),
+        current_branch,
+        &backup.commit_oid.to_string()[..7]
+    );
+
+    println!(
+        "{} Splitting '{}' into {} branches of at most {} lines",
+        "→".blue(),
+        current_branch.green(),
+        total.to_string().yellow(),
+        max_lines
+    );
+
+    // Each part is a branch off the previous one with the next group applied
+    let mut prev_branch = parent_branch.clone();
+    for (i, (name, group)) in part_names.iter().zip(&groups).enumerate() {
+        gateway.create_branch_at_ref(name, &prev_branch)?;
+        gateway.checkout_branch_worktree_safe(name)?;
+        if !gateway.apply_patch_3way(&render_group(&files, group))? {
+            anyhow::bail!(
+                "Changes for '{}' did not apply cleanly. Resolve the conflicts or run 'git reset --hard' and delete the '-part' branches.",
+                name
+            );
+        }
+        gateway.commit(&format!("{} (part {}/{})", subject, i + 1, total))?;
+        ref_store.set_parent(name, &prev_branch)?;
+
+        println!(
+            "  {} {} ({} lines)",
+            "•".blue(),
+            name.green(),
+            group.iter().map(|unit| unit.lines).sum::<usize>()
+        );
+        prev_branch = name.clone();
+    }
+
+    // The original branch keeps its tree: squash it onto the last part
+    gateway.checkout_branch_worktree_safe(&current_branch)?;
+    gateway.soft_reset_to(&prev_branch)?;
+    gateway.commit(&format!("{} (part {}/{})", subject, total, total))?;
+    ref_store.set_parent(&current_branch, &prev_branch)?;
+    println!(
+        "  {} {} ({} lines)",
+        "•".blue(),
+        current_branch.green(),
+        groups[total - 1].iter().map(|unit| unit.lines).sum::<usize>()
+    );
+
+    println!();
+    println!("{} Split complete!", "✓".green().bold());
+    println!();
+    println!("Stack structure:");
+    println!(
+        "  {} -> {} -> {}",
+        parent_branch.blue(),
+        part_names.join(" -> ").green(),
+        current_branch.green()
+    );
+    if !ref_store.get_children(&current_branch)?.is_empty() {
+        println!();
+        println!(
+            "Run '{} restack' to move the branches above '{}' onto the new commit.",
+            program_name(),
+            current_branch
+        );
+    }
+
+    Ok(())
+}
+
+/// One file's section of a `git diff`
+#[derive(Debug)]
+struct FilePatch {
+    /// Everything before the first hunk (`diff --git`, mode and `---`/`+++` lines)
+    header: Vec<u8>,
+    /// `@@` hunks; empty for binary files and pure renames/mode changes
+    hunks: Vec<Vec<u8>>,
+    /// Added plus removed lines in each hunk
+    hunk_lines: Vec<usize>,
+    /// Added plus removed lines in the whole file
+    lines: usize,
+}
+
+/// A piece of the diff that `--by-size` keeps together: a whole file, or one
+/// hunk of a file that is over the limit on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SizeUnit {
+    file: usize,
+    hunk: Option<usize>,
+    lines: usize,
+}
+
+/// Split a `git diff` into per-file patches and their hunks
+fn parse_file_patches(patch: &[u8]) -> Vec<FilePatch> {
+    let mut files: Vec<FilePatch> = Vec::new();
+
+    for line in patch.split_inclusive(|&b| b == b'\n') {
+        if line.starts_with(b"diff --git ") {
+            files.push(FilePatch {
+                header: line.to_vec(),
+                hunks: Vec::new(),
+                hunk_lines: Vec::new(),
+                lines: 0,
+            });
+            continue;
+        }
+        let Some(file) = files.last_mut() else {
+            continue;
+        };
+
+        if line.starts_with(b"@@") {
+            file.hunks.push(line.to_vec());
+            file.hunk_lines.push(0);
+        } else if let Some(hunk) = file.hunks.last_mut() {
+            hunk.extend_from_slice(line);
+            if line.starts_with(b"+") || line.starts_with(b"-") {
+                *file.hunk_lines.last_mut().unwrap() += 1;
+                file.lines += 1;
+            }
+        } else {
+            file.header.extend_from_slice(line);
+        }
+    }
+
+    files
+}
+
+/// Greedily pack the diff into groups of at most `max_lines` changed lines
+///
+/// Groups follow diff order. A file is only broken into its hunks when the
+/// file alone is over the limit.
+fn pack_by_size(files: &[FilePatch], max_lines: usize) -> Vec<Vec<SizeUnit>> {
+    let mut units = Vec::new();
+    for (index, file) in files.iter().enumerate() {
+        if file.lines > max_lines && file.hunks.len() > 1 {
+            for (hunk, &lines) in file.hunk_lines.iter().enumerate() {
+                units.push(SizeUnit {
+                    file: index,
+                    hunk: Some(hunk),
+                    lines,
+                });
+            }
+        } else {
+            units.push(SizeUnit {
+                file: index,
+                hunk: None,
+                lines: file.lines,
+            });
+        }
+    }
+
+    let mut groups: Vec<Vec<SizeUnit>> = Vec::new();
+    let mut size = 0;
+    for unit in units {
+        match groups.last_mut() {
+            Some(group) if size + unit.lines <= max_lines => group.push(unit),
+            _ => {
+                groups.push(vec![unit]);
+                size = 0;
+            }
+        }
+        size += unit.lines;
+    }
+
+    groups
+}
+
+/// The patch for one group, with hunks of the same file under a single header
+///
+/// A file's full header (renames, mode changes, new files) only goes with its
+/// first hunk; by the time later hunks apply, the earlier part has already
+/// made those changes.
+fn render_group(files: &[FilePatch], group: &[SizeUnit]) -> Vec<u8> {
+    let mut patch = Vec::new();
+    let mut open_file = None;
+
+    for unit in group {
+        let file = &files[unit.file];
+        match unit.hunk {
+            None => {
+                patch.extend_from_slice(&file.header);
+                for hunk in &file.hunks {
+                    patch.extend_from_slice(hunk);
+                }
+                open_file = None;
+            }
+            Some(hunk) => {
+                if open_file != Some(unit.file) {
+                    if hunk == 0 {
+                        patch.extend_from_slice(&file.header);
+                    } else {
+                        patch.extend_from_slice(&continuation_header(&file.header));
+                    }
+                    open_file = Some(unit.file);
+                }
+                patch.extend_from_slice(&file.hunks[hunk]);
+            }
+        }
+    }
+
+    patch
+}
+
+/// A plain header for the rest of a file's hunks, modifying the file at its new path
+fn continuation_header(header: &[u8]) -> Vec<u8> {
+    let Some(new_path) = header
+        .split_inclusive(|&b| b == b'\n')
+        .find_map(|line| line.strip_prefix(b"+++ "))
+    else {
+        return header.to_vec();
+    };
+    let new_path = new_path.strip_suffix(b"\n").unwrap_or(new_path);
+
+    let parts: [&[u8]; 9] = [
+        b"diff --git ",
+        new_path,
+        b" ",
+        new_path,
+        b"\n--- ",
+        new_path,
+        b"\n+++ ",
+        new_path,
+        b"\n",
+    ];
+    parts.concat()
+}
+
 /// Simple glob matching for file patterns
 fn glob_match(pattern: &str, text: &str) -> bool {
     let pattern_parts: Vec<&str> = pattern.split('*').collect();
@@ -476,6 +796,8 @@ fn run_at_commit(new_branch: Option<String>, commit: Option<String>) -> Result<(
         )
     })?;
 
+    let new_branch = with_prefix(new_branch);
+
     // Check if new branch already exists
     if gateway.branch_exists(&new_branch)? {
         anyhow::bail!("Branch '{}' already exists", new_branch);
@@ -598,7 +920,7 @@ mod tests {
         let ref_store = RefStore::new()?;
         ref_store.set_trunk("main")?;
 
-        let result = run(None, None, false, None, false);
+        let result = run(None, None, false, None, false, None);
         // Should show usage, not error
         assert!(result.is_ok());
 
@@ -620,6 +942,7 @@ mod tests {
             false,
             None,
             false,
+            None,
         );
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not tracked"));
@@ -637,7 +960,7 @@ mod tests {
         ref_store.set_trunk("main")?;
 
         // by_hunk should fail in non-TTY environment
-        let result = run(None, None, false, None, true);
+        let result = run(None, None, false, None, true, None);
         assert!(result.is_err());
         // The error should mention interactive or TTY
         let err = result.unwrap_err().to_string();
@@ -645,4 +968,164 @@ mod tests {
 
         Ok(())
     }
+
+    /// Added plus removed lines between two revisions
+    fn changed_lines(repo: &git2::Repository, from: &str, to: &str) -> Result<usize> {
+        let from = repo.revparse_single(from)?.peel_to_tree()?;
+        let to = repo.revparse_single(to)?.peel_to_tree()?;
+        let stats = repo.diff_tree_to_tree(Some(&from), Some(&to), None)?.stats()?;
+        Ok(stats.insertions() + stats.deletions())
+    }
+
+    #[test]
+    fn test_pack_by_size_keeps_files_whole_when_they_fit() {
+        let patch = b"diff --git a/a.txt b/a.txt\n--- a/a.txt\n+++ b/a.txt\n@@ -1,2 +1,2 @@\n-one\n+uno\n two\n\
+diff --git a/b.txt b/b.txt\n--- a/b.txt\n+++ b/b.txt\n@@ -1 +1 @@\n-x\n+y\n@@ -9 +9 @@\n-p\n+q\n";
+        let files = parse_file_patches(patch);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].lines, 2);
+        assert_eq!(files[1].hunk_lines, vec![2, 2]);
+
+        // b.txt (4 lines) fits in the limit, so it is not broken up
+        let groups = pack_by_size(&files, 4);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(
+            groups[1],
+            vec![SizeUnit {
+                file: 1,
+                hunk: None,
+                lines: 4
+            }]
+        );
+
+        // Over the limit on its own, b.txt is packed hunk by hunk
+        let groups = pack_by_size(&files, 3);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[2][0].hunk, Some(1));
+        let rendered = String::from_utf8_lossy(&render_group(&files, &groups[2])).to_string();
+        assert!(
+            rendered.starts_with("diff --git b/b.txt b/b.txt") && rendered.contains("+q") && !rendered.contains("+y")
+        );
+    }
+
+    #[test]
+    fn test_render_group_keeps_rename_header_with_first_hunk_only() {
+        let patch = b"diff --git a/old.txt b/new.txt\nsimilarity index 90%\nrename from old.txt\nrename to new.txt\n\
+--- a/old.txt\n+++ b/new.txt\n@@ -1 +1 @@\n-x\n+y\n@@ -9 +9 @@\n-p\n+q\n";
+        let files = parse_file_patches(patch);
+        let groups = pack_by_size(&files, 2);
+        assert_eq!(groups.len(), 2);
+
+        let first = String::from_utf8_lossy(&render_group(&files, &groups[0])).to_string();
+        assert!(first.contains("rename from old.txt") && first.contains("+y"));
+
+        // The rename has already happened when the second hunk applies
+        let second = String::from_utf8_lossy(&render_group(&files, &groups[1])).to_string();
+        assert_eq!(
+            second,
+            "diff --git b/new.txt b/new.txt\n--- b/new.txt\n+++ b/new.txt\n@@ -9 +9 @@\n-p\n+q\n"
+        );
+    }
+
+    #[test]
+    fn test_by_size_splits_300_line_change_into_stacked_branches() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let gateway = GitGateway::new()?;
+
+        let original: Vec<String> = (0..400).map(|i| format!("line {}", i)).collect();
+        std::fs::write(dir.path().join("big.txt"), original.join("\n") + "\n")?;
+        gateway.stage_all()?;
+        gateway.commit("Add big.txt")?;
+
+        gateway.create_branch("feature")?;
+        // Three far-apart 25-line rewrites in big.txt (150 changed lines) plus a new
+        // 150-line file: 300 changed lines in total
+        let mut edited = original.clone();
+        for start in [10, 150, 300] {
+            for line in &mut edited[start..start + 25] {
+                *line = format!("{} edited", line);
+            }
+        }
+        std::fs::write(dir.path().join("big.txt"), edited.join("\n") + "\n")?;
+        let other: Vec<String> = (0..150).map(|i| format!("other {}", i)).collect();
+        std::fs::write(dir.path().join("other.txt"), other.join("\n") + "\n")?;
+        gateway.stage_all()?;
+        gateway.commit("Big mechanical change")?;
+        let original_tree = repo.revparse_single("feature")?.peel_to_tree()?.id();
+        let original_commit = repo.revparse_single("feature")?.id().to_string();
+        assert_eq!(changed_lines(&repo, "main", "feature")?, 300);
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feature", "main")?;
+
+        run(None, None, false, None, false, Some(100))?;
+
+        // big.txt is over the limit so its hunks are packed (50 + 50, then 50);
+        // other.txt fits in one branch and is kept whole
+        assert_eq!(ref_store.get_parent("feature-part1")?, Some("main".to_string()));
+        assert_eq!(
+            ref_store.get_parent("feature-part2")?,
+            Some("feature-part1".to_string())
+        );
+        assert_eq!(ref_store.get_parent("feature")?, Some("feature-part2".to_string()));
+        assert_eq!(changed_lines(&repo, "main", "feature-part1")?, 100);
+        assert_eq!(changed_lines(&repo, "feature-part1", "feature-part2")?, 50);
+        assert_eq!(changed_lines(&repo, "feature-part2", "feature")?, 150);
+
+        // Together the branches reproduce the original tree
+        assert_eq!(repo.revparse_single("feature")?.peel_to_tree()?.id(), original_tree);
+        assert_eq!(gateway.get_current_branch_name()?, "feature");
+        assert!(!gateway.has_uncommitted_changes()?);
+
+        // The squashed original branch can be recovered from its backup
+        let backups = gateway.list_backup_refs()?;
+        assert!(backups
+            .iter()
+            .any(|b| b.branch_name == "feature" && b.commit_oid == original_commit));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_by_size_splits_renamed_file_by_hunks() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let gateway = GitGateway::new()?;
+
+        let original: Vec<String> = (0..400).map(|i| format!("line {}", i)).collect();
+        std::fs::write(dir.path().join("old.txt"), original.join("\n") + "\n")?;
+        gateway.stage_all()?;
+        gateway.commit("Add old.txt")?;
+
+        gateway.create_branch("feature")?;
+        // Rename with two far-apart 10-line edits, each hunk over half the limit
+        let mut edited = original.clone();
+        for start in [10, 300] {
+            for line in &mut edited[start..start + 10] {
+                *line = format!("{} edited", line);
+            }
+        }
+        std::fs::remove_file(dir.path().join("old.txt"))?;
+        std::fs::write(dir.path().join("new.txt"), edited.join("\n") + "\n")?;
+        gateway.stage_all()?;
+        gateway.commit("Rename and edit")?;
+        let original_tree = repo.revparse_single("feature")?.peel_to_tree()?.id();
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feature", "main")?;
+
+        run(None, None, false, None, false, Some(25))?;
+
+        assert_eq!(ref_store.get_parent("feature")?, Some("feature-part1".to_string()));
+        let part1 = repo.revparse_single("feature-part1")?.peel_to_tree()?;
+        assert!(part1.get_name("new.txt").is_some() && part1.get_name("old.txt").is_none());
+        assert_eq!(repo.revparse_single("feature")?.peel_to_tree()?.id(), original_tree);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,334 @@
+//! `dm status`: a one-screen summary of where you are in the stack.
+//!
+//! Combines what `dm log --short`, `dm info` and `git status` would tell you:
+//! the current branch and its place in the stack, working tree state, freeze
+//! and PR status, and any interrupted operation.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::cache::Cache;
+use crate::forge::{get_forge, Forge};
+use crate::git_gateway::GitGateway;
+use crate::program_name::program_name;
+use crate::ref_store::RefStore;
+use crate::state::OperationState;
+use crate::ui;
+
+/// Everything `dm status` reports, in the shape emitted by `--json`
+///
+/// Field names are part of the scripting interface; add fields rather than
+/// renaming them.
+#[derive(Debug, Serialize)]
+pub struct StatusReport {
+    pub branch: String,
+    pub parent: Option<String>,
+    pub trunk: Option<String>,
+    /// Whether the branch is trunk or has a Diamond parent
+    pub tracked: bool,
+    /// Tracked branches between trunk and this branch (trunk itself not counted)
+    pub ancestors: usize,
+    /// Branches stacked on top of this one, at any depth
+    pub descendants: usize,
+    pub uncommitted_changes: bool,
+    pub staged_changes: bool,
+    pub frozen: bool,
+    pub pr: Option<PrStatus>,
+    pub operation: Option<OperationStatus>,
+}
+
+/// The branch's PR as far as Diamond knows it
+///
+/// `review` and `ci` are `None` when the forge could not be reached.
+#[derive(Debug, Serialize)]
+pub struct PrStatus {
+    pub url: String,
+    pub number: Option<u64>,
+    pub state: Option<String>,
+    pub review: Option<String>,
+    pub ci: Option<String>,
+}
+
+/// An interrupted sync/restack/move waiting for `continue` or `abort`
+#[derive(Debug, Serialize)]
+pub struct OperationStatus {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub current_branch: Option<String>,
+    pub remaining_branches: Vec<String>,
+}
+
+/// Print a summary of the current branch, or a JSON object with `json`
+pub fn run(json: bool) -> Result<()> {
+    let gateway = GitGateway::new()?;
+    let ref_store = RefStore::new()?;
+    let cache = Cache::load().unwrap_or_default();
+
+    // Only talk to the forge when there is a PR to ask about
+    let branch = gateway.get_current_branch_name()?;
+    let forge = if cache.get_pr_url(&branch).is_some() {
+        get_forge(None).ok()
+    } else {
+        None
+    };
+
+    let report = collect(&gateway, &ref_store, &cache, forge.as_deref())?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).context("Failed to serialize status")?
+        );
+    } else {
+        for line in report_lines(&report) {
+            println!("{}", line);
+        }
+    }
+
+    Ok(())
+}
+
+/// Gather the status of the current branch
+///
+/// PR review/CI state is fetched through `forge` when given; without one
+/// only the cached PR URL is reported.
+fn collect(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    cache: &Cache,
+    forge: Option<&dyn Forge>,
+) -> Result<StatusReport> {
+    let branch = gateway.get_current_branch_name()?;
+    let trunk = ref_store.get_trunk()?;
+    let parent = ref_store.get_parent(&branch)?;
+    let is_trunk = trunk.as_deref() == Some(branch.as_str());
+    let tracked = is_trunk || parent.is_some();
+
+    let ancestors = if tracked && !is_trunk {
+        ref_store.ancestors(&branch)?.len() - 1
+    } else {
+        0
+    };
+    let descendants = if tracked {
+        ref_store.descendants(&branch)?.len()
+    } else {
+        0
+    };
+
+    let pr = cache.get_pr_url(&branch).map(|url| {
+        let info = forge.and_then(|forge| forge.get_pr_full_info(url).ok());
+        PrStatus {
+            url: url.to_string(),
+            number: info.as_ref().map(|pr| pr.number),
+            state: info.as_ref().map(|pr| pr.state_display().to_lowercase()),
+            review: info.as_ref().map(|pr| pr.review.to_string()),
+            ci: info.as_ref().map(|pr| pr.ci.to_string()),
+        }
+    });
+
+    let operation = OperationState::load()?.map(|state| OperationStatus {
+        kind: state.operation_type.to_string(),
+        current_branch: state.current_branch,
+        remaining_branches: state.remaining_branches,
+    });
+
+    Ok(StatusReport {
+        frozen: ref_store.is_frozen(&branch)?,
+        uncommitted_changes: gateway.has_uncommitted_changes()?,
+        staged_changes: gateway.has_staged_changes()?,
+        branch,
+        parent,
+        trunk,
+        tracked,
+        ancestors,
+        descendants,
+        pr,
+        operation,
+    })
+}
+
+/// Human-readable rendering of a report
+fn report_lines(report: &StatusReport) -> Vec<String> {
+    let mut lines = vec![format!("{} {}", "On".dimmed(), report.branch.green().bold())];
+
+    let parent = match (&report.parent, report.tracked) {
+        (Some(parent), _) => parent.blue().to_string(),
+        (None, true) => "(none - trunk)".dimmed().to_string(),
+        (None, false) => format!("not tracked (run '{} track')", program_name())
+            .yellow()
+            .to_string(),
+    };
+    lines.push(format!("  {}: {}", "Parent".bold(), parent));
+    if report.tracked {
+        lines.push(format!(
+            "  {}: {} below, {} above",
+            "Stack".bold(),
+            report.ancestors,
+            report.descendants
+        ));
+    }
+
+    let working_tree = match (report.staged_changes, report.uncommitted_changes) {
+        (true, _) => "staged changes".yellow().to_string(),
+        (false, true) => "uncommitted changes".yellow().to_string(),
+        (false, false) => "clean".green().to_string(),
+    };
+    lines.push(format!("  {}: {}", "Working tree".bold(), working_tree));
+
+    if report.frozen {
+        lines.push(format!("  {}: {}", "Frozen".bold(), "yes".cyan()));
+    }
+
+    match &report.pr {
+        Some(pr) => {
+            let label = pr.number.map(|n| format!("#{}", n)).unwrap_or_else(|| pr.url.clone());
+            let mut line = format!("  {}: {}", "PR".bold(), ui::hyperlink(&pr.url, &label).cyan());
+            if let Some(state) = &pr.state {
+                line.push_str(&format!(" ({})", state));
+            }
+            lines.push(line);
+            let unknown = || "unknown".dimmed().to_string();
+            lines.push(format!(
+                "    {}: {}, {}: {}",
+                "Review".bold(),
+                pr.review.clone().unwrap_or_else(unknown),
+                "CI".bold(),
+                pr.ci.clone().unwrap_or_else(unknown)
+            ));
+        }
+        None => lines.push(format!("  {}: {}", "PR".bold(), "(not submitted)".dimmed())),
+    }
+
+    if let Some(op) = &report.operation {
+        let at = op
+            .current_branch
+            .as_ref()
+            .map(|b| format!(" at '{}'", b))
+            .unwrap_or_default();
+        lines.push(format!(
+            "  {}: {} stopped{} ({} remaining) - run '{} continue' or '{} abort'",
+            "In progress".bold(),
+            op.kind.red().bold(),
+            at,
+            op.remaining_branches.len(),
+            program_name(),
+            program_name()
+        ));
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::mock::MockForge;
+    use crate::forge::ForgeType;
+    use crate::test_context::{init_test_repo, TestRepoContext};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_status_reports_stack_position_and_working_tree() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let main_commit = repo.head()?.peel_to_commit()?;
+        repo.branch("f1", &main_commit, false)?;
+        repo.branch("f2", &main_commit, false)?;
+        repo.branch("f3", &main_commit, false)?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("f1", "main")?;
+        ref_store.set_parent("f2", "f1")?;
+        ref_store.set_parent("f3", "f2")?;
+        ref_store.set_frozen("f2", true)?;
+        repo.set_head("refs/heads/f2")?;
+
+        std::fs::write(dir.path().join("new.txt"), "content")?;
+        let mut index = repo.index()?;
+        index.add_path(std::path::Path::new("new.txt"))?;
+        index.write()?;
+
+        let gateway = GitGateway::new()?;
+        let report = collect(&gateway, &ref_store, &Cache::default(), None)?;
+
+        assert_eq!(report.branch, "f2");
+        assert_eq!(report.parent.as_deref(), Some("f1"));
+        assert!(report.tracked);
+        assert_eq!(report.ancestors, 1);
+        assert_eq!(report.descendants, 1);
+        assert!(report.staged_changes);
+        assert!(report.uncommitted_changes);
+        assert!(report.frozen);
+        assert!(report.pr.is_none());
+        assert!(report.operation.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_json_includes_pr_and_operation() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let main_commit = repo.head()?.peel_to_commit()?;
+        repo.branch("feature", &main_commit, false)?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feature", "main")?;
+        repo.set_head("refs/heads/feature")?;
+
+        let mut cache = Cache::default();
+        cache.set_pr_url("feature", "https://github.com/test/repo/pull/1");
+
+        let mut state = OperationState::new_restack("feature".to_string(), vec!["feature".to_string()]);
+        state.current_branch = Some("feature".to_string());
+        state.save_to(dir.path())?;
+
+        let gateway = GitGateway::new()?;
+        let forge = MockForge::new(ForgeType::GitHub);
+        let report = collect(&gateway, &ref_store, &cache, Some(&forge))?;
+        let value = serde_json::to_value(&report)?;
+
+        assert_eq!(value["branch"], "feature");
+        assert_eq!(value["parent"], "main");
+        assert_eq!(value["ancestors"], 0);
+        assert_eq!(value["uncommitted_changes"], false);
+        assert_eq!(value["pr"]["url"], "https://github.com/test/repo/pull/1");
+        assert_eq!(value["pr"]["review"], "pending");
+        assert_eq!(value["pr"]["ci"], "success");
+        assert_eq!(value["operation"]["type"], "restack");
+        assert_eq!(value["operation"]["current_branch"], "feature");
+
+        // Without a forge the PR is still reported, with unknown review/CI
+        let report = collect(&gateway, &ref_store, &cache, None)?;
+        let pr = report.pr.expect("cached PR should be reported");
+        assert!(pr.review.is_none() && pr.ci.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_untracked_branch() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let main_commit = repo.head()?.peel_to_commit()?;
+        repo.branch("loose", &main_commit, false)?;
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        repo.set_head("refs/heads/loose")?;
+
+        let gateway = GitGateway::new()?;
+        let report = collect(&gateway, &ref_store, &Cache::default(), None)?;
+        assert!(!report.tracked);
+        assert_eq!(report.descendants, 0);
+        assert!(report_lines(&report).iter().any(|l| l.contains("not tracked")));
+
+        Ok(())
+    }
+}
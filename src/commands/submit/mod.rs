@@ -8,18 +8,27 @@ mod tests;
 
 use std::collections::HashMap;
 use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
 
-use crate::forge::{get_async_forge, get_forge, PrInfo, PrOptions};
+use crate::config::Config;
+use crate::forge::{
+    get_async_forge, get_forge, invalidate_pr_status, rewrite_web_url, AsyncForge, Forge, PrInfo, PrOptions,
+};
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
-use crate::stack_viz::{collect_full_stack, update_stack_visualization_async};
+use crate::stack_viz::{collect_full_stack, strip_stack_visualization_async, update_stack_visualization_async};
 
-use self::submission::{submit_branch, submit_stack};
-use self::validation::{check_trunk_sync, show_submit_preview_async, validate_stack_integrity};
+use self::submission::{
+    collect_branches_since, record_failed_submits, retry_targets, submit_branch, submit_branches, submit_since,
+    submit_stack, EmptyBranchPolicy, SubmitSettings,
+};
+use self::validation::{
+    check_force_push_protection, check_trunk_sync, plan_submit_actions, show_submit_preview, validate_stack_integrity,
+};
 
 /// PR existence cache - maps branch name to optional PR info
 pub(crate) type PrCache = HashMap<String, Option<PrInfo>>;
@@ -27,41 +36,242 @@ pub(crate) type PrCache = HashMap<String, Option<PrInfo>>;
 /// Collect all branches that need PR existence checks (branches + their ancestors)
 fn collect_branches_for_pr_check(branches: &[String], ref_store: &RefStore) -> Result<Vec<String>> {
     let mut all_branches = std::collections::HashSet::new();
-    let trunk = ref_store.get_trunk()?;
+    let snapshot = ref_store.snapshot()?;
 
+    // Each branch and its ancestors up to (not including) trunk
     for branch in branches {
-        // Add the branch itself
-        all_branches.insert(branch.clone());
-
-        // Walk up to trunk, collecting ancestors
-        let mut current = ref_store.get_parent(branch)?;
-        while let Some(parent) = current {
-            if trunk.as_ref() == Some(&parent) {
-                break;
+        all_branches.extend(snapshot.ancestors(branch)?);
+    }
+
+    Ok(all_branches.into_iter().collect())
+}
+
+/// The names the forge knows `branches` by (see [`GitGateway::remote_head`])
+fn remote_heads(gateway: &GitGateway, branches: &[String]) -> Vec<String> {
+    branches.iter().map(|b| gateway.remote_head(b)).collect()
+}
+
+/// Look up which of `branches` have PRs, keyed by local branch name
+///
+/// With `no_cache`, the cached PR status of the whole stack around `current`
+/// is dropped first, so every branch is looked up on the forge.
+async fn check_prs(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    async_forge: &dyn AsyncForge,
+    current: &str,
+    branches: &[String],
+    no_cache: bool,
+) -> Result<PrCache> {
+    if no_cache {
+        invalidate_pr_status(&remote_heads(gateway, &collect_full_stack(current, ref_store)?));
+    }
+    // The forge knows prefixed downloads by their original names; key the cache by local name
+    let pr_results = async_forge.check_prs_exist(&remote_heads(gateway, branches)).await;
+    Ok(branches
+        .iter()
+        .cloned()
+        .zip(pr_results.into_iter().map(|(_, pr)| pr))
+        .collect())
+}
+
+/// Drop the PR author from the reviewer list
+///
+/// Forges refuse review requests from the author, so `@me` and the
+/// authenticated user's own login are removed with a warning. If the user
+/// can't be looked up, only `@me` is removed.
+fn exclude_self_from_reviewers(reviewers: Vec<String>, forge: &dyn Forge) -> Vec<String> {
+    if reviewers.is_empty() {
+        return reviewers;
+    }
+
+    let me = forge.current_user().ok();
+    let is_me = |reviewer: &str| {
+        let login = reviewer.trim_start_matches('@');
+        reviewer == "@me" || me.as_deref().is_some_and(|me| login.eq_ignore_ascii_case(me))
+    };
+
+    let (own, others): (Vec<String>, Vec<String>) = reviewers.into_iter().partition(|r| is_me(r));
+    for reviewer in &own {
+        eprintln!(
+            "{} Not requesting a review from '{}': you can't review your own PR",
+            "⚠".yellow(),
+            reviewer
+        );
+    }
+    others
+}
+
+/// What submit does with the stack visualization block in PR bodies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StackVizMode {
+    /// Add or refresh the block (default)
+    Update,
+    /// Leave PR bodies alone, including any existing block
+    Skip,
+    /// Remove existing blocks
+    Strip,
+}
+
+impl StackVizMode {
+    /// `--strip-viz` wins over `--no-viz`, which wins over `submit.stack_viz`
+    fn resolve(no_viz: bool, strip_viz: bool) -> Self {
+        if strip_viz {
+            StackVizMode::Strip
+        } else if no_viz || !Config::load().map(|c| c.submit.stack_viz_enabled()).unwrap_or(true) {
+            StackVizMode::Skip
+        } else {
+            StackVizMode::Update
+        }
+    }
+}
+
+/// Apply the stack visualization mode to every PR in the stack
+async fn apply_stack_viz(
+    mode: StackVizMode,
+    full_stack: &[String],
+    forge: &dyn AsyncForge,
+    ref_store: &RefStore,
+) -> Result<()> {
+    match mode {
+        StackVizMode::Update => {
+            // Show beautiful progress - the tracker handles the summary output
+            update_stack_visualization_async(full_stack, forge, ref_store, true).await?;
+        }
+        StackVizMode::Skip => {}
+        StackVizMode::Strip => {
+            let stripped = strip_stack_visualization_async(full_stack, forge).await?;
+            if stripped > 0 {
+                println!(
+                    "{} Removed stack visualization from {} PR{}",
+                    "✓".green(),
+                    stripped,
+                    if stripped == 1 { "" } else { "s" }
+                );
             }
-            all_branches.insert(parent.clone());
-            current = ref_store.get_parent(&parent)?;
         }
     }
+    Ok(())
+}
 
-    Ok(all_branches.into_iter().collect())
+/// Conventional PR template location, used when no template is configured
+const DEFAULT_PR_TEMPLATE: &str = ".github/pull_request_template.md";
+
+/// Resolve the body template for new PRs
+///
+/// `--template` wins over `submit.template_path`; both must exist. Without
+/// either, the repo's `.github/pull_request_template.md` is used if present.
+/// Relative paths are resolved against the repository root.
+fn load_body_template(gateway: &GitGateway, template: Option<PathBuf>) -> Result<Option<String>> {
+    let configured = template.or_else(|| {
+        Config::load()
+            .ok()
+            .and_then(|c| c.submit.template_path)
+            .map(PathBuf::from)
+    });
+    let resolve = |path: &Path| {
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            gateway.workdir().join(path)
+        }
+    };
+
+    let path = match configured {
+        Some(path) => resolve(&path),
+        None => {
+            let default = resolve(Path::new(DEFAULT_PR_TEMPLATE));
+            if !default.is_file() {
+                return Ok(None);
+            }
+            default
+        }
+    };
+
+    let content =
+        std::fs::read_to_string(&path).with_context(|| format!("Failed to read PR template '{}'", path.display()))?;
+    Ok(Some(content))
+}
+
+/// Options for [`run`], one per `submit` flag
+#[derive(Debug, Default)]
+pub struct SubmitOptions {
+    /// Submit the entire stack (ancestors and descendants)
+    pub stack: bool,
+    /// Force push (instead of --force-with-lease)
+    pub force: bool,
+    /// Create new PRs as drafts
+    pub draft: bool,
+    /// Mark draft PRs as ready for review
+    pub publish: bool,
+    /// Enable auto-merge once CI passes
+    pub merge_when_ready: bool,
+    /// Branch to submit instead of the current one
+    pub target_branch: Option<String>,
+    /// Reviewers to request
+    pub reviewers: Vec<String>,
+    /// Don't open new PRs in the browser
+    pub no_open: bool,
+    /// Skip the stack integrity checks
+    pub skip_validation: bool,
+    /// Only push branches that already have PRs
+    pub update_only: bool,
+    /// Preview the submission and ask before doing it
+    pub confirm: bool,
+    /// Base URL to open PRs in the browser with (overrides `web.base_url`)
+    pub web_base: Option<String>,
+    /// Submit only the branches above this ancestor
+    pub since: Option<String>,
+    /// Warn about branches protected against force-push before pushing
+    pub check_base_protection: bool,
+    /// Stop on a branch with no commits (and, with `check_base_protection`,
+    /// on a protected branch) instead of skipping or warning
+    pub strict: bool,
+    /// Re-submit only the branches that failed in the last stack submit
+    pub retry_failed: bool,
+    /// Seed new PR bodies from this file (overrides `submit.template_path`)
+    pub template: Option<PathBuf>,
+    /// Leave the stack visualization in PR bodies alone
+    pub no_viz: bool,
+    /// Remove the stack visualization from PR bodies
+    pub strip_viz: bool,
+    /// Ask the forge for PR status instead of using the cached copy
+    pub no_cache: bool,
 }
 
 /// Submit the current branch or stack by pushing and creating PRs (default: submit current branch only)
-#[allow(clippy::too_many_arguments)]
-pub async fn run(
-    stack: bool,
-    force: bool,
-    draft: bool,
-    publish: bool,
-    merge_when_ready: bool,
-    target_branch: Option<String>,
-    reviewers: Vec<String>,
-    no_open: bool,
-    skip_validation: bool,
-    update_only: bool,
-    confirm: bool,
-) -> Result<()> {
+///
+/// `--stack` keeps going past branches that fail and records them; `retry_failed`
+/// submits only those recorded branches.
+///
+/// `no_viz` (or `submit.stack_viz = false`) leaves PR bodies' stack visualization
+/// alone; `strip_viz` removes existing visualization blocks.
+///
+/// Branches with no commits ahead of their base get no PR: they are skipped
+/// along with the branches stacked on them, or refused under `strict`.
+pub async fn run(options: SubmitOptions) -> Result<()> {
+    let SubmitOptions {
+        stack,
+        force,
+        draft,
+        publish,
+        merge_when_ready,
+        target_branch,
+        reviewers,
+        no_open,
+        skip_validation,
+        update_only,
+        confirm,
+        web_base,
+        since,
+        check_base_protection,
+        strict,
+        retry_failed,
+        template,
+        no_viz,
+        strip_viz,
+        no_cache,
+    } = options;
     let gateway = GitGateway::new()?;
     let current = if let Some(ref branch) = target_branch {
         // Verify the branch exists
@@ -92,23 +302,46 @@ pub async fn run(
     // Check auth before proceeding
     forge.check_auth()?;
 
+    // You can't review your own PR
+    let reviewers = exclude_self_from_reviewers(reviewers, forge.as_ref());
+
     // Pre-flight validation (unless skipped)
     if !skip_validation {
         validate_stack_integrity(&current, &ref_store, &gateway, trunk.as_deref())?;
         check_trunk_sync(&gateway, trunk.as_deref())?;
     }
 
-    let options = PrOptions {
-        draft,
-        publish,
-        merge_when_ready,
-        reviewers,
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft,
+            publish,
+            merge_when_ready,
+            reviewers,
+        },
+        body_template: load_body_template(&gateway, template)?,
+        empty_branches: if strict {
+            EmptyBranchPolicy::Error
+        } else {
+            EmptyBranchPolicy::Skip
+        },
+        force,
+        update_only,
     };
 
     // Build list of branches to submit for preview/confirmation
     // Default: submit current branch only
     // --stack: submit downstack + all descendants (full stack)
-    let branches_to_submit: Vec<String> = if stack {
+    // --since: submit current branch and its ancestors above the given one
+    let branches_to_submit: Vec<String> = if retry_failed {
+        let targets = retry_targets(&ref_store, &gateway)?;
+        if targets.is_empty() {
+            println!("No failed branches to retry.");
+            return Ok(());
+        }
+        targets
+    } else if let Some(ref since) = since {
+        collect_branches_since(&current, since, &ref_store)?
+    } else if stack {
         // Full stack: downstack + all descendants
         let mut all = ref_store.ancestors(&current)?;
         for descendant in ref_store.collect_branches_dfs(std::slice::from_ref(&current))? {
@@ -122,9 +355,29 @@ pub async fn run(
         vec![current.clone()]
     };
 
-    // Show confirmation prompt if requested (uses async for batch PR checks)
+    // Pre-check PR existence for all branches (batch async for performance)
+    let branches_to_check = collect_branches_for_pr_check(&branches_to_submit, &ref_store)?;
+    let pr_cache = check_prs(
+        &gateway,
+        &ref_store,
+        async_forge.as_ref(),
+        &current,
+        &branches_to_check,
+        no_cache,
+    )
+    .await?;
+
+    // Show confirmation prompt if requested
     if confirm {
-        show_submit_preview_async(&branches_to_submit, &ref_store, async_forge.as_ref(), update_only).await?;
+        let preview = plan_submit_actions(
+            &branches_to_submit,
+            &ref_store,
+            &gateway,
+            &pr_cache,
+            update_only,
+            options.empty_branches,
+        )?;
+        show_submit_preview(&preview);
 
         // Check if stdin is a TTY before prompting
         if !io::stdin().is_terminal() {
@@ -144,23 +397,39 @@ pub async fn run(
         println!();
     }
 
-    // Pre-check PR existence for all branches (batch async for performance)
-    let branches_to_check = collect_branches_for_pr_check(&branches_to_submit, &ref_store)?;
-    let pr_results = async_forge.check_prs_exist(&branches_to_check).await;
-    let pr_cache: PrCache = pr_results.into_iter().collect();
+    // Surface force-push protection before the first push rather than mid-stack
+    if check_base_protection {
+        check_force_push_protection(&remote_heads(&gateway, &branches_to_submit), forge.as_ref(), strict)?;
+    }
 
     // Submit branches and collect results
-    let results: Vec<submission::SubmitResult> = if stack {
-        submit_stack(
+    // (stack and retry submissions also report failures instead of stopping)
+    let mut failed: Option<Vec<(String, String)>> = None;
+    let results: Vec<submission::SubmitResult> = if retry_failed {
+        let submission = submit_branches(
+            &branches_to_submit,
+            &ref_store,
+            &gateway,
+            forge.as_ref(),
+            &options,
+            &pr_cache,
+        )?;
+        failed = Some(submission.failed);
+        submission.results
+    } else if let Some(ref since) = since {
+        submit_since(
             &current,
+            since,
             &ref_store,
             &gateway,
             forge.as_ref(),
-            force,
             &options,
-            update_only,
             &pr_cache,
         )?
+    } else if stack {
+        let submission = submit_stack(&current, &ref_store, &gateway, forge.as_ref(), &options, &pr_cache)?;
+        failed = Some(submission.failed);
+        submission.results
     } else {
         // Single branch submission
         if let Some(result) = submit_branch(
@@ -168,9 +437,7 @@ pub async fn run(
             &ref_store,
             &gateway,
             forge.as_ref(),
-            force,
             &options,
-            update_only,
             &pr_cache,
             None, // No progress counter for single branch
         )? {
@@ -182,6 +449,10 @@ pub async fn run(
 
     // Show summary if any PRs were submitted
     if !results.is_empty() {
+        // Submitting may have changed the PRs' base, draft state or title
+        let submitted: Vec<String> = results.iter().map(|r| r.branch.clone()).collect();
+        invalidate_pr_status(&remote_heads(&gateway, &submitted));
+
         let created_count = results.iter().filter(|r| r.created).count();
         let updated_count = results.len() - created_count;
 
@@ -216,12 +487,27 @@ pub async fn run(
 
         // Update stack visualization in all PRs (once, at the end, using async for parallelism)
         let full_stack = collect_full_stack(&current, &ref_store)?;
-        // Show beautiful progress - the tracker handles the summary output
-        let _updated = update_stack_visualization_async(&full_stack, async_forge.as_ref(), &ref_store, true).await?;
+        let viz_mode = StackVizMode::resolve(no_viz, strip_viz);
+        apply_stack_viz(
+            viz_mode,
+            &remote_heads(&gateway, &full_stack),
+            async_forge.as_ref(),
+            &ref_store,
+        )
+        .await?;
 
         // Open newly created PRs in browser (unless --no-open)
         if !no_open {
-            let created_urls: Vec<&String> = results.iter().filter(|r| r.created).map(|r| &r.url).collect();
+            // --web-base overrides web.base_url; stored PR URLs are left untouched
+            let web_base = web_base.or_else(|| Config::load().ok().and_then(|c| c.web.base_url));
+            let created_urls: Vec<String> = results
+                .iter()
+                .filter(|r| r.created)
+                .map(|r| match web_base {
+                    Some(ref base) => rewrite_web_url(&r.url, base),
+                    None => r.url.clone(),
+                })
+                .collect();
             if !created_urls.is_empty() {
                 for url in &created_urls {
                     if let Err(e) = open::that(url) {
@@ -232,5 +518,25 @@ pub async fn run(
         }
     }
 
+    // Remember what failed for --retry-failed (a clean run clears it)
+    if let Some(failed) = failed {
+        record_failed_submits(&failed)?;
+        if !failed.is_empty() {
+            println!(
+                "\n{} {} branch{} failed:",
+                "✗".red().bold(),
+                failed.len(),
+                if failed.len() == 1 { "" } else { "es" }
+            );
+            for (branch, reason) in &failed {
+                println!("  • {}: {}", branch, reason);
+            }
+            anyhow::bail!(
+                "Submit incomplete. Fix the errors above, then run '{} submit --retry-failed'.",
+                program_name()
+            );
+        }
+    }
+
     Ok(())
 }
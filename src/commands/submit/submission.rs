@@ -6,10 +6,10 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 
 use crate::cache::Cache;
-use crate::forge::{Forge, PrInfo, PrOptions};
+use crate::forge::{CiStatus, Forge, PrFullInfo, PrInfo, PrOptions, PrState, ReviewState};
 use crate::git_gateway::{BranchSyncState, GitGateway};
 use crate::program_name::program_name;
-use crate::ref_store::RefStore;
+use crate::ref_store::{RefStore, RefStoreLockGuard};
 
 use super::PrCache;
 
@@ -21,6 +21,41 @@ pub(super) struct SubmitResult {
     pub created: bool, // true if created, false if updated
 }
 
+/// How a submit run treats each branch, beyond the options for its PR
+#[derive(Debug, Clone, Default)]
+pub(super) struct SubmitSettings {
+    /// Options for the PRs that are created or updated
+    pub pr: PrOptions,
+    /// Body template for new PRs, with `{branch}`/`{parent}`/`{commits}` placeholders
+    pub body_template: Option<String>,
+    /// What to do with a branch that has no commits ahead of its base
+    pub empty_branches: EmptyBranchPolicy,
+    /// Force push (instead of --force-with-lease)
+    pub force: bool,
+    /// Only update branches that already have a PR
+    pub update_only: bool,
+}
+
+/// How submit treats a branch with no commits ahead of its base
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum EmptyBranchPolicy {
+    /// Create the PR anyway
+    #[default]
+    Allow,
+    /// Skip the branch (and the branches stacked on it) without creating a PR
+    Skip,
+    /// Refuse to submit the branch
+    Error,
+}
+
+/// Outcome of submitting several branches, where one failure doesn't stop the rest
+#[derive(Debug, Default)]
+pub(super) struct StackSubmission {
+    pub results: Vec<SubmitResult>,
+    /// Branches that failed (or were skipped because their parent failed), with the reason
+    pub failed: Vec<(String, String)>,
+}
+
 /// Get PR title for a branch - uses tip commit message or falls back to branch name
 pub(super) fn get_pr_title_for_branch(gateway: &GitGateway, branch: &str) -> Result<String> {
     // Try to get commit subject (first line of commit message)
@@ -77,7 +112,7 @@ fn push_diverged_ancestors(
             let has_pr = pr_cache
                 .get(ancestor)
                 .map(|opt| opt.is_some())
-                .unwrap_or_else(|| forge.pr_exists(ancestor).ok().flatten().is_some());
+                .unwrap_or_else(|| forge.pr_exists(&gateway.remote_head(ancestor)).ok().flatten().is_some());
 
             if has_pr {
                 println!("{} Pushing rebased ancestor {}...", "↑".blue(), ancestor.yellow());
@@ -124,21 +159,24 @@ pub(super) fn check_branch_sync_state(gateway: &GitGateway, branch: &str) -> Res
 }
 
 /// Submit a single branch
-#[allow(clippy::too_many_arguments)]
 pub(super) fn submit_branch(
     branch: &str,
     ref_store: &RefStore,
     gateway: &GitGateway,
     forge: &dyn Forge,
-    force: bool,
-    options: &PrOptions,
-    update_only: bool,
+    options: &SubmitSettings,
     pr_cache: &PrCache,
     progress: Option<(usize, usize)>, // (current, total) for progress counter
 ) -> Result<Option<SubmitResult>> {
+    // Frozen branches are usually someone else's (see `get`), so never push them
+    if ref_store.is_frozen(branch)? {
+        println!("{} Skipping {} (frozen)", "⏭".dimmed(), branch.yellow());
+        return Ok(None);
+    }
+
     // First, ensure any diverged ancestor branches are pushed.
     // This prevents PRs from showing incorrect diffs when the stack was rebased locally.
-    push_diverged_ancestors(branch, ref_store, gateway, forge, force, pr_cache)?;
+    push_diverged_ancestors(branch, ref_store, gateway, forge, options.force, pr_cache)?;
 
     let trunk = ref_store.get_trunk()?;
 
@@ -156,7 +194,7 @@ pub(super) fn submit_branch(
         .get(branch)
         .cloned()
         .flatten()
-        .or_else(|| forge.pr_exists(branch).ok().flatten());
+        .or_else(|| forge.pr_exists(&gateway.remote_head(branch)).ok().flatten());
 
     if let Some(ref pr_info) = existing_pr {
         // PR exists - push updates
@@ -176,12 +214,12 @@ pub(super) fn submit_branch(
         std::io::stdout().flush().ok();
 
         // Check for remote divergence before pushing (safety check)
-        if !force {
+        if !options.force {
             check_branch_sync_state(gateway, branch)?;
         }
 
         // Push the branch
-        forge.push_branch(branch, force)?;
+        forge.push_branch(branch, options.force)?;
 
         // Still push diamond ref in case it changed
         if let Err(e) = gateway.push_diamond_ref(branch) {
@@ -189,8 +227,8 @@ pub(super) fn submit_branch(
         }
 
         // Handle publish - mark draft PR as ready for review
-        if options.publish {
-            match forge.mark_pr_ready(branch) {
+        if options.pr.publish {
+            match forge.mark_pr_ready(&gateway.remote_head(branch)) {
                 Ok(()) => {}
                 Err(e) => {
                     eprintln!("\n  {} Could not mark as ready: {}", "!".yellow(), e);
@@ -199,8 +237,8 @@ pub(super) fn submit_branch(
         }
 
         // Handle merge-when-ready - enable auto-merge
-        if options.merge_when_ready {
-            match forge.enable_auto_merge(branch, "squash") {
+        if options.pr.merge_when_ready {
+            match forge.enable_auto_merge(&gateway.remote_head(branch), "squash") {
                 Ok(()) => {}
                 Err(e) => {
                     eprintln!("\n  {} Could not enable auto-merge: {}", "!".yellow(), e);
@@ -218,11 +256,30 @@ pub(super) fn submit_branch(
     }
 
     // No PR exists - check if we should skip (update_only mode)
-    if update_only {
+    if options.update_only {
         println!("{} Skipping {} (no PR, --update-only)", "⏭".dimmed(), branch.yellow());
         return Ok(None);
     }
 
+    // A branch with nothing ahead of its base would only produce an empty PR
+    if options.empty_branches != EmptyBranchPolicy::Allow && gateway.get_commits_between(base, branch)?.is_empty() {
+        if options.empty_branches == EmptyBranchPolicy::Error {
+            anyhow::bail!(
+                "Branch '{}' has no commits to submit (nothing ahead of '{}').\n\
+                 Add commits or delete the branch, or submit without --strict to skip it.",
+                branch,
+                base
+            );
+        }
+        println!(
+            "{} Skipping {} (no commits to submit ahead of {})",
+            "⏭".dimmed(),
+            branch.yellow(),
+            base
+        );
+        return Ok(None);
+    }
+
     // Ensure parent branch has a PR before creating one for this branch
     // (If parent is a tracked branch and not trunk, submit it first)
     let is_trunk = trunk.as_ref() == Some(base);
@@ -233,10 +290,10 @@ pub(super) fn submit_branch(
         let parent_has_pr = pr_cache
             .get(base)
             .map(|opt| opt.is_some())
-            .unwrap_or_else(|| forge.pr_exists(base).ok().flatten().is_some());
+            .unwrap_or_else(|| forge.pr_exists(&gateway.remote_head(base)).ok().flatten().is_some());
 
         if !parent_has_pr {
-            if update_only {
+            if options.update_only {
                 // In update_only mode, don't recursively create parent PRs
                 anyhow::bail!(
                     "Cannot create PR for '{}': parent '{}' has no PR.\n\
@@ -248,23 +305,27 @@ pub(super) fn submit_branch(
             }
             println!("Parent branch {} needs a PR first, submitting it...\n", base.yellow());
             // Recursively submit the parent branch (no progress for recursive calls)
-            submit_branch(
-                base,
-                ref_store,
-                gateway,
-                forge,
-                force,
-                options,
-                update_only,
-                pr_cache,
+            let parent_result = submit_branch(
+                base, ref_store, gateway, forge, options, pr_cache,
                 None, // No progress counter for recursive calls
             )?;
             println!(); // Add spacing after parent submission
+
+            // A parent that was skipped isn't on the remote to be a PR base
+            if parent_result.is_none() {
+                println!(
+                    "{} Skipping {} (parent '{}' was not submitted)",
+                    "⏭".dimmed(),
+                    branch.yellow(),
+                    base
+                );
+                return Ok(None);
+            }
         }
     }
 
     // Check for remote divergence before pushing (safety check)
-    if !force {
+    if !options.force {
         check_branch_sync_state(gateway, branch)?;
     }
 
@@ -276,7 +337,7 @@ pub(super) fn submit_branch(
     };
 
     // Show one-line output for push and PR creation
-    let draft_str = if options.draft { " (draft)" } else { "" };
+    let draft_str = if options.pr.draft { " (draft)" } else { "" };
     print!(
         "{} {}Creating{} {} → {}... ",
         "→".blue(),
@@ -288,7 +349,7 @@ pub(super) fn submit_branch(
     std::io::stdout().flush().ok();
 
     // Push the branch
-    forge.push_branch(branch, force)?;
+    forge.push_branch(branch, options.force)?;
 
     // Push diamond parent ref for collaboration (Phase 2)
     if let Err(e) = gateway.push_diamond_ref(branch) {
@@ -297,18 +358,49 @@ pub(super) fn submit_branch(
 
     // Use tip commit message as title, fall back to branch name if no commits
     let title = get_pr_title_for_branch(gateway, branch)?;
-    let body = String::new();
+    let body = match options.body_template {
+        Some(ref template) => {
+            // Oldest first, as they'd read in the PR
+            let mut commits: Vec<String> = gateway
+                .get_commits_between(base, branch)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(_, subject)| subject)
+                .collect();
+            commits.reverse();
+            render_body_template(template, branch, base, &commits)
+        }
+        None => String::new(),
+    };
 
-    let url = forge.create_pr(branch, base, &title, &body, options)?;
+    // Branches downloaded under a prefix (`get --branch-prefix`) are known to the forge by their original names
+    let (url, created) = create_pr_once(
+        gateway.git_dir(),
+        forge,
+        &gateway.remote_head(branch),
+        &gateway.remote_head(base),
+        &title,
+        &body,
+        &options.pr,
+    )?;
 
     // Update cache with PR URL
     let mut cache = Cache::load().unwrap_or_default();
     cache.set_pr_url(branch, &url);
     cache.save()?;
 
+    if !created {
+        println!("{} PR already exists (created by a concurrent submit)", "✓".green());
+        return Ok(Some(SubmitResult {
+            branch: branch.to_string(),
+            url,
+            created: false,
+        }));
+    }
+
     // Handle merge-when-ready for new PRs
-    if options.merge_when_ready {
-        match forge.enable_auto_merge(branch, "squash") {
+    if options.pr.merge_when_ready {
+        match forge.enable_auto_merge(&gateway.remote_head(branch), "squash") {
             Ok(()) => {}
             Err(e) => {
                 eprintln!("\n  {} Could not enable auto-merge: {}", "!".yellow(), e);
@@ -327,18 +419,58 @@ pub(super) fn submit_branch(
     }))
 }
 
+/// Fill a PR body template's placeholders: `{branch}`, `{parent}` and
+/// `{commits}` (one `- subject` line per commit)
+pub(super) fn render_body_template(template: &str, branch: &str, parent: &str, commits: &[String]) -> String {
+    let commit_list = commits
+        .iter()
+        .map(|subject| format!("- {}", subject))
+        .collect::<Vec<_>>()
+        .join("\n");
+    template
+        .replace("{branch}", branch)
+        .replace("{parent}", parent)
+        .replace("{commits}", &commit_list)
+}
+
+/// Create the PR for `branch` unless one appeared since it was last checked
+///
+/// Holds the submit lock so concurrent submits in this repository can't both
+/// create a PR, and re-checks the forge right before creating to close the
+/// window for submits running elsewhere (e.g., a CI job).
+/// Returns the PR URL and whether this call created it.
+pub(super) fn create_pr_once(
+    git_dir: &std::path::Path,
+    forge: &dyn Forge,
+    branch: &str,
+    base: &str,
+    title: &str,
+    body: &str,
+    options: &PrOptions,
+) -> Result<(String, bool)> {
+    // One lock file for every branch, so locking never leaves files behind per branch
+    let _lock = RefStoreLockGuard::acquire_named(git_dir, "submit")?;
+
+    if let Some(existing) = forge.pr_exists(branch)? {
+        return Ok((existing.url, false));
+    }
+
+    let url = forge.create_pr(branch, base, title, body, options)?;
+    Ok((url, true))
+}
+
 /// Submit all branches in the stack (parent-first order)
-#[allow(clippy::too_many_arguments)]
+///
+/// A branch that fails doesn't stop the others; its descendants are skipped
+/// and reported as failed too, so `--retry-failed` picks them all up.
 pub(super) fn submit_stack(
     branch: &str,
     ref_store: &RefStore,
     gateway: &GitGateway,
     forge: &dyn Forge,
-    force: bool,
-    options: &PrOptions,
-    update_only: bool,
+    options: &SubmitSettings,
     pr_cache: &PrCache,
-) -> Result<Vec<SubmitResult>> {
+) -> Result<StackSubmission> {
     // Collect all descendants in DFS order (parent-first)
     let mut to_submit = vec![branch.to_string()];
     let mut i = 0;
@@ -351,19 +483,169 @@ pub(super) fn submit_stack(
         i += 1;
     }
 
+    // Auto-merge is enabled afterwards, bottom-up, once every PR exists
+    let mut branch_options = options.clone();
+    branch_options.pr.merge_when_ready = false;
+
+    let submission = submit_branches(&to_submit, ref_store, gateway, forge, &branch_options, pr_cache)?;
+
+    let submitted: Vec<String> = submission.results.iter().map(|r| r.branch.clone()).collect();
+    link_pr_dependencies(&submitted, ref_store, gateway, forge)?;
+
+    if options.pr.merge_when_ready {
+        enable_auto_merge_bottom_up(&submitted, ref_store, gateway, forge)?;
+    }
+
+    Ok(submission)
+}
+
+/// Submit `branches` (parent-first order) with progress counters, collecting failures
+pub(super) fn submit_branches(
+    branches: &[String],
+    ref_store: &RefStore,
+    gateway: &GitGateway,
+    forge: &dyn Forge,
+    options: &SubmitSettings,
+    pr_cache: &PrCache,
+) -> Result<StackSubmission> {
+    let total = branches.len();
+    let mut submission = StackSubmission::default();
+
+    for (idx, b) in branches.iter().enumerate() {
+        // Submitting a child would retry its failed parent, so skip it
+        if let Some(parent) = ref_store.get_parent(b)? {
+            if submission.failed.iter().any(|(f, _)| *f == parent) {
+                println!("{} Skipping {} (parent '{}' failed)", "⏭".dimmed(), b.yellow(), parent);
+                submission
+                    .failed
+                    .push((b.clone(), format!("parent '{}' failed", parent)));
+                continue;
+            }
+        }
+
+        match submit_branch(
+            b,
+            ref_store,
+            gateway,
+            forge,
+            options,
+            pr_cache,
+            Some((idx + 1, total)), // Progress: (current, total)
+        ) {
+            Ok(Some(result)) => submission.results.push(result),
+            Ok(None) => {}
+            Err(e) => {
+                println!("{} Failed", "✗".red());
+                eprintln!("  {}", e.to_string().red());
+                submission.failed.push((b.clone(), e.to_string()));
+            }
+        }
+    }
+
+    Ok(submission)
+}
+
+/// Remember which branches failed so `submit --retry-failed` can re-attempt them
+///
+/// An empty `failed` clears the set.
+pub(super) fn record_failed_submits(failed: &[(String, String)]) -> Result<()> {
+    let mut cache = Cache::load().unwrap_or_default();
+    cache.set_failed_submits(failed.iter().map(|(branch, _)| branch.clone()).collect());
+    cache.save()
+}
+
+/// Branches recorded by the last failed submit, parent-first
+///
+/// Branches that were deleted or untracked since are dropped.
+pub(super) fn retry_targets(ref_store: &RefStore, gateway: &GitGateway) -> Result<Vec<String>> {
+    let cache = Cache::load().unwrap_or_default();
+    let mut targets = Vec::new();
+    for branch in cache.failed_submits() {
+        if gateway.branch_exists(branch)? && ref_store.is_tracked(branch)? {
+            targets.push((ref_store.ancestors(branch)?.len(), branch.clone()));
+        }
+    }
+    targets.sort();
+    Ok(targets.into_iter().map(|(_, branch)| branch).collect())
+}
+
+/// Collect the branches above `since`, from the one just above it up to `branch` (parent-first order).
+///
+/// Fails if `since` is not an ancestor of `branch`. Trunk counts as an ancestor.
+pub(super) fn collect_branches_since(branch: &str, since: &str, ref_store: &RefStore) -> Result<Vec<String>> {
+    let mut branches = vec![branch.to_string()];
+    let mut current = ref_store.get_parent(branch)?;
+
+    loop {
+        match current {
+            Some(parent) if parent == since => break,
+            Some(parent) => {
+                // Guard against corrupted (circular) metadata
+                if branches.contains(&parent) {
+                    anyhow::bail!(
+                        "Circular parent reference detected while walking down from '{}'",
+                        branch
+                    );
+                }
+                current = ref_store.get_parent(&parent)?;
+                branches.push(parent);
+            }
+            None => anyhow::bail!(
+                "Branch '{}' is not an ancestor of '{}':\n  \
+                 Run '{} log' to see the stack.",
+                since,
+                branch,
+                program_name()
+            ),
+        }
+    }
+
+    branches.reverse();
+    Ok(branches)
+}
+
+/// Submit `branch` and its ancestors above `since` (parent-first order)
+pub(super) fn submit_since(
+    branch: &str,
+    since: &str,
+    ref_store: &RefStore,
+    gateway: &GitGateway,
+    forge: &dyn Forge,
+    options: &SubmitSettings,
+    pr_cache: &PrCache,
+) -> Result<Vec<SubmitResult>> {
+    let to_submit = collect_branches_since(branch, since, ref_store)?;
+
+    // The lowest submitted PR targets `since`, so it must already have a PR
+    // (otherwise submit_branch would recursively submit it)
+    if ref_store.get_trunk()?.as_deref() != Some(since) {
+        let since_has_pr = pr_cache
+            .get(since)
+            .map(|opt| opt.is_some())
+            .unwrap_or_else(|| forge.pr_exists(&gateway.remote_head(since)).ok().flatten().is_some());
+        if !since_has_pr {
+            anyhow::bail!(
+                "Cannot submit above '{}': it has no PR yet.\n  \
+                 Submit it first, or pass a lower branch to --since.",
+                since
+            );
+        }
+    }
+
     let total = to_submit.len();
     let mut results = Vec::new();
 
-    // Submit each branch in order with progress counters
+    // Auto-merge is enabled afterwards, bottom-up, once every PR exists
+    let mut branch_options = options.clone();
+    branch_options.pr.merge_when_ready = false;
+
     for (idx, b) in to_submit.iter().enumerate() {
         if let Some(result) = submit_branch(
             b,
             ref_store,
             gateway,
             forge,
-            force,
-            options,
-            update_only,
+            &branch_options,
             pr_cache,
             Some((idx + 1, total)), // Progress: (current, total)
         )? {
@@ -371,5 +653,120 @@ pub(super) fn submit_stack(
         }
     }
 
+    let submitted: Vec<String> = results.iter().map(|r| r.branch.clone()).collect();
+    link_pr_dependencies(&submitted, ref_store, gateway, forge)?;
+
+    if options.pr.merge_when_ready {
+        enable_auto_merge_bottom_up(&submitted, ref_store, gateway, forge)?;
+    }
+
     Ok(results)
 }
+
+/// Mark each PR in `branches` as depending on its parent's PR, where the forge supports it
+///
+/// GitLab shows this as "blocked by" on the MR, so reviewers see the stack order.
+/// Failures only warn: the link helps review but isn't needed to submit.
+pub(super) fn link_pr_dependencies(
+    branches: &[String],
+    ref_store: &RefStore,
+    gateway: &GitGateway,
+    forge: &dyn Forge,
+) -> Result<()> {
+    if !forge.supports_pr_dependencies() {
+        return Ok(());
+    }
+
+    let trunk = ref_store.get_trunk()?;
+    for branch in branches {
+        let Some(parent) = ref_store.get_parent(branch)?.filter(|p| trunk.as_ref() != Some(p)) else {
+            continue;
+        };
+        if let Err(e) = forge.add_pr_dependency(&gateway.remote_head(branch), &gateway.remote_head(&parent)) {
+            eprintln!(
+                "  {} Could not mark {} as depending on {}: {}",
+                "!".yellow(),
+                branch,
+                parent,
+                e
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Why a PR can't be merged yet, if anything
+///
+/// Pending CI doesn't block: auto-merge already waits for checks to finish.
+fn merge_blocker(pr: &PrFullInfo) -> Option<&'static str> {
+    match pr.state {
+        PrState::Merged => return None,
+        PrState::Closed => return Some("is closed"),
+        PrState::Open => {}
+    }
+    if pr.is_draft {
+        Some("is a draft")
+    } else if pr.ci == CiStatus::Failure {
+        Some("has failing CI")
+    } else if pr.review == ReviewState::ChangesRequested {
+        Some("has changes requested")
+    } else {
+        None
+    }
+}
+
+/// Enable auto-merge on `branches` (parent-first order), strictly bottom-up
+///
+/// A PR is gated - left without auto-merge - while its parent PR isn't
+/// mergeable or was gated itself, so a child can never merge before its parent.
+/// Returns the gated branches with the reason for each.
+pub(super) fn enable_auto_merge_bottom_up(
+    branches: &[String],
+    ref_store: &RefStore,
+    gateway: &GitGateway,
+    forge: &dyn Forge,
+) -> Result<Vec<(String, String)>> {
+    let trunk = ref_store.get_trunk()?;
+    let mut gated: Vec<(String, String)> = Vec::new();
+
+    for branch in branches {
+        let parent = ref_store.get_parent(branch)?.filter(|p| trunk.as_ref() != Some(p));
+
+        let reason = match parent {
+            None => None,
+            Some(ref p) if gated.iter().any(|(g, _)| g == p) => Some(format!("parent '{}' is gated", p)),
+            Some(ref p) => match forge.get_pr_full_info(&gateway.remote_head(p)) {
+                Ok(info) => merge_blocker(&info).map(|why| format!("parent '{}' {}", p, why)),
+                Err(e) => Some(format!("could not check parent '{}': {}", p, e)),
+            },
+        };
+
+        match reason {
+            Some(reason) => gated.push((branch.clone(), reason)),
+            None => {
+                if let Err(e) = forge.enable_auto_merge(&gateway.remote_head(branch), "squash") {
+                    eprintln!("  {} Could not enable auto-merge on {}: {}", "!".yellow(), branch, e);
+                }
+            }
+        }
+    }
+
+    if !gated.is_empty() {
+        println!(
+            "\n{} Auto-merge not enabled on {} PR{} (parents merge first):",
+            "!".yellow(),
+            gated.len(),
+            if gated.len() == 1 { "" } else { "s" }
+        );
+        for (branch, reason) in &gated {
+            println!("  • {}: {}", branch.yellow(), reason);
+        }
+        println!(
+            "  Re-run '{} submit --stack --merge-when-ready' once they're ready.",
+            program_name()
+        );
+    }
+
+    Ok(gated)
+}
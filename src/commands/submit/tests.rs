@@ -1,8 +1,14 @@
 //! Tests for submit command.
 
-use super::submission::{submit_branch, submit_stack};
-use super::validation::validate_stack_integrity;
+use super::submission::{
+    collect_branches_since, link_pr_dependencies, create_pr_once, enable_auto_merge_bottom_up, record_failed_submits,
+    retry_targets, submit_branch, submit_branches, submit_since, submit_stack, EmptyBranchPolicy, SubmitSettings,
+};
+use super::validation::{
+    check_force_push_protection, plan_submit_actions, validate_stack_integrity, SubmitAction, SubmitPreviewEntry,
+};
 use super::*;
+use crate::cache::Cache;
 use crate::forge::{CiStatus, ForgeType, PrFullInfo, PrInfo, PrState, ReviewState};
 use crate::stack_viz::collect_full_stack;
 use crate::test_context::{init_test_repo, TestRepoContext};
@@ -23,6 +29,8 @@ struct MockForge {
     pushed_branches: RwLock<Vec<String>>,
     /// PRs that were created (branch, base) in order
     created_prs: RwLock<Vec<(String, String)>>,
+    /// Bodies of created PRs (branch, body) in order
+    created_bodies: RwLock<Vec<(String, String)>>,
     /// Branches whose full info was requested
     full_info_requests: RwLock<Vec<String>>,
     /// PR bodies that were updated (pr_ref, body)
@@ -31,6 +39,20 @@ struct MockForge {
     marked_ready: RwLock<Vec<String>>,
     /// PRs that had auto-merge enabled (pr_ref, merge_method)
     auto_merge_enabled: RwLock<Vec<(String, String)>>,
+    /// Branches whose PRs report failing CI
+    failing_ci: RwLock<HashSet<String>>,
+    /// Branches protected against force-push on the remote
+    protected: RwLock<HashSet<String>>,
+    /// How long `create_pr` takes, to widen race windows
+    create_delay: RwLock<std::time::Duration>,
+    /// Branches whose `create_pr` fails
+    failing_create: RwLock<HashSet<String>>,
+    /// Login reported by `current_user`
+    login: RwLock<Option<String>>,
+    /// Forge this mock stands in for (GitLab supports PR dependencies)
+    forge_type: ForgeType,
+    /// Dependency links that were added (branch, depends_on) in order
+    dependencies: RwLock<Vec<(String, String)>>,
 }
 
 impl MockForge {
@@ -39,13 +61,55 @@ impl MockForge {
             existing_prs: RwLock::new(HashSet::new()),
             pushed_branches: RwLock::new(Vec::new()),
             created_prs: RwLock::new(Vec::new()),
+            created_bodies: RwLock::new(Vec::new()),
             full_info_requests: RwLock::new(Vec::new()),
             updated_bodies: RwLock::new(Vec::new()),
             marked_ready: RwLock::new(Vec::new()),
             auto_merge_enabled: RwLock::new(Vec::new()),
+            failing_ci: RwLock::new(HashSet::new()),
+            protected: RwLock::new(HashSet::new()),
+            create_delay: RwLock::new(std::time::Duration::ZERO),
+            failing_create: RwLock::new(HashSet::new()),
+            login: RwLock::new(None),
+            forge_type: ForgeType::GitHub,
+            dependencies: RwLock::new(Vec::new()),
         }
     }
 
+    fn with_gitlab(mut self) -> Self {
+        self.forge_type = ForgeType::GitLab;
+        self
+    }
+
+    fn get_dependencies(&self) -> Vec<(String, String)> {
+        self.dependencies.read().unwrap().clone()
+    }
+
+    fn with_login(self, login: &str) -> Self {
+        *self.login.write().unwrap() = Some(login.to_string());
+        self
+    }
+
+    fn with_slow_create(self, delay: std::time::Duration) -> Self {
+        *self.create_delay.write().unwrap() = delay;
+        self
+    }
+
+    fn with_failing_create(self, branch: &str) -> Self {
+        self.failing_create.write().unwrap().insert(branch.to_string());
+        self
+    }
+
+    fn with_failing_ci(self, branch: &str) -> Self {
+        self.failing_ci.write().unwrap().insert(branch.to_string());
+        self
+    }
+
+    fn with_protected_branch(self, branch: &str) -> Self {
+        self.protected.write().unwrap().insert(branch.to_string());
+        self
+    }
+
     fn with_existing_pr(self, branch: &str) -> Self {
         self.existing_prs.write().unwrap().insert(branch.to_string());
         self
@@ -59,6 +123,10 @@ impl MockForge {
         self.created_prs.read().unwrap().clone()
     }
 
+    fn get_created_bodies(&self) -> Vec<(String, String)> {
+        self.created_bodies.read().unwrap().clone()
+    }
+
     #[allow(dead_code)]
     fn get_full_info_requests(&self) -> Vec<String> {
         self.full_info_requests.read().unwrap().clone()
@@ -82,7 +150,7 @@ impl MockForge {
 
 impl crate::forge::Forge for MockForge {
     fn forge_type(&self) -> ForgeType {
-        ForgeType::GitHub
+        self.forge_type
     }
 
     fn cli_name(&self) -> &str {
@@ -93,6 +161,10 @@ impl crate::forge::Forge for MockForge {
         Ok(())
     }
 
+    fn current_user(&self) -> Result<String> {
+        self.login.read().unwrap().clone().context("no login configured")
+    }
+
     fn pr_exists(&self, branch: &str) -> Result<Option<PrInfo>> {
         if self.existing_prs.read().unwrap().contains(branch) {
             Ok(Some(PrInfo {
@@ -108,7 +180,15 @@ impl crate::forge::Forge for MockForge {
         }
     }
 
-    fn create_pr(&self, branch: &str, base: &str, _title: &str, _body: &str, _options: &PrOptions) -> Result<String> {
+    fn create_pr(&self, branch: &str, base: &str, _title: &str, body: &str, _options: &PrOptions) -> Result<String> {
+        std::thread::sleep(*self.create_delay.read().unwrap());
+        if self.failing_create.read().unwrap().contains(branch) {
+            anyhow::bail!("GraphQL: could not create pull request for {}", branch);
+        }
+        self.created_bodies
+            .write()
+            .unwrap()
+            .push((branch.to_string(), body.to_string()));
         self.created_prs
             .write()
             .unwrap()
@@ -159,9 +239,14 @@ impl crate::forge::Forge for MockForge {
             state: PrState::Open,
             is_draft: false,
             review: ReviewState::Pending,
-            ci: CiStatus::None,
+            ci: if self.failing_ci.read().unwrap().contains(pr_ref) {
+                CiStatus::Failure
+            } else {
+                CiStatus::None
+            },
             head_ref: pr_ref.to_string(),
             base_ref: "main".to_string(),
+            mergeable: None,
         })
     }
 
@@ -203,12 +288,31 @@ impl crate::forge::Forge for MockForge {
         Ok(())
     }
 
+    fn is_force_push_protected(&self, branch: &str) -> Result<bool> {
+        Ok(self.protected.read().unwrap().contains(branch))
+    }
+
+    fn supports_pr_dependencies(&self) -> bool {
+        self.forge_type == ForgeType::GitLab
+    }
+
+    fn add_pr_dependency(&self, branch: &str, depends_on: &str) -> Result<()> {
+        self.dependencies
+            .write()
+            .unwrap()
+            .push((branch.to_string(), depends_on.to_string()));
+        Ok(())
+    }
+
     fn push_branch(&self, branch: &str, _force: bool) -> Result<()> {
         self.pushed_branches.write().unwrap().push(branch.to_string());
         Ok(())
     }
 }
 
+#[async_trait::async_trait]
+impl AsyncForge for MockForge {}
+
 // Helper to create a minimal test repo
 // Helper to create a git branch at HEAD
 fn create_branch(repo: &git2::Repository, name: &str) -> Result<()> {
@@ -227,20 +331,10 @@ async fn test_submit_untracked_branch_fails() {
     let _ref_store = RefStore::new().unwrap();
 
     // Run should fail because branch is not tracked
-    // run(stack, force, draft, publish, merge_when_ready, target_branch, reviewers, no_open, skip_validation, update_only, confirm)
-    let result = run(
-        false,
-        false,
-        false,
-        false,
-        false,
-        None,
-        vec![],
-        true,
-        false,
-        false,
-        false,
-    )
+    let result = run(SubmitOptions {
+        no_open: true,
+        ..Default::default()
+    })
     .await;
     assert!(result.is_err());
     let err_msg = result.unwrap_err().to_string();
@@ -258,20 +352,11 @@ async fn test_submit_target_branch_nonexistent_fails() {
     let _ctx = TestRepoContext::new(dir.path());
 
     // Run should fail because the target branch doesn't exist
-    // run(stack, force, draft, publish, merge_when_ready, target_branch, reviewers, no_open, skip_validation, update_only, confirm)
-    let result = run(
-        false,
-        false,
-        false,
-        false,
-        false,
-        Some("nonexistent-branch".to_string()),
-        vec![],
-        true,
-        false,
-        false,
-        false,
-    )
+    let result = run(SubmitOptions {
+        target_branch: Some("nonexistent-branch".to_string()),
+        no_open: true,
+        ..Default::default()
+    })
     .await;
     assert!(result.is_err());
     let err_msg = result.unwrap_err().to_string();
@@ -394,23 +479,16 @@ fn test_submit_branch_creates_parent_pr_first() -> Result<()> {
 
     // Mock forge with no existing PRs
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     // Submit the child branch
-    submit_branch(
-        "child",
-        &ref_store,
-        &gateway,
-        &forge,
-        false,
-        &options,
-        false,
-        &empty_pr_cache(),
-        None,
-    )?;
+    submit_branch("child", &ref_store, &gateway, &forge, &options, &empty_pr_cache(), None)?;
 
     // Verify parent was pushed before child
     let pushed = forge.get_pushed_branches();
@@ -429,6 +507,290 @@ fn test_submit_branch_creates_parent_pr_first() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_submit_branch_skips_frozen_branch() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "feature")?;
+    ref_store.set_parent("feature", "main")?;
+    ref_store.set_frozen("feature", true)?;
+    let gateway = GitGateway::new()?;
+
+    let forge = MockForge::new().with_existing_pr("feature");
+    let result = submit_branch(
+        "feature",
+        &ref_store,
+        &gateway,
+        &forge,
+        &SubmitSettings::default(),
+        &empty_pr_cache(),
+        None,
+    )?;
+
+    assert!(result.is_none(), "Frozen branch should not be submitted");
+    assert!(forge.get_pushed_branches().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_submit_preview_states_action_per_branch() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // main -> existing (has a PR) -> fresh (no PR) -> frozen
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "existing")?;
+    create_branch(&repo, "fresh")?;
+    create_branch(&repo, "frozen")?;
+    ref_store.set_parent("existing", "main")?;
+    ref_store.set_parent("fresh", "existing")?;
+    ref_store.set_parent("frozen", "fresh")?;
+    ref_store.set_frozen("frozen", true)?;
+    let gateway = GitGateway::new()?;
+
+    let branches = vec!["existing".to_string(), "fresh".to_string(), "frozen".to_string()];
+    let forge = MockForge::new().with_existing_pr("existing");
+    let pr_cache: PrCache = forge.check_prs_exist(&branches).await.into_iter().collect();
+
+    let preview = plan_submit_actions(
+        &branches,
+        &ref_store,
+        &gateway,
+        &pr_cache,
+        false,
+        EmptyBranchPolicy::Allow,
+    )?;
+
+    let entry = |branch: &str, base: &str, action| SubmitPreviewEntry {
+        branch: branch.to_string(),
+        base: base.to_string(),
+        action,
+    };
+    assert_eq!(
+        preview,
+        vec![
+            entry("existing", "main", SubmitAction::UpdatePr(1)),
+            entry("fresh", "existing", SubmitAction::CreatePr),
+            entry("frozen", "fresh", SubmitAction::SkipFrozen),
+        ]
+    );
+    let actions: Vec<String> = preview.iter().map(|e| e.action.to_string()).collect();
+    assert_eq!(actions, vec!["update PR #1", "create PR", "skip (frozen)"]);
+
+    // With --update-only the branch without a PR is skipped instead of created
+    let preview = plan_submit_actions(
+        &branches,
+        &ref_store,
+        &gateway,
+        &pr_cache,
+        true,
+        EmptyBranchPolicy::Allow,
+    )?;
+    assert_eq!(preview[1].action, SubmitAction::SkipNoPr);
+
+    // The preview comes from the cache alone: no pushes or PRs yet
+    assert!(forge.get_pushed_branches().is_empty());
+    assert!(forge.get_created_prs().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_cache_looks_up_prs_despite_fresh_cache_entry() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "feature")?;
+    ref_store.set_parent("feature", "main")?;
+    let gateway = GitGateway::new()?;
+
+    // A fresh entry for a PR that has since been closed and deleted on the forge
+    let mut cache = Cache::load()?;
+    cache.pr_status.record_info(
+        "feature",
+        &PrInfo {
+            number: 7,
+            url: "https://github.com/test/repo/pull/7".to_string(),
+            head_ref: "feature".to_string(),
+            base_ref: "main".to_string(),
+            state: PrState::Open,
+            title: "Feature".to_string(),
+        },
+    );
+    cache.save()?;
+
+    let forge = MockForge::new();
+    let branches = vec!["feature".to_string()];
+
+    let cached = check_prs(&gateway, &ref_store, &forge, "feature", &branches, false).await?;
+    assert_eq!(cached["feature"].as_ref().map(|pr| pr.number), Some(7));
+
+    // --no-cache asks the forge, which knows of no PR
+    let looked_up = check_prs(&gateway, &ref_store, &forge, "feature", &branches, true).await?;
+    assert!(looked_up["feature"].is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_branch_fills_body_template() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    let gateway = GitGateway::new()?;
+
+    // feature has two commits on top of main
+    gateway.create_branch("feature")?;
+    ref_store.set_parent("feature", "main")?;
+    for (file, subject) in [("a.txt", "Add a"), ("b.txt", "Add b")] {
+        std::fs::write(dir.path().join(file), subject)?;
+        gateway.stage_all()?;
+        gateway.commit(subject)?;
+    }
+    drop(repo);
+
+    let forge = MockForge::new();
+    let options = SubmitSettings {
+        body_template: Some("## {branch} (onto {parent})\n\n{commits}\n".to_string()),
+        ..Default::default()
+    };
+    submit_branch(
+        "feature",
+        &ref_store,
+        &gateway,
+        &forge,
+        &options,
+        &empty_pr_cache(),
+        None,
+    )?;
+
+    let bodies = forge.get_created_bodies();
+    assert_eq!(
+        bodies,
+        vec![(
+            "feature".to_string(),
+            "## feature (onto main)\n\n- Add a\n- Add b\n".to_string()
+        )]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_branch_skips_branch_with_no_commits() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // feature points at the same commit as main
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "feature")?;
+    ref_store.set_parent("feature", "main")?;
+    let gateway = GitGateway::new()?;
+
+    let forge = MockForge::new();
+    let options = SubmitSettings {
+        empty_branches: EmptyBranchPolicy::Skip,
+        ..Default::default()
+    };
+    let result = submit_branch(
+        "feature",
+        &ref_store,
+        &gateway,
+        &forge,
+        &options,
+        &empty_pr_cache(),
+        None,
+    )?;
+
+    assert!(result.is_none(), "Empty branch should not be submitted");
+    assert!(forge.get_pushed_branches().is_empty());
+    assert!(forge.get_created_prs().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_stack_skips_branches_stacked_on_empty_parent() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // main -> empty (no commits) -> child (one commit)
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "empty")?;
+    ref_store.set_parent("empty", "main")?;
+    let gateway = GitGateway::new()?;
+    gateway.checkout_branch("empty")?;
+    gateway.create_branch("child")?;
+    ref_store.set_parent("child", "empty")?;
+    std::fs::write(dir.path().join("child.txt"), "child")?;
+    gateway.stage_all()?;
+    gateway.commit("Add child")?;
+
+    let forge = MockForge::new();
+    let options = SubmitSettings {
+        empty_branches: EmptyBranchPolicy::Skip,
+        ..Default::default()
+    };
+    submit_stack("empty", &ref_store, &gateway, &forge, &options, &empty_pr_cache())?;
+
+    // The child's PR base would not exist on the remote, so it is skipped too
+    assert!(forge.get_pushed_branches().is_empty());
+    assert!(forge.get_created_prs().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_branch_with_no_commits_errors_with_strict() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "feature")?;
+    ref_store.set_parent("feature", "main")?;
+    let gateway = GitGateway::new()?;
+
+    let forge = MockForge::new();
+    let options = SubmitSettings {
+        empty_branches: EmptyBranchPolicy::Error,
+        ..Default::default()
+    };
+    let err = submit_branch(
+        "feature",
+        &ref_store,
+        &gateway,
+        &forge,
+        &options,
+        &empty_pr_cache(),
+        None,
+    )
+    .unwrap_err();
+
+    assert!(err.to_string().contains("no commits to submit"), "got: {}", err);
+    assert!(forge.get_created_prs().is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn test_submit_branch_skips_parent_with_existing_pr() -> Result<()> {
     let dir = tempdir()?;
@@ -449,23 +811,16 @@ fn test_submit_branch_skips_parent_with_existing_pr() -> Result<()> {
 
     // Mock forge where parent already has a PR
     let forge = MockForge::new().with_existing_pr("parent");
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     // Submit the child branch
-    submit_branch(
-        "child",
-        &ref_store,
-        &gateway,
-        &forge,
-        false,
-        &options,
-        false,
-        &empty_pr_cache(),
-        None,
-    )?;
+    submit_branch("child", &ref_store, &gateway, &forge, &options, &empty_pr_cache(), None)?;
 
     // Only child should be pushed (parent already has PR)
     let pushed = forge.get_pushed_branches();
@@ -500,9 +855,12 @@ fn test_submit_branch_with_trunk_parent_no_parent_pr() -> Result<()> {
 
     // Mock forge with no existing PRs
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     // Submit the feature branch
@@ -511,9 +869,7 @@ fn test_submit_branch_with_trunk_parent_no_parent_pr() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     )?;
@@ -557,23 +913,16 @@ fn test_submit_branch_deep_stack_creates_all_parent_prs() -> Result<()> {
 
     // Mock forge with no existing PRs
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     // Submit branch d (deepest)
-    submit_branch(
-        "d",
-        &ref_store,
-        &gateway,
-        &forge,
-        false,
-        &options,
-        false,
-        &empty_pr_cache(),
-        None,
-    )?;
+    submit_branch("d", &ref_store, &gateway, &forge, &options, &empty_pr_cache(), None)?;
 
     // All branches should be pushed in order (ancestors first)
     let pushed = forge.get_pushed_branches();
@@ -1084,9 +1433,12 @@ fn test_submit_blocks_when_branch_is_behind_remote() -> Result<()> {
 
     // Mock forge for submit
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     // Submit should fail (without force) because branch is behind
@@ -1095,9 +1447,7 @@ fn test_submit_blocks_when_branch_is_behind_remote() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false, // no force
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -1178,9 +1528,13 @@ fn test_submit_succeeds_with_force_when_behind() -> Result<()> {
 
     // Mock forge
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
+        force: true,
         ..Default::default()
     };
     // Submit WITH force should succeed (divergence check is skipped)
@@ -1189,9 +1543,7 @@ fn test_submit_succeeds_with_force_when_behind() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        true, // force = true
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -1262,9 +1614,12 @@ fn test_submit_succeeds_after_amend() -> Result<()> {
 
     // Mock forge for submit
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     // Submit should SUCCEED even without --force (normal workflow)
@@ -1274,9 +1629,7 @@ fn test_submit_succeeds_after_amend() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false, // no force needed!
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -1321,9 +1674,12 @@ fn test_submit_succeeds_after_multiple_amends() -> Result<()> {
     ref_store.set_parent("feature", "main")?;
 
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
 
@@ -1347,9 +1703,7 @@ fn test_submit_succeeds_after_multiple_amends() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -1375,9 +1729,7 @@ fn test_submit_succeeds_after_multiple_amends() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -1404,9 +1756,13 @@ fn test_submit_update_only_skips_branch_without_pr() -> Result<()> {
 
     // Mock forge with NO existing PRs
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
+        update_only: true,
         ..Default::default()
     };
     // Submit with update_only=true
@@ -1415,9 +1771,7 @@ fn test_submit_update_only_skips_branch_without_pr() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        true, // update_only
         &empty_pr_cache(),
         None,
     )?;
@@ -1453,9 +1807,13 @@ fn test_submit_update_only_updates_branch_with_existing_pr() -> Result<()> {
 
     // Mock forge WITH existing PR
     let forge = MockForge::new().with_existing_pr("feature");
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
+        update_only: true,
         ..Default::default()
     };
     // Submit with update_only=true
@@ -1464,9 +1822,7 @@ fn test_submit_update_only_updates_branch_with_existing_pr() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        true, // update_only
         &empty_pr_cache(),
         None,
     )?;
@@ -1503,24 +1859,18 @@ fn test_submit_update_only_fails_when_parent_has_no_pr() -> Result<()> {
 
     // Mock forge: child has PR but parent doesn't
     let forge = MockForge::new().with_existing_pr("child");
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
+        update_only: true,
         ..Default::default()
     };
     // Submit with update_only=true - should fail because parent has no PR
     // and we can't recursively create parent PRs in update_only mode
-    let result = submit_branch(
-        "child",
-        &ref_store,
-        &gateway,
-        &forge,
-        false,
-        &options,
-        true, // update_only
-        &empty_pr_cache(),
-        None,
-    );
+    let result = submit_branch("child", &ref_store, &gateway, &forge, &options, &empty_pr_cache(), None);
 
     // This should succeed since child already has a PR
     // (only fails when trying to CREATE a new PR for a branch whose parent has no PR)
@@ -1555,10 +1905,13 @@ fn test_submit_publish_marks_existing_pr_as_ready() -> Result<()> {
     let forge = MockForge::new().with_existing_pr("feature");
 
     // Submit with publish=true
-    let options = PrOptions {
-        draft: false,
-        publish: true,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            publish: true,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     submit_branch(
@@ -1566,9 +1919,7 @@ fn test_submit_publish_marks_existing_pr_as_ready() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     )?;
@@ -1607,10 +1958,13 @@ fn test_submit_merge_when_ready_enables_auto_merge_for_existing_pr() -> Result<(
     let forge = MockForge::new().with_existing_pr("feature");
 
     // Submit with merge_when_ready=true
-    let options = PrOptions {
-        draft: false,
-        merge_when_ready: true,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            merge_when_ready: true,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     submit_branch(
@@ -1618,9 +1972,7 @@ fn test_submit_merge_when_ready_enables_auto_merge_for_existing_pr() -> Result<(
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     )?;
@@ -1663,10 +2015,13 @@ fn test_submit_merge_when_ready_enables_auto_merge_for_new_pr() -> Result<()> {
     let forge = MockForge::new();
 
     // Submit with merge_when_ready=true
-    let options = PrOptions {
-        draft: false,
-        merge_when_ready: true,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            merge_when_ready: true,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     submit_branch(
@@ -1674,9 +2029,7 @@ fn test_submit_merge_when_ready_enables_auto_merge_for_new_pr() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     )?;
@@ -1723,10 +2076,13 @@ fn test_submit_without_publish_does_not_mark_ready() -> Result<()> {
     let forge = MockForge::new().with_existing_pr("feature");
 
     // Submit without publish flag
-    let options = PrOptions {
-        draft: false,
-        publish: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            publish: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     submit_branch(
@@ -1734,9 +2090,7 @@ fn test_submit_without_publish_does_not_mark_ready() -> Result<()> {
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     )?;
@@ -1777,10 +2131,13 @@ fn test_submit_without_merge_when_ready_does_not_enable_auto_merge() -> Result<(
     let forge = MockForge::new().with_existing_pr("feature");
 
     // Submit without merge_when_ready flag
-    let options = PrOptions {
-        draft: false,
-        merge_when_ready: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            merge_when_ready: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     submit_branch(
@@ -1788,9 +2145,7 @@ fn test_submit_without_merge_when_ready_does_not_enable_auto_merge() -> Result<(
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     )?;
@@ -1835,22 +2190,16 @@ fn test_submit_stack_submits_all_descendants() -> Result<()> {
 
     // Mock forge with no existing PRs
     let forge = MockForge::new();
-    let options = PrOptions {
-        draft: false,
-        reviewers: vec![],
+    let options = SubmitSettings {
+        pr: PrOptions {
+            draft: false,
+            reviewers: vec![],
+            ..Default::default()
+        },
         ..Default::default()
     };
     // Submit the stack starting from "a"
-    submit_stack(
-        "a",
-        &ref_store,
-        &gateway,
-        &forge,
-        false,
-        &options,
-        false,
-        &empty_pr_cache(),
-    )?;
+    submit_stack("a", &ref_store, &gateway, &forge, &options, &empty_pr_cache())?;
 
     // All branches should be pushed
     let pushed = forge.get_pushed_branches();
@@ -1867,6 +2216,219 @@ fn test_submit_stack_submits_all_descendants() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_submit_stack_addresses_prefixed_branches_by_remote_name() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // Stack downloaded with `get --branch-prefix alice-`: main -> alice-feature-1 -> alice-feature-2
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "alice-feature-1")?;
+    create_branch(&repo, "alice-feature-2")?;
+    ref_store.set_parent("alice-feature-1", "main")?;
+    ref_store.set_parent("alice-feature-2", "alice-feature-1")?;
+
+    let gateway = GitGateway::new()?;
+    gateway.set_push_head("alice-feature-1", "feature-1")?;
+    gateway.set_push_head("alice-feature-2", "feature-2")?;
+
+    // The forge knows the downloaded PR by its original head
+    let forge = MockForge::new().with_existing_pr("feature-1");
+    submit_stack(
+        "alice-feature-1",
+        &ref_store,
+        &gateway,
+        &forge,
+        &SubmitSettings::default(),
+        &empty_pr_cache(),
+    )?;
+
+    // No duplicate PR for the existing one; the new PR is based on the original head
+    assert_eq!(
+        forge.get_created_prs(),
+        vec![("feature-2".to_string(), "feature-1".to_string())]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_stack_merge_when_ready_gates_child_of_failing_parent() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // Stack: main -> parent -> child, where parent's CI is failing
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "parent")?;
+    create_branch(&repo, "child")?;
+    ref_store.set_parent("parent", "main")?;
+    ref_store.set_parent("child", "parent")?;
+
+    let gateway = GitGateway::new()?;
+    let forge = MockForge::new()
+        .with_existing_pr("parent")
+        .with_existing_pr("child")
+        .with_failing_ci("parent");
+    let options = SubmitSettings {
+        pr: PrOptions {
+            merge_when_ready: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    submit_stack("parent", &ref_store, &gateway, &forge, &options, &empty_pr_cache())?;
+
+    // Both branches are still pushed, but only the parent gets auto-merge
+    assert_eq!(forge.get_pushed_branches(), vec!["parent", "child"]);
+    assert_eq!(
+        forge.get_auto_merge_enabled(),
+        vec![("parent".to_string(), "squash".to_string())]
+    );
+
+    // The gate is reported with the reason
+    let gated = enable_auto_merge_bottom_up(
+        &["parent".to_string(), "child".to_string()],
+        &ref_store,
+        &gateway,
+        &forge,
+    )?;
+    assert_eq!(
+        gated,
+        vec![("child".to_string(), "parent 'parent' has failing CI".to_string())]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_stack_records_failures_and_retry_only_resubmits_them() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // Stack: main -> a -> {b -> d, c}, where creating b's PR fails
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    for (branch, parent) in [("a", "main"), ("b", "a"), ("c", "a"), ("d", "b")] {
+        create_branch(&repo, branch)?;
+        ref_store.set_parent(branch, parent)?;
+    }
+
+    let gateway = GitGateway::new()?;
+    let forge = MockForge::new().with_failing_create("b");
+    let options = SubmitSettings::default();
+
+    let submission = submit_stack("a", &ref_store, &gateway, &forge, &options, &empty_pr_cache())?;
+
+    // c still gets its PR; d is skipped because its parent failed
+    let submitted: Vec<&str> = submission.results.iter().map(|r| r.branch.as_str()).collect();
+    assert_eq!(submitted, vec!["a", "c"]);
+    let failed: Vec<&str> = submission.failed.iter().map(|(b, _)| b.as_str()).collect();
+    assert_eq!(failed, vec!["b", "d"]);
+    assert!(submission.failed[0].1.contains("could not create pull request"));
+
+    record_failed_submits(&submission.failed)?;
+    assert_eq!(Cache::load()?.failed_submits(), ["b", "d"]);
+
+    // Retry once the forge recovers: only the failed branches are re-attempted
+    forge.failing_create.write().unwrap().clear();
+    let pushed_before = forge.get_pushed_branches().len();
+    let targets = retry_targets(&ref_store, &gateway)?;
+    assert_eq!(targets, vec!["b", "d"]);
+
+    let retry = submit_branches(&targets, &ref_store, &gateway, &forge, &options, &empty_pr_cache())?;
+    assert!(retry.failed.is_empty());
+    assert_eq!(forge.get_pushed_branches()[pushed_before..], ["b", "d"]);
+    assert_eq!(
+        forge.get_created_prs(),
+        vec![
+            ("a".to_string(), "main".to_string()),
+            ("c".to_string(), "a".to_string()),
+            ("b".to_string(), "a".to_string()),
+            ("d".to_string(), "b".to_string()),
+        ]
+    );
+
+    // A clean run clears the failed set
+    record_failed_submits(&retry.failed)?;
+    assert!(Cache::load()?.failed_submits().is_empty());
+    assert!(retry_targets(&ref_store, &gateway)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_submit_since_only_submits_branches_above_ancestor() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // Create a stack: main -> a -> b -> c -> d
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "a")?;
+    create_branch(&repo, "b")?;
+    create_branch(&repo, "c")?;
+    create_branch(&repo, "d")?;
+    ref_store.set_parent("a", "main")?;
+    ref_store.set_parent("b", "a")?;
+    ref_store.set_parent("c", "b")?;
+    ref_store.set_parent("d", "c")?;
+
+    let gateway = GitGateway::new()?;
+
+    // Lower branches already have PRs
+    let forge = MockForge::new().with_existing_pr("a").with_existing_pr("b");
+    let options = SubmitSettings::default();
+
+    // Submit from "d" down to (but not including) "b"
+    let results = submit_since("d", "b", &ref_store, &gateway, &forge, &options, &empty_pr_cache())?;
+
+    assert_eq!(forge.get_pushed_branches(), vec!["c", "d"]);
+    assert_eq!(
+        forge.get_created_prs(),
+        vec![("c".to_string(), "b".to_string()), ("d".to_string(), "c".to_string())]
+    );
+    assert_eq!(results.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_collect_branches_since_rejects_non_ancestor() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // Create two stacks: main -> a -> b, main -> other
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "a")?;
+    create_branch(&repo, "b")?;
+    create_branch(&repo, "other")?;
+    ref_store.set_parent("a", "main")?;
+    ref_store.set_parent("b", "a")?;
+    ref_store.set_parent("other", "main")?;
+
+    assert_eq!(collect_branches_since("b", "main", &ref_store)?, vec!["a", "b"]);
+    assert_eq!(collect_branches_since("b", "a", &ref_store)?, vec!["b"]);
+
+    let err = collect_branches_since("b", "other", &ref_store)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("not an ancestor"), "Unexpected error: {}", err);
+
+    // A branch is not its own ancestor
+    assert!(collect_branches_since("b", "b", &ref_store).is_err());
+
+    Ok(())
+}
+
 // ===== PR Cache Collection Tests =====
 
 #[test]
@@ -2255,16 +2817,14 @@ fn test_diverged_ancestors_are_pushed_before_leaf() -> Result<()> {
         .with_existing_pr("branch-a")
         .with_existing_pr("branch-b");
 
-    let options = PrOptions::default();
+    let options = SubmitSettings::default();
     // Submit branch-b (the leaf)
     let result = submit_branch(
         "branch-b",
         &ref_store,
         &gateway,
         &forge,
-        false, // no force
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -2355,15 +2915,13 @@ fn test_diverged_ancestor_without_pr_not_pushed() -> Result<()> {
     // Mock forge: branch-b has PR, but branch-a does NOT
     let forge = MockForge::new().with_existing_pr("branch-b");
 
-    let options = PrOptions::default();
+    let options = SubmitSettings::default();
     let result = submit_branch(
         "branch-b",
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -2452,15 +3010,13 @@ fn test_in_sync_ancestor_not_pushed() -> Result<()> {
         .with_existing_pr("branch-a")
         .with_existing_pr("branch-b");
 
-    let options = PrOptions::default();
+    let options = SubmitSettings::default();
     let result = submit_branch(
         "branch-b",
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -2582,15 +3138,13 @@ fn test_multiple_diverged_ancestors_pushed_in_order() -> Result<()> {
         .with_existing_pr("branch-b")
         .with_existing_pr("branch-c");
 
-    let options = PrOptions::default();
+    let options = SubmitSettings::default();
     let result = submit_branch(
         "branch-c",
         &ref_store,
         &gateway,
         &forge,
-        false,
         &options,
-        false,
         &empty_pr_cache(),
         None,
     );
@@ -2688,3 +3242,213 @@ fn test_pr_title_fallback_to_branch_name_if_get_commit_fails() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_check_force_push_protection_warns_by_default() -> Result<()> {
+    let forge = MockForge::new().with_protected_branch("feature-2");
+    let branches = vec!["feature-1".to_string(), "feature-2".to_string()];
+
+    let protected = check_force_push_protection(&branches, &forge, false)?;
+
+    assert_eq!(protected, vec!["feature-2".to_string()]);
+    assert!(forge.get_pushed_branches().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_check_force_push_protection_strict_fails() {
+    let forge = MockForge::new().with_protected_branch("feature-2");
+    let branches = vec!["feature-1".to_string(), "feature-2".to_string()];
+
+    let err = check_force_push_protection(&branches, &forge, true)
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.contains("feature-2"),
+        "error should name the protected branch: {}",
+        err
+    );
+    assert!(
+        !err.contains("feature-1"),
+        "unprotected branches should not be listed: {}",
+        err
+    );
+    assert!(forge.get_pushed_branches().is_empty());
+}
+
+#[test]
+fn test_check_force_push_protection_passes_when_unprotected() -> Result<()> {
+    let forge = MockForge::new();
+    let branches = vec!["feature-1".to_string()];
+
+    assert!(check_force_push_protection(&branches, &forge, true)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_concurrent_submits_create_only_one_pr() -> Result<()> {
+    let dir = tempdir()?;
+    let _repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let gateway = GitGateway::new()?;
+    let git_dir = gateway.git_dir().to_path_buf();
+    let forge = MockForge::new().with_slow_create(std::time::Duration::from_millis(50));
+    let options = PrOptions::default();
+    let barrier = std::sync::Barrier::new(2);
+
+    let results: Vec<(String, bool)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                scope.spawn(|| {
+                    barrier.wait();
+                    create_pr_once(&git_dir, &forge, "feature", "main", "Feature", "", &options)
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect::<Result<_>>()
+    })?;
+
+    // Exactly one submit created the PR; the other found it and reused its URL
+    assert_eq!(
+        forge.get_created_prs(),
+        vec![("feature".to_string(), "main".to_string())]
+    );
+    assert_eq!(results.iter().filter(|(_, created)| *created).count(), 1);
+    assert!(results
+        .iter()
+        .all(|(url, _)| url == "https://github.com/test/repo/pull/1"));
+
+    // Every branch shares one lock file rather than leaving one behind per branch
+    create_pr_once(&git_dir, &forge, "other", "main", "Other", "", &options)?;
+    let lock_files: Vec<_> = std::fs::read_dir(git_dir.join("diamond").join("locks"))?
+        .map(|entry| entry.map(|e| e.file_name()))
+        .collect::<std::io::Result<_>>()?;
+    assert_eq!(lock_files, vec![std::ffi::OsString::from("submit.lock")]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_viz_leaves_pr_bodies_alone() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "parent")?;
+    create_branch(&repo, "child")?;
+    ref_store.set_parent("parent", "main")?;
+    ref_store.set_parent("child", "parent")?;
+    let stack = vec!["parent".to_string(), "child".to_string()];
+
+    let forge = MockForge::new().with_existing_pr("parent").with_existing_pr("child");
+    apply_stack_viz(StackVizMode::Skip, &stack, &forge, &ref_store).await?;
+
+    assert!(
+        forge.get_full_info_requests().is_empty(),
+        "--no-viz should not look up PRs"
+    );
+    assert!(
+        forge.get_updated_bodies().is_empty(),
+        "--no-viz should not touch PR bodies"
+    );
+
+    // The default mode does write the visualization
+    apply_stack_viz(StackVizMode::Update, &stack, &forge, &ref_store).await?;
+    assert_eq!(forge.get_updated_bodies().len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_exclude_self_from_reviewers() {
+    let forge = MockForge::new().with_login("octocat");
+    let reviewers = vec![
+        "alice".to_string(),
+        "@me".to_string(),
+        "OctoCat".to_string(),
+        "bob".to_string(),
+    ];
+
+    let reviewers = exclude_self_from_reviewers(reviewers, &forge);
+
+    assert_eq!(reviewers, vec!["alice".to_string(), "bob".to_string()]);
+}
+
+#[test]
+fn test_exclude_self_from_reviewers_without_known_user_drops_only_me() {
+    let forge = MockForge::new();
+    let reviewers = vec!["@me".to_string(), "octocat".to_string()];
+
+    assert_eq!(
+        exclude_self_from_reviewers(reviewers, &forge),
+        vec!["octocat".to_string()]
+    );
+}
+
+#[test]
+fn test_submit_stack_links_gitlab_mr_dependencies() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    // main -> parent -> child
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "parent")?;
+    create_branch(&repo, "child")?;
+    ref_store.set_parent("parent", "main")?;
+    ref_store.set_parent("child", "parent")?;
+    let gateway = GitGateway::new()?;
+
+    let forge = MockForge::new().with_gitlab();
+    let submission = submit_stack(
+        "parent",
+        &ref_store,
+        &gateway,
+        &forge,
+        &SubmitSettings::default(),
+        &empty_pr_cache(),
+    )?;
+
+    assert_eq!(submission.results.len(), 2);
+    // Only the child depends on another MR; trunk is not an MR
+    assert_eq!(
+        forge.get_dependencies(),
+        vec![("child".to_string(), "parent".to_string())]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_link_pr_dependencies_is_noop_without_forge_support() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let _ctx = TestRepoContext::new(dir.path());
+
+    let ref_store = RefStore::new()?;
+    ref_store.set_trunk("main")?;
+    create_branch(&repo, "parent")?;
+    create_branch(&repo, "child")?;
+    ref_store.set_parent("parent", "main")?;
+    ref_store.set_parent("child", "parent")?;
+
+    let gateway = GitGateway::new()?;
+    let forge = MockForge::new();
+    link_pr_dependencies(
+        &["parent".to_string(), "child".to_string()],
+        &ref_store,
+        &gateway,
+        &forge,
+    )?;
+
+    assert!(forge.get_dependencies().is_empty());
+
+    Ok(())
+}
@@ -3,11 +3,15 @@
 use anyhow::Result;
 use colored::Colorize;
 
-use crate::forge::AsyncForge;
+use crate::forge::Forge;
+
+use super::submission::EmptyBranchPolicy;
 use crate::git_gateway::{BranchSyncState, GitGateway};
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
 
+use super::PrCache;
+
 /// Validate that the stack is properly structured before submitting.
 ///
 /// Checks that each branch in the ancestry chain is actually rebased onto its parent.
@@ -68,6 +72,53 @@ pub(super) fn validate_stack_integrity(
     Ok(())
 }
 
+/// Check branches in scope for force-push protection before anything is pushed.
+///
+/// Submit pushes with `--force-with-lease` (or `--force`), which a protected
+/// branch rejects partway through a stack. Warns about protected branches, or
+/// with `strict` refuses to continue. Returns the protected branches found.
+pub(super) fn check_force_push_protection(branches: &[String], forge: &dyn Forge, strict: bool) -> Result<Vec<String>> {
+    let mut protected = Vec::new();
+    for branch in branches {
+        match forge.is_force_push_protected(branch) {
+            Ok(true) => protected.push(branch.clone()),
+            Ok(false) => {}
+            Err(e) => eprintln!(
+                "{} Could not check branch protection for '{}': {}",
+                "⚠".yellow(),
+                branch,
+                e
+            ),
+        }
+    }
+
+    if protected.is_empty() {
+        return Ok(protected);
+    }
+
+    let list = protected
+        .iter()
+        .map(|b| format!("  - {}", b))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if strict {
+        anyhow::bail!(
+            "Refusing to submit: {} branch(es) are protected against force-push on the remote:\n{}\n\n\
+             Unprotect them or submit without --strict to push anyway.",
+            protected.len(),
+            list
+        );
+    }
+
+    eprintln!(
+        "{} {} branch(es) are protected against force-push; pushing them will fail:\n{}",
+        "⚠".yellow(),
+        protected.len(),
+        list
+    );
+    Ok(protected)
+}
+
 /// Check if trunk is behind remote and warn (but don't block).
 pub(super) fn check_trunk_sync(gateway: &GitGateway, trunk: Option<&str>) -> Result<()> {
     if let Some(trunk_name) = trunk {
@@ -99,46 +150,102 @@ pub(super) fn check_trunk_sync(gateway: &GitGateway, trunk: Option<&str>) -> Res
     Ok(())
 }
 
-/// Show a preview of what will be submitted (async batch version)
-///
-/// This version uses batch API calls for better performance with many branches.
-pub(super) async fn show_submit_preview_async(
+/// What `submit` will do with a branch, as announced by `--confirm`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum SubmitAction {
+    /// Push and open a new PR
+    CreatePr,
+    /// Push to the branch's existing PR
+    UpdatePr(u64),
+    /// No PR and `--update-only`
+    SkipNoPr,
+    /// Nothing ahead of the base, so it would only produce an empty PR
+    SkipNoCommits,
+    /// Nothing ahead of the base under `--strict`, which stops the submit
+    FailNoCommits,
+    /// Frozen branches are never pushed
+    SkipFrozen,
+}
+
+impl std::fmt::Display for SubmitAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitAction::CreatePr => write!(f, "create PR"),
+            SubmitAction::UpdatePr(number) => write!(f, "update PR #{}", number),
+            SubmitAction::SkipNoPr => write!(f, "skip (no PR)"),
+            SubmitAction::SkipNoCommits => write!(f, "skip (no commits)"),
+            SubmitAction::FailNoCommits => write!(f, "fail (no commits, --strict)"),
+            SubmitAction::SkipFrozen => write!(f, "skip (frozen)"),
+        }
+    }
+}
+
+/// One line of the submit preview: a branch, the base its PR targets, and the action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct SubmitPreviewEntry {
+    pub branch: String,
+    pub base: String,
+    pub action: SubmitAction,
+}
+
+/// Work out what submit will do with each branch, in the order `submit_branch` decides it
+pub(super) fn plan_submit_actions(
     branches: &[String],
     ref_store: &RefStore,
-    forge: &dyn AsyncForge,
+    gateway: &GitGateway,
+    pr_cache: &PrCache,
     update_only: bool,
-) -> Result<()> {
-    println!("{} Will submit:", "→".blue());
-
-    // Batch check all PR existence in parallel
-    let pr_results = forge.check_prs_exist(branches).await;
-
-    // Build a map of branch -> PR info for quick lookup
-    let pr_map: std::collections::HashMap<String, _> = pr_results.into_iter().collect();
+    empty_branches: EmptyBranchPolicy,
+) -> Result<Vec<SubmitPreviewEntry>> {
+    let trunk = ref_store.get_trunk()?;
+    let mut entries = Vec::with_capacity(branches.len());
 
     for branch in branches {
-        let parent = ref_store.get_parent(branch)?;
-        let trunk = ref_store.get_trunk()?;
-        let base = parent.as_ref().or(trunk.as_ref()).map(|s| s.as_str());
-
-        // Look up PR info from batch results
-        let pr_exists = pr_map.get(branch).and_then(|opt| opt.as_ref());
+        let base = ref_store.get_parent(branch)?.or_else(|| trunk.clone());
+        let existing_pr = pr_cache.get(branch).and_then(|opt| opt.as_ref());
 
-        let action = if let Some(pr_info) = pr_exists {
-            format!("update PR #{}", pr_info.number)
+        let action = if ref_store.is_frozen(branch)? {
+            SubmitAction::SkipFrozen
+        } else if let Some(pr_info) = existing_pr {
+            SubmitAction::UpdatePr(pr_info.number)
         } else if update_only {
-            "skip (no PR)".dimmed().to_string()
+            SubmitAction::SkipNoPr
+        } else if empty_branches != EmptyBranchPolicy::Allow
+            && base
+                .as_deref()
+                .is_some_and(|base| gateway.get_commits_between(base, branch).is_ok_and(|c| c.is_empty()))
+        {
+            if empty_branches == EmptyBranchPolicy::Error {
+                SubmitAction::FailNoCommits
+            } else {
+                SubmitAction::SkipNoCommits
+            }
         } else {
-            "new PR".to_string()
+            SubmitAction::CreatePr
         };
 
-        let base_str = base.unwrap_or("?");
-        println!("  • {} → {} ({})", branch.green(), base_str.blue(), action);
+        entries.push(SubmitPreviewEntry {
+            branch: branch.clone(),
+            base: base.unwrap_or_else(|| "?".to_string()),
+            action,
+        });
     }
 
-    if update_only {
-        println!("\n{} --update-only: branches without PRs will be skipped", "ℹ".blue());
-    }
+    Ok(entries)
+}
 
-    Ok(())
+/// Show a preview of what will be submitted, one action per branch
+///
+/// PR lookups come from the batch-fetched cache, so this makes no API calls.
+pub(super) fn show_submit_preview(entries: &[SubmitPreviewEntry]) {
+    println!("{} Will submit:", "→".blue());
+
+    for entry in entries {
+        let action = match entry.action {
+            SubmitAction::CreatePr | SubmitAction::UpdatePr(_) => entry.action.to_string(),
+            SubmitAction::FailNoCommits => entry.action.to_string().red().to_string(),
+            _ => entry.action.to_string().dimmed().to_string(),
+        };
+        println!("  • {} → {} ({})", entry.branch.green(), entry.base.blue(), action);
+    }
 }
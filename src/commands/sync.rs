@@ -1,13 +1,17 @@
 use anyhow::Result;
 use colored::Colorize;
-use std::collections::HashSet;
+use futures::stream::{self, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 use crate::cache::Cache;
 use crate::ui;
 use crate::commands::cleanup::{cleanup_merged_branches_for_sync_async, find_merged_prs_async};
+use crate::config::Config;
 use crate::context::ExecutionContext;
-use crate::forge::get_async_forge;
-use crate::git_gateway::GitGateway;
+use crate::forge::{get_async_forge, get_forge, AsyncForge, Forge, PrState};
+use crate::git_gateway::backup::new_backup_operation_id;
+use crate::git_gateway::{GitGateway, Strategy};
 use crate::operation_log::{Operation, OperationRecorder};
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
@@ -37,17 +41,156 @@ impl SyncOutcome {
     fn total_branches(&self) -> usize {
         self.rebased.len() + self.already_in_sync.len()
     }
+
+    /// Rows of the end-of-sync summary as (category, count, details)
+    ///
+    /// Empty categories are left out. Up-to-date branches are only listed by
+    /// name in verbose mode; otherwise just their count is shown.
+    fn summary_rows(&self, verbose: bool) -> Vec<(&'static str, usize, String)> {
+        let mut rows = Vec::new();
+        if !self.rebased.is_empty() {
+            rows.push(("Rebased", self.rebased.len(), self.rebased.join(", ")));
+        }
+        if !self.already_in_sync.is_empty() {
+            let details = if verbose {
+                self.already_in_sync.join(", ")
+            } else {
+                String::new()
+            };
+            rows.push(("Up to date", self.already_in_sync.len(), details));
+        }
+        if !self.skipped_branches.is_empty() {
+            let details = self
+                .skipped_branches
+                .iter()
+                .map(|(branch, reason)| format!("{} ({})", branch, reason))
+                .collect::<Vec<_>>()
+                .join(", ");
+            rows.push(("Skipped", self.skipped_branches.len(), details));
+        }
+        if let Some(branch) = &self.conflict_branch {
+            rows.push(("Conflicted", 1, format!("{} (sync paused)", branch)));
+        }
+        rows
+    }
+
+    /// Print the summary table, one line per non-empty category
+    fn print_summary(&self, verbose: bool) {
+        let rows = self.summary_rows(verbose);
+        if rows.is_empty() {
+            return;
+        }
+        let width = rows.iter().map(|(label, _, _)| label.len()).max().unwrap_or(0);
+        println!("{}", "Summary:".bold());
+        for (label, count, details) in rows {
+            let label = format!("{:<width$}", label, width = width);
+            let label = match label.trim_end() {
+                "Rebased" => label.green(),
+                "Skipped" => label.yellow(),
+                "Conflicted" => label.red(),
+                _ => label.bright_black(),
+            };
+            if details.is_empty() {
+                println!("  {}  {}", label, count);
+            } else {
+                println!("  {}  {}  {}", label, count, details);
+            }
+        }
+    }
 }
 
-/// Sync stacks by rebasing onto updated trunk (default: restack after sync)
-pub async fn run(
-    continue_sync: bool,
-    abort: bool,
+/// Options for [`run`], one per `sync` flag
+#[derive(Debug, Default)]
+pub struct SyncOptions {
+    /// Continue a sync stopped on conflicts
+    pub continue_sync: bool,
+    /// Abort a sync stopped on conflicts
+    pub abort: bool,
+    /// Proceed even if external changes are detected
+    pub force: bool,
+    /// Skip the cleanup of branches with merged PRs
+    pub no_cleanup: bool,
+    /// Restack branches onto their updated parents
+    pub restack: bool,
+    /// Print progress for each branch
+    pub verbose: bool,
+    /// Stash uncommitted changes around the sync
+    pub autostash: bool,
+    /// Adopt stacked PR branches that only exist on the remote
+    pub include_remote_only: bool,
+    /// Delete local branches with merged PRs once rebasing finishes
+    pub prune_local_merged: bool,
+    /// How branches are updated onto their parents (overrides `sync.strategy`)
+    pub strategy: Option<Strategy>,
+    /// Fetch without tags
+    pub no_tags: bool,
+    /// Only rebase branches within this many levels of trunk
+    pub max_depth: Option<usize>,
+    /// Rebase independent stacks concurrently, up to this many at a time
+    pub parallel: Option<usize>,
+}
+
+/// How a fresh sync runs, once `SyncOptions` are resolved against the config
+#[derive(Debug, Clone, Copy, Default)]
+struct SyncSettings {
     force: bool,
     no_cleanup: bool,
     restack: bool,
     verbose: bool,
-) -> Result<()> {
+    autostash: bool,
+    include_remote_only: bool,
+    prune_local_merged: bool,
+    strategy: Strategy,
+    fetch_tags: bool,
+    max_depth: Option<usize>,
+    parallel: Option<usize>,
+}
+
+/// Sync stacks by rebasing onto updated trunk (default: restack after sync)
+///
+/// With `autostash` (or `sync.autostash`), uncommitted changes are stashed for the
+/// duration of the sync and restored afterwards.
+///
+/// With `include_remote_only`, PRs stacked under tracked branches whose branches
+/// only exist on the remote are checked out locally and inserted into the stack.
+///
+/// With `prune_local_merged`, the pre-rebase cleanup prompt is skipped; instead,
+/// once rebasing finishes, local branches with merged PRs are deleted and their
+/// children reparented.
+///
+/// `strategy` (or `sync.strategy`) selects how branches are updated onto their
+/// parents: rebasing (the default) or merging the parent in, which keeps SHAs.
+///
+/// `no_tags` (or `sync.fetch_tags = false`) fetches with `--no-tags`.
+///
+/// `max_depth` limits rebasing to branches within that many levels of trunk;
+/// deeper branches are left untouched.
+///
+/// `parallel` rebases independent stacks concurrently, up to that many at a
+/// time, each in its own scratch worktree.
+pub async fn run(options: SyncOptions) -> Result<()> {
+    let SyncOptions {
+        continue_sync,
+        abort,
+        force,
+        no_cleanup,
+        restack,
+        verbose,
+        autostash,
+        include_remote_only,
+        prune_local_merged,
+        strategy,
+        no_tags,
+        max_depth,
+        parallel,
+    } = options;
+    if max_depth == Some(0) {
+        anyhow::bail!("--max-depth must be at least 1");
+    }
+    if parallel == Some(0) {
+        anyhow::bail!("--parallel must be at least 1");
+    }
+
     // Handle abort
     if abort {
         return handle_abort();
@@ -77,7 +220,26 @@ pub async fn run(
     let _lock = acquire_operation_lock()?;
 
     // Start fresh sync
-    run_sync(force, no_cleanup, restack, verbose).await
+    let config = Config::load().ok();
+    let autostash = autostash || config.as_ref().is_some_and(|c| c.sync.autostash_enabled());
+    let strategy = strategy
+        .or_else(|| config.as_ref().and_then(|c| c.sync.strategy))
+        .unwrap_or_default();
+    let fetch_tags = !no_tags && config.as_ref().is_none_or(|c| c.sync.fetch_tags_enabled());
+    run_sync(SyncSettings {
+        force,
+        no_cleanup,
+        restack,
+        verbose,
+        autostash,
+        include_remote_only,
+        prune_local_merged,
+        strategy,
+        fetch_tags,
+        max_depth,
+        parallel,
+    })
+    .await
 }
 
 /// Handle dm sync --abort (delegates to general abort logic)
@@ -106,10 +268,17 @@ fn handle_abort() -> Result<()> {
         );
     }
 
-    // Abort any git rebase in progress
+    if state.autostash_conflicted {
+        return abort_autostash_conflict();
+    }
+
+    // Abort any git rebase or merge in progress
     if gateway.rebase_in_progress()? {
         gateway.rebase_abort()?;
     }
+    if gateway.merge_in_progress()? {
+        gateway.merge_abort()?;
+    }
 
     // Return to original branch
     gateway.checkout_branch_worktree_safe(&state.original_branch)?;
@@ -117,6 +286,10 @@ fn handle_abort() -> Result<()> {
     // Clear operation state
     OperationState::clear()?;
 
+    if state.autostash {
+        restore_autostash(&gateway, &state.original_branch)?;
+    }
+
     ui::success_bold("Sync aborted");
     Ok(())
 }
@@ -151,8 +324,15 @@ fn handle_continue() -> Result<bool> {
         );
     }
 
-    // If there's a rebase in progress, continue it
-    if gateway.rebase_in_progress()? && gateway.rebase_continue()?.has_conflicts() {
+    // If there's a rebase or merge in progress, continue it
+    let in_progress = if gateway.rebase_in_progress()? {
+        Some(gateway.rebase_continue()?)
+    } else if gateway.merge_in_progress()? {
+        Some(gateway.merge_continue()?)
+    } else {
+        None
+    };
+    if in_progress.is_some_and(|outcome| outcome.has_conflicts()) {
         ui::warning(&format!(
             "Conflicts remain. Resolve them and run '{} continue'",
             program_name()
@@ -167,8 +347,157 @@ fn handle_continue() -> Result<bool> {
     Ok(outcome.any_work_done())
 }
 
+/// Create local branches for PRs in the chains of tracked stacks that only exist
+/// on the remote, and insert them into the parent graph.
+///
+/// Each stack tip's PR chain is walked parent-first; a missing branch is created
+/// from its remote-tracking ref and parented on its PR's base, and any tracked
+/// branch whose PR is based on an adopted branch is reparented onto it.
+/// Returns the adopted branches in the order they were created.
+fn adopt_remote_only_branches(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    trunk: &str,
+    forge: &dyn Forge,
+) -> Result<Vec<String>> {
+    let tips: Vec<String> = ref_store
+        .collect_branches_dfs(&[trunk.to_string()])?
+        .into_iter()
+        .filter(|b| b != trunk)
+        .filter(|b| ref_store.get_children(b).map(|c| c.is_empty()).unwrap_or(false))
+        .collect();
+
+    let mut cache = Cache::load().unwrap_or_default();
+    let mut adopted: Vec<String> = Vec::new();
+
+    for tip in &tips {
+        let chain = match forge.get_pr_chain(tip) {
+            Ok(chain) => chain,
+            Err(e) => {
+                ui::warning(&format!("Could not get PR chain for '{}': {}", tip, e));
+                continue;
+            }
+        };
+
+        for pr in chain.iter().filter(|pr| pr.state == PrState::Open) {
+            let branch = &pr.head_ref;
+            if branch == trunk {
+                continue;
+            }
+
+            if !gateway.branch_exists(branch)? {
+                let remote_ref = format!("refs/remotes/{}/{}", gateway.remote(), branch);
+                if let Err(e) = gateway.create_branch_at_ref(branch, &remote_ref) {
+                    ui::warning(&format!("Could not create '{}' from {}: {}", branch, remote_ref, e));
+                    continue;
+                }
+                ref_store.set_parent(branch, &pr.base_ref)?;
+                cache.set_pr_url(branch, &pr.url);
+                ui::step(&format!(
+                    "Adopted {} from {} (parent: {})",
+                    ui::print_branch(branch),
+                    gateway.remote(),
+                    ui::print_branch(&pr.base_ref)
+                ));
+                adopted.push(branch.clone());
+            } else if adopted.contains(&pr.base_ref)
+                && ref_store.is_tracked(branch)?
+                && ref_store.get_parent(branch)?.as_deref() != Some(pr.base_ref.as_str())
+            {
+                ref_store.set_parent(branch, &pr.base_ref)?;
+                ui::step(&format!(
+                    "Moved {} onto {}",
+                    ui::print_branch(branch),
+                    ui::print_branch(&pr.base_ref)
+                ));
+            }
+        }
+    }
+
+    if !adopted.is_empty() {
+        cache.save()?;
+    }
+
+    Ok(adopted)
+}
+
+/// One branch's step in a sync dry-run plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RebasePlan {
+    branch: String,
+    /// The parent the branch would be rebased onto
+    parent: String,
+    /// Short SHA of the branch's current base (merge-base with the target)
+    current_base: String,
+    /// Short SHA the branch would land on (None when it lands on a parent that moves first)
+    target_base: Option<String>,
+    /// Nothing to do: already on its target and its parent doesn't move
+    up_to_date: bool,
+    /// Files predicted to conflict that this branch introduces (not inherited from its parent)
+    conflicts: Vec<String>,
+}
+
+/// Work out what sync would do to each branch without modifying any of them
+///
+/// `trunk_target` is the commit trunk would be fast-forwarded to. Branches are
+/// expected parent-first. Conflicts are predicted with `git merge-tree` against
+/// `trunk_target`; a branch is only flagged for files its parents don't already
+/// conflict on, so the warning lands on the branch that introduces the collision.
+fn plan_sync_rebases(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    branches: &[String],
+    trunk: &str,
+    trunk_target: &str,
+) -> Result<Vec<RebasePlan>> {
+    let mut moving: HashSet<String> = HashSet::new();
+    let mut cumulative_conflicts: HashMap<String, Vec<String>> = HashMap::new();
+    let mut plan = Vec::new();
+
+    for branch in branches {
+        let parent = ref_store.get_parent(branch)?.unwrap_or_else(|| trunk.to_string());
+        let parent_ref = if parent == trunk { trunk_target } else { parent.as_str() };
+
+        let base = gateway.get_merge_base(branch, parent_ref)?;
+        let parent_tip = gateway.resolve_ref(parent_ref)?.to_string();
+        let parent_moves = moving.contains(&parent);
+        let up_to_date = base == parent_tip && !parent_moves;
+
+        let conflicts = if up_to_date {
+            Vec::new()
+        } else {
+            moving.insert(branch.clone());
+            let all = gateway.predict_conflicts(trunk_target, branch)?;
+            let inherited = cumulative_conflicts.get(&parent).cloned().unwrap_or_default();
+            let own = all.iter().filter(|f| !inherited.contains(f)).cloned().collect();
+            cumulative_conflicts.insert(branch.clone(), all);
+            own
+        };
+
+        plan.push(RebasePlan {
+            branch: branch.clone(),
+            parent,
+            current_base: base.chars().take(7).collect(),
+            target_base: (!parent_moves).then(|| parent_tip.chars().take(7).collect()),
+            up_to_date,
+            conflicts,
+        });
+    }
+
+    Ok(plan)
+}
+
 /// Dry-run preview of sync operation
-fn run_sync_dry_run(ref_store: &RefStore) -> Result<()> {
+///
+/// Fetches (which only updates remote-tracking refs) and prints the exact rebase
+/// plan against the fetched trunk, including predicted conflicts. No branch is
+/// modified; if the fetch fails, the last fetched refs are used.
+fn run_sync_dry_run(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    fetch_tags: bool,
+    max_depth: Option<usize>,
+) -> Result<()> {
     let trunk = ref_store.require_trunk()?;
 
     // Find all branches that would be rebased (roots are branches whose parent is trunk)
@@ -192,15 +521,62 @@ fn run_sync_dry_run(ref_store: &RefStore) -> Result<()> {
 
     // Collect all branches in DFS order from roots
     let branches_to_rebase = ref_store.collect_branches_dfs(&roots)?;
+    let (branches_to_rebase, beyond_depth) = limit_to_depth(ref_store, branches_to_rebase, max_depth)?;
 
-    let gateway = GitGateway::new()?;
     println!("{} Dry run - would perform:", "[preview]".yellow().bold());
-    println!("  • Fetch from {}", gateway.remote());
-    println!("  • Update {} to latest", trunk.green());
-    println!("  • Rebase {} branches:", branches_to_rebase.len().to_string().yellow());
-    for b in &branches_to_rebase {
-        let parent = ref_store.get_parent(b)?.unwrap_or_else(|| trunk.clone());
-        println!("    - {} onto {}", b.green(), parent.blue());
+    let remote_trunk = match gateway.fetch_snapshot(&trunk, fetch_tags) {
+        Ok(snapshot) => {
+            println!("  • Fetched from {} (remote-tracking refs only)", gateway.remote());
+            snapshot.trunk_tip
+        }
+        Err(e) => {
+            println!(
+                "  • Could not fetch from {} ({}); using last fetched refs",
+                gateway.remote(),
+                e
+            );
+            let trunk_ref = format!("refs/remotes/{}/{}", gateway.remote(), trunk);
+            gateway.resolve_ref(&trunk_ref).ok().map(|oid| oid.to_string())
+        }
+    };
+
+    // Sync fast-forwards trunk to the remote tip when it can; otherwise it stays put
+    let trunk_target = match remote_trunk {
+        Some(tip) if gateway.is_ancestor(&trunk, &tip)? => tip,
+        _ => gateway.get_branch_sha(&trunk)?,
+    };
+    println!(
+        "  • Update {} to {}",
+        trunk.green(),
+        trunk_target.chars().take(7).collect::<String>().yellow()
+    );
+
+    let plan = plan_sync_rebases(gateway, ref_store, &branches_to_rebase, &trunk, &trunk_target)?;
+    let to_rebase = plan.iter().filter(|p| !p.up_to_date).count();
+    println!("  • Rebase {} branches:", to_rebase.to_string().yellow());
+    for step in &plan {
+        if step.up_to_date {
+            println!("    - {} already on {}", step.branch.green(), step.parent.blue());
+            continue;
+        }
+        let target = match &step.target_base {
+            Some(sha) => format!("{} ({})", step.parent.blue(), sha),
+            None => format!("{} (after it is rebased)", step.parent.blue()),
+        };
+        println!("    - {}: {} → {}", step.branch.green(), step.current_base, target);
+        if !step.conflicts.is_empty() {
+            println!(
+                "      {} likely conflict in {}",
+                "⚠".yellow(),
+                step.conflicts.join(", ").yellow()
+            );
+        }
+    }
+    if !beyond_depth.is_empty() {
+        println!(
+            "  • Leave {} branches beyond --max-depth untouched",
+            beyond_depth.len().to_string().yellow()
+        );
     }
     println!("  • Update stack visualization in PRs");
     println!();
@@ -210,18 +586,21 @@ fn run_sync_dry_run(ref_store: &RefStore) -> Result<()> {
 }
 
 /// Start a fresh sync operation
-async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -> Result<()> {
+async fn run_sync(settings: SyncSettings) -> Result<()> {
     let gateway = GitGateway::new()?;
 
     // Check for staged or modified changes (allow untracked files)
-    if gateway.has_staged_or_modified_changes()? {
+    let dirty = gateway.has_staged_or_modified_changes()?;
+    if dirty && !settings.autostash {
         anyhow::bail!(
             "Cannot sync with staged or modified changes.\n\
             Commit or stash your changes first:\n\
             • git add -A && git commit -m \"WIP\"\n\
             • git stash\n\
+            • {} sync --autostash\n\
             \n\
-            Note: Untracked files are OK and will be preserved."
+            Note: Untracked files are OK and will be preserved.",
+            program_name()
         );
     }
 
@@ -230,21 +609,124 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
 
     // Handle dry-run mode
     if ExecutionContext::is_dry_run() {
-        return run_sync_dry_run(&ref_store);
+        return run_sync_dry_run(&gateway, &ref_store, settings.fetch_tags, settings.max_depth);
+    }
+
+    if !dirty {
+        return run_sync_stages(&gateway, &ref_store, &original_branch, settings).await;
+    }
+
+    gateway.stash_push(&format!("{} sync autostash", program_name()))?;
+    ui::step("Stashed uncommitted changes");
+
+    let result = run_sync_stages(&gateway, &ref_store, &original_branch, settings).await;
+
+    match OperationState::load()? {
+        // Sync stopped on conflicts: restore the stash when continue/abort ends it
+        Some(mut state) => {
+            state.autostash = true;
+            state.save()?;
+            ui::step("Your uncommitted changes stay stashed until the sync is continued or aborted");
+        }
+        None => restore_autostash(&gateway, &original_branch)?,
     }
 
+    result
+}
+
+/// Restore changes stashed by `sync --autostash`.
+///
+/// If the stash conflicts with the synced branch, git keeps it; the saved state
+/// lets `continue` drop it once the conflicts are resolved.
+pub(crate) fn restore_autostash(gateway: &GitGateway, original_branch: &str) -> Result<()> {
+    let Err(e) = gateway.stash_pop() else {
+        ui::step("Restored stashed changes");
+        return Ok(());
+    };
+
+    if gateway.get_conflicted_files()?.is_empty() {
+        anyhow::bail!(
+            "Could not restore stashed changes:\n  {}\n\nThey are still in 'git stash list'.",
+            e
+        );
+    }
+
+    let mut state = OperationState::new_sync(original_branch.to_string(), Vec::new());
+    state.autostash = true;
+    state.autostash_conflicted = true;
+    state.save()?;
+
+    ui::warning("Restoring stashed changes caused conflicts.");
+    println!(
+        "Resolve them and mark them with 'git add', then run '{} continue' to finish.",
+        program_name()
+    );
+    Ok(())
+}
+
+/// Drop the restored stash once its conflicts have been resolved
+fn finish_autostash_conflict(gateway: &GitGateway) -> Result<()> {
+    let conflicted = gateway.get_conflicted_files()?;
+    if !conflicted.is_empty() {
+        let files: Vec<&str> = conflicted.iter().map(|f| f.path.as_str()).collect();
+        anyhow::bail!(
+            "Stashed changes still have conflicts:\n  {}\n\nResolve them, 'git add' the files, then run '{} continue'.",
+            files.join("\n  "),
+            program_name()
+        );
+    }
+
+    gateway.stash_drop()?;
+    OperationState::clear()?;
+    ui::success_bold("Restored stashed changes");
+    Ok(())
+}
+
+/// Give up on restoring a conflicted autostash, leaving it in `git stash list`
+pub(crate) fn abort_autostash_conflict() -> Result<()> {
+    OperationState::clear()?;
+    ui::warning("Left stashed changes in 'git stash list'; resolve or reset the working tree manually");
+    Ok(())
+}
+
+/// The sync proper: fetch, cleanup, and rebase all stacks
+async fn run_sync_stages(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    original_branch: &str,
+    settings: SyncSettings,
+) -> Result<()> {
+    let SyncSettings {
+        force,
+        no_cleanup,
+        restack,
+        verbose,
+        include_remote_only,
+        prune_local_merged,
+        strategy,
+        fetch_tags,
+        max_depth,
+        parallel,
+        ..
+    } = settings;
+
     // Verify we have a trunk
     let trunk = ref_store.require_trunk()?;
 
+    // Fetch exactly once for the whole sync; every stack reuses this snapshot
     let spin = ui::spinner(&format!("Fetching from {}...", gateway.remote()));
-    match gateway.fetch_origin() {
-        Ok(()) => ui::spinner_success(spin, &format!("Fetched from {}", gateway.remote())),
+    let snapshot = match gateway.fetch_snapshot(&trunk, fetch_tags) {
+        Ok(snapshot) => {
+            ui::spinner_success(spin, &format!("Fetched from {}", gateway.remote()));
+            Some(snapshot)
+        }
         Err(e) => {
             // Non-fatal: might not have remote configured or SSH auth issues
             ui::spinner_warning(spin, &format!("Could not fetch from {}: {}", gateway.remote(), e));
             ui::bullet_step("Continuing with local branches...");
+            None
         }
-    }
+    };
 
     // Note: We intentionally do NOT fetch diamond refs here.
     // Refs travel with branches (pushed on submit, fetched on checkout).
@@ -252,7 +734,11 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
 
     // Try to fast-forward trunk
     let spin = ui::spinner(&format!("Updating {}...", ui::print_branch(&trunk)));
-    match gateway.fast_forward_branch(&trunk) {
+    let fast_forward = match snapshot.as_ref().and_then(|s| s.trunk_tip.as_deref()) {
+        Some(tip) => gateway.fast_forward_branch_to(&trunk, tip),
+        None => gateway.fast_forward_branch(&trunk),
+    };
+    match fast_forward {
         Ok(()) => ui::spinner_success(spin, &format!("{} is up to date", trunk)),
         Err(e) => {
             // Non-fatal: trunk might have local changes or no remote
@@ -260,10 +746,26 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
         }
     }
 
+    if include_remote_only {
+        match get_forge(None) {
+            Ok(forge) => {
+                let adopted = adopt_remote_only_branches(gateway, ref_store, &trunk, forge.as_ref())?;
+                if !adopted.is_empty() {
+                    ui::success(&format!(
+                        "Adopted {} remote-only branch{}",
+                        adopted.len(),
+                        if adopted.len() == 1 { "" } else { "es" }
+                    ));
+                }
+            }
+            Err(e) => ui::warning(&format!("Skipping remote-only branches: {}", e)),
+        }
+    }
+
     // Detect and fix orphaned branches BEFORE collecting the branch tree
     // This handles the case where a parent branch was merged/deleted via GitHub
     // and child branches are now "orphaned" (parent doesn't exist in git)
-    repair_orphaned_branches(&gateway, &ref_store, &trunk)?;
+    repair_orphaned_branches(gateway, ref_store, &trunk)?;
 
     // Find all branches that need rebasing (roots are branches whose parent is trunk)
     let all_branches = ref_store.collect_branches_dfs(std::slice::from_ref(&trunk))?;
@@ -281,12 +783,22 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
 
     if roots.is_empty() {
         ui::success_bold("No branches to sync");
-        gateway.checkout_branch_worktree_safe(&original_branch)?;
+        gateway.checkout_branch_worktree_safe(original_branch)?;
         return Ok(());
     }
 
     // Collect all branches in parent-first order
-    let mut branches_to_rebase = ref_store.collect_branches_dfs(&roots)?;
+    let branches_to_rebase = ref_store.collect_branches_dfs(&roots)?;
+    let (mut branches_to_rebase, beyond_depth) = limit_to_depth(ref_store, branches_to_rebase, max_depth)?;
+    if let Some(depth) = max_depth.filter(|_| !beyond_depth.is_empty()) {
+        ui::step(&format!(
+            "Leaving {} branch{} deeper than {} level{} untouched (--max-depth)",
+            beyond_depth.len(),
+            if beyond_depth.len() == 1 { "" } else { "es" },
+            depth,
+            if depth == 1 { "" } else { "s" }
+        ));
+    }
 
     // Validate that all branches actually exist in git
     for branch in &branches_to_rebase {
@@ -306,7 +818,8 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
     // Check PR status and cleanup merged branches BEFORE sync from remote
     // This handles squash-merged PRs that git can't detect
     // Uses async for parallel PR status checks
-    if !no_cleanup {
+    // (--prune-local-merged replaces this with a prompt-free prune after rebasing)
+    if !no_cleanup && !prune_local_merged {
         match get_async_forge(None) {
             Ok(forge) => {
                 if let Err(e) = forge.check_auth() {
@@ -347,8 +860,8 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
 
                             ui::step(&format!("Cleaning up {} merged PR(s):", filtered_prs.len()));
                             let deleted = cleanup_merged_branches_for_sync_async(
-                                &gateway,
-                                &ref_store,
+                                gateway,
+                                ref_store,
                                 &mut cache,
                                 &trunk,
                                 &filtered_prs,
@@ -378,8 +891,8 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
     if branches_to_rebase.is_empty() {
         ui::success_bold("Sync complete! All branches were merged.");
         // Return to original branch if it still exists, otherwise stay on trunk
-        if gateway.branch_exists(&original_branch)? {
-            gateway.checkout_branch_worktree_safe(&original_branch)?;
+        if gateway.branch_exists(original_branch)? {
+            gateway.checkout_branch_worktree_safe(original_branch)?;
         } else {
             ui::step(&format!(
                 "Original branch '{}' was merged, staying on {}",
@@ -392,10 +905,13 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
 
     // If --no-restack was specified, stop here (cleanup only, no rebasing)
     if !restack {
+        if prune_local_merged {
+            prune_merged_after_sync(gateway, ref_store, &trunk).await;
+        }
         ui::success_bold("Sync complete (cleanup only)");
         // Return to original branch if it still exists, otherwise stay on trunk
-        if gateway.branch_exists(&original_branch)? {
-            gateway.checkout_branch_worktree_safe(&original_branch)?;
+        if gateway.branch_exists(original_branch)? {
+            gateway.checkout_branch_worktree_safe(original_branch)?;
         } else {
             ui::step(&format!(
                 "Original branch '{}' was deleted, staying on {}",
@@ -424,8 +940,9 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
 
     // Create backup refs for all branches silently BEFORE starting
     let recorder = OperationRecorder::new()?;
+    let backup_op = new_backup_operation_id()?;
     for branch in &branches_to_rebase {
-        let backup = gateway.create_backup_ref(branch)?;
+        let backup = gateway.create_operation_backup_ref(&backup_op, branch)?;
         // Log backup creation (silent - only recorded for recovery)
         recorder.record(Operation::BackupCreated {
             branch: branch.clone(),
@@ -441,11 +958,18 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
     // Create operation state for sync and save immediately
     // This ensures we can recover even if crash happens before first rebase
     let synced_branches = branches_to_rebase.clone(); // Keep for stack viz update
-    let mut state = OperationState::new_sync(original_branch.clone(), branches_to_rebase.clone());
+    let mut state = OperationState::new_sync(original_branch.to_string(), branches_to_rebase.clone());
+    state.strategy = strategy;
     state.save()?;
 
     // Start rebasing - returns outcome tracking what was done
-    let outcome = continue_sync_from_state(&mut state, &ref_store, no_cleanup, verbose)?;
+    let outcome = match parallel {
+        Some(jobs) => {
+            let synced = sync_stacks_in_worktrees(gateway, ref_store, &trunk, &mut state, jobs).await?;
+            sync_remaining_branches(&mut state, ref_store, verbose, synced)?
+        }
+        None => continue_sync_from_state(&mut state, ref_store, no_cleanup, verbose)?,
+    };
 
     // Log sync completion
     recorder.record(Operation::SyncCompleted {
@@ -453,6 +977,11 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
         success: outcome.conflict_branch.is_none(),
     })?;
 
+    // Prune only once every branch is rebased; a sync stopped on conflicts leaves them alone
+    if prune_local_merged && outcome.conflict_branch.is_none() {
+        prune_merged_after_sync(gateway, ref_store, &trunk).await;
+    }
+
     // Update stack visualization in PRs only if rebasing actually happened
     // Skip if all branches were already rebased (nothing changed)
     if outcome.any_work_done() {
@@ -465,6 +994,248 @@ async fn run_sync(force: bool, no_cleanup: bool, restack: bool, verbose: bool) -
     Ok(())
 }
 
+/// Split branches into those within `max_depth` levels of trunk and those deeper
+///
+/// A branch directly on trunk is at depth 1. Order is preserved, so the kept
+/// branches stay parent-first. With no limit, every branch is kept.
+fn limit_to_depth(
+    ref_store: &RefStore,
+    branches: Vec<String>,
+    max_depth: Option<usize>,
+) -> Result<(Vec<String>, Vec<String>)> {
+    let Some(max_depth) = max_depth else {
+        return Ok((branches, Vec::new()));
+    };
+
+    let mut kept = Vec::new();
+    let mut beyond = Vec::new();
+    for branch in branches {
+        if ref_store.ancestors(&branch)?.len() <= max_depth {
+            kept.push(branch);
+        } else {
+            beyond.push(branch);
+        }
+    }
+    Ok((kept, beyond))
+}
+
+/// One independent stack for `--parallel`: a branch whose parent isn't being
+/// synced, and everything above it
+#[derive(Clone)]
+struct StackJob {
+    root: String,
+    /// Parent-first
+    branches: Vec<String>,
+    parents: HashMap<String, String>,
+}
+
+/// What syncing one stack in its scratch worktree did
+#[derive(Default)]
+struct StackResult {
+    rebased: Vec<String>,
+    already_in_sync: Vec<String>,
+    /// Branch that conflicted; it and the rest of its stack were left alone
+    conflict: Option<String>,
+}
+
+/// Sync independent stacks concurrently, up to `jobs` at a time
+///
+/// `state.remaining_branches` is split into one tree per root. Each tree is
+/// updated parent-first on a blocking task in its own scratch worktree, so a
+/// conflict only stops its own tree: it is aborted there, and the conflicting
+/// branch and the rest of its tree stay in `state.remaining_branches` for the
+/// sequential sync, which stops or skips on the conflict as usual. Each tree
+/// reports its outcome as it finishes. With a single tree nothing is done.
+async fn sync_stacks_in_worktrees(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    trunk: &str,
+    state: &mut OperationState,
+    jobs: usize,
+) -> Result<SyncOutcome> {
+    let order = state.remaining_branches.clone();
+    let mut parents = HashMap::new();
+    for branch in &order {
+        let parent = ref_store.get_parent(branch)?.unwrap_or_else(|| trunk.to_string());
+        parents.insert(branch.clone(), parent);
+    }
+
+    let roots: Vec<&String> = order.iter().filter(|b| !order.contains(&parents[*b])).collect();
+    if roots.len() < 2 {
+        return Ok(SyncOutcome::default());
+    }
+
+    let mut stacks = Vec::new();
+    for root in roots {
+        let branches: Vec<String> = ref_store
+            .collect_branches_dfs(std::slice::from_ref(root))?
+            .into_iter()
+            .filter(|b| order.contains(b))
+            .collect();
+        stacks.push(StackJob {
+            root: root.clone(),
+            parents: branches.iter().map(|b| (b.clone(), parents[b].clone())).collect(),
+            branches,
+        });
+    }
+
+    // The checked-out branch may be rebased in another worktree, so step off it first
+    if order.contains(&state.original_branch) {
+        gateway.checkout_detached()?;
+    }
+
+    println!(
+        "{} Syncing {} stacks with up to {} parallel worktrees",
+        "→".blue(),
+        stacks.len(),
+        jobs
+    );
+    let strategy = state.strategy;
+    let mut running = stream::iter(stacks.into_iter().enumerate())
+        .map(|(i, stack)| async move {
+            let name = format!("sync-{}", i);
+            let result = match worktree::create_diamond_worktree(&name, &stack.root) {
+                Ok(path) => {
+                    let (job, job_path) = (stack.clone(), path.clone());
+                    let result = tokio::task::spawn_blocking(move || sync_stack_in_worktree(&job_path, &job, strategy))
+                        .await
+                        .unwrap_or_else(|_| Err(anyhow::anyhow!("Sync worker panicked")));
+                    worktree::remove_diamond_worktree(&path);
+                    result
+                }
+                Err(e) => Err(e),
+            };
+            (stack, result)
+        })
+        .buffer_unordered(jobs);
+
+    let mut cache = Cache::load().unwrap_or_default();
+    let mut outcome = SyncOutcome::default();
+    let mut done: HashSet<String> = HashSet::new();
+    while let Some((stack, result)) = running.next().await {
+        match result {
+            Ok(result) => {
+                for branch in &result.rebased {
+                    cache.set_base_sha(branch, &gateway.get_branch_sha(branch)?);
+                }
+                match &result.conflict {
+                    Some(branch) => ui::warning(&format!(
+                        "Stack {}: conflicts in {}; syncing the rest of it one branch at a time",
+                        stack.root.yellow(),
+                        branch.yellow()
+                    )),
+                    None => ui::success(&format!(
+                        "Synced stack {} ({} rebased, {} up to date)",
+                        stack.root.green(),
+                        result.rebased.len(),
+                        result.already_in_sync.len()
+                    )),
+                }
+                done.extend(result.rebased.iter().cloned());
+                done.extend(result.already_in_sync.iter().cloned());
+                outcome.rebased.extend(result.rebased);
+                outcome.already_in_sync.extend(result.already_in_sync);
+            }
+            Err(e) => ui::warning(&format!(
+                "Stack {} could not be synced in a worktree ({}); syncing it one branch at a time",
+                stack.root.yellow(),
+                e
+            )),
+        }
+
+        state.remaining_branches = order.iter().filter(|b| !done.contains(*b)).cloned().collect();
+        state.save()?;
+    }
+    cache.save()?;
+
+    Ok(outcome)
+}
+
+/// Update one stack parent-first in a scratch worktree, stopping at the first conflict
+///
+/// Branches are checked out and updated in the worktree, which moves their
+/// refs for the whole repository. A conflicted update is aborted so the
+/// worktree can be removed.
+fn sync_stack_in_worktree(path: &Path, stack: &StackJob, strategy: Strategy) -> Result<StackResult> {
+    let gateway = GitGateway::from_path(path)?;
+    let mut result = StackResult::default();
+
+    for branch in &stack.branches {
+        let onto = &stack.parents[branch];
+        if gateway.is_branch_based_on(branch, onto)? {
+            result.already_in_sync.push(branch.clone());
+            continue;
+        }
+
+        if gateway.update_onto(branch, onto, strategy)?.has_conflicts() {
+            match strategy {
+                Strategy::Rebase => gateway.rebase_abort()?,
+                Strategy::Merge => gateway.merge_abort()?,
+            }
+            result.conflict = Some(branch.clone());
+            break;
+        }
+        result.rebased.push(branch.clone());
+    }
+
+    Ok(result)
+}
+
+/// Run `--prune-local-merged`, reporting problems as warnings
+///
+/// Pruning happens after the sync proper, so failures here never fail the sync.
+async fn prune_merged_after_sync(gateway: &GitGateway, ref_store: &RefStore, trunk: &str) {
+    let forge = match get_async_forge(None) {
+        Ok(forge) => forge,
+        Err(e) => {
+            ui::warning(&format!("Skipping merged-branch pruning: {}", e));
+            return;
+        }
+    };
+
+    match prune_local_merged(gateway, ref_store, trunk, forge.as_ref()).await {
+        Ok(deleted) if !deleted.is_empty() => ui::success(&format!(
+            "Pruned {} merged branch{}",
+            deleted.len(),
+            if deleted.len() == 1 { "" } else { "es" }
+        )),
+        Ok(_) => {}
+        Err(e) => ui::warning(&format!("Could not prune merged branches: {}", e)),
+    }
+}
+
+/// Delete local branches whose PRs the forge reports as merged
+///
+/// Does not prompt. Children of a pruned branch are reparented onto its parent
+/// (and their PR bases updated). If the current branch is pruned, trunk is
+/// checked out first. Returns the deleted branches.
+pub(crate) async fn prune_local_merged(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    trunk: &str,
+    forge: &dyn AsyncForge,
+) -> Result<Vec<String>> {
+    let branches: Vec<String> = ref_store
+        .collect_branches_dfs(&[trunk.to_string()])?
+        .into_iter()
+        .filter(|b| b != trunk)
+        .collect();
+
+    let merged = find_merged_prs_async(forge, &branches).await;
+    if merged.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let current = gateway.get_current_branch_name()?;
+    if merged.iter().any(|(branch, _)| *branch == current) {
+        gateway.checkout_branch_worktree_safe(trunk)?;
+    }
+
+    ui::step(&format!("Pruning {} merged PR(s):", merged.len()));
+    let mut cache = Cache::load().unwrap_or_default();
+    cleanup_merged_branches_for_sync_async(gateway, ref_store, &mut cache, trunk, &merged, Some(forge)).await
+}
+
 /// Continue syncing from saved state
 /// This is public so it can be called from the standalone continue command
 /// Returns SyncOutcome tracking which branches were rebased vs already in sync
@@ -475,13 +1246,33 @@ pub fn continue_sync_from_state(
     verbose: bool,
 ) -> Result<SyncOutcome> {
     let gateway = GitGateway::new()?;
+
+    // All branches were synced already; only the stashed changes are left to restore
+    if state.autostash_conflicted {
+        finish_autostash_conflict(&gateway)?;
+        return Ok(SyncOutcome::default());
+    }
+
+    sync_remaining_branches(state, ref_store, verbose, SyncOutcome::default())
+}
+
+/// Update `state.remaining_branches` one at a time in this worktree, then finish the sync
+///
+/// `outcome` holds what was already done for this sync (by `--parallel`) so
+/// the final summary covers every branch.
+fn sync_remaining_branches(
+    state: &mut OperationState,
+    ref_store: &RefStore,
+    verbose: bool,
+    mut outcome: SyncOutcome,
+) -> Result<SyncOutcome> {
+    let gateway = GitGateway::new()?;
     let mut cache = Cache::load().unwrap_or_default();
 
     // Re-run repair in case state changed since crash
     let trunk = ref_store.require_trunk()?;
     repair_orphaned_branches(&gateway, ref_store, &trunk)?;
 
-    let mut outcome = SyncOutcome::default();
     let total = state.all_branches.len();
     let mut processed = total - state.remaining_branches.len();
 
@@ -542,21 +1333,27 @@ pub fn continue_sync_from_state(
         }
 
         // Show spinner with progress for active rebase
+        let verb = match state.strategy {
+            Strategy::Rebase => "Rebasing",
+            Strategy::Merge => "Merging parent into",
+        };
         let spin = ui::spinner(&format!(
-            "[{}/{}] Rebasing {}...",
+            "[{}/{}] {} {}...",
             processed,
             total,
+            verb,
             ui::print_branch(&branch)
         ));
 
         // CHECKPOINT: Save state BEFORE rebase (crash recovery)
         state.save()?;
 
-        // Attempt rebase using --fork-point for better handling of merged parents.
+        // Rebase attempts use --fork-point for better handling of merged parents.
         // When a parent branch is merged, --fork-point uses the reflog to find
         // the correct fork point, avoiding conflicts from already-merged commits.
         // Falls back to regular rebase if reflog is unavailable.
-        let rebase_result = gateway.rebase_fork_point(&branch, &onto)?;
+        // The merge strategy merges the parent in instead, leaving existing SHAs alone.
+        let rebase_result = gateway.update_onto(&branch, &onto, state.strategy)?;
 
         if rebase_result.has_conflicts() {
             // Stack-aware conflict handling: only stop if conflict is in YOUR stack
@@ -568,6 +1365,10 @@ pub fn continue_sync_from_state(
                 ui::spinner_error(spin, &format!("Conflicts in {}", branch));
                 ui::blank();
 
+                outcome.conflict_branch = Some(branch.clone());
+                outcome.print_summary(verbose);
+                ui::blank();
+
                 // Show rich conflict message with stack context and conflicted files
                 ui::display_conflict_message(
                     &branch,
@@ -578,12 +1379,14 @@ pub fn continue_sync_from_state(
                     false, // initial conflict
                 )?;
 
-                outcome.conflict_branch = Some(branch);
                 return Ok(outcome);
             } else {
                 // SKIP: Branch is in a different stack, doesn't block your work
-                // Abort the rebase and continue with other branches
-                gateway.rebase_abort()?;
+                // Abort the rebase (or merge) and continue with other branches
+                match state.strategy {
+                    Strategy::Rebase => gateway.rebase_abort()?,
+                    Strategy::Merge => gateway.merge_abort()?,
+                }
                 ui::spinner_warning(spin, &format!("Skipped {} (conflicts)", branch));
 
                 let reason = format!("conflicts with {}", onto);
@@ -624,7 +1427,11 @@ pub fn continue_sync_from_state(
             String::new()
         };
 
-        ui::spinner_success(spin, &format!("Rebased {}{}", branch, pr_display));
+        let done = match state.strategy {
+            Strategy::Rebase => "Rebased",
+            Strategy::Merge => "Merged parent into",
+        };
+        ui::spinner_success(spin, &format!("{} {}{}", done, branch, pr_display));
         outcome.rebased.push(branch.clone());
 
         // Update base_sha after successful rebase
@@ -649,6 +1456,10 @@ pub fn continue_sync_from_state(
         gateway.checkout_branch_worktree_safe(&trunk)?;
     }
 
+    if state.autostash {
+        restore_autostash(&gateway, &state.original_branch)?;
+    }
+
     // Record sync timestamp for staleness tracking
     let trunk_sha = gateway.get_branch_sha(&trunk).ok();
     let mut cache = Cache::load().unwrap_or_default();
@@ -665,11 +1476,6 @@ pub fn continue_sync_from_state(
             outcome.rebased.len(),
             if outcome.rebased.len() == 1 { "" } else { "es" }
         ));
-
-        // Show "already in sync" count if not verbose
-        if !verbose && !outcome.already_in_sync.is_empty() {
-            println!("  • {} already up to date", outcome.already_in_sync.len());
-        }
     } else {
         ui::success(&format!(
             "{} branch{} already in sync",
@@ -678,6 +1484,12 @@ pub fn continue_sync_from_state(
         ));
     }
 
+    // Categorize every branch; a clean no-op sync is already summed up above
+    if outcome.any_work_done() || !outcome.skipped_branches.is_empty() || verbose {
+        ui::blank();
+        outcome.print_summary(verbose);
+    }
+
     // Suggest --verbose if there were branches that didn't need updating
     if !verbose && !outcome.already_in_sync.is_empty() && outcome.any_work_done() {
         ui::step("Use --verbose (-v) to see all branch details");
@@ -753,6 +1565,11 @@ pub async fn update_stack_visualization_for_sync_async(synced_branches: &[String
         return Ok(());
     }
 
+    // Teams that opted out of the PR-body footer (submit.stack_viz = false)
+    if !Config::load().map(|c| c.submit.stack_viz_enabled()).unwrap_or(true) {
+        return Ok(());
+    }
+
     // Get the async forge
     let forge = match get_async_forge(None) {
         Ok(f) => f,
@@ -810,7 +1627,7 @@ mod tests {
     use std::fs;
     use tempfile::tempdir;
 
-    use crate::test_context::{init_test_repo, TestRepoContext};
+    use crate::test_context::{assert_scratch_worktrees_removed, commit_in, git_in, init_test_repo, TestRepoContext};
 
     // ===== SyncOutcome Unit Tests =====
 
@@ -870,6 +1687,44 @@ mod tests {
         assert_eq!(outcome.conflict_branch, Some("feature-2".to_string()));
     }
 
+    #[test]
+    fn test_sync_outcome_summary_rows_categorize_branches() {
+        let outcome = SyncOutcome {
+            rebased: vec!["feature-1".to_string()],
+            already_in_sync: vec!["feature-2".to_string()],
+            conflict_branch: None,
+            skipped_branches: vec![("other".to_string(), "conflicts with main".to_string())],
+        };
+
+        let rows = outcome.summary_rows(true);
+        assert_eq!(
+            rows,
+            vec![
+                ("Rebased", 1, "feature-1".to_string()),
+                ("Up to date", 1, "feature-2".to_string()),
+                ("Skipped", 1, "other (conflicts with main)".to_string()),
+            ]
+        );
+
+        // Without --verbose, up-to-date branches are only counted
+        let rows = outcome.summary_rows(false);
+        assert_eq!(rows[1], ("Up to date", 1, String::new()));
+    }
+
+    #[test]
+    fn test_sync_outcome_summary_rows_report_conflict() {
+        let outcome = SyncOutcome {
+            rebased: vec![],
+            already_in_sync: vec![],
+            conflict_branch: Some("feature-2".to_string()),
+            skipped_branches: vec![],
+        };
+        assert_eq!(
+            outcome.summary_rows(false),
+            vec![("Conflicted", 1, "feature-2 (sync paused)".to_string())]
+        );
+    }
+
     // ===== Integration Tests =====
 
     fn create_branch(repo: &git2::Repository, name: &str) -> Result<()> {
@@ -885,7 +1740,11 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // No trunk configured (RefStore is empty)
-        let result = run(false, false, false, true, false, false).await; // restack=false, verbose=false for tests
+        let result = run(SyncOptions {
+            no_cleanup: true,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -904,7 +1763,12 @@ mod tests {
         // No operation in progress
         OperationState::clear().ok();
 
-        let result = run(false, true, false, true, false, false).await; // restack=false, verbose=false for tests
+        let result = run(SyncOptions {
+            abort: true,
+            no_cleanup: true,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No operation in progress"));
     }
@@ -918,7 +1782,12 @@ mod tests {
         // No operation in progress
         OperationState::clear().ok();
 
-        let result = run(true, false, false, true, false, false).await; // restack=false, verbose=false for tests
+        let result = run(SyncOptions {
+            continue_sync: true,
+            no_cleanup: true,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No operation in progress"));
     }
@@ -944,6 +1813,36 @@ mod tests {
         assert!(result.contains(&"feature-2".to_string()));
     }
 
+    #[test]
+    fn test_limit_to_depth_splits_by_distance_from_trunk() {
+        let dir = tempdir().unwrap();
+        let repo = init_test_repo(dir.path()).unwrap();
+        let _ctx = TestRepoContext::new(dir.path());
+
+        for name in ["a", "b", "c", "d"] {
+            create_branch(&repo, name).unwrap();
+        }
+
+        let ref_store = RefStore::new().unwrap();
+        ref_store.set_trunk("main").unwrap();
+        ref_store.set_parent("a", "main").unwrap();
+        ref_store.set_parent("b", "a").unwrap();
+        ref_store.set_parent("c", "b").unwrap();
+        ref_store.set_parent("d", "main").unwrap();
+
+        let branches = ref_store
+            .collect_branches_dfs(&["a".to_string(), "d".to_string()])
+            .unwrap();
+
+        let (kept, beyond) = limit_to_depth(&ref_store, branches.clone(), Some(2)).unwrap();
+        assert_eq!(kept, vec!["a", "b", "d"]);
+        assert_eq!(beyond, vec!["c"]);
+
+        let (kept, beyond) = limit_to_depth(&ref_store, branches.clone(), None).unwrap();
+        assert_eq!(kept, branches);
+        assert!(beyond.is_empty());
+    }
+
     #[test]
     fn test_collect_branches_dfs_multiple_children() {
         let dir = tempdir().unwrap();
@@ -1021,7 +1920,12 @@ mod tests {
         let state = OperationState::new_restack("main".to_string(), vec!["feature-1".to_string()]);
         state.save().unwrap();
 
-        let result = run(false, true, false, true, false, false).await; // restack=false, verbose=false for tests
+        let result = run(SyncOptions {
+            abort: true,
+            no_cleanup: true,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -1044,7 +1948,12 @@ mod tests {
         );
         state.save().unwrap();
 
-        let result = run(true, false, false, true, false, false).await; // restack=false, verbose=false for tests
+        let result = run(SyncOptions {
+            continue_sync: true,
+            no_cleanup: true,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -1069,7 +1978,13 @@ mod tests {
         ref_store.set_parent("missing-branch", "feature-1").unwrap();
 
         // Try to sync - should auto-clean the stale ref for missing-branch
-        let result = run_sync(false, true, false, false).await; // restack=false, verbose=false for tests
+        let result = run_sync(SyncSettings {
+            no_cleanup: true,
+            strategy: Strategy::Rebase,
+            fetch_tags: true,
+            ..Default::default()
+        })
+        .await;
         assert!(result.is_ok(), "Sync should succeed after cleaning up stale refs");
 
         // Verify the stale ref was cleaned up
@@ -1084,6 +1999,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_adopt_remote_only_branches_inserts_missing_middle_branch() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        // Local stack main -> feature-a -> feature-c; feature-b only exists on the remote
+        let main_commit = repo.head()?.peel_to_commit()?;
+        repo.branch("feature-a", &main_commit, false)?;
+        repo.branch("feature-c", &main_commit, false)?;
+        repo.reference("refs/remotes/origin/feature-b", main_commit.id(), false, "test")?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feature-a", "main")?;
+        ref_store.set_parent("feature-c", "feature-a")?;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response(
+            "get_pr_chain:feature-c",
+            MockResponse::Success("feature-a:main,feature-b:feature-a,feature-c:feature-b".to_string()),
+        );
+
+        let gateway = GitGateway::new()?;
+        let adopted = adopt_remote_only_branches(&gateway, &ref_store, "main", &forge)?;
+
+        assert_eq!(adopted, vec!["feature-b".to_string()]);
+        assert!(gateway.branch_exists("feature-b")?);
+        assert_eq!(ref_store.get_parent("feature-b")?, Some("feature-a".to_string()));
+        assert_eq!(ref_store.get_parent("feature-c")?, Some("feature-b".to_string()));
+        assert_eq!(
+            Cache::load()?.get_pr_url("feature-b"),
+            Some("https://github.com/test/repo/pull/2")
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_prune_local_merged_deletes_only_merged_branch_and_reparents_child() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        // main -> feature-a (merged) -> feature-b (open), main -> side (open)
+        create_branch(&repo, "feature-a")?;
+        create_branch(&repo, "feature-b")?;
+        create_branch(&repo, "side")?;
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feature-a", "main")?;
+        ref_store.set_parent("feature-b", "feature-a")?;
+        ref_store.set_parent("side", "main")?;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature-a", MockResponse::Success("1:merged".to_string()));
+        forge.set_response("pr_exists:feature-b", MockResponse::Success("2".to_string()));
+        forge.set_response("pr_exists:side", MockResponse::Success("3".to_string()));
+
+        let gateway = GitGateway::new()?;
+        let pruned = prune_local_merged(&gateway, &ref_store, "main", &forge).await?;
+
+        assert_eq!(pruned, vec!["feature-a".to_string()]);
+        assert!(!gateway.branch_exists("feature-a")?);
+        assert!(!ref_store.is_tracked("feature-a")?);
+        assert!(gateway.branch_exists("feature-b")?);
+        assert!(gateway.branch_exists("side")?);
+        assert_eq!(ref_store.get_parent("feature-b")?, Some("main".to_string()));
+        assert_eq!(ref_store.get_parent("side")?, Some("main".to_string()));
+
+        // The open child's PR base follows the reparent
+        assert_eq!(forge.get_call_count("update_pr_base:feature-b"), 1);
+
+        Ok(())
+    }
+
     #[test]
     fn test_find_stack_root_finds_trunk_child() {
         let dir = tempdir().unwrap();
@@ -1340,4 +2338,180 @@ mod tests {
             "State should be cleared after successful completion"
         );
     }
+
+    #[test]
+    fn test_sync_dry_run_plans_rebases_and_flags_conflict() -> Result<()> {
+        use std::process::Command;
+
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let path = dir.path();
+
+        let git = |args: &[&str]| {
+            let output = Command::new("git").args(args).current_dir(path).output().unwrap();
+            assert!(output.status.success(), "git {:?} failed: {:?}", args, output);
+        };
+        let commit = |file: &str, content: &str| {
+            fs::write(path.join(file), content).unwrap();
+            git(&["add", file]);
+            git(&["commit", "-m", file]);
+        };
+
+        // main -> feature (edits shared.txt) -> child (c.txt); main -> other (o.txt)
+        commit("shared.txt", "base\n");
+        git(&["checkout", "-b", "feature"]);
+        commit("shared.txt", "feature\n");
+        git(&["checkout", "-b", "child"]);
+        commit("c.txt", "child\n");
+        git(&["checkout", "main"]);
+        git(&["checkout", "-b", "other"]);
+        commit("o.txt", "other\n");
+        git(&["checkout", "main"]);
+        // Trunk advances with a colliding edit
+        commit("shared.txt", "main\n");
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feature", "main")?;
+        ref_store.set_parent("child", "feature")?;
+        ref_store.set_parent("other", "main")?;
+
+        let gateway = GitGateway::new()?;
+        let branches = ["feature", "child", "other"];
+        let before: Vec<String> = branches
+            .iter()
+            .map(|b| gateway.get_branch_sha(b))
+            .collect::<Result<_>>()?;
+
+        let trunk_tip = gateway.get_branch_sha("main")?;
+        let order = ref_store.collect_branches_dfs(&["feature".to_string(), "other".to_string()])?;
+        let plan = plan_sync_rebases(&gateway, &ref_store, &order, "main", &trunk_tip)?;
+        let step = |name: &str| plan.iter().find(|p| p.branch == name).unwrap();
+
+        assert_eq!(plan.len(), 3);
+        assert!(plan.iter().all(|p| !p.up_to_date));
+        assert_eq!(step("feature").conflicts, vec!["shared.txt".to_string()]);
+        assert_eq!(step("feature").target_base.as_deref(), Some(&trunk_tip[..7]));
+        assert!(step("child").conflicts.is_empty());
+        assert_eq!(step("child").target_base, None);
+        assert!(step("other").conflicts.is_empty());
+
+        // The full dry-run (fetch fails without a remote) must not move any branch
+        run_sync_dry_run(&gateway, &ref_store, true, None)?;
+        let after: Vec<String> = branches
+            .iter()
+            .map(|b| gateway.get_branch_sha(b))
+            .collect::<Result<_>>()?;
+        assert_eq!(before, after);
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+
+        Ok(())
+    }
+
+    /// main -> a (edits shared.txt) -> a2; main -> b; then main advances
+    fn setup_independent_stacks(path: &Path, conflicting_trunk: bool) -> Result<RefStore> {
+        commit_in(path, "shared.txt", "base\n");
+        git_in(path, &["checkout", "-b", "a"]);
+        commit_in(path, "shared.txt", "from a\n");
+        git_in(path, &["checkout", "-b", "a2"]);
+        commit_in(path, "a2.txt", "a2\n");
+        git_in(path, &["checkout", "main"]);
+        git_in(path, &["checkout", "-b", "b"]);
+        commit_in(path, "b.txt", "b\n");
+        git_in(path, &["checkout", "main"]);
+        if conflicting_trunk {
+            commit_in(path, "shared.txt", "from main\n");
+        } else {
+            commit_in(path, "main.txt", "main\n");
+        }
+
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("a", "main")?;
+        ref_store.set_parent("a2", "a")?;
+        ref_store.set_parent("b", "main")?;
+        Ok(ref_store)
+    }
+
+    #[tokio::test]
+    async fn test_sync_parallel_rebases_independent_stacks_in_worktrees() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_independent_stacks(dir.path(), false)?;
+        git_in(dir.path(), &["checkout", "a2"]);
+
+        run_sync(SyncSettings {
+            no_cleanup: true,
+            restack: true,
+            strategy: Strategy::Rebase,
+            fetch_tags: true,
+            parallel: Some(2),
+            ..Default::default()
+        })
+        .await?;
+
+        let gateway = GitGateway::new()?;
+        assert!(gateway.is_branch_based_on("a", "main")?);
+        assert!(gateway.is_branch_based_on("a2", "a")?);
+        assert!(gateway.is_branch_based_on("b", "main")?);
+        assert_eq!(gateway.get_changed_files("main", "b")?, vec!["b.txt".to_string()]);
+
+        // Back on the user's branch, with its working tree matching the rebased tip
+        assert_eq!(gateway.get_current_branch_name()?, "a2");
+        assert!(dir.path().join("main.txt").exists());
+        assert!(!gateway.has_uncommitted_changes()?);
+        assert!(OperationState::load()?.is_none());
+        assert_scratch_worktrees_removed(dir.path());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_sync_parallel_conflict_in_one_stack_leaves_other_synced() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        setup_independent_stacks(dir.path(), true)?;
+
+        // On trunk, the conflicting stack is skipped like in a sequential sync
+        run_sync(SyncSettings {
+            no_cleanup: true,
+            restack: true,
+            strategy: Strategy::Rebase,
+            fetch_tags: true,
+            parallel: Some(2),
+            ..Default::default()
+        })
+        .await?;
+
+        let gateway = GitGateway::new()?;
+        assert!(gateway.is_branch_based_on("b", "main")?);
+        assert!(!gateway.is_branch_based_on("a", "main")?);
+        assert!(!gateway.rebase_in_progress()?);
+        assert!(OperationState::load()?.is_none());
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+        assert_scratch_worktrees_removed(dir.path());
+
+        // On the conflicting stack, the sync stops there for continue/abort
+        git_in(dir.path(), &["checkout", "a2"]);
+        run_sync(SyncSettings {
+            no_cleanup: true,
+            restack: true,
+            strategy: Strategy::Rebase,
+            fetch_tags: true,
+            parallel: Some(2),
+            ..Default::default()
+        })
+        .await?;
+
+        assert!(gateway.rebase_in_progress()?);
+        let state = OperationState::load()?.expect("sync state should be saved");
+        assert_eq!(state.current_branch.as_deref(), Some("a"));
+        assert!(!state.remaining_branches.contains(&"b".to_string()));
+        assert_scratch_worktrees_removed(dir.path());
+
+        Ok(())
+    }
 }
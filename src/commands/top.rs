@@ -40,7 +40,7 @@ pub fn run() -> Result<()> {
 
 /// Find the topmost branch (leaf) starting from the given branch
 /// If multiple children exist, picks the first one alphabetically
-fn find_stack_top(ref_store: &RefStore, start: &str) -> Result<String> {
+pub(crate) fn find_stack_top(ref_store: &RefStore, start: &str) -> Result<String> {
     let mut branch = start.to_string();
 
     loop {
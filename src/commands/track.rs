@@ -23,8 +23,13 @@ pub fn run_track(branch: Option<String>, parent: Option<String>) -> Result<()> {
         return Ok(());
     }
 
-    // Determine parent: explicit parent or default to trunk
+    // Determine parent: explicit parent, inferred from history, or default to trunk
     let parent_name = match parent {
+        Some(p) if p == "auto" => {
+            let gateway = GitGateway::new()?;
+            let trunk = ref_store.require_trunk()?;
+            infer_parent(&gateway, &ref_store, &branch_name, &trunk)?
+        }
         Some(p) => p,
         None => {
             // Default to trunk
@@ -69,6 +74,68 @@ pub fn run_untrack(branch: Option<String>) -> Result<()> {
     Ok(())
 }
 
+/// Pick a parent for `branch` for `track --parent auto`.
+///
+/// Uses the closest tracked branch (or trunk) in the branch's history. Ties
+/// prefer trunk, then the alphabetically first candidate; no match falls back to trunk.
+fn infer_parent(gateway: &GitGateway, ref_store: &RefStore, branch: &str, trunk: &str) -> Result<String> {
+    let candidates = infer_parent_candidates(gateway, ref_store, branch, trunk)?;
+    let parent = match candidates.as_slice() {
+        [] => trunk.to_string(),
+        [only] => only.clone(),
+        _ if candidates.iter().any(|c| c == trunk) => trunk.to_string(),
+        _ => candidates[0].clone(),
+    };
+
+    if candidates.len() > 1 {
+        println!(
+            "{} '{}' is equally close to {}; using '{}'",
+            "Note:".yellow(),
+            branch,
+            candidates.join(", "),
+            parent
+        );
+    }
+    Ok(parent)
+}
+
+/// Find the closest tracked branches (or trunk) whose tip is in `branch`'s history.
+///
+/// Returns every candidate tied for the fewest commits between it and `branch`,
+/// sorted alphabetically. Empty if none of them is an ancestor.
+pub(crate) fn infer_parent_candidates(
+    gateway: &GitGateway,
+    ref_store: &RefStore,
+    branch: &str,
+    trunk: &str,
+) -> Result<Vec<String>> {
+    let mut names = ref_store.list_tracked_branches()?;
+    names.push(trunk.to_string());
+    names.sort();
+    names.dedup();
+
+    let mut best: Option<usize> = None;
+    let mut candidates = Vec::new();
+
+    for name in names {
+        if name == branch || !gateway.branch_exists(&name)? || !gateway.is_branch_based_on(branch, &name)? {
+            continue;
+        }
+
+        let distance = gateway.get_commits_between(&name, branch)?.len();
+        match best {
+            Some(d) if distance > d => {}
+            Some(d) if distance == d => candidates.push(name),
+            _ => {
+                best = Some(distance);
+                candidates = vec![name];
+            }
+        }
+    }
+
+    Ok(candidates)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,6 +288,65 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_track_parent_auto_infers_feature_branch() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        let gateway = GitGateway::new()?;
+        ref_store.set_trunk("main")?;
+
+        // main -> feature (tracked), then an untracked branch off feature
+        gateway.create_branch("feature")?;
+        std::fs::write(dir.path().join("feature.txt"), "feature")?;
+        gateway.stage_all()?;
+        gateway.commit("Feature")?;
+        ref_store.set_parent("feature", "main")?;
+
+        gateway.create_branch("child")?;
+        std::fs::write(dir.path().join("child.txt"), "child")?;
+        gateway.stage_all()?;
+        gateway.commit("Child")?;
+
+        run_track(Some("child".to_string()), Some("auto".to_string()))?;
+
+        assert_eq!(ref_store.get_parent("child")?, Some("feature".to_string()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_track_parent_auto_infers_trunk() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let ref_store = RefStore::new()?;
+        let gateway = GitGateway::new()?;
+        ref_store.set_trunk("main")?;
+
+        // A tracked feature exists, but the new branch was cut from main
+        gateway.create_branch("feature")?;
+        std::fs::write(dir.path().join("feature.txt"), "feature")?;
+        gateway.stage_all()?;
+        gateway.commit("Feature")?;
+        ref_store.set_parent("feature", "main")?;
+
+        gateway.checkout_branch_worktree_safe("main")?;
+        gateway.create_branch("side")?;
+        std::fs::write(dir.path().join("side.txt"), "side")?;
+        gateway.stage_all()?;
+        gateway.commit("Side")?;
+
+        run_track(Some("side".to_string()), Some("auto".to_string()))?;
+
+        assert_eq!(ref_store.get_parent("side")?, Some("main".to_string()));
+
+        Ok(())
+    }
+
     #[test]
     fn test_track_with_parent_creates_relationship() -> Result<()> {
         let dir = tempdir()?;
@@ -1,5 +1,5 @@
 use crate::git_gateway::{BackupRef, GitGateway};
-use crate::operation_log::{Operation, OperationLog, OperationRecorder};
+use crate::operation_log::{Operation, OperationLog, OperationRecorder, UndoableOperation};
 use crate::program_name::program_name;
 use anyhow::{bail, Result};
 use chrono::{DateTime, Local, Utc};
@@ -52,7 +52,7 @@ fn list_backups(gateway: &GitGateway) -> Result<()> {
         println!("{}", branch.cyan().bold());
 
         // Sort by timestamp (newest first)
-        backups.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        backups.sort_by_key(|b| std::cmp::Reverse(b.timestamp));
 
         for (i, backup) in backups.iter().enumerate() {
             let timestamp = DateTime::from_timestamp(backup.timestamp as i64, 0)
@@ -135,12 +135,65 @@ fn undo_last_operation(gateway: &GitGateway, force: bool) -> Result<()> {
         return Ok(());
     };
 
-    // Find backups for these branches
-    let backups = gateway.list_backup_refs()?;
+    restore_operation(gateway, &op, force)
+}
+
+/// Undo a specific past operation by its history ID (see `dm history`)
+pub fn undo_operation_by_id(id: &str, force: bool) -> Result<()> {
+    let gateway = GitGateway::new()?;
+    let op = OperationLog::new()?.find_undoable_operation(id)?;
+    restore_operation(&gateway, &op, force)
+}
+
+/// Find the backups taken for an operation
+///
+/// Uses the exact refs logged before the operation; older logs without them
+/// fall back to backups created within 60s before the operation. Either way,
+/// the result is widened to every backup in the matched operation groups.
+fn find_operation_backups(op: &UndoableOperation, backups: &[BackupRef]) -> Result<Vec<BackupRef>> {
+    let matched = match_operation_backups(op, backups)?;
+
+    let groups: Vec<&str> = matched.iter().filter_map(|b| b.operation_id.as_deref()).collect();
+    let mut result: Vec<BackupRef> = backups
+        .iter()
+        .filter(|b| b.operation_id.as_deref().is_some_and(|id| groups.contains(&id)))
+        .cloned()
+        .collect();
+    result.extend(matched.into_iter().filter(|b| b.operation_id.is_none()));
+    Ok(result)
+}
+
+/// Backups directly referenced by (or timed with) an operation
+fn match_operation_backups(op: &UndoableOperation, backups: &[BackupRef]) -> Result<Vec<BackupRef>> {
+    if !op.backup_refs.is_empty() {
+        let missing: Vec<&str> = op
+            .backup_refs
+            .iter()
+            .filter(|r| !backups.iter().any(|b| &b.ref_name == *r))
+            .map(|r| r.as_str())
+            .collect();
+        if !missing.is_empty() {
+            bail!(
+                "Backups for {} operation {} are no longer available:\n  {}\n\n\
+                 They were likely removed by '{} gc', so this operation can't be undone.",
+                op.operation_type,
+                op.id,
+                missing.join("\n  "),
+                program_name()
+            );
+        }
+
+        return Ok(backups
+            .iter()
+            .filter(|b| op.backup_refs.contains(&b.ref_name))
+            .cloned()
+            .collect());
+    }
+
     let op_timestamp = op.timestamp.timestamp() as u64;
 
     // Match backups created just before/at operation time (within 60s tolerance)
-    let matching_backups: Vec<_> = op
+    Ok(op
         .branches
         .iter()
         .filter_map(|branch| {
@@ -151,18 +204,25 @@ fn undo_last_operation(gateway: &GitGateway, force: bool) -> Result<()> {
                 .max_by_key(|b| b.timestamp)
                 .cloned()
         })
-        .collect();
+        .collect())
+}
+
+/// Restore every branch touched by an operation to its pre-operation state
+fn restore_operation(gateway: &GitGateway, op: &UndoableOperation, force: bool) -> Result<()> {
+    let backups = gateway.list_backup_refs()?;
+    let matching_backups = find_operation_backups(op, &backups)?;
 
     if matching_backups.is_empty() {
-        bail!("No backups found for the last {} operation", op.operation_type);
+        bail!("No backups found for {} operation {}", op.operation_type, op.id);
     }
 
     // Show what we're about to do
     let time_ago = format_time_ago(op.timestamp);
     println!(
-        "{} Found last operation: {} ({})",
+        "{} Found operation: {} {} ({})",
         "🔄".blue(),
         op.operation_type.cyan(),
+        op.id.yellow(),
         time_ago
     );
     println!("   Affected branches:");
@@ -189,12 +249,12 @@ fn undo_last_operation(gateway: &GitGateway, force: bool) -> Result<()> {
         }
     }
 
-    // Restore all branches and log each restoration (for chain undo support)
+    // Restore all branches at once, then log each restoration (for chain undo support)
+    gateway.restore_backup_group(&matching_backups)?;
+
     let recorder = OperationRecorder::new()?;
     println!();
     for backup in &matching_backups {
-        gateway.restore_from_backup(backup)?;
-
         // Log the restoration so chain undo knows this operation was undone
         recorder.record(Operation::BackupRestored {
             branch: backup.branch_name.clone(),
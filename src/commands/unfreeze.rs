@@ -5,10 +5,16 @@ use crate::git_gateway::GitGateway;
 use crate::ref_store::RefStore;
 
 /// Unfreeze a branch (and optionally its upstack branches)
-pub fn run(branch: Option<String>, upstack: bool) -> Result<()> {
+///
+/// With `all`, every frozen branch in the repo is unfrozen instead.
+pub fn run(branch: Option<String>, upstack: bool, all: bool) -> Result<()> {
     let gateway = GitGateway::new()?;
     let ref_store = RefStore::new()?;
 
+    if all {
+        return unfreeze_all(&ref_store);
+    }
+
     // Use specified branch or current branch
     let branch = match branch {
         Some(b) => b,
@@ -63,6 +69,33 @@ pub fn run(branch: Option<String>, upstack: bool) -> Result<()> {
     Ok(())
 }
 
+/// Unfreeze every frozen branch, holding the RefStore lock throughout
+fn unfreeze_all(ref_store: &RefStore) -> Result<()> {
+    let _lock = ref_store.lock()?;
+
+    let frozen_branches = ref_store.list_frozen_branches()?;
+    if frozen_branches.is_empty() {
+        println!("{} No frozen branches to unfreeze", "!".yellow());
+        return Ok(());
+    }
+
+    for b in &frozen_branches {
+        ref_store
+            .set_frozen(b, false)
+            .context(format!("Failed to unfreeze '{}'", b))?;
+        println!("{} Unfroze '{}'", "✓".green(), b.cyan());
+    }
+
+    println!(
+        "\n{} Unfroze {} branch{}",
+        "✓".green().bold(),
+        frozen_branches.len(),
+        if frozen_branches.len() == 1 { "" } else { "es" }
+    );
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,7 +132,7 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Unfreeze without upstack flag
-        run(Some("feature".to_string()), false)?;
+        run(Some("feature".to_string()), false, false)?;
 
         assert!(!ref_store.is_frozen("feature")?);
 
@@ -120,7 +153,7 @@ mod tests {
 
         let _ctx = TestRepoContext::new(dir.path());
 
-        run(Some("feature".to_string()), false)?;
+        run(Some("feature".to_string()), false, false)?;
 
         Ok(())
     }
@@ -151,7 +184,7 @@ mod tests {
         let _ctx = TestRepoContext::new(dir.path());
 
         // Unfreeze with upstack=true
-        run(Some("feature".to_string()), true)?;
+        run(Some("feature".to_string()), true, false)?;
 
         assert!(!ref_store.is_frozen("feature")?);
         assert!(!ref_store.is_frozen("child1")?);
@@ -180,11 +213,45 @@ mod tests {
 
         let _ctx = TestRepoContext::new(dir.path());
 
-        run(Some("feature".to_string()), true)?;
+        run(Some("feature".to_string()), true, false)?;
 
         assert!(!ref_store.is_frozen("feature")?);
         assert!(!ref_store.is_frozen("child")?); // Was never frozen
 
         Ok(())
     }
+
+    #[test]
+    fn test_unfreeze_all_across_stacks() -> Result<()> {
+        let dir = tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let trunk = repo.head()?.shorthand().unwrap().to_string();
+
+        let ref_store = RefStore::from_path(dir.path())?;
+        ref_store.set_trunk(&trunk)?;
+
+        // Two independent stacks: trunk -> a1 -> a2, trunk -> b1 -> b2
+        create_branch(&repo, "a1")?;
+        create_branch(&repo, "a2")?;
+        create_branch(&repo, "b1")?;
+        create_branch(&repo, "b2")?;
+
+        ref_store.set_parent("a1", &trunk)?;
+        ref_store.set_parent("a2", "a1")?;
+        ref_store.set_parent("b1", &trunk)?;
+        ref_store.set_parent("b2", "b1")?;
+
+        ref_store.set_frozen("a1", true)?;
+        ref_store.set_frozen("a2", true)?;
+        ref_store.set_frozen("b2", true)?;
+
+        let _ctx = TestRepoContext::new(dir.path());
+
+        run(None, false, true)?;
+
+        assert!(ref_store.list_frozen_branches()?.is_empty());
+        assert!(!ref_store.is_frozen("b1")?);
+
+        Ok(())
+    }
 }
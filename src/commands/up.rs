@@ -1,14 +1,21 @@
+use crate::config::Config;
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use crate::ref_store::RefStore;
+use crate::stack_viz::print_breadcrumb;
 use anyhow::Result;
 
 /// Navigate to a child branch (up the stack)
 ///
 /// If there are multiple children, picks the first one alphabetically.
-/// If steps > 1, navigates multiple levels up.
+/// If steps > 1, navigates multiple levels up, stopping at the top of the stack
+/// when there are fewer levels; a fork on the way is an error, since it's
+/// ambiguous which branch to follow.
 /// If `to` is specified, navigates directly to that specific descendant branch.
-pub fn run(steps: usize, to: Option<String>) -> Result<()> {
+/// At the top of a stack, stays put unless `nav.wrap` is enabled (and `no_wrap`
+/// isn't set), in which case a single step wraps around to trunk, mirroring
+/// `down`, which wraps from trunk to the top of the stack.
+pub fn run(steps: usize, to: Option<String>, no_wrap: bool) -> Result<()> {
     let gateway = GitGateway::new()?;
     let ref_store = RefStore::new()?;
 
@@ -27,50 +34,90 @@ pub fn run(steps: usize, to: Option<String>) -> Result<()> {
         return Ok(());
     }
 
+    let wrap = !no_wrap && Config::load().map(|c| c.nav.wrap_enabled()).unwrap_or(false);
+    let trunk = ref_store.get_trunk()?;
+
+    // Walk the refs before checking anything out, so a fork stops `up N` where it started
+    let mut path: Vec<String> = Vec::new();
+    let mut wrapped = false;
+    let mut sibling_count = 0;
     let mut current_branch = current;
 
-    for step in 0..steps {
-        // Get children from refs
-        let children_set = ref_store.get_children(&current_branch)?;
+    while path.len() < steps {
+        // Get children from refs, sorted so the first alphabetically is picked
+        let mut children: Vec<_> = ref_store.get_children(&current_branch)?.into_iter().collect();
+        children.sort();
 
-        if children_set.is_empty() {
-            if step == 0 {
+        let target = match children.as_slice() {
+            [] if !path.is_empty() => break,
+            [] => {
                 // Check if current branch is tracked
-                let trunk = ref_store.get_trunk()?.unwrap_or_default();
-                if current_branch != trunk && !ref_store.is_tracked(&current_branch)? {
+                if trunk.as_deref() != Some(current_branch.as_str()) && !ref_store.is_tracked(&current_branch)? {
                     anyhow::bail!(
                         "Branch '{}' is not tracked. Run '{} track' to add it to a stack.",
                         current_branch,
                         program_name()
                     );
                 }
-                anyhow::bail!("No child branches");
-            } else {
-                anyhow::bail!("Reached stack top after {} step(s)", step);
-            }
-        }
 
-        // Pick first child (alphabetically)
-        let mut children: Vec<_> = children_set.iter().collect();
-        children.sort();
-        let child = children[0];
+                match trunk.as_deref() {
+                    Some(trunk_branch) if wrap && steps == 1 && trunk_branch != current_branch => {
+                        wrapped = true;
+                        trunk_branch.to_string()
+                    }
+                    _ => {
+                        println!("Already at top of stack: {}", current_branch);
+                        return Ok(());
+                    }
+                }
+            }
+            [only] => only.clone(),
+            // A single step just takes the first child; more steps would be a guess
+            [first, ..] if steps == 1 => {
+                sibling_count = children.len();
+                first.clone()
+            }
+            _ => anyhow::bail!(
+                "Stack forks at '{}' after {} step(s), children:\n  {}\n\
+                 Use '{} up --to <branch>' to pick one.",
+                current_branch,
+                path.len(),
+                children.join("\n  "),
+                program_name()
+            ),
+        };
+
+        path.push(target.clone());
+        current_branch = target;
+    }
 
-        // Checkout child safely (fail if uncommitted changes)
-        gateway.checkout_branch_worktree_safe(child)?;
+    for (step, target) in path.iter().enumerate() {
+        // Checkout target safely (fail if uncommitted changes)
+        gateway.checkout_branch_worktree_safe(target)?;
 
         if steps == 1 {
-            if children.len() > 1 {
-                println!("Switched to child branch: {} (of {} children)", child, children.len());
+            if wrapped {
+                println!("Wrapped to trunk: {}", target);
+            } else if sibling_count > 1 {
+                println!("Switched to child branch: {} (of {} children)", target, sibling_count);
             } else {
-                println!("Switched to child branch: {}", child);
+                println!("Switched to child branch: {}", target);
             }
         } else {
-            println!("Step {}: switched to {}", step + 1, child);
+            println!("Step {}: switched to {}", step + 1, target);
         }
+    }
 
-        current_branch.clone_from(child);
+    if path.len() < steps {
+        println!(
+            "Reached top after {} step{}: {}",
+            path.len(),
+            if path.len() == 1 { "" } else { "s" },
+            current_branch
+        );
     }
 
+    print_breadcrumb(&ref_store, &current_branch);
     Ok(())
 }
 
@@ -90,6 +137,7 @@ fn navigate_to_branch(gateway: &GitGateway, ref_store: &RefStore, from: &str, ta
     // Checkout the target branch safely (fail if uncommitted changes)
     gateway.checkout_branch_worktree_safe(target)?;
     println!("Switched to descendant branch: {}", target);
+    print_breadcrumb(ref_store, target);
 
     Ok(())
 }
@@ -118,6 +166,7 @@ fn is_descendant(ref_store: &RefStore, branch: &str, ancestor: &str) -> Result<b
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::LocalConfig;
     use crate::git_gateway::GitGateway;
     use tempfile::tempdir;
 
@@ -143,7 +192,7 @@ mod tests {
         ref_store.set_parent("feature", "main")?;
 
         // Now on main, go up to feature
-        run(1, None)?;
+        run(1, None, false)?;
 
         assert_eq!(gateway.get_current_branch_name()?, "feature");
         Ok(())
@@ -173,14 +222,14 @@ mod tests {
         ref_store.set_parent("middle", "main")?;
 
         // Now on main, should go to "apple" (first alphabetically)
-        run(1, None)?;
+        run(1, None, false)?;
 
         assert_eq!(gateway.get_current_branch_name()?, "apple");
         Ok(())
     }
 
     #[test]
-    fn test_up_no_children_fails() -> Result<()> {
+    fn test_up_at_top_stays_put() -> Result<()> {
         let dir = tempdir()?;
         let _repo = init_test_repo_with_branch(dir.path(), "main")?;
         let _ctx = TestRepoContext::new(dir.path());
@@ -195,10 +244,9 @@ mod tests {
         ref_store.set_trunk("main")?;
         ref_store.set_parent("feature", "main")?;
 
-        // Try to go up from feature (no children)
-        let result = run(1, None);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("No child"));
+        // Going up from feature (no children) is a friendly no-op
+        run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "feature");
 
         Ok(())
     }
@@ -219,7 +267,7 @@ mod tests {
         gateway.create_branch("untracked")?;
 
         // Try to go up from untracked branch - should give helpful message
-        let result = run(1, None);
+        let result = run(1, None, false);
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -257,18 +305,18 @@ mod tests {
         // Navigate all the way up
         assert_eq!(gateway.get_current_branch_name()?, "main");
 
-        run(1, None)?;
+        run(1, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "level1");
 
-        run(1, None)?;
+        run(1, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "level2");
 
-        run(1, None)?;
+        run(1, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "level3");
 
-        // Can't go further
-        let result = run(1, None);
-        assert!(result.is_err());
+        // Already at the top: stays put
+        run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "level3");
 
         Ok(())
     }
@@ -299,14 +347,14 @@ mod tests {
         // From main, navigate up 2 steps
         assert_eq!(gateway.get_current_branch_name()?, "main");
 
-        run(2, None)?;
+        run(2, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "level2");
 
         Ok(())
     }
 
     #[test]
-    fn test_up_too_many_steps_fails() -> Result<()> {
+    fn test_up_too_many_steps_clamps_to_leaf() -> Result<()> {
         let dir = tempdir()?;
         let _repo = init_test_repo_with_branch(dir.path(), "main")?;
         let _ctx = TestRepoContext::new(dir.path());
@@ -314,18 +362,50 @@ mod tests {
         let gateway = GitGateway::new()?;
         let ref_store = RefStore::new()?;
 
-        // Create feature branch
-        gateway.create_branch("feature")?;
+        // Set up refs: main -> level1 -> level2
+        gateway.create_branch("level1")?;
+        gateway.create_branch("level2")?;
         gateway.checkout_branch_worktree_safe("main")?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("level1", "main")?;
+        ref_store.set_parent("level2", "level1")?;
 
-        // Set up refs: main -> feature
+        // Asking for more steps than there are levels stops at the leaf
+        run(100, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "level2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_up_many_steps_through_fork_fails_without_moving() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+
+        // Set up refs: main -> base -> {left, right}
+        gateway.create_branch("base")?;
+        gateway.create_branch("left")?;
+        gateway.checkout_branch_worktree_safe("base")?;
+        gateway.create_branch("right")?;
+        gateway.checkout_branch_worktree_safe("main")?;
         ref_store.set_trunk("main")?;
-        ref_store.set_parent("feature", "main")?;
+        ref_store.set_parent("base", "main")?;
+        ref_store.set_parent("left", "base")?;
+        ref_store.set_parent("right", "base")?;
 
-        // Try to go up 5 steps (only 1 child exists)
-        let result = run(5, None);
-        assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Reached stack top"));
+        let err = run(100, None, false).unwrap_err().to_string();
+        assert!(err.contains("forks at 'base'"), "got: {}", err);
+        assert!(err.contains("left") && err.contains("right"), "got: {}", err);
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+
+        // A single step still just takes the first child
+        run(1, None, false)?;
+        run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "left");
 
         Ok(())
     }
@@ -346,7 +426,7 @@ mod tests {
         ref_store.set_parent("feature", "main")?;
 
         // Zero steps should be a no-op
-        run(0, None)?;
+        run(0, None, false)?;
         assert_eq!(gateway.get_current_branch_name()?, "main");
 
         Ok(())
@@ -373,7 +453,7 @@ mod tests {
         ref_store.set_parent("level3", "level2")?;
 
         // Navigate directly to level3 using --to
-        run(1, Some("level3".to_string()))?;
+        run(1, Some("level3".to_string()), false)?;
         assert_eq!(gateway.get_current_branch_name()?, "level3");
 
         Ok(())
@@ -395,7 +475,7 @@ mod tests {
         ref_store.set_parent("level1", "main")?;
 
         // Try to navigate to a branch that doesn't exist
-        let result = run(1, Some("nonexistent".to_string()));
+        let result = run(1, Some("nonexistent".to_string()), false);
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(
@@ -427,7 +507,7 @@ mod tests {
         ref_store.set_parent("stack2", "main")?;
 
         // From stack1, try to go to stack2 (not a descendant, it's a sibling)
-        let result = run(1, Some("stack2".to_string()));
+        let result = run(1, Some("stack2".to_string()), false);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("not a descendant"));
 
@@ -471,7 +551,7 @@ mod tests {
         std::fs::write(&file_path, "modified uncommitted content")?;
 
         // Try to navigate up - should fail with uncommitted changes
-        let result = run(1, None);
+        let result = run(1, None, false);
         assert!(result.is_err(), "dm up should fail with uncommitted changes");
 
         let err_msg = result.unwrap_err().to_string();
@@ -493,4 +573,68 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_up_wraps_to_trunk_when_enabled() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+
+        // Set up refs: main -> level1 -> level2, currently on level2
+        gateway.create_branch("level1")?;
+        gateway.create_branch("level2")?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("level1", "main")?;
+        ref_store.set_parent("level2", "level1")?;
+
+        let mut config = LocalConfig::default();
+        config.nav.wrap = Some(true);
+        Config::save_local_config(&config)?;
+
+        // --no-wrap overrides the config
+        run(1, None, true)?;
+        assert_eq!(gateway.get_current_branch_name()?, "level2");
+
+        // With wrap enabled, going up from the top lands on trunk
+        run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "main");
+
+        // ...and going down from trunk lands back on the top
+        crate::commands::down::run(1, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "level2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_up_multiple_steps_does_not_wrap() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo_with_branch(dir.path(), "main")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        let ref_store = RefStore::new()?;
+
+        // Set up refs: main -> level1 -> level2 -> level3, currently on level3
+        gateway.create_branch("level1")?;
+        gateway.create_branch("level2")?;
+        gateway.create_branch("level3")?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("level1", "main")?;
+        ref_store.set_parent("level2", "level1")?;
+        ref_store.set_parent("level3", "level2")?;
+
+        let mut config = LocalConfig::default();
+        config.nav.wrap = Some(true);
+        Config::save_local_config(&config)?;
+
+        // Wrapping and walking back up would be surprising; stay at the top instead
+        run(2, None, false)?;
+        assert_eq!(gateway.get_current_branch_name()?, "level3");
+
+        Ok(())
+    }
 }
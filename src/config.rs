@@ -9,11 +9,14 @@
 
 use anyhow::{Context, Result};
 use chrono::Local;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fs::{self, File};
 use std::io::BufReader;
 use std::path::PathBuf;
+use std::sync::LazyLock;
 
+use crate::git_gateway::Strategy;
 use crate::platform::DisplayPath;
 use crate::state::find_git_root;
 
@@ -85,6 +88,257 @@ impl Default for MergeConfig {
     }
 }
 
+/// Web UI configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct WebConfig {
+    /// Base URL for opening PRs in a browser when the web host differs from the
+    /// API host (e.g., "https://git.example.com" behind a reverse proxy)
+    #[serde(default)]
+    pub base_url: Option<String>,
+}
+
+/// Stack navigation configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NavConfig {
+    /// Wrap `up`/`down` around to the opposite end of the stack. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wrap: Option<bool>,
+
+    /// Print a one-line stack breadcrumb after `up`/`down`/`checkout`. Default: true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breadcrumb: Option<bool>,
+}
+
+impl NavConfig {
+    /// Whether `up`/`down` wrap around (off unless explicitly enabled)
+    pub fn wrap_enabled(&self) -> bool {
+        self.wrap.unwrap_or(false)
+    }
+
+    /// Whether the navigation breadcrumb is shown (on unless explicitly disabled)
+    pub fn breadcrumb_enabled(&self) -> bool {
+        self.breadcrumb.unwrap_or(true)
+    }
+}
+
+/// Checkout configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CheckoutConfig {
+    /// Track untracked branches when checking them out. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_track: Option<bool>,
+
+    /// Order of the interactive checkout list: "depth", "recent" or "alpha". Default: depth
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<CheckoutSort>,
+}
+
+impl CheckoutConfig {
+    /// Whether checkout tracks untracked branches (off unless explicitly enabled)
+    pub fn auto_track_enabled(&self) -> bool {
+        self.auto_track.unwrap_or(false)
+    }
+}
+
+/// How the interactive checkout list is ordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckoutSort {
+    /// Stack order: stacks grouped, children above their parents
+    #[default]
+    Depth,
+    /// Stack order, with the most recently committed stacks on top
+    Recent,
+    /// Flat list of branch names in alphabetical order
+    Alpha,
+}
+
+impl std::fmt::Display for CheckoutSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckoutSort::Depth => write!(f, "depth"),
+            CheckoutSort::Recent => write!(f, "recent"),
+            CheckoutSort::Alpha => write!(f, "alpha"),
+        }
+    }
+}
+
+impl std::str::FromStr for CheckoutSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "depth" => Ok(CheckoutSort::Depth),
+            "recent" => Ok(CheckoutSort::Recent),
+            "alpha" => Ok(CheckoutSort::Alpha),
+            _ => Err(format!(
+                "Unknown checkout sort '{}' (expected 'depth', 'recent' or 'alpha')",
+                s
+            )),
+        }
+    }
+}
+
+/// Log configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LogConfig {
+    /// Where `log short`/`log long` put trunk: "trunk-bottom" or "trunk-top". Default: trunk-bottom
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orientation: Option<LogOrientation>,
+}
+
+/// Vertical orientation of the rendered stack in `log short`/`log long`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LogOrientation {
+    /// Trunk on the last line, branch tips above it
+    #[default]
+    TrunkBottom,
+    /// Trunk on the first line, branch tips below it
+    TrunkTop,
+}
+
+impl LogOrientation {
+    /// The opposite orientation (what `log --reverse` shows)
+    pub fn reversed(self) -> Self {
+        match self {
+            LogOrientation::TrunkBottom => LogOrientation::TrunkTop,
+            LogOrientation::TrunkTop => LogOrientation::TrunkBottom,
+        }
+    }
+}
+
+impl std::fmt::Display for LogOrientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogOrientation::TrunkBottom => write!(f, "trunk-bottom"),
+            LogOrientation::TrunkTop => write!(f, "trunk-top"),
+        }
+    }
+}
+
+impl std::str::FromStr for LogOrientation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "trunk-bottom" => Ok(LogOrientation::TrunkBottom),
+            "trunk-top" => Ok(LogOrientation::TrunkTop),
+            _ => Err(format!(
+                "Unknown log orientation '{}' (expected 'trunk-bottom' or 'trunk-top')",
+                s
+            )),
+        }
+    }
+}
+
+/// Submit configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SubmitConfig {
+    /// PR body template for new PRs. Default: .github/pull_request_template.md if present
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_path: Option<String>,
+
+    /// Maintain the stack visualization block in PR bodies. Default: true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack_viz: Option<bool>,
+}
+
+impl SubmitConfig {
+    /// Whether PR bodies get the stack visualization block (on unless explicitly disabled)
+    pub fn stack_viz_enabled(&self) -> bool {
+        self.stack_viz.unwrap_or(true)
+    }
+}
+
+/// Sync configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SyncConfig {
+    /// Stash uncommitted changes around sync. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autostash: Option<bool>,
+
+    /// How sync updates branches onto their parents: "rebase" or "merge". Default: rebase
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<Strategy>,
+
+    /// Fetch tags along with branches during sync. Default: true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch_tags: Option<bool>,
+
+    /// Flags applied to every `sync` before the command-line ones. Default: none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_flags: Option<Vec<String>>,
+}
+
+impl SyncConfig {
+    /// Whether sync stashes uncommitted changes (off unless explicitly enabled)
+    pub fn autostash_enabled(&self) -> bool {
+        self.autostash.unwrap_or(false)
+    }
+
+    /// Whether sync fetches tags (on unless explicitly disabled)
+    pub fn fetch_tags_enabled(&self) -> bool {
+        self.fetch_tags.unwrap_or(true)
+    }
+}
+
+/// Commit configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CommitConfig {
+    /// Reject `-m` messages that aren't conventional commits. Default: false
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_conventional: Option<bool>,
+}
+
+impl CommitConfig {
+    /// Whether `-m` messages must be conventional commits (off unless explicitly enabled)
+    pub fn require_conventional_enabled(&self) -> bool {
+        self.require_conventional.unwrap_or(false)
+    }
+}
+
+/// Terminal UI configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UiConfig {
+    /// Animate progress spinners. Default: true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spinner: Option<bool>,
+}
+
+impl UiConfig {
+    /// Whether progress spinners are animated (on unless explicitly disabled)
+    pub fn spinner_enabled(&self) -> bool {
+        self.spinner.unwrap_or(true)
+    }
+}
+
+/// Forge CLI configuration
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ForgeCliConfig {
+    /// Path to the `gh` executable when it isn't on PATH. Default: none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gh_path: Option<String>,
+
+    /// Path to the `glab` executable when it isn't on PATH. Default: none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub glab_path: Option<String>,
+
+    /// How long fetched PR status is reused before asking the forge again (seconds, 0 disables). Default: 60
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_cache_ttl_secs: Option<u64>,
+}
+
+/// Default PR status cache lifetime in seconds
+const DEFAULT_PR_CACHE_TTL_SECS: u64 = 60;
+
+impl ForgeCliConfig {
+    /// Seconds a cached PR status stays fresh (0 means never reuse it)
+    pub fn pr_cache_ttl_secs(&self) -> u64 {
+        self.pr_cache_ttl_secs.unwrap_or(DEFAULT_PR_CACHE_TTL_SECS)
+    }
+}
+
 /// Repository-level configuration (stored in .diamond/config.toml, committed)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RepoConfig {
@@ -108,6 +362,24 @@ pub struct UserConfig {
     pub branch: BranchConfig,
     #[serde(default)]
     pub merge: MergeConfig,
+    #[serde(default)]
+    pub web: WebConfig,
+    #[serde(default)]
+    pub nav: NavConfig,
+    #[serde(default)]
+    pub checkout: CheckoutConfig,
+    #[serde(default)]
+    pub log: LogConfig,
+    #[serde(default)]
+    pub submit: SubmitConfig,
+    #[serde(default)]
+    pub sync: SyncConfig,
+    #[serde(default)]
+    pub commit: CommitConfig,
+    #[serde(default)]
+    pub ui: UiConfig,
+    #[serde(default)]
+    pub forge: ForgeCliConfig,
 }
 
 /// Local override configuration (stored in .git/diamond/)
@@ -117,6 +389,24 @@ pub struct LocalConfig {
     pub branch: BranchConfig,
     #[serde(default)]
     pub merge: MergeConfig,
+    #[serde(default)]
+    pub web: WebConfig,
+    #[serde(default)]
+    pub nav: NavConfig,
+    #[serde(default)]
+    pub checkout: CheckoutConfig,
+    #[serde(default)]
+    pub log: LogConfig,
+    #[serde(default)]
+    pub submit: SubmitConfig,
+    #[serde(default)]
+    pub sync: SyncConfig,
+    #[serde(default)]
+    pub commit: CommitConfig,
+    #[serde(default)]
+    pub ui: UiConfig,
+    #[serde(default)]
+    pub forge: ForgeCliConfig,
 }
 
 /// Merged configuration from all layers
@@ -127,6 +417,54 @@ pub struct Config {
     pub remote: String,
     /// Merge operation settings
     pub merge: MergeConfig,
+    /// Web UI settings
+    pub web: WebConfig,
+    /// Navigation settings
+    pub nav: NavConfig,
+    /// Checkout settings
+    pub checkout: CheckoutConfig,
+    /// Log settings
+    pub log: LogConfig,
+    /// Submit settings
+    pub submit: SubmitConfig,
+    /// Sync settings
+    pub sync: SyncConfig,
+    /// Commit settings
+    pub commit: CommitConfig,
+    /// Terminal UI settings
+    pub ui: UiConfig,
+    /// Forge CLI settings
+    pub forge: ForgeCliConfig,
+}
+
+impl Default for Config {
+    /// The effective configuration when no config file sets anything
+    fn default() -> Self {
+        Self {
+            branch: BranchConfig::default(),
+            remote: default_remote(),
+            merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
+        }
+    }
+}
+
+/// Precompiled regex for a conventional commit subject line
+static CONVENTIONAL_COMMIT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z]+(\([^()\s][^()]*\))?!?: \S").unwrap());
+
+/// Whether the subject line follows the conventional commit format:
+/// `type(scope)!: description`, with the scope and `!` optional
+pub fn is_conventional_commit(message: &str) -> bool {
+    CONVENTIONAL_COMMIT_RE.is_match(message.lines().next().unwrap_or_default())
 }
 
 impl Config {
@@ -151,10 +489,76 @@ impl Config {
             &local_config.as_ref().map(|c| &c.merge),
         );
 
+        // Local overrides user
+        let web = WebConfig {
+            base_url: local_config
+                .as_ref()
+                .and_then(|c| c.web.base_url.clone())
+                .or(user_config.web.base_url),
+        };
+
+        let nav = Self::merge_nav_config(&user_config.nav, &local_config.as_ref().map(|c| &c.nav));
+        let checkout = Self::merge_checkout_config(&user_config.checkout, &local_config.as_ref().map(|c| &c.checkout));
+        // Local overrides user
+        let log = LogConfig {
+            orientation: local_config
+                .as_ref()
+                .and_then(|c| c.log.orientation)
+                .or(user_config.log.orientation),
+        };
+        // Local overrides user
+        let submit = SubmitConfig {
+            template_path: local_config
+                .as_ref()
+                .and_then(|c| c.submit.template_path.clone())
+                .or(user_config.submit.template_path),
+            stack_viz: local_config
+                .as_ref()
+                .and_then(|c| c.submit.stack_viz)
+                .or(user_config.submit.stack_viz),
+        };
+        let sync = Self::merge_sync_config(&user_config.sync, &local_config.as_ref().map(|c| &c.sync));
+        let commit = Self::merge_commit_config(&user_config.commit, &local_config.as_ref().map(|c| &c.commit));
+        // Local overrides user
+        let ui = UiConfig {
+            spinner: local_config
+                .as_ref()
+                .and_then(|c| c.ui.spinner)
+                .or(user_config.ui.spinner),
+        };
+        // Local overrides user
+        let forge = ForgeCliConfig {
+            gh_path: local_config
+                .as_ref()
+                .and_then(|c| c.forge.gh_path.clone())
+                .or(user_config.forge.gh_path),
+            glab_path: local_config
+                .as_ref()
+                .and_then(|c| c.forge.glab_path.clone())
+                .or(user_config.forge.glab_path),
+            pr_cache_ttl_secs: local_config
+                .as_ref()
+                .and_then(|c| c.forge.pr_cache_ttl_secs)
+                .or(user_config.forge.pr_cache_ttl_secs),
+        };
+
         // Remote comes from repo config (committed, shared)
         let remote = repo_config.remote;
 
-        Ok(Config { branch, remote, merge })
+        Ok(Config {
+            branch,
+            remote,
+            merge,
+            web,
+            nav,
+            checkout,
+            log,
+            submit,
+            sync,
+            commit,
+            ui,
+            forge,
+        })
     }
 
     /// Load repo config from .diamond/config.toml (committed, shared)
@@ -245,6 +649,41 @@ impl Config {
     }
 
     /// Merge merge configs with priority: local > user > defaults
+    /// Merge nav config: local overrides user for each setting it sets
+    fn merge_nav_config(user: &NavConfig, local: &Option<&NavConfig>) -> NavConfig {
+        NavConfig {
+            wrap: local.and_then(|c| c.wrap).or(user.wrap),
+            breadcrumb: local.and_then(|c| c.breadcrumb).or(user.breadcrumb),
+        }
+    }
+
+    /// Merge checkout config: local overrides user for each setting it sets
+    fn merge_checkout_config(user: &CheckoutConfig, local: &Option<&CheckoutConfig>) -> CheckoutConfig {
+        CheckoutConfig {
+            auto_track: local.and_then(|c| c.auto_track).or(user.auto_track),
+            sort: local.and_then(|c| c.sort).or(user.sort),
+        }
+    }
+
+    /// Merge sync config: local overrides user for each setting it sets
+    fn merge_sync_config(user: &SyncConfig, local: &Option<&SyncConfig>) -> SyncConfig {
+        SyncConfig {
+            autostash: local.and_then(|c| c.autostash).or(user.autostash),
+            strategy: local.and_then(|c| c.strategy).or(user.strategy),
+            fetch_tags: local.and_then(|c| c.fetch_tags).or(user.fetch_tags),
+            default_flags: local
+                .and_then(|c| c.default_flags.clone())
+                .or(user.default_flags.clone()),
+        }
+    }
+
+    /// Merge commit config: local overrides user for each setting it sets
+    fn merge_commit_config(user: &CommitConfig, local: &Option<&CommitConfig>) -> CommitConfig {
+        CommitConfig {
+            require_conventional: local.and_then(|c| c.require_conventional).or(user.require_conventional),
+        }
+    }
+
     fn merge_merge_config(defaults: &MergeConfig, user: &MergeConfig, local: &Option<&MergeConfig>) -> MergeConfig {
         // Start with defaults
         let mut result = defaults.clone();
@@ -312,6 +751,41 @@ impl Config {
             .replace("{name}", name)
     }
 
+    /// Apply the configured prefix to a branch name, unless it already has it
+    pub fn with_branch_prefix(&self, name: &str) -> String {
+        match self.branch.prefix.as_deref() {
+            Some(prefix) if !prefix.is_empty() && !name.starts_with(prefix) => format!("{}{}", prefix, name),
+            _ => name.to_string(),
+        }
+    }
+
+    /// Name for a branch Diamond generates (e.g., from a commit message).
+    ///
+    /// Formats `name` with the template; when the template has no `{prefix}`
+    /// placeholder, the prefix is put in front so every new branch carries it.
+    pub fn generated_branch_name(&self, name: &str) -> String {
+        let formatted = self.format_branch_name(name);
+        if self.branch.format.contains("{prefix}") {
+            formatted
+        } else {
+            self.with_branch_prefix(&formatted)
+        }
+    }
+
+    /// Check a `-m` commit message against `commit.require_conventional`
+    pub fn check_commit_message(&self, message: &str) -> Result<()> {
+        if self.commit.require_conventional_enabled() && !is_conventional_commit(message) {
+            anyhow::bail!(
+                "Commit message '{}' is not a conventional commit (commit.require_conventional is on).\n\
+                 Use '<type>(<optional scope>): <description>', for example:\n  \
+                 feat(auth): add token refresh\n  \
+                 fix: handle empty branch names",
+                message.lines().next().unwrap_or_default()
+            );
+        }
+        Ok(())
+    }
+
     /// Save user config to ~/.config/diamond/config.toml
     pub fn save_user_config(config: &UserConfig) -> Result<()> {
         let path = Self::user_config_path()?;
@@ -382,6 +856,15 @@ mod tests {
             branch: BranchConfig::default(),
             remote: default_remote(),
             merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
         };
 
         // Default format is "{date}-{name}"
@@ -399,6 +882,15 @@ mod tests {
             },
             remote: default_remote(),
             merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
         };
 
         let result = config.format_branch_name("add_feature");
@@ -414,6 +906,15 @@ mod tests {
             },
             remote: default_remote(),
             merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
         };
 
         let result = config.format_branch_name("add_feature");
@@ -430,6 +931,15 @@ mod tests {
             },
             remote: default_remote(),
             merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
         };
 
         // {prefix} becomes empty string when not configured
@@ -446,6 +956,15 @@ mod tests {
             },
             remote: default_remote(),
             merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
         };
 
         // Prefix is set but not in format, so ignored
@@ -463,6 +982,15 @@ mod tests {
             },
             remote: default_remote(),
             merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
         };
 
         let result = config.format_branch_name("my-branch");
@@ -538,6 +1066,15 @@ prefix = "jd-"
                 prefix: Some("test/".to_string()),
             },
             merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
         };
 
         // Write config
@@ -586,6 +1123,22 @@ prefix = "jd-"
         assert!(toml.contains("wait_for_ci = true"));
     }
 
+    #[test]
+    fn test_checkout_sort_deserialization() {
+        let config: UserConfig = toml::from_str("[checkout]\nsort = \"recent\"\n").unwrap();
+        assert_eq!(config.checkout.sort, Some(CheckoutSort::Recent));
+        assert_eq!("ALPHA".parse::<CheckoutSort>(), Ok(CheckoutSort::Alpha));
+        assert!(toml::from_str::<UserConfig>("[checkout]\nsort = \"size\"\n").is_err());
+    }
+
+    #[test]
+    fn test_log_orientation_deserialization() {
+        let config: UserConfig = toml::from_str("[log]\norientation = \"trunk-top\"\n").unwrap();
+        assert_eq!(config.log.orientation, Some(LogOrientation::TrunkTop));
+        assert_eq!(LogOrientation::default().to_string(), "trunk-bottom");
+        assert!("sideways".parse::<LogOrientation>().is_err());
+    }
+
     #[test]
     fn test_merge_config_deserialization_partial() {
         // Only specify some fields, others should default
@@ -635,6 +1188,80 @@ proactive_rebase = false
         assert!(config.merge.wait_for_ci); // default
     }
 
+    #[test]
+    fn test_sync_strategy_deserialization() {
+        let config: UserConfig = toml::from_str("[sync]\nstrategy = \"merge\"\n").unwrap();
+        assert_eq!(config.sync.strategy, Some(Strategy::Merge));
+        assert!(!config.sync.autostash_enabled());
+
+        let config: UserConfig = toml::from_str("[sync]\nautostash = true\n").unwrap();
+        assert_eq!(config.sync.strategy, None);
+
+        assert!(toml::from_str::<UserConfig>("[sync]\nstrategy = \"squash\"\n").is_err());
+    }
+
+    #[test]
+    fn test_merge_nav_config_local_overrides_user() {
+        let user = NavConfig {
+            wrap: Some(true),
+            breadcrumb: None,
+        };
+
+        // A local `false` turns off wrap enabled by the user config
+        let local = NavConfig {
+            wrap: Some(false),
+            breadcrumb: None,
+        };
+        assert!(!Config::merge_nav_config(&user, &Some(&local)).wrap_enabled());
+
+        // Without a local setting the user's choice stands
+        assert!(Config::merge_nav_config(&user, &Some(&NavConfig::default())).wrap_enabled());
+        assert!(Config::merge_nav_config(&user, &None).wrap_enabled());
+    }
+
+    #[test]
+    fn test_merge_checkout_config_local_overrides_user() {
+        let user = CheckoutConfig {
+            auto_track: Some(true),
+            sort: None,
+        };
+        let local = CheckoutConfig {
+            auto_track: Some(false),
+            sort: None,
+        };
+
+        assert!(!Config::merge_checkout_config(&user, &Some(&local)).auto_track_enabled());
+        assert!(Config::merge_checkout_config(&user, &None).auto_track_enabled());
+    }
+
+    #[test]
+    fn test_merge_sync_config_local_overrides_user() {
+        let user = SyncConfig {
+            autostash: Some(true),
+            ..Default::default()
+        };
+        let local = SyncConfig {
+            autostash: Some(false),
+            ..Default::default()
+        };
+
+        assert!(!Config::merge_sync_config(&user, &Some(&local)).autostash_enabled());
+        assert!(Config::merge_sync_config(&user, &None).autostash_enabled());
+    }
+
+    #[test]
+    fn test_merge_commit_config_local_overrides_user() {
+        let user = CommitConfig {
+            require_conventional: Some(true),
+        };
+        let local = CommitConfig {
+            require_conventional: Some(false),
+        };
+
+        assert!(!Config::merge_commit_config(&user, &Some(&local)).require_conventional_enabled());
+        assert!(Config::merge_commit_config(&user, &None).require_conventional_enabled());
+    }
+
     #[test]
     fn test_merge_merge_config_priority() {
         let defaults = MergeConfig::default();
@@ -658,4 +1285,50 @@ proactive_rebase = false
         // wait_for_ci: user is false, local can't re-enable (once disabled, stays disabled)
         assert!(!result.wait_for_ci);
     }
+
+    #[test]
+    fn test_conventional_commit_accepts_typed_subjects() {
+        assert!(is_conventional_commit("feat(x): y"));
+        assert!(is_conventional_commit("fix: handle empty names"));
+        assert!(is_conventional_commit(
+            "refactor(config)!: drop legacy keys\n\nBody text"
+        ));
+    }
+
+    #[test]
+    fn test_conventional_commit_rejects_free_form_subjects() {
+        assert!(!is_conventional_commit("added stuff"));
+        assert!(!is_conventional_commit("feat:missing space"));
+        assert!(!is_conventional_commit("feat(): empty scope"));
+        assert!(!is_conventional_commit("Feat: capitalized type"));
+        assert!(!is_conventional_commit(""));
+    }
+
+    #[test]
+    fn test_check_commit_message_only_enforced_when_enabled() {
+        let mut config = Config {
+            branch: BranchConfig::default(),
+            remote: default_remote(),
+            merge: MergeConfig::default(),
+            web: WebConfig::default(),
+            nav: NavConfig::default(),
+            checkout: CheckoutConfig::default(),
+            log: LogConfig::default(),
+            submit: SubmitConfig::default(),
+            sync: SyncConfig::default(),
+            commit: CommitConfig::default(),
+            ui: UiConfig::default(),
+            forge: ForgeCliConfig::default(),
+        };
+        assert!(config.check_commit_message("added stuff").is_ok());
+
+        config.commit.require_conventional = Some(true);
+        let err = config.check_commit_message("added stuff").unwrap_err().to_string();
+        assert!(
+            err.contains("feat(auth): add token refresh"),
+            "error should show an example: {}",
+            err
+        );
+        assert!(config.check_commit_message("feat(x): y").is_ok());
+    }
 }
@@ -228,6 +228,7 @@ mod tests {
                 ci: status,
                 head_ref: "branch".to_string(),
                 base_ref: "main".to_string(),
+                mergeable: None,
             })
         }
 
@@ -554,6 +555,7 @@ mod tests {
                 ci: self.final_status,
                 head_ref: "branch".to_string(),
                 base_ref: "main".to_string(),
+                mergeable: None,
             })
         }
 
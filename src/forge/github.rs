@@ -3,10 +3,14 @@
 //! This implementation wraps the GitHub CLI (`gh`) to provide
 //! PR operations for GitHub repositories.
 
-use super::{AsyncForge, CiStatus, Forge, ForgeConfig, ForgeType, MergeMethod, PrFullInfo, PrInfo, PrState, ReviewState};
+use super::{
+    resolve_cli_program, AsyncForge, CiStatus, Forge, ForgeConfig, ForgeType, MergeMethod, PrDiffStat, PrFullInfo,
+    PrInfo, PrState, ReviewState,
+};
 use crate::git_gateway::GitGateway;
 use anyhow::{Context, Result};
 use colored::Colorize;
+use std::path::PathBuf;
 use std::process::Command;
 use std::thread;
 use std::time::Duration;
@@ -21,13 +25,16 @@ const RATE_LIMIT_BASE_DELAY_SECS: u64 = 5;
 pub struct GitHubForge {
     /// Custom host for GitHub Enterprise
     host: Option<String>,
+    /// The `gh` executable: a configured path (`forge.gh_path`) or "gh" on PATH
+    program: PathBuf,
 }
 
 impl GitHubForge {
     /// Create a new GitHub forge instance
     pub fn new(config: Option<&ForgeConfig>) -> Self {
         let host = config.and_then(|c| c.host.clone());
-        Self { host }
+        let program = resolve_cli_program(|forge| forge.gh_path, "gh");
+        Self { host, program }
     }
 
     /// Run a gh command with optional host override
@@ -37,7 +44,7 @@ impl GitHubForge {
     /// messages that we detect and handle.
     fn run_gh(&self, args: &[&str]) -> Result<std::process::Output> {
         if crate::context::ExecutionContext::is_verbose() {
-            eprintln!("  {} gh {}", "[cmd]".dimmed(), args.join(" "));
+            eprintln!("  {} {} {}", "[cmd]".dimmed(), self.program.display(), args.join(" "));
         }
         self.run_gh_with_retry(args, MAX_RATE_LIMIT_RETRIES)
     }
@@ -47,17 +54,20 @@ impl GitHubForge {
         let mut retries = 0;
 
         loop {
-            let mut cmd = Command::new("gh");
+            let mut cmd = Command::new(&self.program);
 
             // Add host if configured (for GitHub Enterprise)
             if let Some(ref host) = self.host {
                 cmd.env("GH_HOST", host);
             }
 
-            let output = cmd
-                .args(args)
-                .output()
-                .with_context(|| format!("Failed to run 'gh {}'. Is gh CLI installed?", args.join(" ")))?;
+            let output = cmd.args(args).output().with_context(|| {
+                format!(
+                    "Failed to run '{} {}'. Is gh CLI installed? (set forge.gh_path if it isn't on PATH)",
+                    self.program.display(),
+                    args.join(" ")
+                )
+            })?;
 
             // Check for rate limiting
             if !output.status.success() {
@@ -221,13 +231,31 @@ impl Forge for GitHubForge {
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             if stderr.contains("not logged") {
-                anyhow::bail!("Not authenticated with GitHub CLI. Run 'gh auth login' to authenticate.");
+                anyhow::bail!(
+                    "Not authenticated with GitHub CLI ({}). Run 'gh auth login' to authenticate.",
+                    self.program.display()
+                );
             }
-            anyhow::bail!("GitHub CLI auth check failed: {}", stderr);
+            anyhow::bail!("GitHub CLI auth check failed ({}): {}", self.program.display(), stderr);
         }
         Ok(())
     }
 
+    fn current_user(&self) -> Result<String> {
+        let args = ["api", "user", "--jq", ".login"];
+        let output = self.run_gh(&args)?;
+
+        if !output.status.success() {
+            anyhow::bail!("{}", Self::format_gh_error(&args, &output));
+        }
+
+        let login = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if login.is_empty() {
+            anyhow::bail!("GitHub CLI did not report a user login");
+        }
+        Ok(login)
+    }
+
     fn pr_exists(&self, branch: &str) -> Result<Option<PrInfo>> {
         let args = [
             "pr",
@@ -400,7 +428,7 @@ impl Forge for GitHubForge {
             "view",
             pr_ref,
             "--json",
-            "number,url,title,state,isDraft,headRefName,baseRefName,reviews,statusCheckRollup",
+            "number,url,title,state,isDraft,headRefName,baseRefName,reviews,statusCheckRollup,mergeable",
         ])?;
 
         if !output.status.success() {
@@ -421,6 +449,11 @@ impl Forge for GitHubForge {
             ci: Self::parse_ci_status(&json["statusCheckRollup"]),
             head_ref: json["headRefName"].as_str().unwrap_or("").to_string(),
             base_ref: json["baseRefName"].as_str().unwrap_or("").to_string(),
+            mergeable: match json["mergeable"].as_str() {
+                Some("MERGEABLE") => Some(true),
+                Some("CONFLICTING") => Some(false),
+                _ => None,
+            },
         })
     }
 
@@ -438,6 +471,24 @@ impl Forge for GitHubForge {
         Ok(json["body"].as_str().unwrap_or("").to_string())
     }
 
+    fn get_pr_diff_stat(&self, pr_ref: &str) -> Result<PrDiffStat> {
+        let output = self.run_gh(&["pr", "view", pr_ref, "--json", "additions,deletions,changedFiles"])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to get PR diff stat: {}", stderr);
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).context("Failed to parse gh pr view output")?;
+
+        Ok(PrDiffStat {
+            files: json["changedFiles"].as_u64().unwrap_or(0),
+            additions: json["additions"].as_u64().unwrap_or(0),
+            deletions: json["deletions"].as_u64().unwrap_or(0),
+        })
+    }
+
     fn update_pr_body(&self, pr_ref: &str, body: &str) -> Result<()> {
         let output = self.run_gh(&["pr", "edit", pr_ref, "--body", body])?;
 
@@ -572,6 +623,40 @@ impl Forge for GitHubForge {
 
         Ok(())
     }
+
+    fn is_force_push_protected(&self, branch: &str) -> Result<bool> {
+        let endpoint = format!("repos/{{owner}}/{{repo}}/branches/{}/protection", branch);
+        let args = ["api", endpoint.as_str()];
+        let output = self.run_gh(&args)?;
+
+        if !output.status.success() {
+            // Unprotected (and nonexistent) branches are a 404
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("Branch not protected") || stderr.contains("Not Found") {
+                return Ok(false);
+            }
+            anyhow::bail!("{}", Self::format_gh_error(&args, &output));
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).context("Failed to parse branch protection response")?;
+        Ok(!json["allow_force_pushes"]["enabled"].as_bool().unwrap_or(false))
+    }
+
+    fn close_pr(&self, pr_ref: &str, comment: Option<&str>) -> Result<()> {
+        let mut args = vec!["pr", "close", pr_ref];
+        if let Some(comment) = comment {
+            args.extend(["--comment", comment]);
+        }
+        let output = self.run_gh(&args)?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to close PR: {}", stderr.trim());
+        }
+
+        Ok(())
+    }
 }
 
 /// AsyncForge implementation uses default methods that wrap sync Forge calls
@@ -583,6 +668,7 @@ impl AsyncForge for GitHubForge {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_context::{init_test_repo, TestRepoContext};
 
     #[test]
     fn test_parse_pr_state() {
@@ -611,6 +697,71 @@ mod tests {
         assert_eq!(forge.host, Some("github.mycompany.com".to_string()));
     }
 
+    /// Point `forge.gh_path` at `stub` in the local config of the test repo
+    #[cfg(unix)]
+    fn configure_gh_path(stub: &std::path::Path) -> Result<()> {
+        let mut config = crate::config::LocalConfig::default();
+        config.forge.gh_path = Some(stub.display().to_string());
+        crate::config::Config::save_local_config(&config)
+    }
+
+    /// Write an executable shell script that logs its arguments to `log`
+    #[cfg(unix)]
+    fn write_stub(path: &std::path::Path, log: &std::path::Path, tail: &str) -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::write(
+            path,
+            format!("#!/bin/sh\necho \"$@\" >> '{}'\n{}\n", log.display(), tail),
+        )?;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_configured_cli_path_is_invoked() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let stubs = tempfile::tempdir()?;
+        let log = stubs.path().join("calls.log");
+        let stub = stubs.path().join("gh-stub");
+        write_stub(&stub, &log, "exit 0")?;
+        configure_gh_path(&stub)?;
+
+        let forge = GitHubForge::new(None);
+        forge.check_auth()?;
+
+        assert_eq!(std::fs::read_to_string(&log)?, "auth status\n");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_check_auth_error_reports_cli_path() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let stubs = tempfile::tempdir()?;
+        let log = stubs.path().join("calls.log");
+        let stub = stubs.path().join("gh-stub");
+        write_stub(
+            &stub,
+            &log,
+            "echo 'You are not logged into any GitHub hosts' >&2\nexit 1",
+        )?;
+
+        configure_gh_path(&stub)?;
+        let err = GitHubForge::new(None).check_auth().unwrap_err().to_string();
+        assert!(err.contains(&stub.display().to_string()), "got: {}", err);
+
+        configure_gh_path(&stubs.path().join("no-such-gh"))?;
+        let err = format!("{:#}", GitHubForge::new(None).check_auth().unwrap_err());
+        assert!(err.contains("no-such-gh"), "got: {}", err);
+        Ok(())
+    }
+
     #[test]
     fn test_parse_review_state_pending() {
         let reviews = serde_json::json!([]);
@@ -4,12 +4,13 @@
 //! MR (Merge Request) operations for GitLab repositories.
 
 use super::{
-    AsyncForge, CiStatus, Forge, ForgeConfig, ForgeType, MergeMethod, PrFullInfo, PrInfo, PrOptions, PrState,
-    ReviewState,
+    resolve_cli_program, AsyncForge, CiStatus, Forge, ForgeConfig, ForgeType, MergeMethod, PrFullInfo, PrInfo,
+    PrOptions, PrState, ReviewState,
 };
 use crate::git_gateway::GitGateway;
 use anyhow::{Context, Result};
 use colored::Colorize;
+use std::path::PathBuf;
 use std::process::Command;
 
 /// GitLab forge implementation
@@ -17,26 +18,52 @@ pub struct GitLabForge {
     /// Custom host for self-hosted GitLab (auto-detected by glab from remote URL)
     #[allow(dead_code)]
     host: Option<String>,
+    /// The `glab` executable: a configured path (`forge.glab_path`) or "glab" on PATH
+    program: PathBuf,
 }
 
 impl GitLabForge {
     /// Create a new GitLab forge instance
     pub fn new(config: Option<&ForgeConfig>) -> Self {
         let host = config.and_then(|c| c.host.clone());
-        Self { host }
+        let program = resolve_cli_program(|forge| forge.glab_path, "glab");
+        Self { host, program }
     }
 
     /// Run a glab command
     fn run_glab(&self, args: &[&str]) -> Result<std::process::Output> {
         if crate::context::ExecutionContext::is_verbose() {
-            eprintln!("  {} glab {}", "[cmd]".dimmed(), args.join(" "));
+            eprintln!("  {} {} {}", "[cmd]".dimmed(), self.program.display(), args.join(" "));
         }
 
-        let mut cmd = Command::new("glab");
+        let mut cmd = Command::new(&self.program);
 
-        cmd.args(args)
-            .output()
-            .context("Failed to run glab CLI. Is it installed? Install with: brew install glab")
+        cmd.args(args).output().with_context(|| {
+            format!(
+                "Failed to run glab CLI at '{}'. Is it installed? Install with: brew install glab \
+                 (or set forge.glab_path if it isn't on PATH)",
+                self.program.display()
+            )
+        })
+    }
+
+    /// The (iid, global id) of the open MR for `branch`, if any
+    ///
+    /// MR dependencies are keyed by the global id, not the project-local iid.
+    fn find_mr_ids(&self, branch: &str) -> Result<Option<(u64, u64)>> {
+        let output = self.run_glab(&["mr", "list", "--source-branch", branch, "--output", "json"])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to look up MR for '{}': {}", branch, stderr.trim());
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).context("Failed to parse glab mr list output")?;
+        Ok(json
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|mr| Some((mr["iid"].as_u64()?, mr["id"].as_u64()?))))
     }
 
     /// Parse MR state from glab CLI output
@@ -125,13 +152,32 @@ impl Forge for GitLabForge {
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             if stderr.contains("not logged") || stderr.contains("no token") {
-                anyhow::bail!("Not authenticated with GitLab CLI. Run 'glab auth login' to authenticate.");
+                anyhow::bail!(
+                    "Not authenticated with GitLab CLI ({}). Run 'glab auth login' to authenticate.",
+                    self.program.display()
+                );
             }
-            anyhow::bail!("GitLab CLI auth check failed: {}", stderr);
+            anyhow::bail!("GitLab CLI auth check failed ({}): {}", self.program.display(), stderr);
         }
         Ok(())
     }
 
+    fn current_user(&self) -> Result<String> {
+        let output = self.run_glab(&["api", "user"])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to look up the GitLab user: {}", stderr.trim());
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).context("Failed to parse glab api user output")?;
+        json["username"]
+            .as_str()
+            .map(str::to_string)
+            .context("GitLab CLI did not report a username")
+    }
+
     fn pr_exists(&self, branch: &str) -> Result<Option<PrInfo>> {
         // glab mr list with source branch filter
         let output = self.run_glab(&["mr", "list", "--source-branch", branch, "--output", "json"])?;
@@ -323,6 +369,11 @@ impl Forge for GitLabForge {
             ci: Self::parse_ci_status(&json),
             head_ref: json["source_branch"].as_str().unwrap_or("").to_string(),
             base_ref: json["target_branch"].as_str().unwrap_or("").to_string(),
+            mergeable: match json["merge_status"].as_str() {
+                Some("can_be_merged") => Some(true),
+                Some("cannot_be_merged") => Some(false),
+                _ => None,
+            },
         })
     }
 
@@ -472,6 +523,78 @@ impl Forge for GitLabForge {
 
         Ok(())
     }
+
+    fn is_force_push_protected(&self, branch: &str) -> Result<bool> {
+        let endpoint = format!("projects/:id/protected_branches/{}", branch.replace('/', "%2F"));
+        let output = self.run_glab(&["api", &endpoint])?;
+
+        if !output.status.success() {
+            // Unprotected (and nonexistent) branches are a 404
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.contains("404") || stderr.contains("Not Found") {
+                return Ok(false);
+            }
+            anyhow::bail!("Failed to check protection for '{}': {}", branch, stderr.trim());
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_slice(&output.stdout).context("Failed to parse protected branch response")?;
+        Ok(!json["allow_force_push"].as_bool().unwrap_or(false))
+    }
+
+    fn supports_pr_dependencies(&self) -> bool {
+        true
+    }
+
+    fn add_pr_dependency(&self, branch: &str, depends_on: &str) -> Result<()> {
+        let (iid, _) = self
+            .find_mr_ids(branch)?
+            .with_context(|| format!("No MR found for '{}'", branch))?;
+        let (_, blocking_id) = self
+            .find_mr_ids(depends_on)?
+            .with_context(|| format!("No MR found for '{}'", depends_on))?;
+
+        // A re-submit shouldn't add the same link twice
+        let endpoint = format!("projects/:id/merge_requests/{}/blocks", iid);
+        let output = self.run_glab(&["api", &endpoint])?;
+        if output.status.success() {
+            let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap_or_default();
+            let linked = json.as_array().is_some_and(|blocks| {
+                blocks
+                    .iter()
+                    .any(|b| b["blocking_merge_request"]["id"].as_u64() == Some(blocking_id))
+            });
+            if linked {
+                return Ok(());
+            }
+        }
+
+        let endpoint = format!("{}?blocking_merge_request_id={}", endpoint, blocking_id);
+        let output = self.run_glab(&["api", "--method", "POST", &endpoint])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to add MR dependency: {}", stderr.trim());
+        }
+
+        Ok(())
+    }
+
+    fn close_pr(&self, pr_ref: &str, comment: Option<&str>) -> Result<()> {
+        // glab mr close has no comment flag, so leave a note first (best effort)
+        if let Some(comment) = comment {
+            let _ = self.run_glab(&["mr", "note", pr_ref, "--message", comment]);
+        }
+
+        let output = self.run_glab(&["mr", "close", pr_ref])?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to close MR: {}", stderr.trim());
+        }
+
+        Ok(())
+    }
 }
 
 /// AsyncForge implementation uses default methods that wrap sync Forge calls
@@ -483,6 +606,15 @@ impl AsyncForge for GitLabForge {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_context::{init_test_repo, TestRepoContext};
+
+    /// Point `forge.glab_path` at `stub` in the local config of the test repo
+    #[cfg(unix)]
+    fn configure_glab_path(stub: &std::path::Path) -> Result<()> {
+        let mut config = crate::config::LocalConfig::default();
+        config.forge.glab_path = Some(stub.display().to_string());
+        crate::config::Config::save_local_config(&config)
+    }
 
     #[test]
     fn test_parse_mr_state() {
@@ -512,6 +644,72 @@ mod tests {
         assert_eq!(forge.host, Some("gitlab.mycompany.com".to_string()));
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_add_pr_dependency_links_child_mr_to_parent_mr() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let stubs = tempfile::tempdir()?;
+        let log = stubs.path().join("calls.log");
+        let stub = stubs.path().join("glab-stub");
+        std::fs::write(
+            &stub,
+            format!(
+                "#!/bin/sh\necho \"$@\" >> '{}'\ncase \"$*\" in\n\
+                 \"mr list --source-branch child \"*) echo '[{{\"iid\": 2, \"id\": 202}}]' ;;\n\
+                 \"mr list --source-branch parent \"*) echo '[{{\"iid\": 1, \"id\": 101}}]' ;;\n\
+                 \"api projects/:id/merge_requests/2/blocks\") echo '[]' ;;\n\
+                 esac\n",
+                log.display()
+            ),
+        )?;
+        std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755))?;
+
+        configure_glab_path(&stub)?;
+        let forge = GitLabForge::new(None);
+        assert!(forge.supports_pr_dependencies());
+        forge.add_pr_dependency("child", "parent")?;
+
+        // The blocking MR is given by its global id, the blocked one by its iid
+        let calls = std::fs::read_to_string(&log)?;
+        assert_eq!(
+            calls.lines().last(),
+            Some("api --method POST projects/:id/merge_requests/2/blocks?blocking_merge_request_id=101")
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_configured_cli_path_is_invoked() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+        let stubs = tempfile::tempdir()?;
+        let log = stubs.path().join("calls.log");
+        let stub = stubs.path().join("glab-stub");
+        std::fs::write(
+            &stub,
+            format!(
+                "#!/bin/sh\necho \"$@\" >> '{}'\necho 'no token found' >&2\nexit 1\n",
+                log.display()
+            ),
+        )?;
+        std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755))?;
+
+        configure_glab_path(&stub)?;
+        let err = GitLabForge::new(None).check_auth().unwrap_err().to_string();
+
+        assert_eq!(std::fs::read_to_string(&log)?, "auth status\n");
+        assert!(err.contains(&stub.display().to_string()), "got: {}", err);
+        Ok(())
+    }
+
     #[test]
     fn test_parse_review_state_pending() {
         let json = serde_json::json!({});
@@ -61,12 +61,15 @@ impl MockForge {
         *self.call_count.lock().unwrap().get(operation).unwrap_or(&0)
     }
 
-    /// Record a call and return the configured response
-    fn handle_call(&self, operation: &str) -> Result<String> {
-        // Increment call count
+    /// Count a call without consulting the configured responses
+    fn record_call(&self, operation: &str) {
         let mut counts = self.call_count.lock().unwrap();
         *counts.entry(operation.to_string()).or_insert(0) += 1;
-        drop(counts);
+    }
+
+    /// Record a call and return the configured response
+    fn handle_call(&self, operation: &str) -> Result<String> {
+        self.record_call(operation);
 
         // Get configured response
         let responses = self.responses.lock().unwrap();
@@ -104,6 +107,7 @@ impl Forge for MockForge {
             ForgeType::GitLab => "glab",
             ForgeType::Bitbucket => "bb",
             ForgeType::Gitea => "tea",
+            ForgeType::Azure => "az",
         }
     }
 
@@ -112,45 +116,86 @@ impl Forge for MockForge {
         Ok(())
     }
 
+    /// Response value is the PR number, optionally with a state: "42" or "42:merged"
     fn pr_exists(&self, branch: &str) -> Result<Option<PrInfo>> {
         let key = format!("pr_exists:{}", branch);
         match self.handle_call(&key) {
-            Ok(pr_number) if !pr_number.is_empty() => Ok(Some(PrInfo {
-                number: pr_number.parse().unwrap_or(123),
-                url: format!("https://github.com/test/repo/pull/{}", pr_number),
-                title: "Test PR".to_string(),
-                state: PrState::Open,
-                head_ref: branch.to_string(),
-                base_ref: "main".to_string(),
-            })),
+            Ok(value) if !value.is_empty() => {
+                let (pr_number, state) = match value.split_once(':') {
+                    Some((number, "merged")) => (number.to_string(), PrState::Merged),
+                    Some((number, "closed")) => (number.to_string(), PrState::Closed),
+                    Some((number, _)) => (number.to_string(), PrState::Open),
+                    None => (value, PrState::Open),
+                };
+                Ok(Some(PrInfo {
+                    number: pr_number.parse().unwrap_or(123),
+                    url: format!("https://github.com/test/repo/pull/{}", pr_number),
+                    title: "Test PR".to_string(),
+                    state,
+                    head_ref: branch.to_string(),
+                    base_ref: "main".to_string(),
+                }))
+            }
             Ok(_) => Ok(None),
             Err(e) => Err(e),
         }
     }
 
-    fn create_pr(&self, branch: &str, _base: &str, _title: &str, _body: &str, _options: &PrOptions) -> Result<String> {
+    /// Also counts `create_pr_base:<branch>:<base>` and, for drafts, `create_pr_draft:<branch>`
+    fn create_pr(&self, branch: &str, base: &str, _title: &str, _body: &str, options: &PrOptions) -> Result<String> {
+        self.record_call(&format!("create_pr_base:{}:{}", branch, base));
+        if options.draft {
+            self.record_call(&format!("create_pr_draft:{}", branch));
+        }
         let key = format!("create_pr:{}", branch);
         self.handle_call(&key)
     }
 
+    /// A configured response of `head:base` sets the PR's branches
+    /// (default `feature` into `main`)
     fn get_pr_info(&self, pr_ref: &str) -> Result<PrInfo> {
         let key = format!("get_pr_info:{}", pr_ref);
-        let _ = self.handle_call(&key)?;
+        let value = self.handle_call(&key)?;
+        let (head_ref, base_ref) = value.split_once(':').unwrap_or(("feature", "main"));
 
         Ok(PrInfo {
             number: pr_ref.parse().unwrap_or(123),
             url: format!("https://github.com/test/repo/pull/{}", pr_ref),
             title: "Test PR".to_string(),
             state: PrState::Open,
-            head_ref: "feature".to_string(),
-            base_ref: "main".to_string(),
+            head_ref: head_ref.to_string(),
+            base_ref: base_ref.to_string(),
         })
     }
 
+    /// A configured response of `head:base,head:base,...` yields that chain
+    /// (numbered from 1); an empty response yields the single PR from `get_pr_info`.
     fn get_pr_chain(&self, pr_ref: &str) -> Result<Vec<PrInfo>> {
         let key = format!("get_pr_chain:{}", pr_ref);
-        let _ = self.handle_call(&key)?;
-        Ok(vec![self.get_pr_info(pr_ref)?])
+        let chain = self.handle_call(&key)?;
+        if chain.is_empty() {
+            return Ok(vec![self.get_pr_info(pr_ref)?]);
+        }
+
+        Ok(chain
+            .split(',')
+            .enumerate()
+            .filter_map(|(i, link)| {
+                let (head, base) = link.split_once(':')?;
+                Some(PrInfo {
+                    number: i as u64 + 1,
+                    url: format!("https://github.com/test/repo/pull/{}", i + 1),
+                    title: "Test PR".to_string(),
+                    state: PrState::Open,
+                    head_ref: head.to_string(),
+                    base_ref: base.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    fn current_user(&self) -> Result<String> {
+        self.handle_call("current_user")
     }
 
     fn is_branch_merged(&self, branch: &str, _into: &str) -> Result<bool> {
@@ -159,9 +204,13 @@ impl Forge for MockForge {
         Ok(result == "true")
     }
 
+    /// Response value may be a JSON-encoded `PrFullInfo`; otherwise a default open PR is returned
     fn get_pr_full_info(&self, pr_ref: &str) -> Result<PrFullInfo> {
         let key = format!("get_pr_full_info:{}", pr_ref);
-        let _ = self.handle_call(&key)?;
+        let response = self.handle_call(&key)?;
+        if !response.is_empty() {
+            return Ok(serde_json::from_str(&response)?);
+        }
 
         Ok(PrFullInfo {
             number: pr_ref.parse().unwrap_or(123),
@@ -173,6 +222,7 @@ impl Forge for MockForge {
             is_draft: false,
             review: ReviewState::Pending,
             ci: CiStatus::Success,
+            mergeable: None,
         })
     }
 
@@ -181,6 +231,15 @@ impl Forge for MockForge {
         self.handle_call(&key)
     }
 
+    fn get_pr_diff_stat(&self, pr_ref: &str) -> Result<PrDiffStat> {
+        let key = format!("get_pr_diff_stat:{}", pr_ref);
+        let response = self.handle_call(&key)?;
+        if response.is_empty() {
+            return Ok(PrDiffStat::default());
+        }
+        Ok(serde_json::from_str(&response)?)
+    }
+
     fn update_pr_body(&self, pr_ref: &str, _body: &str) -> Result<()> {
         let key = format!("update_pr_body:{}", pr_ref);
         self.handle_call(&key)?;
@@ -217,6 +276,17 @@ impl Forge for MockForge {
         Ok(())
     }
 
+    fn close_pr(&self, pr_ref: &str, _comment: Option<&str>) -> Result<()> {
+        let key = format!("close_pr:{}", pr_ref);
+        self.handle_call(&key)?;
+        Ok(())
+    }
+
+    fn is_force_push_protected(&self, branch: &str) -> Result<bool> {
+        let key = format!("is_force_push_protected:{}", branch);
+        Ok(self.handle_call(&key)? == "true")
+    }
+
     fn push_branch(&self, branch: &str, _force: bool) -> Result<()> {
         let key = format!("push_branch:{}", branch);
         self.handle_call(&key)?;
@@ -372,6 +442,64 @@ mod tests {
         assert_eq!(mock.get_call_count("update_pr_body:456"), 1);
     }
 
+    #[tokio::test]
+    async fn test_update_pr_bodies_with_progress_reports_each_result() {
+        let mock = MockForge::new(ForgeType::GitHub);
+        mock.set_response("update_pr_body:456", MockResponse::Error("PR not found".to_string()));
+
+        let updates = vec![
+            ("123".to_string(), "body".to_string()),
+            ("456".to_string(), "body".to_string()),
+            ("789".to_string(), "body".to_string()),
+        ];
+        let seen = Mutex::new(Vec::new());
+        let updated = mock
+            .update_pr_bodies_with_progress(&updates, &|pr_ref, result| {
+                seen.lock().unwrap().push((pr_ref.to_string(), result.is_ok()));
+            })
+            .await;
+
+        assert_eq!(updated, 2);
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort();
+        assert_eq!(
+            seen,
+            vec![
+                ("123".to_string(), true),
+                ("456".to_string(), false),
+                ("789".to_string(), true),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_pr_bases_with_progress_reports_each_result() {
+        let mock = MockForge::new(ForgeType::GitHub);
+        mock.set_response("update_pr_base:child-b", MockResponse::RateLimit);
+
+        let updates = vec![
+            ("child-a".to_string(), "main".to_string()),
+            ("child-b".to_string(), "main".to_string()),
+        ];
+        let seen = Mutex::new(Vec::new());
+        let updated = mock
+            .update_pr_bases_with_progress(&updates, &|branch, result| {
+                seen.lock().unwrap().push((branch.to_string(), result.is_ok()));
+            })
+            .await;
+
+        assert_eq!(updated, 1);
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort();
+        assert_eq!(
+            seen,
+            vec![("child-a".to_string(), true), ("child-b".to_string(), false)]
+        );
+
+        // The count-only wrapper agrees
+        assert_eq!(mock.update_pr_bases(&updates).await, 1);
+    }
+
     #[test]
     fn test_update_pr_base_handles_failures() {
         let mock = MockForge::new(ForgeType::GitHub);
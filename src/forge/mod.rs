@@ -37,13 +37,18 @@ pub mod types;
 pub use ci_wait::{wait_for_ci, CiWaitConfig, CiWaitResult};
 pub use github::GitHubForge;
 pub use gitlab::GitLabForge;
-pub use types::{CiStatus, ForgeConfig, ForgeType, MergeMethod, PrFullInfo, PrInfo, PrOptions, PrState, ReviewState};
+pub use types::{
+    CiStatus, ForgeConfig, ForgeType, MergeMethod, PrDiffStat, PrFullInfo, PrInfo, PrOptions, PrState, ReviewState,
+};
 
+use crate::cache::Cache;
+use crate::config::Config;
 use crate::git_gateway::GitGateway;
 use crate::program_name::program_name;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use std::process::Command;
+use std::path::PathBuf;
 
 /// Trait defining the interface for git forge operations
 ///
@@ -59,6 +64,11 @@ pub trait Forge: Send + Sync {
     /// Check if the CLI is installed and authenticated
     fn check_auth(&self) -> Result<()>;
 
+    /// Get the login of the user the CLI is authenticated as
+    fn current_user(&self) -> Result<String> {
+        anyhow::bail!("Looking up the current user is not supported for {}", self.cli_name())
+    }
+
     /// Check if a PR exists for the given branch
     ///
     /// Returns `Some(PrInfo)` if a PR exists, `None` otherwise
@@ -153,6 +163,43 @@ pub trait Forge: Send + Sync {
     /// or the CLI failed.
     fn open_pr_in_browser(&self, pr_ref: &str) -> Result<()>;
 
+    /// Close a PR/MR without merging it
+    ///
+    /// # Arguments
+    /// * `pr_ref` - PR reference (number, URL, or branch name)
+    /// * `comment` - Optional comment explaining why the PR was closed
+    fn close_pr(&self, pr_ref: &str, _comment: Option<&str>) -> Result<()> {
+        anyhow::bail!("Closing PRs is not supported for {} ('{}')", self.cli_name(), pr_ref)
+    }
+
+    /// Get the changed-file count and added/deleted line totals of a PR/MR
+    ///
+    /// # Arguments
+    /// * `pr_ref` - PR reference (number, URL, or branch name)
+    fn get_pr_diff_stat(&self, pr_ref: &str) -> Result<PrDiffStat> {
+        anyhow::bail!("Diff stats are not supported for {} ('{}')", self.cli_name(), pr_ref)
+    }
+
+    /// Check whether a branch on the remote rejects force-pushes
+    ///
+    /// Unprotected and unknown branches report `false`. Forges without a
+    /// branch-protection API report every branch as unprotected.
+    fn is_force_push_protected(&self, _branch: &str) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Whether the forge can record that one PR depends on another
+    fn supports_pr_dependencies(&self) -> bool {
+        false
+    }
+
+    /// Record that the PR for `branch` depends on (is blocked by) the PR for `depends_on`
+    ///
+    /// A no-op for forges without PR dependencies; check `supports_pr_dependencies` first.
+    fn add_pr_dependency(&self, _branch: &str, _depends_on: &str) -> Result<()> {
+        Ok(())
+    }
+
     /// Push a branch to the configured remote
     ///
     /// # Arguments
@@ -162,20 +209,26 @@ pub trait Forge: Send + Sync {
         let gateway = GitGateway::new()?;
         let force_arg = if force { "--force" } else { "--force-with-lease" };
 
+        // Branches downloaded under a prefix (`get --branch-prefix`) push to their original head ref
+        let refspec = match gateway.push_head(branch) {
+            Some(head) => format!("{}:refs/heads/{}", branch, head),
+            None => branch.to_string(),
+        };
+
         if crate::context::ExecutionContext::is_verbose() {
             use colored::Colorize;
             eprintln!(
                 "  {} git push --quiet {} {} {}",
                 "[cmd]".dimmed(),
                 gateway.remote(),
-                branch,
+                refspec,
                 force_arg
             );
         }
 
         // Use --quiet to suppress remote messages, capture output to reduce noise
         let output = Command::new("git")
-            .args(["push", "--quiet", gateway.remote(), branch, force_arg])
+            .args(["push", "--quiet", gateway.remote(), &refspec, force_arg])
             .output()
             .context("Failed to run git push")?;
 
@@ -218,9 +271,11 @@ pub trait Forge: Send + Sync {
 }
 
 /// Detect the forge type from the git remote URL
+///
+/// Uses the effective push URL so `insteadOf` aliases resolve to the real host.
 pub fn detect_forge_type() -> Result<ForgeType> {
     let gateway = GitGateway::new()?;
-    let url = gateway.get_remote_url(gateway.remote())?;
+    let url = gateway.get_effective_remote_url(gateway.remote())?;
     detect_forge_from_url(&url)
 }
 
@@ -240,22 +295,72 @@ pub fn detect_forge_from_url(url: &str) -> Result<ForgeType> {
     if url_lower.contains("gitea.") || url_lower.contains("codeberg.org") {
         return Ok(ForgeType::Gitea);
     }
+    if url_lower.contains("dev.azure.com") || url_lower.contains("visualstudio.com") {
+        return Ok(ForgeType::Azure);
+    }
 
     // Default to GitHub as it's most common
     Ok(ForgeType::GitHub)
 }
 
+/// Rewrite the scheme and host of a PR URL to a different web base URL.
+///
+/// Used when the forge API reports URLs on a host that isn't reachable from a
+/// browser (e.g., behind a reverse proxy). The path is preserved, and any path
+/// on `web_base` is kept as a prefix. URLs without a scheme are returned unchanged.
+pub fn rewrite_web_url(url: &str, web_base: &str) -> String {
+    let Some(scheme_end) = url.find("://") else {
+        return url.to_string();
+    };
+    let after_scheme = &url[scheme_end + 3..];
+    let path = after_scheme.find('/').map(|i| &after_scheme[i..]).unwrap_or("");
+
+    format!("{}{}", web_base.trim_end_matches('/'), path)
+}
+
+/// Environment variable that forces the forge type (e.g. in CI)
+const FORGE_ENV_VAR: &str = "DIAMOND_FORGE";
+
+/// Pick the forge type: explicit config, then `DIAMOND_FORGE`, then remote URL detection
+fn resolve_forge_type(config: Option<&ForgeConfig>) -> Result<ForgeType> {
+    let env_override = std::env::var(FORGE_ENV_VAR).ok();
+    resolve_forge_type_with(config, env_override.as_deref())
+}
+
+fn resolve_forge_type_with(config: Option<&ForgeConfig>, env_override: Option<&str>) -> Result<ForgeType> {
+    if let Some(ft) = config.and_then(|cfg| cfg.forge_type) {
+        return Ok(ft);
+    }
+
+    match env_override.map(str::trim).filter(|v| !v.is_empty()) {
+        Some(value) => value.parse::<ForgeType>().map_err(|_| {
+            anyhow::anyhow!(
+                "{} is set to an unknown forge:\n  '{}' (expected one of: github, gitlab, bitbucket, gitea, azure)",
+                FORGE_ENV_VAR,
+                value
+            )
+        }),
+        None => detect_forge_type(),
+    }
+}
+
+/// Program to run for a forge CLI: the `forge.*_path` config key, else
+/// `default` looked up on PATH
+fn resolve_cli_program(
+    configured: impl FnOnce(crate::config::ForgeCliConfig) -> Option<String>,
+    default: &str,
+) -> PathBuf {
+    crate::config::Config::load()
+        .ok()
+        .and_then(|c| configured(c.forge))
+        .filter(|path| !path.trim().is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(default))
+}
+
 /// Get a forge instance based on the detected or configured type
 pub fn get_forge(config: Option<&ForgeConfig>) -> Result<Box<dyn Forge>> {
-    let forge_type = if let Some(cfg) = config {
-        if let Some(ft) = cfg.forge_type {
-            ft
-        } else {
-            detect_forge_type()?
-        }
-    } else {
-        detect_forge_type()?
-    };
+    let forge_type = resolve_forge_type(config)?;
 
     match forge_type {
         ForgeType::GitHub => Ok(Box::new(GitHubForge::new(config))),
@@ -266,20 +371,15 @@ pub fn get_forge(config: Option<&ForgeConfig>) -> Result<Box<dyn Forge>> {
         ForgeType::Gitea => {
             anyhow::bail!("Gitea support not yet implemented. Contributions welcome!")
         }
+        ForgeType::Azure => {
+            anyhow::bail!("Azure DevOps support not yet implemented. Contributions welcome!")
+        }
     }
 }
 
 /// Get an async forge instance based on the detected or configured type
 pub fn get_async_forge(config: Option<&ForgeConfig>) -> Result<Box<dyn AsyncForge>> {
-    let forge_type = if let Some(cfg) = config {
-        if let Some(ft) = cfg.forge_type {
-            ft
-        } else {
-            detect_forge_type()?
-        }
-    } else {
-        detect_forge_type()?
-    };
+    let forge_type = resolve_forge_type(config)?;
 
     match forge_type {
         ForgeType::GitHub => Ok(Box::new(GitHubForge::new(config))),
@@ -290,9 +390,15 @@ pub fn get_async_forge(config: Option<&ForgeConfig>) -> Result<Box<dyn AsyncForg
         ForgeType::Gitea => {
             anyhow::bail!("Gitea support not yet implemented. Contributions welcome!")
         }
+        ForgeType::Azure => {
+            anyhow::bail!("Azure DevOps support not yet implemented. Contributions welcome!")
+        }
     }
 }
 
+/// Per-item progress callback for batch updates: (item key, outcome)
+pub type BatchProgressFn<'a> = dyn Fn(&str, &Result<()>) + Send + Sync + 'a;
+
 /// Async trait for batch forge operations
 ///
 /// This trait extends `Forge` with batch methods that enable parallel
@@ -308,21 +414,28 @@ pub trait AsyncForge: Forge + Send + Sync {
     /// This is a key optimization point - implementations can use GraphQL
     /// to fetch all PRs in a single API call instead of N calls.
     ///
-    /// Default implementation calls sync `get_pr_full_info` concurrently.
+    /// Default implementation answers from the PR status cache where fresh
+    /// and calls sync `get_pr_full_info` concurrently for the rest.
     async fn get_prs_full_info(&self, branches: &[String]) -> Vec<PrFullInfo> {
+        let mut pr_cache = PrStatusLookup::load();
         let futures: Vec<_> = branches
             .iter()
             .map(|branch| {
-                let branch = branch.clone();
-                // We need to clone self for spawn_blocking, but Forge is not Clone
-                // Instead, just call sync method directly (blocking in async context for now)
-                // This will be replaced with proper async implementation later
-                let result = Forge::get_pr_full_info(self, &branch);
+                // Served from the PR status cache while fresh. Otherwise we
+                // call the sync method directly (blocking in async context for
+                // now, since Forge is not Clone and can't go to spawn_blocking)
+                let result = match pr_cache.full_info(branch) {
+                    Some(info) => Ok(info),
+                    None => {
+                        Forge::get_pr_full_info(self, branch).inspect(|info| pr_cache.record_full_info(branch, info))
+                    }
+                };
                 async move { result }
             })
             .collect();
 
         let results = futures::future::join_all(futures).await;
+        pr_cache.save();
 
         // Collect successful results, skip failures (branches without PRs)
         results.into_iter().filter_map(|r| r.ok()).collect()
@@ -331,18 +444,53 @@ pub trait AsyncForge: Forge + Send + Sync {
     /// Batch check PR existence for multiple branches
     ///
     /// Returns a vec of (branch_name, Option<PrInfo>) pairs.
-    /// Default implementation calls sync `pr_exists` concurrently.
+    /// Default implementation answers from the PR status cache where fresh and
+    /// calls sync `pr_exists` concurrently for the rest.
     async fn check_prs_exist(&self, branches: &[String]) -> Vec<(String, Option<PrInfo>)> {
+        let mut pr_cache = PrStatusLookup::load();
+        let futures: Vec<_> = branches
+            .iter()
+            .map(|branch| {
+                let result = match pr_cache.info(branch) {
+                    Some(info) => Some(info),
+                    None => Forge::pr_exists(self, branch).ok().flatten().inspect(|info| {
+                        pr_cache.record_info(branch, info);
+                    }),
+                };
+                let branch = branch.clone();
+                async move { (branch, result) }
+            })
+            .collect();
+
+        let results = futures::future::join_all(futures).await;
+        pr_cache.save();
+        results
+    }
+
+    /// Batch check PR existence like [`check_prs_exist`](Self::check_prs_exist),
+    /// but fail on the first lookup error instead of reporting the branch as
+    /// having no PR
+    async fn try_check_prs_exist(&self, branches: &[String]) -> Result<Vec<(String, Option<PrInfo>)>> {
+        let mut pr_cache = PrStatusLookup::load();
         let futures: Vec<_> = branches
             .iter()
             .map(|branch| {
+                let result = match pr_cache.info(branch) {
+                    Some(info) => Ok(Some(info)),
+                    None => Forge::pr_exists(self, branch).inspect(|pr| {
+                        if let Some(info) = pr {
+                            pr_cache.record_info(branch, info);
+                        }
+                    }),
+                };
                 let branch = branch.clone();
-                let result = Forge::pr_exists(self, &branch);
-                async move { (branch, result.ok().flatten()) }
+                async move { result.map(|pr| (branch, pr)) }
             })
             .collect();
 
-        futures::future::join_all(futures).await
+        let results = futures::future::join_all(futures).await;
+        pr_cache.save();
+        results.into_iter().collect()
     }
 
     /// Batch get PR bodies for multiple PRs
@@ -362,16 +510,49 @@ pub trait AsyncForge: Forge + Send + Sync {
         results.into_iter().flatten().collect()
     }
 
+    /// Batch get diff stats for multiple PRs
+    ///
+    /// Returns a vec of (pr_ref, stat) pairs for successful fetches.
+    async fn get_prs_diff_stats(&self, pr_refs: &[String]) -> Vec<(String, PrDiffStat)> {
+        let futures: Vec<_> = pr_refs
+            .iter()
+            .map(|pr_ref| {
+                let pr_ref = pr_ref.clone();
+                let result = Forge::get_pr_diff_stat(self, &pr_ref);
+                async move { result.ok().map(|stat| (pr_ref, stat)) }
+            })
+            .collect();
+
+        let results = futures::future::join_all(futures).await;
+        results.into_iter().flatten().collect()
+    }
+
     /// Batch update PR bodies
     ///
     /// Updates multiple PR bodies concurrently.
     /// Returns the number of successful updates.
     async fn update_pr_bodies(&self, updates: &[(String, String)]) -> usize {
+        self.update_pr_bodies_with_progress(updates, &|_, _| {}).await
+    }
+
+    /// Batch update PR bodies, reporting each completed update
+    ///
+    /// `on_complete` is called once per (pr_ref, body) pair with the PR ref
+    /// and the outcome, as soon as that update finishes.
+    /// Returns the number of successful updates.
+    async fn update_pr_bodies_with_progress(
+        &self,
+        updates: &[(String, String)],
+        on_complete: &BatchProgressFn<'_>,
+    ) -> usize {
         let futures: Vec<_> = updates
             .iter()
             .map(|(pr_ref, body)| {
                 let result = Forge::update_pr_body(self, pr_ref, body);
-                async move { result.is_ok() }
+                async move {
+                    on_complete(pr_ref, &result);
+                    result.is_ok()
+                }
             })
             .collect();
 
@@ -385,22 +566,112 @@ pub trait AsyncForge: Forge + Send + Sync {
     /// Takes a slice of (branch_name, new_base) pairs.
     /// Returns the number of successful updates.
     async fn update_pr_bases(&self, updates: &[(String, String)]) -> usize {
+        self.update_pr_bases_with_progress(updates, &|_, _| {}).await
+    }
+
+    /// Batch update PR base branches, reporting each completed update
+    ///
+    /// `on_complete` is called once per (branch_name, new_base) pair with the
+    /// branch name and the outcome, as soon as that update finishes.
+    /// Returns the number of successful updates.
+    async fn update_pr_bases_with_progress(
+        &self,
+        updates: &[(String, String)],
+        on_complete: &BatchProgressFn<'_>,
+    ) -> usize {
         let futures: Vec<_> = updates
             .iter()
             .map(|(branch, new_base)| {
                 let result = Forge::update_pr_base(self, branch, new_base);
-                async move { result.is_ok() }
+                async move {
+                    on_complete(branch, &result);
+                    result.is_ok()
+                }
             })
             .collect();
 
         let results = futures::future::join_all(futures).await;
+
+        // Cached base branches are now out of date
+        let branches: Vec<String> = updates.iter().map(|(branch, _)| branch.clone()).collect();
+        invalidate_pr_status(&branches);
+
         results.into_iter().filter(|&ok| ok).count()
     }
 }
 
+/// The PR status cache as used by the `AsyncForge` batch methods
+///
+/// Disabled - every lookup misses and nothing is saved - when
+/// `forge.pr_cache_ttl_secs` is 0 or there is no repository to keep the
+/// cache in. The cache is best-effort, so save errors are ignored.
+struct PrStatusLookup {
+    cache: Option<Cache>,
+    ttl_secs: u64,
+    dirty: bool,
+}
+
+impl PrStatusLookup {
+    fn load() -> Self {
+        let ttl_secs = Config::load().unwrap_or_default().forge.pr_cache_ttl_secs();
+        let cache = if ttl_secs == 0 { None } else { Cache::load().ok() };
+        Self {
+            cache,
+            ttl_secs,
+            dirty: false,
+        }
+    }
+
+    fn info(&self, branch: &str) -> Option<PrInfo> {
+        self.cache.as_ref()?.pr_status.fresh_info(branch, self.ttl_secs)
+    }
+
+    fn full_info(&self, branch: &str) -> Option<PrFullInfo> {
+        self.cache.as_ref()?.pr_status.fresh_full_info(branch, self.ttl_secs)
+    }
+
+    fn record_info(&mut self, branch: &str, info: &PrInfo) {
+        if let Some(cache) = &mut self.cache {
+            cache.pr_status.record_info(branch, info);
+            self.dirty = true;
+        }
+    }
+
+    fn record_full_info(&mut self, branch: &str, info: &PrFullInfo) {
+        if let Some(cache) = &mut self.cache {
+            cache.pr_status.record_full_info(branch, info);
+            self.dirty = true;
+        }
+    }
+
+    fn save(&self) {
+        if let (Some(cache), true) = (&self.cache, self.dirty) {
+            let _ = cache.save();
+        }
+    }
+}
+
+/// Drop cached PR status for `branches` so the next batch lookup asks the forge
+///
+/// For callers that act on a PR's current state (deleting merged branches,
+/// `submit --no-cache`) and after changes that make the cached state wrong.
+pub fn invalidate_pr_status(branches: &[String]) {
+    let Ok(mut cache) = Cache::load() else {
+        return;
+    };
+    if branches.iter().all(|branch| !cache.pr_status.contains(branch)) {
+        return;
+    }
+    for branch in branches {
+        cache.pr_status.remove(branch);
+    }
+    let _ = cache.save();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_context::{init_test_repo, TestRepoContext};
 
     // === GitHub URL Detection ===
 
@@ -546,6 +817,24 @@ mod tests {
         );
     }
 
+    // === Azure DevOps URL Detection ===
+
+    #[test]
+    fn test_detect_forge_from_azure_devops_url() {
+        assert_eq!(
+            detect_forge_from_url("https://dev.azure.com/org/project/_git/repo").unwrap(),
+            ForgeType::Azure
+        );
+        assert_eq!(
+            detect_forge_from_url("git@ssh.dev.azure.com:v3/org/project/repo").unwrap(),
+            ForgeType::Azure
+        );
+        assert_eq!(
+            detect_forge_from_url("https://org.visualstudio.com/project/_git/repo").unwrap(),
+            ForgeType::Azure
+        );
+    }
+
     // === Fallback Behavior ===
 
     #[test]
@@ -596,4 +885,152 @@ mod tests {
             ForgeType::GitLab
         );
     }
+
+    // === Web URL Rewriting ===
+
+    #[test]
+    fn test_rewrite_web_url_replaces_host() {
+        assert_eq!(
+            rewrite_web_url(
+                "https://api.internal.example.com/org/repo/pull/42",
+                "https://git.example.com"
+            ),
+            "https://git.example.com/org/repo/pull/42"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_web_url_keeps_base_path_prefix() {
+        assert_eq!(
+            rewrite_web_url(
+                "http://gitlab.internal:8080/group/repo/-/merge_requests/7",
+                "https://proxy.example.com/gitlab/"
+            ),
+            "https://proxy.example.com/gitlab/group/repo/-/merge_requests/7"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_web_url_without_scheme_is_unchanged() {
+        assert_eq!(rewrite_web_url("#42", "https://git.example.com"), "#42");
+    }
+
+    // === DIAMOND_FORGE override ===
+
+    #[test]
+    fn test_env_override_beats_url_detection() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        repo.remote("origin", "git@github.com:user/repo.git")?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        assert_eq!(resolve_forge_type_with(None, None)?, ForgeType::GitHub);
+        assert_eq!(resolve_forge_type_with(None, Some("gitlab"))?, ForgeType::GitLab);
+        assert_eq!(resolve_forge_type_with(None, Some(""))?, ForgeType::GitHub);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_explicit_config_beats_env_override() -> Result<()> {
+        let config = ForgeConfig {
+            forge_type: Some(ForgeType::GitHub),
+            host: None,
+        };
+        assert_eq!(
+            resolve_forge_type_with(Some(&config), Some("gitlab"))?,
+            ForgeType::GitHub
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_env_override_rejects_unknown_forge() {
+        let config = ForgeConfig::default();
+        let err = resolve_forge_type_with(Some(&config), Some("sourcehut")).unwrap_err();
+        assert!(err.to_string().contains("DIAMOND_FORGE"));
+        assert!(err.to_string().contains("'sourcehut'"));
+    }
+
+    #[test]
+    fn test_env_override_accepts_azure() -> Result<()> {
+        let config = ForgeConfig::default();
+        assert_eq!(resolve_forge_type_with(Some(&config), Some("azure"))?, ForgeType::Azure);
+        assert_eq!(resolve_forge_type_with(Some(&config), Some("Azure"))?, ForgeType::Azure);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_forge_reports_azure_as_unsupported() -> Result<()> {
+        let config = ForgeConfig {
+            forge_type: Some(ForgeType::Azure),
+            host: None,
+        };
+        let err = get_forge(Some(&config))
+            .err()
+            .expect("Azure has no forge implementation");
+        assert!(err.to_string().contains("Azure DevOps support not yet implemented"));
+        let err = get_async_forge(Some(&config))
+            .err()
+            .expect("Azure has no forge implementation");
+        assert!(err.to_string().contains("Azure DevOps support not yet implemented"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_batch_lookups_reuse_fresh_pr_status() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+
+        let dir = tempfile::tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature", MockResponse::Success("42".to_string()));
+        let branches = vec!["feature".to_string()];
+
+        forge.check_prs_exist(&branches).await;
+        let results = forge.check_prs_exist(&branches).await;
+        assert_eq!(forge.get_call_count("pr_exists:feature"), 1);
+        assert_eq!(results[0].1.as_ref().map(|pr| pr.number), Some(42));
+
+        // An existence check doesn't know review/CI status, a full fetch does
+        forge.get_prs_full_info(&branches).await;
+        forge.get_prs_full_info(&branches).await;
+        assert_eq!(forge.get_call_count("get_pr_full_info:feature"), 1);
+        assert!(Cache::load()?.pr_status.fresh_full_info("feature", 60).is_some());
+
+        // Invalidated entries are fetched again
+        forge.set_response("pr_exists:feature", MockResponse::Success("42:merged".to_string()));
+        invalidate_pr_status(&branches);
+        let results = forge.check_prs_exist(&branches).await;
+        assert_eq!(forge.get_call_count("pr_exists:feature"), 2);
+        assert_eq!(results[0].1.as_ref().map(|pr| pr.state), Some(PrState::Merged));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_zero_pr_cache_ttl_always_asks_the_forge() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+
+        let dir = tempfile::tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let local_config = Config::local_config_path()?;
+        std::fs::create_dir_all(local_config.parent().unwrap())?;
+        std::fs::write(&local_config, "[forge]\npr_cache_ttl_secs = 0\n")?;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        forge.set_response("pr_exists:feature", MockResponse::Success("42".to_string()));
+        let branches = vec!["feature".to_string()];
+
+        forge.check_prs_exist(&branches).await;
+        forge.check_prs_exist(&branches).await;
+        assert_eq!(forge.get_call_count("pr_exists:feature"), 2);
+        assert!(Cache::load()?.pr_status.is_empty());
+
+        Ok(())
+    }
 }
@@ -185,6 +185,9 @@ pub struct PrFullInfo {
     pub head_ref: String,
     /// Base branch name (the branch being merged into)
     pub base_ref: String,
+    /// Whether the PR can merge without conflicts (None while the forge is still computing it)
+    #[serde(default)]
+    pub mergeable: Option<bool>,
 }
 
 impl PrFullInfo {
@@ -215,6 +218,25 @@ impl PrFullInfo {
     }
 }
 
+/// Size of a PR's diff: changed files and added/deleted lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrDiffStat {
+    /// Number of files the PR touches
+    pub files: u64,
+    /// Lines added
+    pub additions: u64,
+    /// Lines deleted
+    pub deletions: u64,
+}
+
+impl std::fmt::Display for PrDiffStat {
+    /// Formats as `+X -Y (N files)`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = if self.files == 1 { "" } else { "s" };
+        write!(f, "+{} -{} ({} file{})", self.additions, self.deletions, self.files, s)
+    }
+}
+
 /// Supported forge types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ForgeType {
@@ -222,6 +244,7 @@ pub enum ForgeType {
     GitLab,
     Bitbucket,
     Gitea,
+    Azure,
 }
 
 impl std::fmt::Display for ForgeType {
@@ -231,6 +254,7 @@ impl std::fmt::Display for ForgeType {
             ForgeType::GitLab => write!(f, "gitlab"),
             ForgeType::Bitbucket => write!(f, "bitbucket"),
             ForgeType::Gitea => write!(f, "gitea"),
+            ForgeType::Azure => write!(f, "azure"),
         }
     }
 }
@@ -244,6 +268,7 @@ impl std::str::FromStr for ForgeType {
             "gitlab" => Ok(ForgeType::GitLab),
             "bitbucket" => Ok(ForgeType::Bitbucket),
             "gitea" => Ok(ForgeType::Gitea),
+            "azure" => Ok(ForgeType::Azure),
             _ => Err(format!("Unknown forge type: {}", s)),
         }
     }
@@ -327,6 +352,7 @@ mod tests {
             ci: CiStatus::Success,
             head_ref: "feature".to_string(),
             base_ref: "main".to_string(),
+            mergeable: None,
         };
         assert_eq!(pr.state_emoji(), "🔄");
         assert_eq!(pr.state_display(), "Open");
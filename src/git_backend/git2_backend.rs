@@ -282,6 +282,8 @@ impl GitBackend for Git2Backend {
     fn commit(&self, message: &str) -> Result<()> {
         let sig = self.signature()?;
         let mut index = self.repo.index()?;
+        // Pick up index changes made by git CLI commands (e.g. `git apply`)
+        index.read(false)?;
         let tree_id = index.write_tree()?;
         let tree = self.repo.find_tree(tree_id)?;
 
@@ -304,6 +306,23 @@ impl GitBackend for Git2Backend {
 
         let msg = message.unwrap_or_else(|| commit.message().unwrap_or(""));
 
+        // Like `git commit --amend`: keep the author, update the committer
+        commit.amend(Some("HEAD"), None, Some(&sig), None, Some(msg), Some(&tree))?;
+
+        Ok(())
+    }
+
+    fn amend_commit_reset_author(&self, message: Option<&str>) -> Result<()> {
+        let head = self.repo.head()?;
+        let commit = head.peel_to_commit()?;
+
+        let sig = self.signature()?;
+        let mut index = self.repo.index()?;
+        let tree_id = index.write_tree()?;
+        let tree = self.repo.find_tree(tree_id)?;
+
+        let msg = message.unwrap_or_else(|| commit.message().unwrap_or(""));
+
         commit.amend(Some("HEAD"), Some(&sig), Some(&sig), None, Some(msg), Some(&tree))?;
 
         Ok(())
@@ -528,6 +547,26 @@ impl GitBackend for Git2Backend {
         Ok(subject.to_string())
     }
 
+    fn get_commit_author(&self, reference: &str) -> Result<(String, String)> {
+        let oid = self.get_ref_sha(reference)?;
+        let git_oid = git2::Oid::from_str(oid.as_str())?;
+        let commit = self.repo.find_commit(git_oid)?;
+
+        let author = commit.author();
+        Ok((
+            author.name().unwrap_or("").to_string(),
+            author.email().unwrap_or("").to_string(),
+        ))
+    }
+
+    fn get_commit_timestamp(&self, reference: &str) -> Result<i64> {
+        let oid = self.get_ref_sha(reference)?;
+        let git_oid = git2::Oid::from_str(oid.as_str())?;
+        let commit = self.repo.find_commit(git_oid)?;
+
+        Ok(commit.time().seconds())
+    }
+
     fn get_commit_time_relative(&self, reference: &str) -> Result<String> {
         let oid = self.get_ref_sha(reference)?;
         let git_oid = git2::Oid::from_str(oid.as_str())?;
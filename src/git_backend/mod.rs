@@ -200,6 +200,9 @@ pub trait GitBackend: Send {
     /// Amend the last commit
     fn amend_commit(&self, message: Option<&str>) -> Result<()>;
 
+    /// Amend HEAD with staged changes, making the current user its author
+    fn amend_commit_reset_author(&self, message: Option<&str>) -> Result<()>;
+
     // =========================================================================
     // Ref operations (for Diamond's metadata refs)
     // =========================================================================
@@ -264,6 +267,12 @@ pub trait GitBackend: Send {
     /// Get relative time of commit (e.g., "2 hours ago")
     fn get_commit_time_relative(&self, reference: &str) -> Result<String>;
 
+    /// Get the author (name, email) of a commit
+    fn get_commit_author(&self, reference: &str) -> Result<(String, String)>;
+
+    /// Get the committer timestamp of a commit (seconds since the Unix epoch)
+    fn get_commit_timestamp(&self, reference: &str) -> Result<i64>;
+
     /// Count commits between base and HEAD
     fn get_commit_count_since(&self, base: &str) -> Result<usize>;
 }
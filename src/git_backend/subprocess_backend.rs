@@ -198,6 +198,13 @@ impl GitBackend for SubprocessBackend {
         }
     }
 
+    fn amend_commit_reset_author(&self, message: Option<&str>) -> Result<()> {
+        match message {
+            Some(msg) => self.run_git_success(&["commit", "--amend", "--reset-author", "-m", msg]),
+            None => self.run_git_success(&["commit", "--amend", "--reset-author", "--no-edit"]),
+        }
+    }
+
     // =========================================================================
     // Ref operations
     // =========================================================================
@@ -377,6 +384,17 @@ impl GitBackend for SubprocessBackend {
         self.run_git_stdout(&["log", "-1", "--format=%cr", reference])
     }
 
+    fn get_commit_author(&self, reference: &str) -> Result<(String, String)> {
+        let output = self.run_git_stdout(&["log", "-1", "--format=%an%x00%ae", reference])?;
+        let (name, email) = output.split_once('\0').unwrap_or((output.as_str(), ""));
+        Ok((name.to_string(), email.to_string()))
+    }
+
+    fn get_commit_timestamp(&self, reference: &str) -> Result<i64> {
+        let output = self.run_git_stdout(&["log", "-1", "--format=%ct", reference])?;
+        output.parse().context("Failed to parse commit timestamp")
+    }
+
     fn get_commit_count_since(&self, base: &str) -> Result<usize> {
         let output = self.run_git_stdout(&["rev-list", "--count", &format!("{}..HEAD", base)])?;
         output.parse().context("Failed to parse commit count")
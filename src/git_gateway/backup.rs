@@ -14,11 +14,13 @@ pub const DEFAULT_BACKUP_TTL_DAYS: u64 = 30;
 
 /// Represents a backup reference for undo functionality.
 ///
-/// Backup refs are stored under `refs/diamond/backup/<branch>-<timestamp>`
-/// and can be used to restore branches after operations like restack or sync.
+/// Backups taken by one operation are grouped under
+/// `refs/diamond/backup/<operation-id>/<branch>` so they can be restored and
+/// garbage collected as a unit. Older backups live ungrouped under
+/// `refs/diamond/backup/<branch>-<timestamp>`.
 #[derive(Debug, Clone)]
 pub struct BackupRef {
-    /// Full ref name (e.g., "refs/diamond/backup/feature-1234567890")
+    /// Full ref name (e.g., "refs/diamond/backup/op-18a2b3c4d5e6f708-0/feature")
     pub ref_name: String,
     /// The original branch name that was backed up
     pub branch_name: String,
@@ -26,27 +28,51 @@ pub struct BackupRef {
     pub timestamp: u64,
     /// The commit OID the branch pointed to at backup time (40-char hex string)
     pub commit_oid: String,
+    /// The operation this backup belongs to (None for ungrouped legacy backups)
+    pub operation_id: Option<String>,
+}
+
+/// Generate a new backup operation id
+///
+/// Ids combine a nanosecond timestamp with an atomic counter, so two operations
+/// never share one even when they start within the same clock tick.
+pub fn new_backup_operation_id() -> Result<String> {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+    let counter = BACKUP_COUNTER.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("op-{:x}-{}", nanos, counter))
+}
+
+/// Parse a backup operation id into its (nanosecond timestamp, counter)
+fn parse_operation_id(operation_id: &str) -> Option<(u64, u32)> {
+    let (nanos, counter) = operation_id.strip_prefix("op-")?.split_once('-')?;
+    Some((u64::from_str_radix(nanos, 16).ok()?, counter.parse().ok()?))
+}
+
+/// Extract the creation time (Unix seconds) from a backup operation id
+fn operation_id_timestamp(operation_id: &str) -> Option<u64> {
+    parse_operation_id(operation_id).map(|(nanos, _)| nanos / 1_000_000_000)
 }
 
 impl GitGateway {
-    /// Create a backup ref before destructive operations
+    /// Create a backup ref for a single branch before a destructive operation
     ///
-    /// Backup refs use nanosecond timestamps plus an atomic counter to ensure
-    /// uniqueness even when multiple backups are created in rapid succession.
-    /// This prevents data loss in fast CI environments or when operations
-    /// complete faster than the clock resolution.
+    /// The backup gets an operation of its own; use `create_operation_backup_ref`
+    /// to group the backups of a multi-branch operation.
     pub fn create_backup_ref(&self, branch: &str) -> Result<BackupRef> {
-        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
-        // Use nanoseconds for higher precision
-        let nanos = now.as_nanos() as u64;
-        // Add atomic counter to guarantee uniqueness even within same nanosecond
-        let counter = BACKUP_COUNTER.fetch_add(1, Ordering::SeqCst);
+        self.create_operation_backup_ref(&new_backup_operation_id()?, branch)
+    }
 
-        // Format: refs/diamond/backup/<branch>-<nanos>-<counter>
-        let backup_ref_name = format!("refs/diamond/backup/{}-{}-{}", branch, nanos, counter);
+    /// Create a backup ref for `branch` in the group of `operation_id`
+    ///
+    /// Operation ids come from `new_backup_operation_id`, whose nanosecond
+    /// timestamp plus atomic counter keeps refs unique even when operations run
+    /// faster than the clock resolution.
+    pub fn create_operation_backup_ref(&self, operation_id: &str, branch: &str) -> Result<BackupRef> {
+        // Format: refs/diamond/backup/<operation-id>/<branch>
+        let backup_ref_name = format!("refs/diamond/backup/{}/{}", operation_id, branch);
 
-        // Store seconds-precision timestamp in struct for display/age calculations
-        let timestamp = now.as_secs();
+        let timestamp = operation_id_timestamp(operation_id)
+            .with_context(|| format!("Invalid backup operation id '{}'", operation_id))?;
 
         // Get commit SHA for the branch
         let commit_sha = self
@@ -72,12 +98,14 @@ impl GitGateway {
             branch_name: branch.to_string(),
             timestamp,
             commit_oid: commit_sha,
+            operation_id: Some(operation_id.to_string()),
         })
     }
 
     /// List all backup refs
     ///
-    /// Supports both old format (branch-timestamp) and new format (branch-nanos-counter).
+    /// Supports grouped backups (operation-id/branch) as well as the older
+    /// ungrouped formats (branch-timestamp and branch-nanos-counter).
     pub fn list_backup_refs(&self) -> Result<Vec<BackupRef>> {
         let mut backups = Vec::new();
 
@@ -102,21 +130,35 @@ impl GitGateway {
                 let name = parts[0];
                 let oid_str = parts[1];
 
-                if let Some(suffix) = name.strip_prefix("refs/diamond/backup/") {
-                    if let Some(parsed) = Self::parse_backup_ref_suffix(suffix) {
-                        backups.push(BackupRef {
-                            ref_name: name.to_string(),
-                            branch_name: parsed.0,
-                            timestamp: parsed.1,
-                            commit_oid: oid_str.to_string(),
-                        });
-                    }
+                let Some(suffix) = name.strip_prefix("refs/diamond/backup/") else {
+                    continue;
+                };
+
+                let grouped = suffix.split_once('/').and_then(|(operation_id, branch)| {
+                    let timestamp = operation_id_timestamp(operation_id)?;
+                    Some((branch.to_string(), timestamp, Some(operation_id.to_string())))
+                });
+                let parsed = grouped.or_else(|| {
+                    Self::parse_backup_ref_suffix(suffix).map(|(branch, timestamp)| (branch, timestamp, None))
+                });
+
+                if let Some((branch_name, timestamp, operation_id)) = parsed {
+                    backups.push(BackupRef {
+                        ref_name: name.to_string(),
+                        branch_name,
+                        timestamp,
+                        commit_oid: oid_str.to_string(),
+                        operation_id,
+                    });
                 }
             }
         }
 
-        // Sort by timestamp (newest first)
-        backups.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        // Sort by timestamp (newest first), ordering operations within the same second
+        backups.sort_by_key(|b| {
+            let precise = b.operation_id.as_deref().and_then(parse_operation_id);
+            std::cmp::Reverse((b.timestamp, precise))
+        });
 
         Ok(backups)
     }
@@ -196,6 +238,45 @@ impl GitGateway {
         Ok(())
     }
 
+    /// Restore every branch in a group of backups in a single ref transaction
+    ///
+    /// Either all branches are moved back to their backed-up commits or, if any
+    /// update fails, none are.
+    pub fn restore_backup_group(&self, backups: &[BackupRef]) -> Result<()> {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let mut child = std::process::Command::new("git")
+            .args(["update-ref", "--stdin"])
+            .current_dir(&self.workdir)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("Failed to run git update-ref")?;
+
+        {
+            let mut stdin = child.stdin.take().context("Failed to open git update-ref stdin")?;
+            writeln!(stdin, "start")?;
+            for backup in backups {
+                writeln!(stdin, "update refs/heads/{} {}", backup.branch_name, backup.commit_oid)?;
+            }
+            writeln!(stdin, "prepare")?;
+            writeln!(stdin, "commit")?;
+        }
+
+        let output = child.wait_with_output().context("Failed to run git update-ref")?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!(
+                "Failed to restore backups (no branches were changed): {}",
+                stderr.trim()
+            );
+        }
+
+        Ok(())
+    }
+
     /// Delete a backup ref
     pub fn delete_backup_ref(&self, backup_ref: &BackupRef) -> Result<()> {
         let output = std::process::Command::new("git")
@@ -218,7 +299,11 @@ impl GitGateway {
         Ok(())
     }
 
-    /// Clean up old backup refs (keep last N per branch)
+    /// Clean up old backup refs (keep the last N operations per branch)
+    ///
+    /// Backups are pruned by operation: a group is only deleted, as a whole,
+    /// once it is outside the newest `keep_per_branch` operations of every
+    /// branch it contains. Ungrouped legacy backups count as one operation each.
     ///
     /// # Safety
     /// This method checks for in-progress operations before cleaning up.
@@ -231,29 +316,12 @@ impl GitGateway {
         }
 
         let backups = self.list_backup_refs()?;
-        let mut deleted_count = 0;
-
-        // Group by branch name
-        let mut by_branch: std::collections::HashMap<String, Vec<BackupRef>> = std::collections::HashMap::new();
-
-        for backup in backups {
-            by_branch.entry(backup.branch_name.clone()).or_default().push(backup);
+        let to_delete = excess_backups(&backups, keep_per_branch);
+        for backup in &to_delete {
+            self.delete_backup_ref(backup)?;
         }
 
-        // Delete old backups for each branch
-        for (_branch, branch_backups) in by_branch {
-            // Already sorted by timestamp (newest first) from list_backup_refs
-            if branch_backups.len() > keep_per_branch {
-                let mut branch_backups_mut = branch_backups;
-                let to_delete = branch_backups_mut.split_off(keep_per_branch);
-                for backup in to_delete {
-                    self.delete_backup_ref(&backup)?;
-                    deleted_count += 1;
-                }
-            }
-        }
-
-        Ok(deleted_count)
+        Ok(to_delete.len())
     }
 
     /// Clean up backup refs older than specified days
@@ -311,6 +379,41 @@ impl GitGateway {
     }
 }
 
+/// Key identifying the operation a backup belongs to
+///
+/// Ungrouped legacy backups are each their own operation.
+fn backup_group_key(backup: &BackupRef) -> &str {
+    backup.operation_id.as_deref().unwrap_or(&backup.ref_name)
+}
+
+/// Backups beyond the newest `keep_per_branch` operations of every branch they touch
+///
+/// `backups` must be sorted newest first (as returned by `list_backup_refs`).
+pub(crate) fn excess_backups(backups: &[BackupRef], keep_per_branch: usize) -> Vec<BackupRef> {
+    use std::collections::{HashMap, HashSet};
+
+    // Operations each branch has backups in, newest first
+    let mut ops_by_branch: HashMap<&str, Vec<&str>> = HashMap::new();
+    for backup in backups {
+        let ops = ops_by_branch.entry(backup.branch_name.as_str()).or_default();
+        let key = backup_group_key(backup);
+        if !ops.contains(&key) {
+            ops.push(key);
+        }
+    }
+
+    let kept: HashSet<&str> = ops_by_branch
+        .values()
+        .flat_map(|ops| ops.iter().take(keep_per_branch).copied())
+        .collect();
+
+    backups
+        .iter()
+        .filter(|b| !kept.contains(backup_group_key(b)))
+        .cloned()
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -375,6 +478,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cleanup_prunes_whole_operation_groups() -> Result<()> {
+        let dir = tempdir()?;
+        let _repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let gateway = GitGateway::new()?;
+        gateway.create_branch("feature-a")?;
+        gateway.create_branch("feature-b")?;
+
+        // Oldest: one operation touching both branches
+        let both = new_backup_operation_id()?;
+        gateway.create_operation_backup_ref(&both, "feature-a")?;
+        gateway.create_operation_backup_ref(&both, "feature-b")?;
+        // Then a newer operation touching only feature-a
+        gateway.create_backup_ref("feature-a")?;
+
+        // The shared group is still feature-b's newest operation, so it survives whole
+        assert_eq!(gateway.cleanup_old_backups(1)?, 0);
+        assert_eq!(gateway.list_backup_refs()?.len(), 3);
+
+        // A newer feature-b operation makes the shared group excess for both branches
+        gateway.create_backup_ref("feature-b")?;
+        assert_eq!(gateway.cleanup_old_backups(1)?, 2);
+
+        let remaining = gateway.list_backup_refs()?;
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining
+            .iter()
+            .all(|b| b.operation_id.as_deref() != Some(both.as_str())));
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_backup_ref_suffix_old_format() {
         // Old format: branch-timestamp (seconds)
@@ -3,6 +3,7 @@
 use anyhow::{bail, Context, Result};
 
 use crate::platform::DisplayPath;
+use crate::program_name::program_name;
 use super::verbose_cmd;
 use super::GitGateway;
 
@@ -39,6 +40,25 @@ impl GitGateway {
         self.backend.checkout_branch_force(name)
     }
 
+    /// Detach HEAD at the current commit, leaving the working tree untouched
+    ///
+    /// Lets the branch that was checked out be updated by ref without the
+    /// working tree going stale.
+    pub fn checkout_detached(&self) -> Result<()> {
+        verbose_cmd("checkout", &["--detach"]);
+        let output = std::process::Command::new("git")
+            .args(["checkout", "--detach"])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git checkout --detach")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to detach HEAD: {}", stderr.trim());
+        }
+        Ok(())
+    }
+
     /// Checkout a branch with full safety checks
     /// Fails if there are uncommitted changes OR if the branch is checked out in another worktree
     /// This is the safest checkout mode for user-initiated commands
@@ -69,6 +89,64 @@ impl GitGateway {
         self.backend.checkout_branch(name)
     }
 
+    /// Files with uncommitted changes that checking out `name` would overwrite
+    ///
+    /// A dirty file conflicts when it also differs between HEAD and `name`.
+    /// If the target can't be compared (e.g., it only exists on the remote),
+    /// every dirty file is reported.
+    pub fn checkout_conflicts(&self, name: &str) -> Result<Vec<String>> {
+        let dirty = self.get_uncommitted_files()?;
+        if dirty.is_empty() {
+            return Ok(dirty);
+        }
+
+        match self.get_changed_files("HEAD", name) {
+            Ok(changed) => Ok(dirty.into_iter().filter(|f| changed.contains(f)).collect()),
+            Err(_) => Ok(dirty),
+        }
+    }
+
+    /// Checkout a branch, carrying over uncommitted changes that don't conflict
+    ///
+    /// Fails, listing the files, if uncommitted changes would be overwritten -
+    /// unless `force` is set, in which case those changes are discarded.
+    /// Always fails if the branch is checked out in another worktree.
+    pub fn checkout_branch_guarded(&self, name: &str, force: bool) -> Result<()> {
+        if let Some(worktree_path) = crate::worktree::get_worktree_path_for_branch(name)? {
+            bail!(
+                "Branch '{}' is already checked out at:\n  \
+                 {}",
+                name,
+                DisplayPath(&worktree_path)
+            );
+        }
+
+        if force {
+            return self.checkout_branch(name);
+        }
+
+        let conflicts = self.checkout_conflicts(name)?;
+        if !conflicts.is_empty() {
+            let files: String = conflicts.iter().map(|f| format!("  {}\n", f)).collect();
+            bail!(
+                "Cannot checkout '{}' - your uncommitted changes to these files would be overwritten:\n\
+                {}\
+                Commit or stash your changes first:\n\
+                • {} modify -a        # amend them into the current branch\n\
+                • git stash\n\
+                Or discard them with: {} checkout {} --force",
+                name,
+                files,
+                program_name(),
+                program_name(),
+                name
+            );
+        }
+
+        verbose_cmd("checkout", &[name]);
+        self.backend.checkout_branch(name)
+    }
+
     /// List all local branch names
     pub fn list_branches(&self) -> Result<Vec<String>> {
         self.backend.list_branches()
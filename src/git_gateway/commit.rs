@@ -4,6 +4,7 @@ use anyhow::{bail, Context, Result};
 
 use crate::program_name::program_name;
 
+use super::verbose_cmd;
 use super::GitGateway;
 
 impl GitGateway {
@@ -30,11 +31,45 @@ impl GitGateway {
         self.backend.commit(message)
     }
 
+    /// Create a commit on HEAD even if nothing is staged (git commit --allow-empty)
+    pub fn commit_allow_empty(&self, message: &str) -> Result<()> {
+        verbose_cmd("commit", &["--allow-empty", "-m", message]);
+        let output = std::process::Command::new("git")
+            .args(["commit", "--allow-empty", "-m", message])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git commit --allow-empty")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("git commit --allow-empty failed: {}", stderr.trim());
+        }
+        Ok(())
+    }
+
     /// Amend the HEAD commit with staged changes
     pub fn amend_commit(&self, message: Option<&str>) -> Result<()> {
         self.backend.amend_commit(message)
     }
 
+    /// Create a `fixup!` commit targeting another commit (git commit --fixup)
+    pub fn commit_fixup(&self, target: &str) -> Result<()> {
+        verbose_cmd("commit", &["--fixup", target]);
+        let output = std::process::Command::new("git")
+            .args(["commit", "--fixup", target])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git commit --fixup")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let detail = if stderr.trim().is_empty() { stdout } else { stderr };
+            bail!("git commit --fixup failed: {}", detail.trim());
+        }
+        Ok(())
+    }
+
     /// Create a commit using the default editor for the message
     pub fn commit_with_editor(&self) -> Result<()> {
         let workdir = &self.workdir;
@@ -69,26 +104,9 @@ impl GitGateway {
 
     /// Amend the HEAD commit with reset author (uses current user as author)
     pub fn amend_reset_author(&self, message: Option<&str>) -> Result<()> {
-        let workdir = &self.workdir;
-
-        let mut args = vec!["commit", "--amend", "--reset-author"];
-        if let Some(msg) = message {
-            args.push("-m");
-            args.push(msg);
-        } else {
-            args.push("--no-edit");
-        }
-
-        let status = std::process::Command::new("git")
-            .args(&args)
-            .current_dir(workdir)
-            .status()
-            .context("Failed to run git commit --amend --reset-author")?;
-
-        if !status.success() {
-            bail!("git commit --amend --reset-author failed");
-        }
-        Ok(())
+        self.backend
+            .amend_commit_reset_author(message)
+            .context("Failed to amend commit with reset author")
     }
 
     /// Run interactive rebase from a base commit
@@ -266,6 +284,50 @@ impl GitGateway {
         Ok(files)
     }
 
+    /// Get list of tracked files with uncommitted changes (staged or unstaged)
+    ///
+    /// Untracked files are not included.
+    pub fn get_uncommitted_files(&self) -> Result<Vec<String>> {
+        let output = std::process::Command::new("git")
+            .args(["diff", "--name-only", "HEAD", "--"])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to get uncommitted files")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to get uncommitted files: {}", stderr.trim());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect())
+    }
+
+    /// Get list of tracked files with unstaged changes (working tree vs index)
+    ///
+    /// Untracked files are not included.
+    pub fn get_unstaged_files(&self) -> Result<Vec<String>> {
+        let output = std::process::Command::new("git")
+            .args(["diff", "--name-only", "--"])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to get unstaged files")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to get unstaged files: {}", stderr.trim());
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_string())
+            .collect())
+    }
+
     /// Get file content at a specific ref
     ///
     /// Returns the file content as bytes.
@@ -284,4 +346,77 @@ impl GitGateway {
 
         Ok(output.stdout)
     }
+
+    /// Get a binary patch from `base` to the current index (git diff --cached --binary)
+    pub fn get_staged_patch(&self, base: &str) -> Result<Vec<u8>> {
+        let output = std::process::Command::new("git")
+            .args(["diff", "--cached", "--binary", base, "--"])
+            .current_dir(&self.workdir)
+            .output()
+            .context(format!("Failed to diff index against '{}'", base))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to diff index against '{}': {}", base, stderr.trim());
+        }
+
+        Ok(output.stdout)
+    }
+
+    /// Get a binary patch of everything that changed from `from_ref` to `to_ref`
+    pub fn get_diff_patch(&self, from_ref: &str, to_ref: &str) -> Result<Vec<u8>> {
+        let output = std::process::Command::new("git")
+            .args([
+                "diff",
+                "--binary",
+                "--no-color",
+                "--no-ext-diff",
+                from_ref,
+                to_ref,
+                "--",
+            ])
+            .current_dir(&self.workdir)
+            .output()
+            .context(format!("Failed to diff '{}' against '{}'", to_ref, from_ref))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to diff '{}' against '{}': {}", to_ref, from_ref, stderr.trim());
+        }
+
+        Ok(output.stdout)
+    }
+
+    /// Apply a patch to the working tree and index with a three-way fallback
+    ///
+    /// Returns true if the patch applied cleanly, false if it left conflict
+    /// markers in the working tree.
+    pub fn apply_patch_3way(&self, patch: &[u8]) -> Result<bool> {
+        use std::io::Write;
+
+        verbose_cmd("apply", &["--3way", "-"]);
+        let mut child = std::process::Command::new("git")
+            .args(["apply", "--3way", "-"])
+            .current_dir(&self.workdir)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .context("Failed to run git apply")?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(patch).context("Failed to write patch to git apply")?;
+        }
+        let output = child.wait_with_output().context("Failed to run git apply")?;
+
+        if output.status.success() {
+            return Ok(true);
+        }
+        if !self.get_conflicted_files()?.is_empty() {
+            return Ok(false);
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("Failed to apply changes: {}", stderr.trim());
+    }
 }
@@ -49,7 +49,7 @@ pub use refs::RefFormat;
 
 // Re-export public types
 pub use self::backup::BackupRef;
-pub use self::rebase::RebaseOutcome;
+pub use self::rebase::{RebaseOutcome, Strategy};
 pub use self::remote::{BranchSyncState, SyncBranchResult};
 #[allow(unused_imports)] // Used in ui::conflict module
 pub use self::status::{ConflictType, ConflictedFile};
@@ -2,6 +2,7 @@
 
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
 
 use crate::program_name::program_name;
 
@@ -24,7 +25,112 @@ impl RebaseOutcome {
     }
 }
 
+/// How a branch is brought up to date with its parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Strategy {
+    /// Replay the branch's commits on top of the parent (rewrites SHAs)
+    #[default]
+    Rebase,
+    /// Merge the parent into the branch (preserves SHAs, adds merge commits)
+    Merge,
+}
+
+impl std::fmt::Display for Strategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Strategy::Rebase => write!(f, "rebase"),
+            Strategy::Merge => write!(f, "merge"),
+        }
+    }
+}
+
+impl std::str::FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rebase" => Ok(Strategy::Rebase),
+            "merge" => Ok(Strategy::Merge),
+            _ => Err(format!("Unknown strategy '{}' (expected 'rebase' or 'merge')", s)),
+        }
+    }
+}
+
 impl GitGateway {
+    /// Bring a branch up to date with `onto` using the given strategy
+    ///
+    /// Rebase uses `--fork-point` (see `rebase_fork_point`); merge runs
+    /// `git merge <onto>` on the branch.
+    pub fn update_onto(&self, branch: &str, onto: &str, strategy: Strategy) -> Result<RebaseOutcome> {
+        match strategy {
+            Strategy::Rebase => self.rebase_fork_point(branch, onto),
+            Strategy::Merge => self.merge_parent(branch, onto),
+        }
+    }
+
+    /// Merge `onto` into a branch, creating a merge commit if needed
+    /// Command: git merge --no-edit <onto>
+    pub fn merge_parent(&self, branch: &str, onto: &str) -> Result<RebaseOutcome> {
+        self.checkout_branch(branch)?;
+
+        verbose_cmd("merge", &["--no-edit", onto]);
+        let output = std::process::Command::new("git")
+            .args(["merge", "--no-edit", onto])
+            .env("GIT_EDITOR", "true")
+            .stdin(std::process::Stdio::null())
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git merge")?;
+
+        if output.status.success() {
+            return Ok(RebaseOutcome::Success);
+        }
+
+        if self.merge_in_progress()? {
+            return Ok(RebaseOutcome::Conflicts);
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git merge failed: {}", stderr.trim());
+    }
+
+    /// Abort an in-progress merge
+    pub fn merge_abort(&self) -> Result<()> {
+        let status = std::process::Command::new("git")
+            .args(["merge", "--abort"])
+            .current_dir(&self.workdir)
+            .status()
+            .context("Failed to run git merge --abort")?;
+
+        if !status.success() {
+            bail!("git merge --abort failed");
+        }
+        Ok(())
+    }
+
+    /// Conclude a merge after resolving conflicts
+    pub fn merge_continue(&self) -> Result<RebaseOutcome> {
+        let output = std::process::Command::new("git")
+            .args(["commit", "--no-edit"])
+            .env("GIT_EDITOR", "true")
+            .stdin(std::process::Stdio::null())
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git commit to conclude merge")?;
+
+        if output.status.success() {
+            Ok(RebaseOutcome::Success)
+        } else {
+            Ok(RebaseOutcome::Conflicts)
+        }
+    }
+
+    /// Check if there's a merge in progress
+    pub fn merge_in_progress(&self) -> Result<bool> {
+        Ok(self.git_dir.join("MERGE_HEAD").exists())
+    }
+
     /// Rebase a branch onto a new base
     /// Returns Ok(Success) if successful, Ok(Conflicts) if there are conflicts, Err for other failures
     ///
@@ -142,6 +248,33 @@ impl GitGateway {
         bail!("git rebase --onto failed: {}", stderr.trim());
     }
 
+    /// Fold `fixup!`/`squash!` commits into their targets (git rebase -i --autosquash)
+    ///
+    /// The todo list is accepted as-is, so no editor is opened.
+    /// Command: git rebase -i --autosquash <base>
+    pub fn rebase_autosquash(&self, base: &str) -> Result<RebaseOutcome> {
+        verbose_cmd("rebase", &["-i", "--autosquash", base]);
+        let output = std::process::Command::new("git")
+            .args(["rebase", "-i", "--autosquash", base])
+            .env("GIT_SEQUENCE_EDITOR", "true")
+            .env("GIT_EDITOR", "true")
+            .stdin(std::process::Stdio::null())
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git rebase --autosquash")?;
+
+        if output.status.success() {
+            return Ok(RebaseOutcome::Success);
+        }
+
+        if self.rebase_in_progress()? {
+            return Ok(RebaseOutcome::Conflicts);
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("git rebase --autosquash failed: {}", stderr.trim());
+    }
+
     /// Abort an in-progress rebase
     #[allow(dead_code)] // Will be used when migrating commands
     pub fn rebase_abort(&self) -> Result<()> {
@@ -196,4 +329,41 @@ impl GitGateway {
         // base is an ancestor of branch means branch is based on base
         self.backend.is_ancestor(base, branch)
     }
+
+    /// Predict which files would conflict when bringing `branch` onto `onto`
+    ///
+    /// Runs `git merge-tree --write-tree`, which merges in memory without touching
+    /// the index, the working tree, or any ref. Returns an empty list when the
+    /// merge is clean.
+    pub fn predict_conflicts(&self, onto: &str, branch: &str) -> Result<Vec<String>> {
+        let args = [
+            "merge-tree",
+            "--write-tree",
+            "--name-only",
+            "--no-messages",
+            onto,
+            branch,
+        ];
+        verbose_cmd("merge-tree", &args[1..]);
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git merge-tree")?;
+
+        match output.status.code() {
+            Some(0) => Ok(Vec::new()),
+            // Exit 1 means conflicts: the tree OID is followed by the conflicted paths
+            Some(1) => Ok(String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .skip(1)
+                .take_while(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect()),
+            _ => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                bail!("git merge-tree failed: {}", stderr.trim());
+            }
+        }
+    }
 }
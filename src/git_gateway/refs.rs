@@ -3,7 +3,8 @@
 //! These operations delegate to the GitBackend, which handles the
 //! reftable vs files format difference internally.
 
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
 
 use super::GitGateway;
 
@@ -44,6 +45,51 @@ impl GitGateway {
         self.backend.delete_reference(name)
     }
 
+    /// Count loose (unpacked) refs under `refs/diamond/`
+    ///
+    /// Reftable repos store every ref in tables, so this is always 0 for them.
+    pub fn count_loose_diamond_refs(&self) -> Result<usize> {
+        if self.format == RefFormat::Reftable {
+            return Ok(0);
+        }
+        Ok(count_files(&self.common_dir()?.join("refs").join("diamond")))
+    }
+
+    /// Pack refs so later ref listings don't read one file per ref
+    ///
+    /// Runs `git pack-refs --all --prune`, as `git gc` does. Under reftable
+    /// the same command compacts the table stack instead.
+    pub fn pack_refs(&self) -> Result<()> {
+        let output = std::process::Command::new("git")
+            .args(["pack-refs", "--all", "--prune"])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git pack-refs")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("Failed to pack refs: {}", stderr.trim());
+        }
+        Ok(())
+    }
+
+    /// Directory holding shared refs (differs from git_dir in linked worktrees)
+    fn common_dir(&self) -> Result<PathBuf> {
+        let output = std::process::Command::new("git")
+            .args(["rev-parse", "--git-common-dir"])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git rev-parse --git-common-dir")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            bail!("git rev-parse --git-common-dir failed: {}", stderr.trim());
+        }
+
+        let dir = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+        Ok(if dir.is_absolute() { dir } else { self.workdir.join(dir) })
+    }
+
     // === Blob Operations ===
 
     /// Create a blob with the given content
@@ -57,6 +103,21 @@ impl GitGateway {
     }
 }
 
+/// Recursively count regular files below `dir` (0 if it doesn't exist)
+fn count_files(dir: &Path) -> usize {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(t) if t.is_dir() => count_files(&entry.path()),
+            Ok(t) if t.is_file() => 1,
+            _ => 0,
+        })
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,6 +23,16 @@ pub enum BranchSyncState {
     NoRemote,
 }
 
+/// Remote state captured by a single up-front fetch.
+///
+/// Multi-stack operations reuse this for their whole run instead of
+/// fetching (or re-resolving remote refs) once per stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchSnapshot {
+    /// Tip of the remote trunk at fetch time, if the remote has it
+    pub trunk_tip: Option<String>,
+}
+
 /// Result of syncing a branch from remote
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SyncBranchResult {
@@ -43,7 +53,15 @@ pub enum SyncBranchResult {
 impl GitGateway {
     /// Fetch from remote using the git command (for reliable credential handling)
     pub fn fetch_remote(&self, remote: &str) -> Result<()> {
-        verbose_cmd("fetch", &[remote]);
+        self.fetch_remote_with_tags(remote, true)
+    }
+
+    /// Fetch from remote, passing `--no-tags` when `fetch_tags` is false
+    ///
+    /// With tags on, git's default applies: tags pointing into fetched history come along.
+    pub fn fetch_remote_with_tags(&self, remote: &str, fetch_tags: bool) -> Result<()> {
+        let args: &[&str] = if fetch_tags { &[remote] } else { &["--no-tags", remote] };
+        verbose_cmd("fetch", args);
 
         // Verify remote exists first
         if !self.has_remote(remote)? {
@@ -56,7 +74,8 @@ impl GitGateway {
 
         // Shell out to git for fetch - this properly uses credential helpers (osxkeychain, etc.)
         let output = std::process::Command::new("git")
-            .args(["fetch", remote])
+            .arg("fetch")
+            .args(args)
             .current_dir(&self.workdir)
             .output()
             .context("Failed to run git fetch")?;
@@ -80,18 +99,34 @@ impl GitGateway {
         self.fetch_remote(&self.remote)
     }
 
+    /// Fetch the configured remote once and capture the trunk tip it reports
+    ///
+    /// `fetch_tags: false` skips tags (`git fetch --no-tags`).
+    pub fn fetch_snapshot(&self, trunk: &str, fetch_tags: bool) -> Result<FetchSnapshot> {
+        self.fetch_remote_with_tags(&self.remote, fetch_tags)?;
+
+        let trunk_ref = format!("refs/remotes/{}/{}", self.remote, trunk);
+        let trunk_tip = self.resolve_ref(&trunk_ref).ok().map(|oid| oid.to_string());
+
+        Ok(FetchSnapshot { trunk_tip })
+    }
+
     /// Fast-forward a branch to its upstream
     pub fn fast_forward_branch(&self, branch: &str) -> Result<()> {
+        let upstream_name = format!("{}/{}", self.remote, branch);
+        self.fast_forward_branch_to(branch, &upstream_name)
+    }
+
+    /// Fast-forward a branch to a specific commit or ref (e.g. a fetched trunk tip)
+    pub fn fast_forward_branch_to(&self, branch: &str, target: &str) -> Result<()> {
         // Checkout the branch
         self.checkout_branch(branch)?;
 
         // Use git merge --ff-only
-        let upstream_name = format!("{}/{}", self.remote, branch);
-
-        verbose_cmd("merge", &["--ff-only", &upstream_name]);
+        verbose_cmd("merge", &["--ff-only", target]);
 
         let output = std::process::Command::new("git")
-            .args(["merge", "--ff-only", &upstream_name])
+            .args(["merge", "--ff-only", target])
             .current_dir(&self.workdir)
             .output()
             .context("Failed to run git merge --ff-only")?;
@@ -104,6 +139,41 @@ impl GitGateway {
         Ok(())
     }
 
+    /// Record that a local branch pushes to a differently named remote branch
+    ///
+    /// Stored as `branch.<name>.diamondHead` in the repo config (see `get --branch-prefix`).
+    pub fn set_push_head(&self, branch: &str, head: &str) -> Result<()> {
+        let status = std::process::Command::new("git")
+            .args(["config", &format!("branch.{}.diamondHead", branch), head])
+            .current_dir(&self.workdir)
+            .status()
+            .context("Failed to run git config")?;
+
+        if !status.success() {
+            bail!("Failed to record remote branch '{}' for '{}'", head, branch);
+        }
+        Ok(())
+    }
+
+    /// Remote branch a local branch pushes to, when set with `set_push_head`
+    pub fn push_head(&self, branch: &str) -> Option<String> {
+        let output = std::process::Command::new("git")
+            .args(["config", "--get", &format!("branch.{}.diamondHead", branch)])
+            .current_dir(&self.workdir)
+            .output()
+            .ok()?;
+
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Name of `branch` on the remote and forge: its push head if set, else the branch itself
+    pub fn remote_head(&self, branch: &str) -> String {
+        self.push_head(branch).unwrap_or_else(|| branch.to_string())
+    }
+
     /// Get the URL for a remote by name
     ///
     /// # Arguments
@@ -128,6 +198,29 @@ impl GitGateway {
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 
+    /// Get the effective push URL for a remote, with aliases resolved to the real host.
+    ///
+    /// `git remote get-url --push` already applies `url.<base>.insteadOf` and
+    /// `pushInsteadOf` rewrites, so aliases like `gh:org/repo` come back expanded.
+    /// SSH host aliases from `~/.ssh/config` (e.g. `git@work-github:org/repo`) are
+    /// resolved to their `HostName`, so forge detection sees the host git actually
+    /// pushes to. Falls back to the fetch URL.
+    pub fn get_effective_remote_url(&self, remote_name: &str) -> Result<String> {
+        let output = std::process::Command::new("git")
+            .args(["remote", "get-url", "--push", remote_name])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git remote get-url --push")?;
+
+        let url = if output.status.success() {
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        } else {
+            self.get_remote_url(remote_name)?
+        };
+
+        Ok(resolve_ssh_host_alias(&url, ssh_hostname))
+    }
+
     /// Check the sync state between a local branch and its remote tracking branch.
     ///
     /// This detects whether the local branch has diverged from the remote, which is
@@ -145,7 +238,7 @@ impl GitGateway {
     /// * `Diverged { local_ahead, remote_ahead }` - Both have diverged
     /// * `NoRemote` - No remote tracking branch exists
     pub fn check_remote_sync_with_remote(&self, branch: &str, remote: &str) -> Result<BranchSyncState> {
-        let remote_branch = format!("{}/{}", remote, branch);
+        let remote_branch = format!("{}/{}", remote, self.remote_head(branch));
 
         // Check if remote tracking branch exists
         let check_output = std::process::Command::new("git")
@@ -356,6 +449,24 @@ impl GitGateway {
         Ok(())
     }
 
+    /// Drop the most recent stash
+    pub fn stash_drop(&self) -> Result<()> {
+        verbose_cmd("stash", &["drop"]);
+
+        let output = std::process::Command::new("git")
+            .args(["stash", "drop"])
+            .current_dir(&self.workdir)
+            .output()
+            .context("Failed to run git stash drop")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("Failed to drop stash: {}", stderr.trim());
+        }
+
+        Ok(())
+    }
+
     /// Delete a branch from a remote
     ///
     /// Equivalent to `git push <remote> --delete <branch>`
@@ -381,3 +492,64 @@ impl GitGateway {
         self.delete_remote_branch_with_name(branch, &self.remote)
     }
 }
+
+/// Replace the host of an SSH remote URL with the host name `resolve` maps it to
+///
+/// Handles `ssh://[user@]host[:port]/path` and scp-like `[user@]host:path` URLs;
+/// any other URL, or a host `resolve` doesn't map, is returned unchanged.
+pub(crate) fn resolve_ssh_host_alias(url: &str, resolve: impl Fn(&str) -> Option<String>) -> String {
+    let Some((host_start, host_end)) = ssh_host_span(url) else {
+        return url.to_string();
+    };
+    match resolve(&url[host_start..host_end]) {
+        Some(hostname) => format!("{}{}{}", &url[..host_start], hostname, &url[host_end..]),
+        None => url.to_string(),
+    }
+}
+
+/// Byte range of the host in an SSH remote URL, if `url` is one
+fn ssh_host_span(url: &str) -> Option<(usize, usize)> {
+    let (authority_start, authority_end) = match url.find("://") {
+        Some(i) => {
+            if !matches!(&url[..i], "ssh" | "git+ssh" | "ssh+git") {
+                return None;
+            }
+            let start = i + 3;
+            let end = url[start..].find('/').map_or(url.len(), |j| start + j);
+            (start, end)
+        }
+        None => {
+            // scp-like syntax: a colon before any slash (otherwise it's a local path)
+            let colon = url.find(':')?;
+            if url[..colon].contains('/') {
+                return None;
+            }
+            (0, colon)
+        }
+    };
+
+    let authority = &url[authority_start..authority_end];
+    let host_start = authority_start + authority.rfind('@').map_or(0, |i| i + 1);
+    let host = &url[host_start..authority_end];
+    // Bracketed IPv6 addresses are never aliases; a leading '-' would read as an ssh option
+    if host.is_empty() || host.starts_with('[') || host.starts_with('-') {
+        return None;
+    }
+    let host_end = host.find(':').map_or(authority_end, |i| host_start + i);
+    Some((host_start, host_end))
+}
+
+/// Host name ssh connects to for `alias`, from `ssh -G` (which applies `~/.ssh/config`)
+///
+/// None when ssh isn't available or the alias maps to itself.
+fn ssh_hostname(alias: &str) -> Option<String> {
+    let output = std::process::Command::new("ssh").args(["-G", alias]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("hostname "))
+        .map(|hostname| hostname.trim().to_string())
+        .filter(|hostname| !hostname.eq_ignore_ascii_case(alias))
+}
@@ -210,7 +210,9 @@ fn test_backup_ref_creation() -> Result<()> {
     let backup = gateway.create_backup_ref("test-branch")?;
 
     assert_eq!(backup.branch_name, "test-branch");
-    assert!(backup.ref_name.starts_with("refs/diamond/backup/test-branch-"));
+    assert!(backup.ref_name.starts_with("refs/diamond/backup/op-"));
+    assert!(backup.ref_name.ends_with("/test-branch"));
+    assert!(backup.operation_id.is_some());
 
     Ok(())
 }
@@ -1158,6 +1160,58 @@ fn test_get_commit_time_relative() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_get_commit_author() -> Result<()> {
+    let dir = tempdir()?;
+    let _repo = init_repo(dir.path())?;
+
+    let gateway = GitGateway::from_path(dir.path())?;
+    assert_eq!(
+        gateway.get_commit_author("main")?,
+        ("Test User".to_string(), "test@example.com".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_amend_reset_author_uses_current_user_on_both_backends() -> Result<()> {
+    use crate::git_backend::{Git2Backend, SubprocessBackend};
+
+    for use_git2 in [true, false] {
+        let dir = tempdir()?;
+        let repo = init_repo(dir.path())?;
+        let backend: Box<dyn GitBackend> = if use_git2 {
+            Box::new(Git2Backend::open(dir.path())?)
+        } else {
+            Box::new(SubprocessBackend::open(dir.path())?)
+        };
+
+        std::fs::write(dir.path().join("test.txt"), "hello")?;
+        backend.stage_all()?;
+
+        let mut config = repo.config()?;
+        config.set_str("user.name", "New User")?;
+        config.set_str("user.email", "new@example.com")?;
+
+        // A plain amend keeps the original author
+        backend.amend_commit(Some("Plain amend"))?;
+        assert_eq!(
+            backend.get_commit_author("HEAD")?,
+            ("Test User".to_string(), "test@example.com".to_string())
+        );
+
+        backend.amend_commit_reset_author(None)?;
+        assert_eq!(
+            backend.get_commit_author("HEAD")?,
+            ("New User".to_string(), "new@example.com".to_string())
+        );
+        assert_eq!(get_head_message(dir.path())?, "Plain amend");
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_format_relative_time() {
     assert_eq!(format_relative_time(0), "0 seconds ago");
@@ -1311,6 +1365,78 @@ fn test_get_remote_url_nonexistent() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_get_effective_remote_url_applies_insteadof() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_repo(dir.path())?;
+
+    repo.remote("origin", "work:group/repo.git")?;
+    repo.config()?
+        .set_str("url.git@gitlab.example.com:.insteadOf", "work:")?;
+
+    let gateway = GitGateway::from_path(dir.path())?;
+
+    let url = gateway.get_effective_remote_url("origin")?;
+    assert_eq!(url, "git@gitlab.example.com:group/repo.git");
+    assert_eq!(
+        crate::forge::detect_forge_from_url(&url)?,
+        crate::forge::ForgeType::GitLab
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_get_effective_remote_url_applies_insteadof_once() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_repo(dir.path())?;
+
+    // The rewritten URL still starts with the rule's prefix, so a second pass would nest it
+    repo.remote("origin", "git@github.com:repo.git")?;
+    repo.config()?
+        .set_str("url.git@github.com:org/.insteadOf", "git@github.com:")?;
+
+    let gateway = GitGateway::from_path(dir.path())?;
+
+    assert_eq!(
+        gateway.get_effective_remote_url("origin")?,
+        "git@github.com:org/repo.git"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_resolve_ssh_host_alias_rewrites_ssh_hosts_only() {
+    let resolve = |host: &str| (host == "work-gitlab").then(|| "gitlab.example.com".to_string());
+
+    assert_eq!(
+        remote::resolve_ssh_host_alias("git@work-gitlab:group/repo.git", resolve),
+        "git@gitlab.example.com:group/repo.git"
+    );
+    assert_eq!(
+        remote::resolve_ssh_host_alias("work-gitlab:group/repo.git", resolve),
+        "gitlab.example.com:group/repo.git"
+    );
+    assert_eq!(
+        remote::resolve_ssh_host_alias("ssh://git@work-gitlab:2222/group/repo.git", resolve),
+        "ssh://git@gitlab.example.com:2222/group/repo.git"
+    );
+    // Hosts without an alias, non-SSH URLs and local paths are left alone
+    assert_eq!(
+        remote::resolve_ssh_host_alias("git@github.com:org/repo.git", resolve),
+        "git@github.com:org/repo.git"
+    );
+    assert_eq!(
+        remote::resolve_ssh_host_alias("https://work-gitlab/group/repo.git", resolve),
+        "https://work-gitlab/group/repo.git"
+    );
+    assert_eq!(
+        remote::resolve_ssh_host_alias("./work-gitlab:repo", resolve),
+        "./work-gitlab:repo"
+    );
+}
+
 #[test]
 fn test_get_branch_sha() -> Result<()> {
     let dir = tempdir()?;
@@ -1633,6 +1759,29 @@ fn test_check_remote_sync_in_sync() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_check_remote_sync_uses_push_head() -> Result<()> {
+    let remote_dir = tempdir()?;
+    let _remote_repo = init_repo(remote_dir.path())?;
+
+    let local_dir = tempdir()?;
+    let local_repo = Repository::clone(remote_dir.path().to_str().unwrap(), local_dir.path())?;
+    let mut remote = local_repo.find_remote("origin")?;
+    remote.fetch(&["main"], None, None)?;
+    drop(remote);
+    drop(local_repo);
+
+    // A branch downloaded under a prefix tracks the remote branch by its original name
+    let gateway = GitGateway::from_path(local_dir.path())?;
+    gateway.create_branch_at_head("alice-main")?;
+    gateway.set_push_head("alice-main", "main")?;
+
+    assert_eq!(gateway.remote_head("alice-main"), "main");
+    assert_eq!(gateway.check_remote_sync("alice-main")?, BranchSyncState::InSync);
+
+    Ok(())
+}
+
 #[test]
 fn test_check_remote_sync_ahead() -> Result<()> {
     // Create a "remote" repo
@@ -96,11 +96,26 @@ impl GitGateway {
         self.backend.get_commit_time_relative(branch)
     }
 
+    /// Get the commit time of a branch's tip as seconds since the Unix epoch
+    pub fn get_commit_timestamp(&self, branch: &str) -> Result<i64> {
+        self.backend.get_commit_timestamp(branch)
+    }
+
+    /// Get the author (name, email) of a branch's tip commit
+    pub fn get_commit_author(&self, branch: &str) -> Result<(String, String)> {
+        self.backend.get_commit_author(branch)
+    }
+
     /// Check if ancestor_ref is an ancestor of descendant_ref
     pub fn is_ancestor(&self, ancestor_ref: &str, descendant_ref: &str) -> Result<bool> {
         self.backend.is_ancestor(ancestor_ref, descendant_ref)
     }
 
+    /// Check whether `name` is a well-formed local branch name (`git check-ref-format --branch`)
+    pub fn is_valid_branch_name(&self, name: &str) -> bool {
+        git2::Branch::name_is_valid(name).unwrap_or(false)
+    }
+
     /// Validates that a parent branch exists in git
     ///
     /// Returns an actionable error if the parent doesn't exist, suggesting
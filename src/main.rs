@@ -1,4 +1,5 @@
 use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use std::path::PathBuf;
 
 mod branch_tree;
 mod cache;
@@ -52,6 +53,7 @@ Navigate:
 Manage Stack:
   restack     Rebase branches locally
   move        Move branch to new parent
+  rebase      Replay a commit range onto a new base
   fold        Merge branch into parent                   [f]
   split       Split branch into multiple                 [sp]
   squash      Squash commits in branch                   [sq]
@@ -76,6 +78,7 @@ Maintenance:
   cleanup     Remove merged branches
   gc          Clean up old backup refs
   history     View operation history
+  cache       Inspect or clear cached data
 
 Collaboration:
   freeze      Prevent modifications to branch
@@ -90,9 +93,11 @@ Setup:
   completion  Generate shell completions
 
 Info:
+  status      Summarize the current branch and stack
   info        Show branch details
   parent      Show parent branch
   children    Show child branches
+  whoami      Show forge user and remote
 
 Options:
   -v, --verbose  Show git commands being executed
@@ -130,6 +135,9 @@ enum Commands {
         /// Reset Diamond (untrack all branches and reinitialize)
         #[arg(long)]
         reset: bool,
+        /// Import stack metadata from another tool (supported: graphite)
+        #[arg(long, value_name = "TOOL")]
+        adopt: Option<String>,
     },
     /// Create a new stacked branch
     #[command(
@@ -149,20 +157,50 @@ Examples:
         /// Stage only updates to already-tracked files (like git add -u)
         #[arg(short = 'u', long)]
         update: bool,
-        /// Commit message
+        /// Commit message (use '-' to read it from stdin)
         #[arg(short = 'm', long)]
         message: Option<String>,
+        /// Read the commit message from a file ('-' for stdin)
+        #[arg(short = 'F', long, value_name = "FILE", conflicts_with = "message")]
+        file: Option<PathBuf>,
         /// Insert between current branch and its child (auto-detects if one child, or specify child explicitly)
         #[arg(short = 'i', long, value_name = "CHILD", num_args = 0..=1, default_missing_value = "")]
         insert: Option<String>,
+        /// Create and track the branch without switching to it
+        #[arg(long, conflicts_with_all = ["all", "update", "message", "file", "insert"])]
+        no_switch: bool,
+        /// Commit an empty placeholder commit (uses -m, or a generated message)
+        #[arg(long, conflicts_with_all = ["all", "update"])]
+        empty: bool,
+        /// Check the original branch out again after creating (and committing to) the new one
+        #[arg(long, conflicts_with_all = ["no_switch", "insert"])]
+        switch_back: bool,
     },
     /// Visualize your stack
     #[command(visible_alias = "l")]
     #[command(visible_alias = "ls")]
     #[command(visible_alias = "ll")]
     Log {
-        /// Output mode: 'short' for simple text, 'long' for detailed, omit for TUI
+        /// Output mode: 'short' for simple text, 'long' for detailed, 'json' for scripting, omit for TUI
         mode: Option<String>,
+        /// Print the stack as JSON (same as 'dm log json')
+        #[arg(long)]
+        json: bool,
+        /// Hide the relative commit time column in long mode
+        #[arg(long)]
+        no_time: bool,
+        /// Hide the commit author column in long mode
+        #[arg(long)]
+        no_author: bool,
+        /// Show each PR's size in long mode (asks the forge)
+        #[arg(long)]
+        pr_size: bool,
+        /// Flip the stack orientation of short/long mode (see log.orientation)
+        #[arg(long)]
+        reverse: bool,
+        /// Only show N levels above trunk in short/long mode; deeper branches are summarized
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
     },
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -177,18 +215,22 @@ Examples:
   modify -a                Stage all and amend last commit
   modify -am \"New msg\"     Amend with new message
   modify -c -m \"New\"       Create new commit (don't amend)
-  modify --into feature    Amend into a downstack branch"
+  modify --into feature    Amend into a downstack branch
+  modify -a --fixup abc123 Create a fixup! commit for abc123"
     )]
     Modify {
-        /// Stage all changes
+        /// Stage all changes, including new untracked files (like git add -A)
         #[arg(short = 'a', long)]
         all: bool,
-        /// Stage only updates to already-tracked files (like git add -u)
-        #[arg(short = 'u', long)]
+        /// Stage modifications and deletions of tracked files only, never new files (like git add -u)
+        #[arg(short = 'u', long, visible_alias = "tracked")]
         update: bool,
-        /// Commit message
+        /// Commit message (use '-' to read it from stdin)
         #[arg(short = 'm', long)]
         message: Option<String>,
+        /// Read the commit message from a file ('-' for stdin)
+        #[arg(short = 'F', long, value_name = "FILE", conflicts_with = "message")]
+        file: Option<PathBuf>,
         /// Create new commit instead of amending
         #[arg(short = 'c', long)]
         commit: bool,
@@ -204,6 +246,12 @@ Examples:
         /// Amend changes into a downstack branch instead of current
         #[arg(long, value_name = "BRANCH")]
         into: Option<String>,
+        /// Create a fixup! commit targeting this commit instead of amending
+        #[arg(long, value_name = "COMMIT", conflicts_with_all = ["commit", "edit", "reset_author", "interactive_rebase", "into", "message", "file"])]
+        fixup: Option<String>,
+        /// Immediately fold the fixup commit into its target
+        #[arg(long, requires = "fixup")]
+        autosquash: bool,
     },
     /// Push branches and create PRs
     #[command(
@@ -250,6 +298,34 @@ Examples:
         /// Show what would be submitted and ask for confirmation
         #[arg(long)]
         confirm: bool,
+        /// Base URL to open PRs in the browser with (overrides web.base_url)
+        #[arg(long, value_name = "URL")]
+        web_base: Option<String>,
+        /// Submit only branches above this ancestor (current branch down to, but not including, it)
+        #[arg(long, value_name = "BRANCH", conflicts_with = "stack")]
+        since: Option<String>,
+        /// Warn before pushing if a branch in scope is protected against force-push
+        #[arg(long)]
+        check_base_protection: bool,
+        /// Stop on a branch with no commits to submit instead of skipping it (and, with
+        /// --check-base-protection, on a branch protected against force-push instead of warning)
+        #[arg(long)]
+        strict: bool,
+        /// Re-submit only the branches that failed in the last `submit --stack`
+        #[arg(long, conflicts_with_all = ["stack", "since", "branch"])]
+        retry_failed: bool,
+        /// Seed new PR bodies from this file (overrides submit.template_path)
+        #[arg(long, value_name = "PATH")]
+        template: Option<PathBuf>,
+        /// Don't add or refresh the stack visualization in PR bodies (see submit.stack_viz)
+        #[arg(long)]
+        no_viz: bool,
+        /// Remove the stack visualization from PR bodies in the stack
+        #[arg(long, conflicts_with = "no_viz")]
+        strip_viz: bool,
+        /// Ask the forge for PR status instead of using the cached copy
+        #[arg(long)]
+        no_cache: bool,
     },
     /// Submit entire stack including descendants (shorthand for submit --stack)
     #[command(hide = true)]
@@ -284,13 +360,19 @@ Examples:
         /// Show what would be submitted and ask for confirmation
         #[arg(long)]
         confirm: bool,
+        /// Base URL to open PRs in the browser with (overrides web.base_url)
+        #[arg(long, value_name = "URL")]
+        web_base: Option<String>,
     },
     /// Rebase stack onto updated trunk
     #[command(after_help = "\
 Examples:
   sync                     Fetch trunk and rebase all branches
   sync --continue          Continue after resolving conflicts
-  sync --abort             Cancel sync and rollback")]
+  sync --abort             Cancel sync and rollback
+
+Flags in sync.default_flags are applied first; flags given here override them.")]
+    #[command(args_override_self = true)]
     Sync {
         /// Continue after resolving conflicts
         #[arg(long, visible_alias = "continue")]
@@ -302,14 +384,44 @@ Examples:
         #[arg(short = 'f', long)]
         force: bool,
         /// Skip cleanup prompt for merged branches
-        #[arg(long)]
+        #[arg(long, overrides_with = "cleanup")]
         no_cleanup: bool,
+        /// Prompt to clean up merged branches (overrides --no-cleanup, e.g. from sync.default_flags)
+        #[arg(long, overrides_with = "no_cleanup")]
+        cleanup: bool,
         /// Skip automatic restack after sync
-        #[arg(long)]
+        #[arg(long, overrides_with = "restack")]
         no_restack: bool,
+        /// Restack after sync (overrides --no-restack, e.g. from sync.default_flags)
+        #[arg(long, overrides_with = "no_restack")]
+        restack: bool,
         /// Show detailed output for all branches (including up-to-date)
         #[arg(short = 'v', long)]
         verbose: bool,
+        /// Stash uncommitted changes before syncing and restore them afterwards
+        #[arg(long)]
+        autostash: bool,
+        /// Check out stacked PR branches that only exist on the remote and add them to the stack
+        #[arg(long)]
+        include_remote_only: bool,
+        /// After rebasing, delete local branches whose PRs are merged (no prompt) and reparent their children
+        #[arg(long)]
+        prune_local_merged: bool,
+        /// How to update branches onto their parents: rebase (default) or merge (keeps SHAs)
+        #[arg(long, value_name = "rebase|merge")]
+        strategy: Option<git_gateway::Strategy>,
+        /// Don't fetch tags from the remote (see sync.fetch_tags)
+        #[arg(long, overrides_with = "tags")]
+        no_tags: bool,
+        /// Fetch tags (overrides --no-tags, e.g. from sync.default_flags)
+        #[arg(long, overrides_with = "no_tags")]
+        tags: bool,
+        /// Only rebase branches within N levels of trunk; deeper branches are left untouched
+        #[arg(long, value_name = "N")]
+        max_depth: Option<usize>,
+        /// Rebase independent stacks concurrently, up to N at a time (default 4), each in its own scratch worktree
+        #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "4")]
+        parallel: Option<usize>,
     },
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -321,7 +433,7 @@ Examples:
     Checkout {
         /// Name of the branch to checkout
         name: Option<String>,
-        /// Go directly to trunk branch
+        /// Go directly to trunk branch (a NAME given with it must be the trunk)
         #[arg(short = 't', long)]
         trunk: bool,
         /// Show only current stack branches in selection
@@ -333,6 +445,21 @@ Examples:
         /// Include untracked branches in selection
         #[arg(short = 'u', long)]
         untracked: bool,
+        /// Track the branch if it is untracked, inferring its parent (see checkout.auto_track)
+        #[arg(long)]
+        track_on_checkout: bool,
+        /// Discard uncommitted changes that would be overwritten by the checkout
+        #[arg(short = 'f', long)]
+        force: bool,
+        /// Check out the head branch of this PR, fetching it if needed
+        #[arg(long, value_name = "NUMBER", conflicts_with_all = ["name", "trunk"])]
+        pr: Option<u64>,
+        /// Check out the next upstack branch that doesn't have a PR yet
+        #[arg(long, conflicts_with_all = ["name", "trunk", "pr"])]
+        next_unsubmitted: bool,
+        /// Browse the current stack in the interactive log view (checkout, delete, navigate)
+        #[arg(long, conflicts_with_all = ["name", "trunk", "pr", "next_unsubmitted", "all", "untracked"])]
+        tui: bool,
     },
     /// Move to child branch
     #[command(visible_alias = "u")]
@@ -343,6 +470,9 @@ Examples:
         /// Navigate directly to a specific upstack branch
         #[arg(long, value_name = "BRANCH")]
         to: Option<String>,
+        /// Stay at the top of the stack even if nav.wrap is enabled
+        #[arg(long)]
+        no_wrap: bool,
     },
     /// Move to parent branch
     #[command(visible_alias = "d")]
@@ -350,6 +480,12 @@ Examples:
         /// Number of steps to move (default: 1)
         #[arg(default_value = "1")]
         steps: usize,
+        /// Navigate directly to a specific downstack branch
+        #[arg(long, value_name = "BRANCH")]
+        to: Option<String>,
+        /// Stay at the bottom of the stack even if nav.wrap is enabled
+        #[arg(long)]
+        no_wrap: bool,
     },
     /// Jump to top of stack
     #[command(visible_alias = "t")]
@@ -382,6 +518,24 @@ Examples:
         /// Skip branches with approved PRs
         #[arg(long)]
         skip_approved: bool,
+        /// Show which branches need restacking and how many commits would be replayed, without rebasing
+        #[arg(long)]
+        preview: bool,
+        /// Restack up to N sibling subtrees at once, each in its own scratch worktree
+        #[arg(
+            short = 'j',
+            long,
+            value_name = "N",
+            default_value_t = 1,
+            visible_alias = "parallel-worktrees"
+        )]
+        jobs: usize,
+        /// Continue an interrupted restack after resolving conflicts
+        #[arg(long = "continue", conflicts_with_all = ["branch", "only", "downstack", "upstack", "force", "skip_approved", "preview", "jobs", "abort"])]
+        continue_restack: bool,
+        /// Abort an interrupted restack and restore branches
+        #[arg(long, conflicts_with_all = ["branch", "only", "downstack", "upstack", "force", "skip_approved", "preview", "jobs"])]
+        abort: bool,
     },
     /// Move branch to new parent
     Move {
@@ -392,12 +546,27 @@ Examples:
         #[arg(long)]
         source: Option<String>,
     },
+    /// Replay a commit range onto a new base and restack descendants
+    Rebase {
+        /// New base (becomes the branch's parent)
+        #[arg(long)]
+        onto: String,
+        /// Upstream commit the replayed range starts after (git rebase --onto <onto> <from>)
+        #[arg(long)]
+        from: String,
+        /// Branch to rebase (defaults to current branch)
+        #[arg(long)]
+        branch: Option<String>,
+    },
     /// Merge branch into parent
     #[command(visible_alias = "f")]
     Fold {
         /// Keep current branch name instead of parent's name
         #[arg(short = 'k', long)]
         keep: bool,
+        /// Close the open PR of the branch that gets folded away
+        #[arg(long)]
+        close_pr: bool,
     },
     /// Split branch into multiple
     #[command(
@@ -414,14 +583,17 @@ Examples:
         /// Commit to split at (when using legacy mode, e.g., HEAD~2, abc123)
         commit: Option<String>,
         /// Split by commit - creates a branch for each commit in the stack
-        #[arg(short = 'c', long = "by-commit", conflicts_with_all = ["by_file", "by_hunk"])]
+        #[arg(short = 'c', long = "by-commit", conflicts_with_all = ["by_file", "by_hunk", "by_size"])]
         by_commit: bool,
         /// Split by file - extracts files matching patterns into a new parent branch
-        #[arg(short = 'f', long = "by-file", num_args = 1.., conflicts_with_all = ["by_commit", "by_hunk"])]
+        #[arg(short = 'f', long = "by-file", num_args = 1.., conflicts_with_all = ["by_commit", "by_hunk", "by_size"])]
         by_file: Option<Vec<String>>,
         /// Split by hunk - interactively select hunks for new branches (requires TTY)
-        #[arg(short = 'H', long = "by-hunk", conflicts_with_all = ["by_commit", "by_file"])]
+        #[arg(short = 'H', long = "by-hunk", conflicts_with_all = ["by_commit", "by_file", "by_size"])]
         by_hunk: bool,
+        /// Split by size - pack changes into stacked branches of at most N added+removed lines
+        #[arg(long = "by-size", value_name = "LINES", conflicts_with_all = ["by_commit", "by_file", "by_hunk"])]
+        by_size: Option<usize>,
     },
     /// Squash commits in branch
     #[command(visible_alias = "sq")]
@@ -434,9 +606,9 @@ Examples:
     Delete {
         /// Branch name to delete (interactive if not provided)
         name: Option<String>,
-        /// Re-parent children to deleted branch's parent
-        #[arg(long, default_value = "true")]
-        reparent: bool,
+        /// Leave children orphaned instead of re-parenting them to the deleted branch's parent (requires --force)
+        #[arg(long)]
+        no_reparent: bool,
         /// Force delete even if branch is not merged
         #[arg(short = 'f', long)]
         force: bool,
@@ -455,6 +627,9 @@ Examples:
         /// Show current order without opening editor
         #[arg(long)]
         preview: bool,
+        /// With --preview, print the order as a JSON array (accepted by --file)
+        #[arg(long, requires = "preview")]
+        json: bool,
     },
     /// Rename current branch
     Rename {
@@ -496,6 +671,9 @@ Examples:
         /// Don't freeze downloaded branches (allow immediate editing)
         #[arg(short = 'U', long)]
         unfrozen: bool,
+        /// Prefix local branch names (e.g. 'alice-') to avoid collisions with your own
+        #[arg(long, value_name = "PREFIX")]
+        branch_prefix: Option<String>,
     },
     /// Merge PRs from command line
     Merge {
@@ -517,11 +695,29 @@ Examples:
         /// Fast mode: skip proactive rebase and CI wait (reactive-only behavior)
         #[arg(long)]
         fast: bool,
+        /// Delete each merged PR's remote branch (and the local branch unless --keep)
+        #[arg(long)]
+        auto_delete_remote: bool,
+        /// Keep local branches of merged PRs (no local cleanup)
+        #[arg(long)]
+        keep: bool,
     },
     /// Open PR in browser
     Pr {
         /// Branch name or PR number (defaults to current branch)
         branch: Option<String>,
+        /// Print the PR URL instead of opening a browser
+        #[arg(long, conflicts_with = "copy_url")]
+        url: bool,
+        /// Print the PR URL and copy it to the clipboard
+        #[arg(long)]
+        copy_url: bool,
+        /// Push the current branch and open a draft PR, skipping submit's checks
+        #[arg(long, conflicts_with_all = ["branch", "url", "copy_url"])]
+        draft: bool,
+        /// Open every PR in the stack, bottom to top
+        #[arg(long, visible_alias = "stack", conflicts_with_all = ["url", "copy_url", "draft"])]
+        all: bool,
     },
     /// Unlink branch from PR
     Unlink,
@@ -554,6 +750,9 @@ Examples:
         /// Update stack visualization in all PRs
         #[arg(long)]
         fix_viz: bool,
+        /// Also report tracked branches that differ from their remote-tracking refs
+        #[arg(long)]
+        check_remote: bool,
     },
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -571,12 +770,15 @@ Examples:
         /// Maximum age of backups to keep in days (default: 30)
         #[arg(long, value_name = "DAYS")]
         max_age: Option<u64>,
-        /// Maximum number of backups to keep per branch (default: 10)
+        /// Maximum number of backup operations to keep per branch (default: 10)
         #[arg(long, value_name = "COUNT")]
         keep: Option<usize>,
         /// Show what would be deleted without actually deleting
         #[arg(long)]
         dry_run: bool,
+        /// Also pack refs afterwards (compacts the tables under reftable)
+        #[arg(long)]
+        repack: bool,
     },
     /// View operation history
     History {
@@ -586,6 +788,33 @@ Examples:
         /// Show all entries
         #[arg(long)]
         all: bool,
+        /// Only show operations that touched this branch
+        #[arg(long, short = 'b', value_name = "NAME", conflicts_with = "undo")]
+        branch: Option<String>,
+        /// Undo the sync or restack with this history ID
+        #[arg(long, value_name = "ID")]
+        undo: Option<String>,
+        /// Skip confirmation prompt (with --undo)
+        #[arg(short, long, requires = "undo")]
+        force: bool,
+    },
+    /// Export stack metadata to a JSON file
+    Export {
+        /// File to write
+        file: PathBuf,
+    },
+    /// Import stack metadata from a JSON file
+    Import {
+        /// File previously written by export
+        file: PathBuf,
+        /// Skip entries for branches that don't exist locally
+        #[arg(short, long)]
+        force: bool,
+    },
+    /// Inspect or clear cached PR and sync data
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
     },
 
     // ═══════════════════════════════════════════════════════════════════════════
@@ -596,6 +825,12 @@ Examples:
     Freeze {
         /// Branch to freeze (defaults to current)
         branch: Option<String>,
+        /// Also freeze all downstack and upstack branches (except trunk)
+        #[arg(long, conflicts_with = "upstack")]
+        stack: bool,
+        /// Also freeze all upstack branches
+        #[arg(long)]
+        upstack: bool,
     },
     /// Allow modifications to branch
     Unfreeze {
@@ -604,9 +839,16 @@ Examples:
         /// Also unfreeze all upstack branches
         #[arg(long)]
         upstack: bool,
+        /// Unfreeze every frozen branch in the repo
+        #[arg(long, conflicts_with_all = ["branch", "upstack"])]
+        all: bool,
     },
     /// Delete branch, keep changes
-    Pop,
+    Pop {
+        /// Apply the changes onto this branch instead of the parent
+        #[arg(long, value_name = "BRANCH")]
+        to: Option<String>,
+    },
 
     // ═══════════════════════════════════════════════════════════════════════════
     // SETUP
@@ -616,7 +858,7 @@ Examples:
     Track {
         /// Branch name to track (defaults to current branch)
         branch: Option<String>,
-        /// Parent branch for the tracked branch
+        /// Parent branch for the tracked branch ("auto" infers it from history)
         #[arg(short = 'p', long)]
         parent: Option<String>,
     },
@@ -640,21 +882,44 @@ Examples:
         /// Shell to generate completions for
         #[arg(value_enum)]
         shell: clap_complete::shells::Shell,
+        /// Write the script to the shell's completion directory instead of stdout
+        #[arg(long)]
+        install: bool,
+    },
+    /// Print git aliases for common commands (e.g. git sync)
+    SetupAliases {
+        /// Write the aliases to the global git config instead of printing them
+        #[arg(long)]
+        install: bool,
+        /// Also print abbreviations for the detected shell
+        #[arg(long)]
+        shell: bool,
     },
 
     // ═══════════════════════════════════════════════════════════════════════════
     // INFO
     // ═══════════════════════════════════════════════════════════════════════════
     #[command(next_help_heading = "Info")]
+    /// Summarize the current branch: stack position, working tree, PR and in-progress operation
+    Status {
+        /// Output as JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
     /// Show branch details
     Info {
         /// Branch to show info for (defaults to current)
         branch: Option<String>,
+        /// Also fetch the PR's state, review, CI and mergeability from the forge
+        #[arg(long)]
+        remote: bool,
     },
     /// Show parent branch
     Parent,
     /// Show child branches
     Children,
+    /// Show the forge, remote and authenticated forge user
+    Whoami,
 
     /// Pass through to git for native git commands
     #[command(external_subcommand, hide = true)]
@@ -666,6 +931,12 @@ Examples:
 enum ConfigAction {
     /// Show current configuration
     Show,
+    /// List every config key with its effective value (key=value)
+    List {
+        /// Only show keys that are still at their default value
+        #[arg(long)]
+        defaults: bool,
+    },
     /// Get a configuration value
     Get {
         /// Config key (e.g., branch.format, branch.prefix)
@@ -676,6 +947,7 @@ enum ConfigAction {
         /// Config key (e.g., branch.format, branch.prefix)
         key: String,
         /// Value to set
+        #[arg(allow_hyphen_values = true)]
         value: String,
         /// Set in local config (.git/diamond/) instead of user config
         #[arg(long)]
@@ -691,9 +963,64 @@ enum ConfigAction {
     },
 }
 
+/// Cache subcommands
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Pretty-print the cache (.git/diamond/cache.json)
+    Inspect,
+    /// Remove cached data so it is fetched or recomputed again
+    #[command(group(clap::ArgGroup::new("scope").required(true)))]
+    Clear {
+        /// Clear cached PR URLs and PR status
+        #[arg(long, group = "scope")]
+        prs: bool,
+        /// Clear the last sync time and trunk commit
+        #[arg(long, group = "scope")]
+        sync: bool,
+        /// Clear everything
+        #[arg(long, group = "scope")]
+        all: bool,
+    },
+}
+
+/// Re-parse the command line with `sync.default_flags` applied
+///
+/// The configured flags are inserted right after `sync`, so anything given on
+/// the command line comes later and wins (see the `overrides_with` pairs on
+/// `Commands::Sync`).
+fn apply_sync_default_flags(cli: Cli, prog_name: &'static str) -> Cli {
+    if !matches!(cli.command, Some(Commands::Sync { .. })) {
+        return cli;
+    }
+    let defaults = config::Config::load()
+        .ok()
+        .and_then(|c| c.sync.default_flags)
+        .unwrap_or_default();
+    if defaults.is_empty() {
+        return cli;
+    }
+
+    let mut args: Vec<std::ffi::OsString> = std::env::args_os().collect();
+    let Some(pos) = args.iter().skip(1).position(|arg| arg == "sync") else {
+        return cli;
+    };
+    args.splice(pos + 2..pos + 2, defaults.into_iter().map(Into::into));
+
+    match Cli::command().name(prog_name).try_get_matches_from(args) {
+        Ok(matches) => Cli::from_arg_matches(&matches).expect("Failed to parse arguments"),
+        Err(e) => {
+            eprintln!("Error: invalid sync.default_flags ({})", e.kind());
+            e.exit()
+        }
+    }
+}
+
 /// Install signal handler for graceful interruption
 fn install_signal_handler() {
     ctrlc::set_handler(|| {
+        // Scratch worktrees are never part of resumable state, so drop them now
+        worktree::cleanup_diamond_worktrees();
+
         // OperationState is saved at each checkpoint during sync/restack operations
         // so we can simply inform the user about recovery options
         eprintln!("\n\nOperation interrupted. Run:");
@@ -712,6 +1039,7 @@ async fn main() {
     let prog_name = program_name::program_name();
     let matches = Cli::command().name(prog_name).get_matches();
     let cli = Cli::from_arg_matches(&matches).expect("Failed to parse arguments");
+    let cli = apply_sync_default_flags(cli, prog_name);
 
     // Initialize global execution context
     // Thread-local for backward compatibility with sync code
@@ -719,6 +1047,13 @@ async fn main() {
     // Task-local for proper async context propagation
     let ctx = context::ExecutionContext::new(cli.verbose, cli.dry_run);
 
+    // Spinners can be turned off via config (ui.spinner) or DIAMOND_NO_SPINNER
+    let spinner_config = config::Config::load().map_or(true, |c| c.ui.spinner_enabled());
+    ui::set_spinners_enabled(ui::resolve_spinners_enabled(
+        spinner_config,
+        std::env::var(ui::NO_SPINNER_ENV_VAR).ok().as_deref(),
+    ));
+
     // Require a subcommand
     let command = match &cli.command {
         Some(cmd) => cmd,
@@ -731,53 +1066,101 @@ async fn main() {
     // Wrap command execution with async context for proper propagation across await points
     let result = context::with_context(ctx, async {
         match command {
-            Commands::Init { trunk, reset } => commands::init::run(trunk.clone(), *reset),
+            Commands::Init { trunk, reset, adopt } => commands::init::run(trunk.clone(), *reset, adopt.clone()),
             Commands::Create {
                 name,
                 all,
                 update,
                 message,
+                file,
                 insert,
-            } => commands::create::run(name.clone(), *all, *update, message.clone(), insert.clone()),
+                no_switch,
+                empty,
+                switch_back,
+            } => commands::create::resolve_message(message.clone(), file.as_deref()).and_then(|message| {
+                commands::create::run(commands::create::CreateOptions {
+                    name: name.clone(),
+                    all: *all,
+                    update: *update,
+                    message,
+                    insert: insert.clone(),
+                    no_switch: *no_switch,
+                    empty: *empty,
+                    switch_back: *switch_back,
+                })
+            }),
             Commands::Checkout {
                 name,
                 trunk,
                 stack,
                 all,
                 untracked,
-            } => commands::checkout::run(name.clone(), *trunk, *stack, *all, *untracked),
-            Commands::Log { mode } => commands::log::run(mode.clone()),
+                track_on_checkout,
+                force,
+                pr,
+                next_unsubmitted,
+                tui,
+            } => match pr {
+                Some(number) => commands::checkout::run_pr(*number, *force),
+                None if *tui => commands::log::run_stack_tui(),
+                None if *next_unsubmitted => commands::checkout::run_next_unsubmitted(*force).await,
+                None => commands::checkout::run(
+                    name.clone(),
+                    *trunk,
+                    *stack,
+                    *all,
+                    *untracked,
+                    *track_on_checkout,
+                    *force,
+                ),
+            },
+            Commands::Log {
+                mode,
+                json,
+                no_time,
+                no_author,
+                pr_size,
+                reverse,
+                depth,
+            } => commands::log::run(mode.clone(), *json, *no_time, *no_author, *pr_size, *reverse, *depth).await,
             Commands::Track { branch, parent } => commands::track::run_track(branch.clone(), parent.clone()),
             Commands::Untrack { branch } => commands::track::run_untrack(branch.clone()),
-            Commands::Down { steps } => commands::down::run(*steps),
-            Commands::Up { steps, to } => commands::up::run(*steps, to.clone()),
+            Commands::Down { steps, to, no_wrap } => commands::down::run(*steps, to.clone(), *no_wrap),
+            Commands::Up { steps, to, no_wrap } => commands::up::run(*steps, to.clone(), *no_wrap),
             Commands::Delete {
                 name,
-                reparent,
+                no_reparent,
                 force,
                 upstack,
                 downstack,
-            } => commands::delete::run(name.clone(), *reparent, *force, *upstack, *downstack),
-            Commands::Fold { keep } => commands::fold::run(*keep),
+            } => commands::delete::run(name.clone(), !*no_reparent, *force, *upstack, *downstack),
+            Commands::Fold { keep, close_pr } => commands::fold::run(*keep, *close_pr),
             Commands::Modify {
                 all,
                 update,
                 message,
+                file,
                 commit,
                 edit,
                 reset_author,
                 interactive_rebase,
                 into,
-            } => commands::modify::run(
-                *all,
-                *update,
-                message.clone(),
-                *commit,
-                *edit,
-                *reset_author,
-                *interactive_rebase,
-                into.clone(),
-            ),
+                fixup,
+                autosquash,
+            } => commands::create::resolve_message(message.clone(), file.as_deref()).and_then(|message| {
+                commands::modify::run(commands::modify::ModifyOptions {
+                    all: *all,
+                    update: *update,
+                    message,
+                    force_commit: *commit,
+                    edit: *edit,
+                    reset_author: *reset_author,
+                    interactive_rebase: *interactive_rebase,
+                    into: into.clone(),
+                    fixup: fixup.clone(),
+                    autosquash: *autosquash,
+                })
+            }),
             Commands::Submit {
                 stack,
                 force,
@@ -790,20 +1173,38 @@ async fn main() {
                 skip_validation,
                 update_only,
                 confirm,
+                web_base,
+                since,
+                check_base_protection,
+                strict,
+                retry_failed,
+                template,
+                no_viz,
+                strip_viz,
+                no_cache,
             } => {
-                commands::submit::run(
-                    *stack,
-                    *force,
-                    *draft,
-                    *publish,
-                    *merge_when_ready,
-                    branch.clone(),
-                    reviewers.clone(),
-                    *no_open,
-                    *skip_validation,
-                    *update_only,
-                    *confirm,
-                )
+                commands::submit::run(commands::submit::SubmitOptions {
+                    stack: *stack,
+                    force: *force,
+                    draft: *draft,
+                    publish: *publish,
+                    merge_when_ready: *merge_when_ready,
+                    target_branch: branch.clone(),
+                    reviewers: reviewers.clone(),
+                    no_open: *no_open,
+                    skip_validation: *skip_validation,
+                    update_only: *update_only,
+                    confirm: *confirm,
+                    web_base: web_base.clone(),
+                    since: since.clone(),
+                    check_base_protection: *check_base_protection,
+                    strict: *strict,
+                    retry_failed: *retry_failed,
+                    template: template.clone(),
+                    no_viz: *no_viz,
+                    strip_viz: *strip_viz,
+                    no_cache: *no_cache,
+                })
                 .await
             }
             Commands::Ss {
@@ -817,20 +1218,23 @@ async fn main() {
                 skip_validation,
                 update_only,
                 confirm,
+                web_base,
             } => {
-                commands::submit::run(
-                    true, // stack = true
-                    *force,
-                    *draft,
-                    *publish,
-                    *merge_when_ready,
-                    branch.clone(),
-                    reviewers.clone(),
-                    *no_open,
-                    *skip_validation,
-                    *update_only,
-                    *confirm,
-                )
+                commands::submit::run(commands::submit::SubmitOptions {
+                    stack: true,
+                    force: *force,
+                    draft: *draft,
+                    publish: *publish,
+                    merge_when_ready: *merge_when_ready,
+                    target_branch: branch.clone(),
+                    reviewers: reviewers.clone(),
+                    no_open: *no_open,
+                    skip_validation: *skip_validation,
+                    update_only: *update_only,
+                    confirm: *confirm,
+                    web_base: web_base.clone(),
+                    ..Default::default()
+                })
                 .await
             }
             Commands::Sync {
@@ -840,12 +1244,54 @@ async fn main() {
                 no_cleanup,
                 no_restack,
                 verbose,
-            } => commands::sync::run(*continue_sync, *abort, *force, *no_cleanup, !*no_restack, *verbose).await,
-            Commands::Get { pr, force, unfrozen } => commands::get::run(pr.clone(), *force, *unfrozen),
-            Commands::Pr { branch } => commands::pr::run(branch.clone()),
-            Commands::Pop => commands::pop::run(),
-            Commands::Freeze { branch } => commands::freeze::run(branch.clone()),
-            Commands::Unfreeze { branch, upstack } => commands::unfreeze::run(branch.clone(), *upstack),
+                autostash,
+                include_remote_only,
+                prune_local_merged,
+                strategy,
+                no_tags,
+                max_depth,
+                parallel,
+                ..
+            } => {
+                commands::sync::run(commands::sync::SyncOptions {
+                    continue_sync: *continue_sync,
+                    abort: *abort,
+                    force: *force,
+                    no_cleanup: *no_cleanup,
+                    restack: !*no_restack,
+                    verbose: *verbose,
+                    autostash: *autostash,
+                    include_remote_only: *include_remote_only,
+                    prune_local_merged: *prune_local_merged,
+                    strategy: *strategy,
+                    no_tags: *no_tags,
+                    max_depth: *max_depth,
+                    parallel: *parallel,
+                })
+                .await
+            }
+            Commands::Get {
+                pr,
+                force,
+                unfrozen,
+                branch_prefix,
+            } => commands::get::run(pr.clone(), *force, *unfrozen, branch_prefix.clone()),
+            Commands::Pr {
+                branch,
+                url,
+                copy_url,
+                draft,
+                all,
+            } => {
+                if *all {
+                    commands::pr::run_all(branch.clone()).await
+                } else {
+                    commands::pr::run(branch.clone(), *url, *copy_url, *draft)
+                }
+            }
+            Commands::Pop { to } => commands::pop::run(to.clone()),
+            Commands::Freeze { branch, stack, upstack } => commands::freeze::run(branch.clone(), *stack, *upstack),
+            Commands::Unfreeze { branch, upstack, all } => commands::unfreeze::run(branch.clone(), *upstack, *all),
             Commands::Unlink => commands::unlink::run(),
             Commands::Merge {
                 merge,
@@ -854,6 +1300,8 @@ async fn main() {
                 no_sync,
                 no_wait,
                 fast,
+                auto_delete_remote,
+                keep,
             } => {
                 let method = if *merge {
                     forge::MergeMethod::Merge
@@ -863,15 +1311,28 @@ async fn main() {
                     forge::MergeMethod::Squash // default
                 };
                 let dry_run = crate::context::ExecutionContext::is_dry_run();
-                commands::merge::run(method, dry_run, *yes, *no_sync, *no_wait, *fast).await
+                commands::merge::run(commands::merge::MergeOptions {
+                    method,
+                    dry_run,
+                    auto_confirm: *yes,
+                    no_sync: *no_sync,
+                    no_wait: *no_wait,
+                    fast_mode: *fast,
+                    auto_delete_remote: *auto_delete_remote,
+                    keep: *keep,
+                })
+                .await
             }
             Commands::Move { onto, source } => commands::move_cmd::run(onto.clone(), source.clone()),
+            Commands::Rebase { onto, from, branch } => {
+                commands::rebase::run(onto.clone(), from.clone(), branch.clone())
+            }
             Commands::Continue => commands::continue_op::run(),
             Commands::Abort => commands::abort::run(),
             Commands::Absorb { all, force } => commands::absorb::run(*all, *force),
             Commands::Top => commands::top::run(),
             Commands::Bottom => commands::bottom::run(),
-            Commands::Reorder { file, preview } => commands::reorder::run(file.clone(), *preview),
+            Commands::Reorder { file, preview, json } => commands::reorder::run(file.clone(), *preview, *json),
             Commands::Restack {
                 branch,
                 only,
@@ -879,17 +1340,29 @@ async fn main() {
                 upstack,
                 force,
                 skip_approved,
+                preview,
+                jobs,
+                continue_restack,
+                abort,
             } => {
-                commands::restack::run(
-                    branch.clone(),
-                    *only,
-                    *downstack,
-                    *upstack,
-                    *force,
-                    *skip_approved,
-                    false,
-                )
-                .await
+                if *continue_restack {
+                    commands::restack::run_continue()
+                } else if *abort {
+                    commands::restack::run_abort()
+                } else {
+                    commands::restack::run(commands::restack::RestackOptions {
+                        branch: branch.clone(),
+                        only: *only,
+                        downstack: *downstack,
+                        upstack: *upstack,
+                        force: *force,
+                        skip_approved: *skip_approved,
+                        preview: *preview,
+                        jobs: *jobs,
+                        called_from_sync: false,
+                    })
+                    .await
+                }
             }
             Commands::Squash { message } => commands::squash::run(message.clone()),
             Commands::Split {
@@ -898,37 +1371,76 @@ async fn main() {
                 by_commit,
                 by_file,
                 by_hunk,
+                by_size,
             } => commands::split::run(
                 new_branch.clone(),
                 commit.clone(),
                 *by_commit,
                 by_file.clone(),
                 *by_hunk,
+                *by_size,
             ),
             Commands::Rename { name, local, force } => commands::rename::run(name.clone(), *local, *force),
-            Commands::Info { branch } => commands::info::run(branch.clone()),
+            Commands::Status { json } => commands::status::run(*json),
+            Commands::Info { branch, remote } => commands::info::run(branch.clone(), *remote),
             Commands::Parent => commands::info::run_parent(),
             Commands::Children => commands::info::run_children(),
+            Commands::Whoami => commands::info::run_whoami(),
             Commands::Trunk { set } => commands::info::run_trunk(set.clone()),
             Commands::Config { action } => match action {
                 Some(ConfigAction::Show) => commands::config_cmd::show(),
+                Some(ConfigAction::List { defaults }) => commands::config_cmd::list(*defaults),
                 Some(ConfigAction::Get { key }) => commands::config_cmd::get(key),
                 Some(ConfigAction::Set { key, value, local }) => commands::config_cmd::set(key, value, *local),
                 Some(ConfigAction::Unset { key, local }) => commands::config_cmd::unset(key, *local),
                 None => commands::config_cmd::show(), // Default to show
             },
-            Commands::Doctor { fix, fix_viz } => commands::doctor::run(*fix, *fix_viz),
-            Commands::Gc { max_age, keep, dry_run } => commands::gc::run(*max_age, *keep, *dry_run),
+            Commands::Doctor {
+                fix,
+                fix_viz,
+                check_remote,
+            } => commands::doctor::run(*fix, *fix_viz, *check_remote),
+            Commands::Gc {
+                max_age,
+                keep,
+                dry_run,
+                repack,
+            } => commands::gc::run(*max_age, *keep, *dry_run, *repack),
             Commands::Cleanup { force } => commands::cleanup::run(*force),
             Commands::Undo { branch, list, force } => commands::undo::run(branch.clone(), *list, *force),
-            Commands::History { count, all } => commands::history::run(if *all { Some(0) } else { *count }),
-            Commands::Completion { shell } => commands::completion::run(*shell),
+            Commands::History {
+                count,
+                all,
+                branch,
+                undo,
+                force,
+            } => commands::history::run(
+                if *all { Some(0) } else { *count },
+                branch.clone(),
+                undo.clone(),
+                *force,
+            ),
+            Commands::Export { file } => commands::export::run(file),
+            Commands::Import { file, force } => commands::import::run(file, *force),
+            Commands::Cache { action } => match action {
+                CacheAction::Inspect => commands::cache_cmd::inspect(),
+                CacheAction::Clear { prs, sync, .. } => commands::cache_cmd::clear(if *prs {
+                    commands::cache_cmd::ClearScope::Prs
+                } else if *sync {
+                    commands::cache_cmd::ClearScope::Sync
+                } else {
+                    commands::cache_cmd::ClearScope::All
+                }),
+            },
+            Commands::Completion { shell, install } => commands::completion::run(*shell, *install),
+            Commands::SetupAliases { install, shell } => commands::setup_aliases::run(*install, *shell),
             Commands::External(args) => commands::passthrough::run(args.clone()),
         }
     })
     .await;
 
     if let Err(e) = result {
+        worktree::cleanup_diamond_worktrees();
         eprintln!("Error: {:#}", e);
         std::process::exit(1);
     }
@@ -4,10 +4,14 @@ use serde::{Deserialize, Serialize};
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 /// Maximum number of log entries to keep before rotation
 const MAX_LOG_ENTRIES: usize = 1000;
 
+/// Atomic counter to keep entry ids unique even within the same nanosecond
+static ENTRY_COUNTER: AtomicU32 = AtomicU32::new(0);
+
 /// Types of operations that can be logged
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -38,9 +42,49 @@ pub enum Operation {
     BackupRestored { branch: String, backup_ref: String },
 }
 
+impl Operation {
+    /// Branches this operation touched
+    ///
+    /// A rename touches both names, so a branch's history follows it across renames.
+    pub fn branches(&self) -> Vec<&str> {
+        match self {
+            Operation::BranchCreated { branch, .. }
+            | Operation::BranchDeleted { branch }
+            | Operation::BranchMoved { branch, .. }
+            | Operation::BackupCreated { branch, .. }
+            | Operation::BackupRestored { branch, .. } => vec![branch.as_str()],
+            Operation::BranchRenamed { old_name, new_name } => vec![old_name.as_str(), new_name.as_str()],
+            Operation::SyncStarted { branches }
+            | Operation::SyncCompleted { branches, .. }
+            | Operation::RestackStarted { branches }
+            | Operation::RestackCompleted { branches, .. } => branches.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Name of the command that modified `branch`, if this operation did
+    ///
+    /// Only the start of sync/restack counts (the completed event's branch
+    /// list can be partial), and backups are bookkeeping rather than changes.
+    pub fn modified(&self, branch: &str) -> Option<&'static str> {
+        match self {
+            Operation::BranchCreated { branch: b, .. } if b == branch => Some("create"),
+            Operation::BranchDeleted { branch: b } if b == branch => Some("delete"),
+            Operation::BranchMoved { branch: b, .. } if b == branch => Some("move"),
+            Operation::BranchRenamed { new_name, .. } if new_name == branch => Some("rename"),
+            Operation::SyncStarted { branches } if branches.iter().any(|b| b == branch) => Some("sync"),
+            Operation::RestackStarted { branches } if branches.iter().any(|b| b == branch) => Some("restack"),
+            Operation::BackupRestored { branch: b, .. } if b == branch => Some("undo"),
+            _ => None,
+        }
+    }
+}
+
 /// A log entry with timestamp and metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEntry {
+    /// Unique id, assigned when the entry is written (empty in logs written before ids)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
     /// Timestamp when operation occurred
     pub timestamp: DateTime<Utc>,
     /// The operation that was performed
@@ -54,6 +98,7 @@ impl LogEntry {
     /// Create a new log entry with current timestamp
     pub fn new(operation: Operation) -> Self {
         Self {
+            id: String::new(),
             timestamp: Utc::now(),
             operation,
             message: None,
@@ -63,11 +108,34 @@ impl LogEntry {
     /// Create a new log entry with a message
     pub fn with_message(operation: Operation, message: String) -> Self {
         Self {
+            id: String::new(),
             timestamp: Utc::now(),
             operation,
             message: Some(message),
         }
     }
+
+    /// Identifier for this entry in `history` and `undo --id`
+    ///
+    /// Entries from logs written before ids were stored fall back to their hex
+    /// microsecond timestamp, which is what history showed for them.
+    pub fn id(&self) -> String {
+        if self.id.is_empty() {
+            format!("{:x}", self.timestamp.timestamp_micros())
+        } else {
+            self.id.clone()
+        }
+    }
+}
+
+/// Generate a new entry id
+///
+/// Ids combine a nanosecond timestamp, the process id and an atomic counter, so
+/// two entries never share one, even when written in the same clock tick.
+fn new_entry_id() -> String {
+    let nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+    let counter = ENTRY_COUNTER.fetch_add(1, Ordering::SeqCst);
+    format!("{:x}-{}-{}", nanos, std::process::id(), counter)
 }
 
 /// Manages the operation log stored in .git/diamond/operations.jsonl
@@ -96,8 +164,10 @@ impl OperationLog {
         Self { log_path: path }
     }
 
-    /// Append a log entry to the operation log
-    pub fn log(&self, entry: LogEntry) -> Result<()> {
+    /// Append a log entry to the operation log, assigning its id
+    pub fn log(&self, mut entry: LogEntry) -> Result<()> {
+        entry.id = new_entry_id();
+
         let mut file = OpenOptions::new()
             .create(true)
             .append(true)
@@ -216,11 +286,10 @@ impl OperationLog {
 
         // Scan from newest to oldest
         for (i, entry) in entries.iter().enumerate().rev() {
-            let (op_type, branches) = match &entry.operation {
-                Operation::SyncStarted { branches } if !branches.is_empty() => ("sync", branches),
-                Operation::RestackStarted { branches } if !branches.is_empty() => ("restack", branches),
-                _ => continue,
+            let Some(op) = undoable_at(&entries, i) else {
+                continue;
             };
+            let branches = &op.branches;
 
             // Check if there's a corresponding Completed event after this Started event
             // If there's no Completed, the operation might still be in progress
@@ -251,24 +320,94 @@ impl OperationLog {
             });
 
             if !all_restored {
-                return Ok(Some(UndoableOperation {
-                    operation_type: op_type.to_string(),
-                    branches: branches.clone(),
-                    timestamp: entry.timestamp,
-                }));
+                return Ok(Some(op));
             }
         }
         Ok(None)
     }
+
+    /// The last `n` entries that touched `branch` (all of them if `n` is 0)
+    pub fn read_for_branch(&self, branch: &str, n: usize) -> Result<Vec<LogEntry>> {
+        let mut entries: Vec<LogEntry> = self
+            .read_all()?
+            .into_iter()
+            .filter(|e| e.operation.branches().contains(&branch))
+            .collect();
+        if n > 0 {
+            entries.drain(..entries.len().saturating_sub(n));
+        }
+        Ok(entries)
+    }
+
+    /// Most recent operation that modified `branch`: its command name and when it ran
+    pub fn last_modified(&self, branch: &str) -> Result<Option<(&'static str, DateTime<Utc>)>> {
+        let entries = self.read_all()?;
+        Ok(entries
+            .iter()
+            .rev()
+            .find_map(|e| e.operation.modified(branch).map(|kind| (kind, e.timestamp))))
+    }
+
+    /// Find a specific sync/restack operation by its history ID
+    pub fn find_undoable_operation(&self, id: &str) -> Result<UndoableOperation> {
+        let entries = self.read_all()?;
+
+        let Some(i) = entries.iter().position(|e| e.id() == id) else {
+            anyhow::bail!(
+                "No operation with ID '{}' in history.\n  Run '{} history --all' to list IDs.",
+                id,
+                crate::program_name::program_name()
+            );
+        };
+
+        undoable_at(&entries, i).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Operation '{}' cannot be undone:\n  only sync and restack operations record backups.",
+                id
+            )
+        })
+    }
+}
+
+/// Build the undoable operation started at `entries[i]`, if it is one
+fn undoable_at(entries: &[LogEntry], i: usize) -> Option<UndoableOperation> {
+    let entry = &entries[i];
+    let (op_type, branches) = match &entry.operation {
+        Operation::SyncStarted { branches } if !branches.is_empty() => ("sync", branches),
+        Operation::RestackStarted { branches } if !branches.is_empty() => ("restack", branches),
+        _ => return None,
+    };
+
+    // Sync and restack log a BackupCreated per branch right before starting
+    let backup_refs = entries[..i]
+        .iter()
+        .rev()
+        .map_while(|e| match &e.operation {
+            Operation::BackupCreated { branch, backup_ref } if branches.contains(branch) => Some(backup_ref.clone()),
+            _ => None,
+        })
+        .collect();
+
+    Some(UndoableOperation {
+        id: entry.id(),
+        operation_type: op_type.to_string(),
+        branches: branches.clone(),
+        backup_refs,
+        timestamp: entry.timestamp,
+    })
 }
 
 /// Information about an undoable operation
 #[derive(Debug, Clone)]
 pub struct UndoableOperation {
+    /// History ID of the operation's start entry
+    pub id: String,
     /// Type of operation ("sync" or "restack")
     pub operation_type: String,
     /// Branches affected by this operation
     pub branches: Vec<String>,
+    /// Backup refs logged right before the operation (empty for old logs)
+    pub backup_refs: Vec<String>,
     /// When the operation occurred
     pub timestamp: DateTime<Utc>,
 }
@@ -569,6 +708,77 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_for_branch_filters_entries() -> Result<()> {
+        let dir = tempdir()?;
+        let log = OperationLog::from_path(dir.path().join("operations.jsonl"));
+
+        log.log(LogEntry::new(Operation::BranchCreated {
+            branch: "feature-a".to_string(),
+            parent: Some("main".to_string()),
+        }))?;
+        log.log(LogEntry::new(Operation::BranchCreated {
+            branch: "feature-b".to_string(),
+            parent: Some("feature-a".to_string()),
+        }))?;
+        log.log(LogEntry::new(Operation::RestackStarted {
+            branches: vec!["feature-a".to_string(), "feature-b".to_string()],
+        }))?;
+        log.log(LogEntry::new(Operation::BranchRenamed {
+            old_name: "feature-b".to_string(),
+            new_name: "feature-c".to_string(),
+        }))?;
+
+        let entries = log.read_for_branch("feature-a", 0)?;
+        assert_eq!(entries.len(), 2);
+        assert!(matches!(entries[0].operation, Operation::BranchCreated { ref branch, .. } if branch == "feature-a"));
+        assert!(matches!(entries[1].operation, Operation::RestackStarted { .. }));
+
+        // Renames match under both names; the limit keeps the newest entries
+        let entries = log.read_for_branch("feature-b", 2)?;
+        assert_eq!(entries.len(), 2);
+        assert!(matches!(entries[0].operation, Operation::RestackStarted { .. }));
+        assert!(matches!(entries[1].operation, Operation::BranchRenamed { .. }));
+
+        assert!(log.read_for_branch("unrelated", 0)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_modified_filters_by_branch() -> Result<()> {
+        let dir = tempdir()?;
+        let log_path = dir.path().join("operations.jsonl");
+        let log = OperationLog::from_path(log_path);
+
+        log.log(LogEntry::new(Operation::BranchCreated {
+            branch: "feature".to_string(),
+            parent: Some("main".to_string()),
+        }))?;
+        log.log(LogEntry::new(Operation::SyncStarted {
+            branches: vec!["feature".to_string()],
+        }))?;
+        log.log(LogEntry::new(Operation::SyncCompleted {
+            branches: vec![],
+            success: true,
+        }))?;
+        log.log(LogEntry::new(Operation::BackupCreated {
+            branch: "feature".to_string(),
+            backup_ref: "refs/diamond/backup/feature-1".to_string(),
+        }))?;
+        log.log(LogEntry::new(Operation::RestackStarted {
+            branches: vec!["other".to_string()],
+        }))?;
+
+        // Backups and other branches' operations don't count
+        let (kind, _) = log.last_modified("feature")?.unwrap();
+        assert_eq!(kind, "sync");
+        assert_eq!(log.last_modified("other")?.unwrap().0, "restack");
+        assert!(log.last_modified("untouched")?.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_last_undoable_operation_skips_in_progress() -> Result<()> {
         let dir = tempdir()?;
@@ -647,4 +857,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_find_undoable_operation_distinguishes_entries_with_same_timestamp() -> Result<()> {
+        let dir = tempdir()?;
+        let log = OperationLog::from_path(dir.path().join("operations.jsonl"));
+
+        let timestamp = Utc::now();
+        for branch in ["a", "b"] {
+            let mut entry = LogEntry::new(Operation::SyncStarted {
+                branches: vec![branch.to_string()],
+            });
+            entry.timestamp = timestamp;
+            log.log(entry)?;
+        }
+
+        let entries = log.read_all()?;
+        assert_ne!(entries[0].id(), entries[1].id());
+        assert_eq!(log.find_undoable_operation(&entries[0].id())?.branches, vec!["a"]);
+        assert_eq!(log.find_undoable_operation(&entries[1].id())?.branches, vec!["b"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_entry_without_stored_id_uses_timestamp_id() -> Result<()> {
+        let entry: LogEntry = serde_json::from_str(
+            r#"{"timestamp":"2026-01-02T03:04:05Z","operation":{"type":"branch_deleted","branch":"old"}}"#,
+        )?;
+
+        assert_eq!(entry.id(), format!("{:x}", entry.timestamp.timestamp_micros()));
+
+        Ok(())
+    }
 }
@@ -1,10 +1,12 @@
 //! Platform-specific utilities for Diamond.
 //!
 //! This module contains platform-specific functionality, primarily for handling
-//! cross-platform path display differences between Windows and Unix systems.
+//! cross-platform path display differences between Windows and Unix systems,
+//! plus best-effort clipboard access and shell completion install locations.
 
+use clap_complete::Shell;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// A wrapper around `&Path` that displays paths in a user-friendly format.
 ///
@@ -54,6 +56,108 @@ impl<'a> Display for DisplayPath<'a> {
     }
 }
 
+/// Clipboard commands to try, in order, for the current platform
+fn clipboard_commands() -> &'static [(&'static str, &'static [&'static str])] {
+    if cfg!(target_os = "macos") {
+        &[("pbcopy", &[])]
+    } else if cfg!(windows) {
+        &[("clip", &[])]
+    } else {
+        &[
+            ("wl-copy", &[]),
+            ("xclip", &["-selection", "clipboard"]),
+            ("xsel", &["--clipboard", "--input"]),
+        ]
+    }
+}
+
+/// Copy text to the system clipboard.
+///
+/// Returns `false` when no clipboard tool is available (e.g. headless or SSH
+/// sessions), so callers can fall back to just printing.
+pub fn copy_to_clipboard(text: &str) -> bool {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    for (program, args) in clipboard_commands() {
+        let Ok(mut child) = Command::new(program)
+            .args(*args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+
+        let written = child
+            .stdin
+            .take()
+            .map(|mut stdin| stdin.write_all(text.as_bytes()).is_ok())
+            .unwrap_or(false);
+
+        if child.wait().map(|status| status.success()).unwrap_or(false) && written {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Where `completion <shell> --install` writes the script for `bin_name`
+///
+/// `env` looks up environment variables (`XDG_DATA_HOME`, `XDG_CONFIG_HOME`,
+/// `FPATH`); paths fall back to the usual per-user defaults under `home`.
+/// For zsh, the first `FPATH` entry inside `home` is preferred so no `.zshrc`
+/// change is needed. For PowerShell this is the profile that dot-sources the
+/// script, not the script itself. Returns None for shells without a per-user
+/// completion directory (elvish).
+pub fn completion_install_path(
+    shell: Shell,
+    bin_name: &str,
+    home: &Path,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<PathBuf> {
+    let xdg = |var: &str, default: &str| {
+        env(var)
+            .filter(|dir| Path::new(dir).is_absolute())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(default))
+    };
+
+    match shell {
+        Shell::Bash => Some(
+            xdg("XDG_DATA_HOME", ".local/share")
+                .join("bash-completion/completions")
+                .join(bin_name),
+        ),
+        Shell::Zsh => {
+            let user_fpath = env("FPATH").and_then(|fpath| {
+                fpath
+                    .split(':')
+                    .map(PathBuf::from)
+                    .find(|dir| dir.starts_with(home) && dir != home)
+            });
+            let dir = user_fpath.unwrap_or_else(|| home.join(".zsh/completions"));
+            Some(dir.join(format!("_{}", bin_name)))
+        }
+        Shell::Fish => Some(
+            xdg("XDG_CONFIG_HOME", ".config")
+                .join("fish/completions")
+                .join(format!("{}.fish", bin_name)),
+        ),
+        Shell::PowerShell => {
+            let dir = if cfg!(windows) {
+                home.join("Documents").join("PowerShell")
+            } else {
+                xdg("XDG_CONFIG_HOME", ".config").join("powershell")
+            };
+            Some(dir.join("Microsoft.PowerShell_profile.ps1"))
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,4 +271,57 @@ mod tests {
         // Just verify it doesn't panic - exact output depends on platform
         assert!(result.contains("tmp") || result.contains(r"\tmp"));
     }
+
+    #[test]
+    fn test_completion_install_path_per_shell() {
+        let home = Path::new("/home/alice");
+        let no_env = |_: &str| None;
+
+        assert_eq!(
+            completion_install_path(Shell::Bash, "dm", home, no_env),
+            Some(home.join(".local/share/bash-completion/completions/dm"))
+        );
+        assert_eq!(
+            completion_install_path(Shell::Zsh, "dm", home, no_env),
+            Some(home.join(".zsh/completions/_dm"))
+        );
+        assert_eq!(
+            completion_install_path(Shell::Fish, "dm", home, no_env),
+            Some(home.join(".config/fish/completions/dm.fish"))
+        );
+        let profile = if cfg!(windows) {
+            home.join("Documents").join("PowerShell")
+        } else {
+            home.join(".config/powershell")
+        };
+        assert_eq!(
+            completion_install_path(Shell::PowerShell, "dm", home, no_env),
+            Some(profile.join("Microsoft.PowerShell_profile.ps1"))
+        );
+        assert_eq!(completion_install_path(Shell::Elvish, "dm", home, no_env), None);
+    }
+
+    #[test]
+    fn test_completion_install_path_honours_env() {
+        let home = Path::new("/home/alice");
+        let env = |var: &str| match var {
+            "XDG_DATA_HOME" => Some("/data".to_string()),
+            "XDG_CONFIG_HOME" => Some("relative/ignored".to_string()),
+            "FPATH" => Some("/usr/share/zsh/functions:/home/alice/.zfunc".to_string()),
+            _ => None,
+        };
+
+        assert_eq!(
+            completion_install_path(Shell::Bash, "dm", home, env),
+            Some(PathBuf::from("/data/bash-completion/completions/dm"))
+        );
+        assert_eq!(
+            completion_install_path(Shell::Zsh, "dm", home, env),
+            Some(home.join(".zfunc/_dm"))
+        );
+        assert_eq!(
+            completion_install_path(Shell::Fish, "dm", home, env),
+            Some(home.join(".config/fish/completions/dm.fish"))
+        );
+    }
 }
@@ -13,6 +13,9 @@ use std::path::{Path, PathBuf};
 /// Lock file name within .git/diamond/
 const LOCK_FILE: &str = "lock";
 
+/// Directory within .git/diamond/ holding named locks
+const LOCKS_DIR: &str = "locks";
+
 /// Guard that holds an exclusive lock on the Diamond ref store.
 ///
 /// The lock is automatically released when this guard is dropped.
@@ -34,15 +37,27 @@ impl RefStoreLockGuard {
     ///
     /// Returns an error if the lock file cannot be created or locked.
     pub fn acquire(git_dir: &Path) -> Result<Self> {
-        let lock_path = Self::lock_path_for_git_dir(git_dir);
+        Self::acquire_path(&Self::lock_path_for_git_dir(git_dir))
+    }
 
+    /// Acquire an exclusive lock on a named lock file in `.git/diamond/locks/`.
+    ///
+    /// For short critical sections that must not run concurrently but
+    /// shouldn't hold up the whole ref store (e.g., creating a branch's PR).
+    /// Blocks until the lock can be acquired.
+    pub fn acquire_named(git_dir: &Path, name: &str) -> Result<Self> {
+        let file_name = format!("{}.lock", name.replace('/', "%2F"));
+        Self::acquire_path(&git_dir.join("diamond").join(LOCKS_DIR).join(file_name))
+    }
+
+    fn acquire_path(lock_path: &Path) -> Result<Self> {
         // Ensure parent directory exists
         if let Some(parent) = lock_path.parent() {
             fs::create_dir_all(parent).context("Failed to create diamond directory")?;
         }
 
         // Open or create the lock file
-        let file = File::create(&lock_path).context("Failed to create lock file")?;
+        let file = File::create(lock_path).context("Failed to create lock file")?;
 
         // Acquire exclusive lock (blocks until available)
         file.lock_exclusive()
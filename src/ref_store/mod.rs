@@ -14,6 +14,7 @@
 mod frozen;
 mod lock;
 mod parent;
+mod snapshot;
 mod traversal;
 mod trunk;
 
@@ -22,6 +23,7 @@ mod tests;
 
 pub use lock::RefStoreLockGuard;
 pub use parent::validate_parent_name;
+pub use snapshot::StackSnapshot;
 
 use crate::git_gateway::GitGateway;
 use anyhow::{Context, Result};
@@ -114,6 +114,28 @@ impl RefStore {
         Ok(())
     }
 
+    /// Write a branch's parent ref verbatim, without validation (for diagnostic tools only)
+    ///
+    /// **Warning:** This skips the existence and self-reference checks in
+    /// `set_parent()`. It exists so `dm doctor --fix` can roll metadata back to
+    /// exactly what it found, including orphaned or corrupted parents.
+    pub fn set_parent_unchecked(&self, branch: &str, parent: &str) -> Result<()> {
+        let ref_name = format!("{}{}", PARENT_REF_PREFIX, branch);
+        let blob_oid = self
+            .gateway
+            .create_blob(parent.as_bytes())
+            .context("Failed to create parent blob")?;
+        self.gateway
+            .create_reference(
+                &ref_name,
+                &blob_oid,
+                true,
+                &format!("dm: restore parent of {} to {}", branch, parent),
+            )
+            .context(format!("Failed to restore parent ref for {}", branch))?;
+        Ok(())
+    }
+
     /// Get a branch's parent without validation (for diagnostic tools only)
     ///
     /// **Warning:** This bypasses CRITICAL-8 corruption detection.
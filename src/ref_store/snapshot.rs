@@ -0,0 +1,157 @@
+//! Read-only, in-memory view of the stack graph.
+//!
+//! A `StackSnapshot` is built from a single scan of `refs/diamond/parent/*`
+//! and answers parent/child/traversal queries without further git calls.
+//! Use it for commands that walk the whole graph repeatedly (doctor, log, submit).
+//! The snapshot does not observe writes made after it was taken.
+
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+
+use super::{validate_parent_name, RefStore, PARENT_REF_PREFIX};
+
+/// Maximum depth for walking the parent chain (prevents runaway on circular refs)
+const MAX_ANCESTOR_DEPTH: usize = 1000;
+
+/// Immutable adjacency map of tracked branches.
+#[derive(Debug, Clone, Default)]
+pub struct StackSnapshot {
+    trunk: Option<String>,
+    /// Raw parent ref content keyed by child branch (unvalidated)
+    parents: HashMap<String, String>,
+    /// Children keyed by parent branch, sorted alphabetically
+    children: HashMap<String, Vec<String>>,
+}
+
+impl RefStore {
+    /// Take a read-only snapshot of the full stack graph.
+    ///
+    /// Performs one `list_references` over the parent prefix and reads each blob once.
+    pub fn snapshot(&self) -> Result<StackSnapshot> {
+        let trunk = self.get_trunk()?;
+        let mut parents = HashMap::new();
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+
+        let pattern = format!("{}*", PARENT_REF_PREFIX);
+        for (ref_name, oid) in self.gateway.list_references(&pattern)? {
+            let Some(branch) = ref_name.strip_prefix(PARENT_REF_PREFIX) else {
+                continue;
+            };
+            // Match get_children(): unreadable blobs are skipped, content is read lossily
+            let Ok(blob_content) = self.gateway.read_blob(&oid) else {
+                continue;
+            };
+            let parent = String::from_utf8_lossy(&blob_content).to_string();
+            children.entry(parent.clone()).or_default().push(branch.to_string());
+            parents.insert(branch.to_string(), parent);
+        }
+
+        for list in children.values_mut() {
+            list.sort();
+        }
+
+        Ok(StackSnapshot {
+            trunk,
+            parents,
+            children,
+        })
+    }
+}
+
+impl StackSnapshot {
+    /// The configured trunk at the time of the snapshot
+    pub fn trunk(&self) -> Option<&str> {
+        self.trunk.as_deref()
+    }
+
+    /// Raw parent ref content for a branch, without validation.
+    ///
+    /// Equivalent to `RefStore::get_parent_unchecked()`.
+    pub fn parent_of_unchecked(&self, branch: &str) -> Option<&str> {
+        self.parents.get(branch).map(|s| s.as_str())
+    }
+
+    /// Parent of a branch, validated like `RefStore::get_parent()`.
+    pub fn parent_of(&self, branch: &str) -> Result<Option<&str>> {
+        match self.parent_of_unchecked(branch) {
+            Some(parent) => {
+                validate_parent_name(parent, branch)?;
+                Ok(Some(parent))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Direct children of a branch, sorted alphabetically
+    pub fn children_of(&self, branch: &str) -> &[String] {
+        self.children.get(branch).map(|v| v.as_slice()).unwrap_or(&[])
+    }
+
+    /// Check if a branch is tracked (has a parent ref)
+    pub fn is_tracked(&self, branch: &str) -> bool {
+        self.parents.contains_key(branch)
+    }
+
+    /// All tracked branches, sorted alphabetically
+    pub fn tracked_branches(&self) -> Vec<String> {
+        let mut branches: Vec<String> = self.parents.keys().cloned().collect();
+        branches.sort();
+        branches
+    }
+
+    /// Ancestors of a branch ordered trunk-to-branch (trunk excluded).
+    ///
+    /// Same semantics as `RefStore::ancestors()`.
+    pub fn ancestors(&self, branch: &str) -> Result<Vec<String>> {
+        let trunk = self
+            .trunk
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("ancestors() requires trunk to be configured"))?;
+
+        let mut result = vec![branch.to_string()];
+        let mut current = branch.to_string();
+        let mut seen = HashSet::new();
+        seen.insert(current.clone());
+
+        while let Some(parent) = self.parent_of(&current)? {
+            if parent == trunk {
+                break;
+            }
+
+            if !seen.insert(parent.to_string()) {
+                anyhow::bail!("Circular parent reference detected: {} -> ... -> {}", branch, parent);
+            }
+
+            if result.len() >= MAX_ANCESTOR_DEPTH {
+                anyhow::bail!("Parent chain exceeds maximum depth ({})", MAX_ANCESTOR_DEPTH);
+            }
+
+            result.push(parent.to_string());
+            current = parent.to_string();
+        }
+
+        result.reverse();
+        Ok(result)
+    }
+
+    /// Descendants of a branch in DFS order with siblings sorted (branch excluded).
+    ///
+    /// Same semantics as `RefStore::descendants()`.
+    pub fn descendants(&self, branch: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        let mut visited = HashSet::new();
+        visited.insert(branch.to_string());
+
+        // Iterative DFS: push children in reverse so the alphabetically first is visited first
+        let mut stack: Vec<&str> = self.children_of(branch).iter().rev().map(|s| s.as_str()).collect();
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current.to_string()) {
+                continue;
+            }
+            result.push(current.to_string());
+            stack.extend(self.children_of(current).iter().rev().map(|s| s.as_str()));
+        }
+
+        result
+    }
+}
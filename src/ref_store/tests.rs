@@ -1364,3 +1364,71 @@ fn test_compute_tree_prefix_wide_tree_many_siblings() -> Result<()> {
 
     Ok(())
 }
+
+// ============================================================================
+// snapshot() tests - In-memory stack graph
+// ============================================================================
+
+#[test]
+fn test_snapshot_matches_live_traversal() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let trunk = get_current_branch(&repo)?;
+    for name in ["feature", "child-a", "child-b", "grandchild", "other"] {
+        create_branch(&repo, name)?;
+    }
+
+    let store = RefStore::from_path(dir.path())?;
+    store.set_trunk(&trunk)?;
+    store.set_parent("feature", &trunk)?;
+    store.set_parent("child-a", "feature")?;
+    store.set_parent("child-b", "feature")?;
+    store.set_parent("grandchild", "child-a")?;
+    store.set_parent("other", &trunk)?;
+
+    let snapshot = store.snapshot()?;
+
+    assert_eq!(snapshot.trunk(), Some(trunk.as_str()));
+    assert_eq!(snapshot.tracked_branches(), store.list_tracked_branches()?);
+
+    let mut all = store.list_tracked_branches()?;
+    all.push(trunk.clone());
+    for branch in &all {
+        assert_eq!(
+            snapshot.parent_of(branch)?.map(String::from),
+            store.get_parent(branch)?,
+            "parent mismatch for {}",
+            branch
+        );
+
+        let mut live_children: Vec<String> = store.get_children(branch)?.into_iter().collect();
+        live_children.sort();
+        assert_eq!(snapshot.children_of(branch), live_children.as_slice());
+
+        assert_eq!(snapshot.descendants(branch), store.descendants(branch)?);
+        if branch != &trunk {
+            assert_eq!(snapshot.ancestors(branch)?, store.ancestors(branch)?);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_snapshot_does_not_see_later_writes() -> Result<()> {
+    let dir = tempdir()?;
+    let repo = init_test_repo(dir.path())?;
+    let trunk = get_current_branch(&repo)?;
+    create_branch(&repo, "feature")?;
+
+    let store = RefStore::from_path(dir.path())?;
+    store.set_trunk(&trunk)?;
+
+    let snapshot = store.snapshot()?;
+    store.set_parent("feature", &trunk)?;
+
+    assert!(!snapshot.is_tracked("feature"));
+    assert!(store.is_tracked("feature")?);
+
+    Ok(())
+}
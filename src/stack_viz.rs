@@ -302,6 +302,77 @@ pub fn collect_full_stack(branch: &str, ref_store: &RefStore) -> Result<Vec<Stri
     ref_store.collect_branches_dfs(&[root])
 }
 
+/// Separator between branches in the navigation breadcrumb
+const BREADCRUMB_SEPARATOR: &str = " › ";
+
+/// Linear path through `branch`'s stack for the navigation breadcrumb
+///
+/// Trunk and the branch's ancestors, the branch itself, then its descendants,
+/// following the first child alphabetically at each fork (the one `up` picks).
+pub fn breadcrumb_path(branch: &str, ref_store: &RefStore) -> Result<Vec<String>> {
+    let mut seen = std::collections::HashSet::new();
+    seen.insert(branch.to_string());
+
+    let mut path = vec![branch.to_string()];
+    let mut current = branch.to_string();
+    while let Some(parent) = ref_store.get_parent(&current)? {
+        if !seen.insert(parent.clone()) {
+            break;
+        }
+        path.push(parent.clone());
+        current = parent;
+    }
+    path.reverse();
+
+    let mut current = branch.to_string();
+    loop {
+        let mut children: Vec<String> = ref_store.get_children(&current)?.into_iter().collect();
+        children.sort();
+        match children.into_iter().next() {
+            Some(child) if seen.insert(child.clone()) => {
+                path.push(child.clone());
+                current = child;
+            }
+            _ => break,
+        }
+    }
+
+    Ok(path)
+}
+
+/// Render a breadcrumb on one line (`main › a › [b] › c`), bracketing `current`
+pub fn render_breadcrumb(path: &[String], current: &str) -> String {
+    path.iter()
+        .map(|branch| {
+            if branch == current {
+                format!("[{}]", branch).green().bold().to_string()
+            } else {
+                branch.dimmed().to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(BREADCRUMB_SEPARATOR)
+}
+
+/// Print the breadcrumb for `current` after navigating, unless `nav.breadcrumb` is off
+///
+/// Prints nothing when there is no stack to show (untracked branch or lone trunk).
+/// Best effort: metadata errors are ignored so navigation never fails here.
+pub fn print_breadcrumb(ref_store: &RefStore, current: &str) {
+    let enabled = crate::config::Config::load()
+        .map(|c| c.nav.breadcrumb_enabled())
+        .unwrap_or(true);
+    if !enabled {
+        return;
+    }
+
+    if let Ok(path) = breadcrumb_path(current, ref_store) {
+        if path.len() > 1 {
+            println!("{}", render_breadcrumb(&path, current));
+        }
+    }
+}
+
 /// Update the stack visualization in all open PRs
 ///
 /// This fetches full info for all PRs, generates the stack markdown,
@@ -500,17 +571,20 @@ pub async fn update_stack_visualization_async(
         }
     }
 
-    // Batch update all PR bodies in parallel
-    let updated_count = forge.update_pr_bodies(&updates).await;
-
-    // Mark successful updates as done
-    if let Some(t) = &tracker {
-        for (pr_ref, _) in &updates {
-            if let Ok(number) = pr_ref.parse::<u64>() {
-                t.update_status(number, PrStatus::Done);
+    // Batch update all PR bodies in parallel, marking each PR as it completes
+    let updated_count = forge
+        .update_pr_bodies_with_progress(&updates, &|pr_ref, result| {
+            if let (Some(t), Ok(number)) = (&tracker, pr_ref.parse::<u64>()) {
+                let status = if result.is_ok() {
+                    PrStatus::Done
+                } else {
+                    PrStatus::Failed
+                };
+                t.update_status(number, status);
             }
-        }
-    }
+        })
+        .await;
+    failed_count += updates.len() - updated_count;
 
     let skipped_count = pr_infos.len() - open_prs.len();
 
@@ -521,6 +595,40 @@ pub async fn update_stack_visualization_async(
     Ok(updated_count)
 }
 
+/// Remove the stack visualization block from open PRs in the stack
+///
+/// Only PRs whose body actually contains a stack section are updated; the
+/// rest of the body is left as the user wrote it.
+///
+/// # Returns
+/// The number of PRs updated
+pub async fn strip_stack_visualization_async(branches: &[String], forge: &dyn AsyncForge) -> Result<usize> {
+    if branches.is_empty() {
+        return Ok(0);
+    }
+
+    let open_prs: Vec<String> = forge
+        .get_prs_full_info(branches)
+        .await
+        .into_iter()
+        .filter(|pr| pr.state != PrState::Merged && pr.state != PrState::Closed)
+        .map(|pr| pr.number.to_string())
+        .collect();
+    if open_prs.is_empty() {
+        return Ok(0);
+    }
+
+    let updates: Vec<(String, String)> = forge
+        .get_pr_bodies(&open_prs)
+        .await
+        .into_iter()
+        .filter(|(_, body)| find_stack_section(body).is_some())
+        .map(|(pr_ref, body)| (pr_ref, extract_user_content(&body)))
+        .collect();
+
+    Ok(forge.update_pr_bodies_with_progress(&updates, &|_, _| {}).await)
+}
+
 /// Update stack visualization for all tracked branches that have PRs
 ///
 /// This is useful for repairing stack visualization in existing PRs.
@@ -586,9 +694,51 @@ mod tests {
             ci: CiStatus::Success,
             head_ref: head_ref.to_string(),
             base_ref: "main".to_string(),
+            mergeable: None,
         }
     }
 
+    #[test]
+    fn test_render_breadcrumb_brackets_current_branch() {
+        let path: Vec<String> = ["main", "feat-a", "feat-b", "feat-c"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        assert_eq!(render_breadcrumb(&path, "feat-b"), "main › feat-a › [feat-b] › feat-c");
+        assert_eq!(render_breadcrumb(&path, "main"), "[main] › feat-a › feat-b › feat-c");
+        assert_eq!(render_breadcrumb(&path, "feat-c"), "main › feat-a › feat-b › [feat-c]");
+    }
+
+    #[test]
+    fn test_breadcrumb_path_follows_first_child() -> Result<()> {
+        use crate::test_context::{init_test_repo, TestRepoContext};
+
+        let dir = tempfile::tempdir()?;
+        let repo = init_test_repo(dir.path())?;
+        let _ctx = TestRepoContext::new(dir.path());
+
+        let head = repo.head()?.peel_to_commit()?;
+        for branch in ["feat-a", "feat-b", "feat-c", "feat-z", "other"] {
+            repo.branch(branch, &head, false)?;
+        }
+        let ref_store = RefStore::new()?;
+        ref_store.set_trunk("main")?;
+        ref_store.set_parent("feat-a", "main")?;
+        ref_store.set_parent("feat-b", "feat-a")?;
+        ref_store.set_parent("feat-z", "feat-b")?;
+        ref_store.set_parent("feat-c", "feat-b")?;
+        ref_store.set_parent("other", "main")?;
+
+        assert_eq!(
+            breadcrumb_path("feat-b", &ref_store)?,
+            vec!["main", "feat-a", "feat-b", "feat-c"]
+        );
+        assert_eq!(breadcrumb_path("other", &ref_store)?, vec!["main", "other"]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_find_stack_section_with_versioned_markers() {
         // Backwards compatibility: should still find versioned markers
@@ -1022,4 +1172,33 @@ mod tests {
         // Check stack position
         assert!(result.contains("Stack (2 of 4)"));
     }
+
+    #[tokio::test]
+    async fn test_strip_stack_visualization_only_touches_bodies_with_a_stack_section() -> Result<()> {
+        use crate::forge::mock::{MockForge, MockResponse};
+        use crate::forge::ForgeType;
+
+        let forge = MockForge::new(ForgeType::GitHub);
+        let with_viz = format!("Summary\n\n{}\nstack table\n{}", STACK_START, STACK_END);
+        forge.set_response(
+            "get_pr_full_info:with-viz",
+            MockResponse::Success(pr_json(1, "with-viz")),
+        );
+        forge.set_response("get_pr_full_info:plain", MockResponse::Success(pr_json(2, "plain")));
+        forge.set_response("get_pr_body:1", MockResponse::Success(with_viz));
+        forge.set_response("get_pr_body:2", MockResponse::Success("Just text".to_string()));
+
+        let branches = vec!["with-viz".to_string(), "plain".to_string()];
+        let stripped = strip_stack_visualization_async(&branches, &forge).await?;
+
+        assert_eq!(stripped, 1);
+        assert_eq!(forge.get_call_count("update_pr_body:1"), 1);
+        assert_eq!(forge.get_call_count("update_pr_body:2"), 0);
+
+        Ok(())
+    }
+
+    fn pr_json(number: u64, head_ref: &str) -> String {
+        serde_json::to_string(&make_test_pr(number, head_ref, false)).unwrap()
+    }
 }
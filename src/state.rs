@@ -15,6 +15,7 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
+use crate::git_gateway::Strategy;
 use crate::program_name::program_name;
 
 /// Maximum age (in seconds) for a lock file to be considered stale.
@@ -196,14 +197,14 @@ impl OperationLock {
 
 /// Check if a process with the given PID is still running.
 #[cfg(unix)]
-fn is_process_running(pid: u32) -> bool {
+pub(crate) fn is_process_running(pid: u32) -> bool {
     // On Unix, we can use kill(pid, 0) to check if process exists
     // Without sending an actual signal
     unsafe { libc::kill(pid as i32, 0) == 0 }
 }
 
 #[cfg(not(unix))]
-fn is_process_running(_pid: u32) -> bool {
+pub(crate) fn is_process_running(_pid: u32) -> bool {
     // On non-Unix systems, assume process is running (be conservative)
     // This is a safe default that won't accidentally clean up valid locks
     //
@@ -269,6 +270,18 @@ pub struct OperationState {
     pub move_target_parent: Option<String>,
     /// For move: the old parent branch (for rollback on abort)
     pub old_parent: Option<String>,
+    /// For rebase: the upstream to replay the moved branch from (instead of old_parent)
+    #[serde(default)]
+    pub rebase_from: Option<String>,
+    /// For sync --autostash: uncommitted changes are stashed until the operation ends
+    #[serde(default)]
+    pub autostash: bool,
+    /// For sync --autostash: restoring the stash conflicted; `continue` drops it once resolved
+    #[serde(default)]
+    pub autostash_conflicted: bool,
+    /// For sync: how branches are updated (so `continue` keeps using the same strategy)
+    #[serde(default)]
+    pub strategy: Strategy,
 }
 
 impl OperationState {
@@ -366,6 +379,10 @@ impl OperationState {
             original_branch,
             move_target_parent: None,
             old_parent: None,
+            rebase_from: None,
+            autostash: false,
+            autostash_conflicted: false,
+            strategy: Strategy::default(),
         }
     }
 
@@ -381,6 +398,10 @@ impl OperationState {
             original_branch,
             move_target_parent: None,
             old_parent: None,
+            rebase_from: None,
+            autostash: false,
+            autostash_conflicted: false,
+            strategy: Strategy::default(),
         }
     }
 
@@ -402,6 +423,10 @@ impl OperationState {
             original_branch,
             move_target_parent: Some(target_parent),
             old_parent,
+            rebase_from: None,
+            autostash: false,
+            autostash_conflicted: false,
+            strategy: Strategy::default(),
         }
     }
 
@@ -422,6 +447,10 @@ impl OperationState {
             original_branch: new_branch,
             move_target_parent: None,
             old_parent: Some(original_parent),
+            rebase_from: None,
+            autostash: false,
+            autostash_conflicted: false,
+            strategy: Strategy::default(),
         }
     }
 }
@@ -455,6 +484,15 @@ fn check_for_interrupted_operation() -> Result<()> {
             // Check if git actually has a rebase in progress
             let git_rebase_active = is_git_rebase_in_progress()?;
 
+            if !git_rebase_active && state.autostash_conflicted {
+                bail!(
+                    "A sync is waiting for stashed changes to be restored.\n\
+                     Resolve the conflicts, then run '{} continue' (or '{} abort' to keep the stash).",
+                    program_name(),
+                    program_name()
+                );
+            }
+
             if !git_rebase_active {
                 // State is stale - user likely ran `git rebase --abort` directly
                 // Clean it up automatically but warn about potential inconsistency
@@ -149,6 +149,70 @@ pub fn init_test_repo_with_branch(path: &Path, branch_name: &str) -> Result<git2
     Ok(repo)
 }
 
+/// Create branch `name` pointing at `base` without checking it out.
+#[cfg(test)]
+pub fn create_branch_from(repo: &git2::Repository, name: &str, base: &str) -> Result<()> {
+    let commit = repo.revparse_single(base)?.peel_to_commit()?;
+    repo.branch(name, &commit, false)?;
+    Ok(())
+}
+
+/// Commit `file` with `content` on top of `branch` without checking it out.
+#[cfg(test)]
+pub fn commit_on_branch(repo: &git2::Repository, branch: &str, file: &str, content: &str) -> Result<()> {
+    let parent = repo.revparse_single(branch)?.peel_to_commit()?;
+    let blob = repo.blob(content.as_bytes())?;
+    let mut builder = repo.treebuilder(Some(&parent.tree()?))?;
+    builder.insert(file, blob, 0o100644)?;
+    let tree = repo.find_tree(builder.write()?)?;
+    let sig = git2::Signature::now("Test", "test@test.com")?;
+    repo.commit(
+        Some(&format!("refs/heads/{}", branch)),
+        &sig,
+        &sig,
+        &format!("Add {}", file),
+        &tree,
+        &[&parent],
+    )?;
+    Ok(())
+}
+
+/// Run the git CLI in `path`, failing the test if the command fails.
+#[cfg(test)]
+pub fn git_in(path: &Path, args: &[&str]) {
+    let output = std::process::Command::new("git")
+        .args(args)
+        .current_dir(path)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "git {:?} failed: {:?}", args, output);
+}
+
+/// Write `content` to `file` and commit it on the checked-out branch, using the file name as message.
+#[cfg(test)]
+pub fn commit_in(path: &Path, file: &str, content: &str) {
+    std::fs::write(path.join(file), content).unwrap();
+    git_in(path, &["add", file]);
+    git_in(path, &["commit", "-m", file]);
+}
+
+/// Assert that only the main worktree is left, i.e. no scratch worktrees leaked.
+#[cfg(test)]
+pub fn assert_scratch_worktrees_removed(path: &Path) {
+    let output = std::process::Command::new("git")
+        .args(["worktree", "list", "--porcelain"])
+        .current_dir(path)
+        .output()
+        .unwrap();
+    let listing = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        listing.matches("worktree ").count(),
+        1,
+        "scratch worktrees left: {}",
+        listing
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,7 +24,6 @@
 use colored::Colorize;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use std::collections::HashMap;
-use std::io::IsTerminal;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -81,7 +80,7 @@ pub struct PrUpdateInfo {
 /// Progress tracker for parallel PR updates.
 ///
 /// In TTY mode, shows animated spinners for each PR.
-/// In non-TTY mode, prints simple progress messages.
+/// In non-TTY mode (or with spinners disabled), prints simple progress messages.
 pub struct PrProgressTracker {
     /// Whether we're in TTY mode
     is_tty: bool,
@@ -96,7 +95,7 @@ pub struct PrProgressTracker {
 impl PrProgressTracker {
     /// Create a new progress tracker.
     pub fn new(header: &str) -> Self {
-        let is_tty = std::io::stdout().is_terminal();
+        let is_tty = super::animate_progress();
 
         // Print header
         println!("{} {}", MARK_STEP.blue(), header);
@@ -257,7 +256,7 @@ pub struct SimplePrProgress {
 impl SimplePrProgress {
     /// Create a new simple progress indicator.
     pub fn new(message: &str, total: usize) -> Self {
-        if !std::io::stdout().is_terminal() {
+        if !super::animate_progress() {
             println!("{} {} ({} PRs)", MARK_STEP.blue(), message, total);
             return Self { spinner: None, total };
         }
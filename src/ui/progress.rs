@@ -1,22 +1,58 @@
 //! Progress indicators: spinners and progress bars.
 //!
-//! All functions gracefully degrade when not in a TTY.
+//! All functions gracefully degrade when not in a TTY, or when spinners
+//! are turned off with `ui.spinner = false` / `DIAMOND_NO_SPINNER`.
 
 use colored::Colorize;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use super::style::*;
 
+/// Environment variable that turns spinners off regardless of config
+pub const NO_SPINNER_ENV_VAR: &str = "DIAMOND_NO_SPINNER";
+
+static SPINNERS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turn animated spinners on or off for the rest of the process.
+///
+/// When off, progress helpers print plain step lines as in non-TTY mode.
+pub fn set_spinners_enabled(enabled: bool) {
+    SPINNERS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether animated spinners are turned on.
+pub fn spinners_enabled() -> bool {
+    SPINNERS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Resolve the spinner setting from config and a `DIAMOND_NO_SPINNER` value.
+///
+/// Any non-empty env value disables spinners, overriding config.
+pub fn resolve_spinners_enabled(config_enabled: bool, env_value: Option<&str>) -> bool {
+    config_enabled && env_value.is_none_or(str::is_empty)
+}
+
+/// Whether progress should be animated (spinners on and stdout is a TTY).
+pub(crate) fn animate_progress() -> bool {
+    spinners_enabled() && std::io::stdout().is_terminal()
+}
+
+/// Plain (non-animated) progress line: `→ message`, optionally indented.
+fn plain_line(indent: bool, mark: &str, message: &str) -> String {
+    format!("{}{} {}", if indent { "  " } else { "" }, mark, message)
+}
+
 // ──────────────────────────────────────────────────────────────
 // Spinners
 // ──────────────────────────────────────────────────────────────
 
 /// Create a spinner for indeterminate operations.
 ///
-/// Returns `Some(ProgressBar)` in TTY mode, `None` otherwise.
-/// When not in TTY, prints a plain step message instead.
+/// Returns `Some(ProgressBar)` in TTY mode with spinners enabled, `None` otherwise.
+/// Otherwise, prints a plain step message instead.
 ///
 /// # Example
 /// ```ignore
@@ -25,9 +61,9 @@ use super::style::*;
 /// ui::spinner_success(spin, "Fetched from origin");
 /// ```
 pub fn spinner(message: &str) -> Option<ProgressBar> {
-    if !std::io::stdout().is_terminal() {
-        // Non-TTY: print plain message
-        println!("{} {}", MARK_STEP.blue(), message);
+    if !animate_progress() {
+        // Non-TTY or spinners disabled: print plain message
+        println!("{}", plain_line(false, &MARK_STEP.blue().to_string(), message));
         return None;
     }
 
@@ -45,8 +81,8 @@ pub fn spinner(message: &str) -> Option<ProgressBar> {
 
 /// Create an indented spinner (for sub-steps).
 pub fn spinner_indented(message: &str) -> Option<ProgressBar> {
-    if !std::io::stdout().is_terminal() {
-        println!("  {} {}", MARK_STEP.blue(), message);
+    if !animate_progress() {
+        println!("{}", plain_line(true, &MARK_STEP.blue().to_string(), message));
         return None;
     }
 
@@ -69,8 +105,8 @@ pub fn spinner_success(spinner: Option<ProgressBar>, message: &str) {
             pb.finish_with_message(format!("{} {}", MARK_SUCCESS.green(), message));
         }
         None => {
-            // Non-TTY: print success message
-            println!("  {} {}", MARK_SUCCESS.green(), message);
+            // Non-TTY or spinners disabled: print success message
+            println!("{}", plain_line(true, &MARK_SUCCESS.green().to_string(), message));
         }
     }
 }
@@ -82,8 +118,8 @@ pub fn spinner_error(spinner: Option<ProgressBar>, message: &str) {
             pb.finish_with_message(format!("{} {}", MARK_ERROR.red(), message));
         }
         None => {
-            // Non-TTY: print error message
-            println!("  {} {}", MARK_ERROR.red(), message);
+            // Non-TTY or spinners disabled: print error message
+            println!("{}", plain_line(true, &MARK_ERROR.red().to_string(), message));
         }
     }
 }
@@ -95,7 +131,7 @@ pub fn spinner_warning(spinner: Option<ProgressBar>, message: &str) {
             pb.finish_with_message(format!("{} {}", MARK_WARNING.yellow(), message));
         }
         None => {
-            println!("  {} {}", MARK_WARNING.yellow(), message);
+            println!("{}", plain_line(true, &MARK_WARNING.yellow().to_string(), message));
         }
     }
 }
@@ -107,7 +143,7 @@ pub fn spinner_info(spinner: Option<ProgressBar>, message: &str) {
             pb.finish_with_message(format!("{} {}", MARK_INFO.blue(), message));
         }
         None => {
-            println!("  {} {}", MARK_INFO.blue(), message);
+            println!("{}", plain_line(true, &MARK_INFO.blue().to_string(), message));
         }
     }
 }
@@ -137,7 +173,7 @@ pub fn spinner_clear(spinner: Option<ProgressBar>) {
 /// ui::progress_finish(pb, "Rebased all branches");
 /// ```
 pub fn progress_bar(total: u64, message: &str) -> Option<ProgressBar> {
-    if !std::io::stdout().is_terminal() {
+    if !animate_progress() {
         println!("{} {} ({})", MARK_STEP.blue(), message, total);
         return None;
     }
@@ -252,6 +288,35 @@ mod tests {
         progress_error(pb, "Failed");
     }
 
+    #[test]
+    fn test_plain_lines_have_no_spinner_frames_and_report_completion() {
+        set_spinners_enabled(false);
+        assert!(!animate_progress());
+
+        // Disabled spinners fall back to the plain path and still finish
+        let spin = spinner("Fetching from origin...");
+        assert!(spin.is_none());
+        spinner_success(spin, "Fetched from origin");
+
+        let step = plain_line(false, MARK_STEP, "Fetching from origin...");
+        let done = plain_line(true, MARK_SUCCESS, "Fetched from origin");
+        assert_eq!(step, "→ Fetching from origin...");
+        assert_eq!(done, "  ✓ Fetched from origin");
+        for line in [&step, &done] {
+            assert!(!line.chars().any(|c| SPINNER_FRAMES.contains(c)));
+        }
+
+        set_spinners_enabled(true);
+    }
+
+    #[test]
+    fn test_resolve_spinners_enabled() {
+        assert!(resolve_spinners_enabled(true, None));
+        assert!(resolve_spinners_enabled(true, Some("")));
+        assert!(!resolve_spinners_enabled(true, Some("1")));
+        assert!(!resolve_spinners_enabled(false, None));
+    }
+
     #[test]
     fn test_multi_progress() {
         let mp = multi_progress();
@@ -8,11 +8,23 @@
 //! - Detect branches checked out in other worktrees (conflict prevention)
 //! - Detect orphaned worktrees (branches deleted while worktree exists)
 //! - Validate current worktree state at command startup
+//! - Track temporary worktrees Diamond creates and remove them on interrupt or error
 
 use anyhow::{Context, Result};
 use crate::platform::DisplayPath;
-use std::path::PathBuf;
+use crate::state::is_process_running;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Mutex;
+
+/// Location (inside the git common dir) of worktrees created by Diamond.
+///
+/// Worktrees live in a subdirectory named after the pid of the process that
+/// created them; once that process is gone they are safe to remove.
+const DIAMOND_WORKTREE_DIR: &str = "diamond/worktrees";
+
+/// Worktrees created by this process that have not been removed yet
+static CREATED_WORKTREES: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
 
 /// Information about a single worktree
 #[derive(Debug, Clone)]
@@ -471,6 +483,185 @@ pub fn has_multiple_worktrees() -> Result<bool> {
     Ok(active_worktrees > 1)
 }
 
+/// `git` in the current repository for managing Diamond's own worktrees
+///
+/// In test mode, runs in the thread-local test repository if set via `TestRepoContext`.
+fn repo_git() -> Command {
+    #[cfg(test)]
+    if let Some(path) = crate::test_context::test_repo_path() {
+        let mut cmd = Command::new("git");
+        cmd.current_dir(path);
+        return cmd;
+    }
+    Command::new("git")
+}
+
+/// Directory under which Diamond creates its temporary worktrees
+fn diamond_worktree_root() -> Result<PathBuf> {
+    let mut cmd = repo_git();
+    let output = cmd
+        .args(["rev-parse", "--git-common-dir"])
+        .output()
+        .context("Failed to run git rev-parse --git-common-dir")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git rev-parse --git-common-dir failed: {}", stderr.trim());
+    }
+
+    // The common dir may be printed relative to the directory git ran in
+    let common_dir = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    let common_dir = match cmd.get_current_dir() {
+        Some(dir) if common_dir.is_relative() => dir.join(common_dir),
+        _ => common_dir,
+    };
+    let common_dir = common_dir.canonicalize().unwrap_or(common_dir);
+    Ok(common_dir.join(DIAMOND_WORKTREE_DIR))
+}
+
+/// Register a worktree so it is removed by [`cleanup_diamond_worktrees`]
+pub fn register_worktree(path: PathBuf) {
+    if let Ok(mut created) = CREATED_WORKTREES.lock() {
+        created.push(path);
+    }
+}
+
+/// Create a detached temporary worktree at `commitish` and register it for cleanup.
+///
+/// The worktree lives under `.git/diamond/worktrees/<pid>/<name>`, which marks
+/// it as owned by this process even if it dies before it can clean up.
+pub fn create_diamond_worktree(name: &str, commitish: &str) -> Result<PathBuf> {
+    let owner_dir = diamond_worktree_root()?.join(std::process::id().to_string());
+    std::fs::create_dir_all(&owner_dir).context("Failed to create Diamond worktree directory")?;
+    let path = owner_dir.join(name);
+
+    let output = repo_git()
+        .args(["worktree", "add", "--detach"])
+        .arg(&path)
+        .arg(commitish)
+        .output()
+        .context("Failed to run git worktree add")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git worktree add failed: {}", stderr.trim());
+    }
+
+    register_worktree(path.clone());
+    Ok(path)
+}
+
+/// Rebase the detached HEAD of a scratch worktree onto `onto`.
+///
+/// Returns the rebased HEAD, or None on conflicts; a conflicted rebase is
+/// aborted so the worktree can be removed cleanly.
+pub fn rebase_in_worktree(path: &Path, onto: &str) -> Result<Option<String>> {
+    let output = Command::new("git")
+        .args(["rebase", onto])
+        .env("GIT_EDITOR", "true")
+        .current_dir(path)
+        .output()
+        .context("Failed to run git rebase")?;
+
+    if !output.status.success() {
+        let abort = Command::new("git")
+            .args(["rebase", "--abort"])
+            .current_dir(path)
+            .output()
+            .context("Failed to run git rebase --abort")?;
+        if abort.status.success() {
+            return Ok(None);
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git rebase failed in {}: {}", DisplayPath(path), stderr.trim());
+    }
+
+    let head = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(path)
+        .output()
+        .context("Failed to run git rev-parse")?;
+    Ok(Some(String::from_utf8_lossy(&head.stdout).trim().to_string()))
+}
+
+/// Remove a worktree created by [`create_diamond_worktree`] (best-effort)
+pub fn remove_diamond_worktree(path: &Path) {
+    let _ = repo_git().args(["worktree", "remove", "--force"]).arg(path).output();
+    if let Ok(mut created) = CREATED_WORKTREES.lock() {
+        created.retain(|p| p != path);
+    }
+}
+
+/// Remove every Diamond-created worktree.
+///
+/// Covers worktrees registered by this process as well as leftovers from an
+/// earlier run that was killed, identified by living under the Diamond
+/// worktree directory with an owning pid that is no longer running. Scratch
+/// worktrees of other live `dm` processes and worktrees created by the user
+/// are never touched.
+/// Best-effort: called from the Ctrl-C handler and on error exit, so failures
+/// are ignored. Returns the paths that were removed.
+pub fn cleanup_diamond_worktrees() -> Vec<PathBuf> {
+    let mut candidates: Vec<PathBuf> = match CREATED_WORKTREES.try_lock() {
+        Ok(mut created) => created.drain(..).collect(),
+        Err(_) => Vec::new(),
+    };
+
+    if let Ok(root) = diamond_worktree_root() {
+        if root.exists() {
+            if let Ok(worktrees) = list_worktrees() {
+                candidates.extend(
+                    worktrees
+                        .into_iter()
+                        .filter(|wt| !wt.is_current && is_orphaned_diamond_worktree(&wt.path, &root))
+                        .map(|wt| wt.path),
+                );
+            }
+        }
+    }
+
+    let mut removed: Vec<PathBuf> = Vec::new();
+    for path in candidates {
+        let path = path.canonicalize().unwrap_or(path);
+        if removed.contains(&path) {
+            continue;
+        }
+        let status = repo_git().args(["worktree", "remove", "--force"]).arg(&path).output();
+        if matches!(status, Ok(ref o) if o.status.success()) {
+            // Drop the per-process directory once its last worktree is gone
+            if let Some(owner_dir) = path.parent() {
+                let _ = std::fs::remove_dir(owner_dir);
+            }
+            removed.push(path);
+        }
+    }
+
+    if !removed.is_empty() {
+        let _ = repo_git().args(["worktree", "prune"]).output();
+    }
+
+    removed
+}
+
+/// Whether `path` is inside the Diamond worktree directory `root` and its
+/// owning process (named by the first directory below `root`) has exited
+fn is_orphaned_diamond_worktree(path: &Path, root: &Path) -> bool {
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+    let Ok(relative) = path.strip_prefix(&root) else {
+        return false;
+    };
+    let owner = relative
+        .components()
+        .next()
+        .and_then(|c| c.as_os_str().to_str())
+        .and_then(|c| c.parse::<u32>().ok());
+    match owner {
+        Some(pid) => pid == std::process::id() || !is_process_running(pid),
+        // Not created by a pid-tagged run; nothing can still be using it
+        None => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1099,4 +1290,52 @@ mod tests {
             err_msg
         );
     }
+
+    #[test]
+    #[serial]
+    #[cfg(unix)]
+    fn test_cleanup_removes_only_orphaned_diamond_worktrees() {
+        let dir = tempdir().unwrap();
+        let main_path = dir.path().join("main");
+        let user_wt = dir.path().join("user-worktree");
+        std::fs::create_dir_all(&main_path).unwrap();
+        init_test_repo(&main_path);
+
+        let _guard = DirGuard::new(&main_path);
+
+        // A worktree the user made themselves
+        Command::new("git")
+            .args(["worktree", "add", user_wt.to_str().unwrap(), "-b", "user-branch"])
+            .output()
+            .expect("git worktree add failed");
+
+        // One registered by this process, one left behind by a killed run,
+        // and one belonging to another dm process that is still running
+        let registered = create_diamond_worktree("scratch", "HEAD").unwrap();
+        let root = diamond_worktree_root().unwrap();
+        let leftover = root.join("999999999").join("leftover");
+        let busy = root.join(std::os::unix::process::parent_id().to_string()).join("busy");
+        for path in [&leftover, &busy] {
+            Command::new("git")
+                .args(["worktree", "add", "--detach", path.to_str().unwrap(), "HEAD"])
+                .output()
+                .expect("git worktree add failed");
+        }
+        assert_eq!(list_worktrees().unwrap().len(), 5);
+
+        let removed = cleanup_diamond_worktrees();
+
+        assert_eq!(removed.len(), 2, "removed: {:?}", removed);
+        assert!(!registered.exists());
+        assert!(!leftover.exists());
+        assert!(busy.exists(), "a live process's worktree must be kept");
+        assert!(user_wt.exists());
+
+        let remaining = list_worktrees().unwrap();
+        assert_eq!(remaining.len(), 3);
+        assert!(remaining.iter().any(|wt| wt.branch.as_deref() == Some("user-branch")));
+
+        // Nothing left to do on a second pass
+        assert!(cleanup_diamond_worktrees().is_empty());
+    }
 }
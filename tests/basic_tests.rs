@@ -52,6 +52,80 @@ fn test_create_with_commit() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_create_with_message_file() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("test.txt"), "test content")?;
+    // Keep the message file outside the repo so -a doesn't commit it
+    let msg_dir = TempDir::new()?;
+    let msg_file = msg_dir.path().join("msg.txt");
+    fs::write(&msg_file, "Add test file\n\nExplain why it is needed.\n\n")?;
+
+    let output = run_dm(
+        temp_dir.path(),
+        &["create", "feature-1", "-a", "-F", msg_file.to_str().unwrap()],
+    )?;
+    assert!(
+        output.status.success(),
+        "dm create -F failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let body = run_git(temp_dir.path(), &["log", "-1", "--pretty=format:%B"])?;
+    assert_eq!(
+        String::from_utf8_lossy(&body.stdout),
+        "Add test file\n\nExplain why it is needed."
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_create_and_modify_with_message_from_stdin() -> Result<()> {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    let run_with_stdin = |args: &[&str], input: &str| -> Result<std::process::Output> {
+        let mut child = Command::new(dm_binary())
+            .args(args)
+            .current_dir(temp_dir.path())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child.stdin.take().unwrap().write_all(input.as_bytes())?;
+        Ok(child.wait_with_output()?)
+    };
+
+    fs::write(temp_dir.path().join("test.txt"), "test content")?;
+    let output = run_with_stdin(&["create", "-a", "-m", "-"], "Piped subject\n\nPiped body\n")?;
+    assert!(
+        output.status.success(),
+        "dm create -m - failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(get_last_commit_message(temp_dir.path())?, "Piped subject");
+    let body = run_git(temp_dir.path(), &["log", "-1", "--pretty=format:%b"])?;
+    assert_eq!(String::from_utf8_lossy(&body.stdout).trim(), "Piped body");
+
+    let output = run_with_stdin(&["modify", "-m", "-"], "Reworded subject\n")?;
+    assert!(output.status.success());
+    assert_eq!(get_last_commit_message(temp_dir.path())?, "Reworded subject");
+
+    // An empty message is rejected rather than committed
+    let output = run_with_stdin(&["modify", "-m", "-"], "\n\n")?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("empty"));
+    assert_eq!(get_last_commit_message(temp_dir.path())?, "Reworded subject");
+
+    Ok(())
+}
+
 #[test]
 fn test_modify_without_message_preserves_commit() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -156,6 +230,38 @@ fn test_modify_with_commit_flag_creates_new_commit() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_modify_reset_author_uses_configured_user() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("file1.txt"), "initial")?;
+    run_dm(temp_dir.path(), &["create", "feature-1", "-a", "-m", "First commit"])?;
+
+    // Switch identity, then reset the author of the branch commit
+    run_git(temp_dir.path(), &["config", "user.name", "New Author"])?;
+    run_git(temp_dir.path(), &["config", "user.email", "new@example.com"])?;
+
+    let output = run_dm(temp_dir.path(), &["modify", "--reset-author"])?;
+    assert!(
+        output.status.success(),
+        "dm modify --reset-author failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = Command::new("git")
+        .args(["log", "-1", "--format=%an <%ae>"])
+        .current_dir(temp_dir.path())
+        .output()?;
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "New Author <new@example.com>"
+    );
+    assert_eq!(get_last_commit_message(temp_dir.path())?, "First commit");
+
+    Ok(())
+}
+
 #[test]
 fn test_create_and_modify_workflow() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -1242,6 +1242,50 @@ fn test_history_all_flag() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_history_branch_filter() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Operations on two separate branches
+    fs::write(temp_dir.path().join("a.txt"), "a")?;
+    run_dm(temp_dir.path(), &["create", "feature-a", "-a", "-m", "Feature A"])?;
+    run_dm(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("b.txt"), "b")?;
+    run_dm(temp_dir.path(), &["create", "feature-b", "-a", "-m", "Feature B"])?;
+    run_dm(temp_dir.path(), &["rename", "feature-renamed"])?;
+
+    let output = run_dm(temp_dir.path(), &["history", "--branch", "feature-a"])?;
+    assert!(
+        output.status.success(),
+        "history --branch failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Created branch feature-a"), "stdout: {}", stdout);
+    assert!(
+        !stdout.contains("feature-b"),
+        "feature-b leaked into the filter: {}",
+        stdout
+    );
+
+    let output = run_dm(temp_dir.path(), &["history", "--branch", "feature-b"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Created branch feature-b"), "stdout: {}", stdout);
+    assert!(
+        stdout.contains("Renamed feature-b to feature-renamed"),
+        "stdout: {}",
+        stdout
+    );
+    assert!(
+        !stdout.contains("feature-a"),
+        "feature-a leaked into the filter: {}",
+        stdout
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_history_default_behavior() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -26,6 +26,55 @@ fn test_config_show_works() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_config_list_fresh_repo_shows_defaults() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let config_home = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Isolate from any user config on the machine running the tests
+    let list = |args: &[&str]| -> Result<String> {
+        let output = std::process::Command::new(dm_binary())
+            .args(args)
+            .current_dir(temp_dir.path())
+            .env("XDG_CONFIG_HOME", config_home.path())
+            .output()?;
+        assert!(
+            output.status.success(),
+            "dm {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    };
+
+    let all = list(&["config", "list"])?;
+    for line in [
+        "repo.remote=origin",
+        "branch.format={date}-{name}",
+        "branch.prefix=",
+        "merge.ci_timeout_secs=600",
+        "merge.proactive_rebase=true",
+        "merge.wait_for_ci=true",
+        "nav.wrap=false",
+        "sync.autostash=false",
+        "sync.strategy=rebase",
+    ] {
+        assert!(all.lines().any(|l| l == line), "missing '{}' in:\n{}", line, all);
+    }
+
+    // Nothing is customized yet, so every key is at its default
+    assert_eq!(list(&["config", "list", "--defaults"])?, all);
+
+    // A customized key drops out of --defaults
+    run_dm_success(temp_dir.path(), &["config", "set", "nav.wrap", "true", "--local"])?;
+    let defaults = list(&["config", "list", "--defaults"])?;
+    assert!(!defaults.contains("nav.wrap"), "{}", defaults);
+    assert!(list(&["config", "list"])?.lines().any(|l| l == "nav.wrap=true"));
+
+    Ok(())
+}
+
 #[test]
 fn test_config_get_branch_format() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -104,10 +153,10 @@ fn test_create_explicit_name_not_formatted() -> Result<()> {
     run_dm(temp_dir.path(), &["config", "set", "branch.prefix", "test/", "--local"])?;
     run_dm(
         temp_dir.path(),
-        &["config", "set", "branch.format", "{prefix}{name}", "--local"],
+        &["config", "set", "branch.format", "{prefix}{date}-{name}", "--local"],
     )?;
 
-    // Create branch with explicit name - should NOT apply prefix
+    // Create branch with explicit name - should apply prefix but not the template
     let output = run_dm(temp_dir.path(), &["create", "my-branch"])?;
     assert!(
         output.status.success(),
@@ -115,13 +164,19 @@ fn test_create_explicit_name_not_formatted() -> Result<()> {
         String::from_utf8_lossy(&output.stderr)
     );
 
-    // Branch should be exactly "my-branch", not "test/my-branch"
+    // Branch should be "test/my-branch", without the date from the template
     let current_branch = get_current_branch(temp_dir.path())?;
     assert_eq!(
-        current_branch, "my-branch",
-        "Explicit branch name should not be formatted"
+        current_branch, "test/my-branch",
+        "Explicit branch name should only get the prefix"
     );
 
+    // A name that already carries the prefix is not prefixed twice
+    run_dm(temp_dir.path(), &["checkout", "main"])?;
+    let output = run_dm(temp_dir.path(), &["create", "test/other"])?;
+    assert!(output.status.success());
+    assert_eq!(get_current_branch(temp_dir.path())?, "test/other");
+
     Ok(())
 }
 
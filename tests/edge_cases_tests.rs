@@ -462,6 +462,42 @@ fn test_absorb_with_no_staged_changes() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_absorb_without_all_only_absorbs_staged_files() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("staged.txt"), "one\n")?;
+    fs::write(temp_dir.path().join("unstaged.txt"), "one\n")?;
+    run_dm_success(temp_dir.path(), &["create", "feature", "-a", "-m", "Add files"])?;
+
+    // Stage one edit, leave the other only in the working tree
+    fs::write(temp_dir.path().join("staged.txt"), "two\n")?;
+    fs::write(temp_dir.path().join("unstaged.txt"), "two\n")?;
+    run_git(temp_dir.path(), &["add", "staged.txt"])?;
+
+    let output = run_dm_success(temp_dir.path(), &["absorb"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("unstaged changes untouched"),
+        "absorb should mention the unstaged file it skipped: {}",
+        stdout
+    );
+
+    // The staged edit was absorbed into a commit; the index is now clean
+    let absorbed = run_git(temp_dir.path(), &["show", "--name-only", "--format=", "HEAD"])?;
+    assert_eq!(String::from_utf8_lossy(&absorbed.stdout).trim(), "staged.txt");
+    let staged = run_git(temp_dir.path(), &["diff", "--cached", "--name-only"])?;
+    assert!(String::from_utf8_lossy(&staged.stdout).trim().is_empty());
+
+    // The unstaged edit is still sitting in the working tree
+    let dirty = run_git(temp_dir.path(), &["diff", "--name-only"])?;
+    assert_eq!(String::from_utf8_lossy(&dirty.stdout).trim(), "unstaged.txt");
+    assert_eq!(fs::read_to_string(temp_dir.path().join("unstaged.txt"))?, "two\n");
+
+    Ok(())
+}
+
 // ============================================================================
 // EMPTY/BARE REPOSITORY TESTS
 // ============================================================================
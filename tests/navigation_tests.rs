@@ -89,7 +89,7 @@ fn test_navigate_deep_stack_up_down() -> Result<()> {
 }
 
 #[test]
-fn test_navigate_down_at_trunk_fails() -> Result<()> {
+fn test_navigate_down_at_trunk_stays_put() -> Result<()> {
     let temp_dir = TempDir::new()?;
     init_test_repo(temp_dir.path())?;
 
@@ -100,21 +100,22 @@ fn test_navigate_down_at_trunk_fails() -> Result<()> {
     // Go back to main
     run_dm(temp_dir.path(), &["checkout", "main"])?;
 
-    // Try to go down from main (should fail)
+    // Going down from main stays put with a friendly message
     let output = run_dm(temp_dir.path(), &["down"])?;
-    assert!(!output.status.success());
-    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
-        stderr.contains("root") || stderr.contains("parent"),
-        "Expected 'root' or 'parent' error, got: {}",
-        stderr
+        stdout.contains("Already at bottom"),
+        "Expected 'Already at bottom' message, got: {}",
+        stdout
     );
+    assert_eq!(get_current_branch(temp_dir.path())?, "main");
 
     Ok(())
 }
 
 #[test]
-fn test_navigate_up_at_leaf_fails() -> Result<()> {
+fn test_navigate_up_at_leaf_stays_put() -> Result<()> {
     let temp_dir = TempDir::new()?;
     init_test_repo(temp_dir.path())?;
 
@@ -122,15 +123,16 @@ fn test_navigate_up_at_leaf_fails() -> Result<()> {
     fs::write(temp_dir.path().join("f.txt"), "f")?;
     run_dm(temp_dir.path(), &["create", "feature", "-a", "-m", "F"])?;
 
-    // Try to go up from feature (should fail - no children)
+    // Going up from feature (no children) stays put with a friendly message
     let output = run_dm(temp_dir.path(), &["up"])?;
-    assert!(!output.status.success());
-    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
-        stderr.contains("child") || stderr.contains("No child"),
-        "Expected 'child' error, got: {}",
-        stderr
+        stdout.contains("Already at top"),
+        "Expected 'Already at top' message, got: {}",
+        stdout
     );
+    assert_eq!(get_current_branch(temp_dir.path())?, "feature");
 
     Ok(())
 }
@@ -216,6 +218,32 @@ fn test_checkout_nonexistent_branch_fails() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_checkout_trunk_flag_takes_trunk_name_as_separate_argument() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("f1.txt"), "f1")?;
+    run_dm(temp_dir.path(), &["create", "f1", "-a", "-m", "F1"])?;
+    run_dm(temp_dir.path(), &["checkout", "main"])?;
+    run_git(temp_dir.path(), &["checkout", "-b", "release"])?;
+
+    // A name that isn't the trunk is refused rather than checked out
+    let output = run_dm(temp_dir.path(), &["checkout", "-t", "f1"])?;
+    assert!(!output.status.success());
+    assert_eq!(get_current_branch(temp_dir.path())?, "release");
+
+    let output = run_dm(temp_dir.path(), &["checkout", "-t", "main"])?;
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(get_current_branch(temp_dir.path())?, "main");
+
+    Ok(())
+}
+
 #[test]
 fn test_checkout_without_arg_lists_branches() -> Result<()> {
     let temp_dir = TempDir::new()?;
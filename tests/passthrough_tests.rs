@@ -5,16 +5,17 @@ use common::*;
 use tempfile::TempDir;
 
 #[test]
-fn test_passthrough_status_command() -> Result<()> {
+fn test_passthrough_stash_command() -> Result<()> {
     let temp_dir = TempDir::new()?;
     init_test_repo(temp_dir.path())?;
+    std::fs::write(temp_dir.path().join("README.md"), "changed")?;
 
-    let output = run_dm(temp_dir.path(), &["status"])?;
+    let output = run_dm(temp_dir.path(), &["stash"])?;
 
     // Should succeed
     assert!(
         output.status.success(),
-        "dm status failed: {}",
+        "dm stash failed: {}",
         String::from_utf8_lossy(&output.stderr)
     );
 
@@ -26,11 +27,11 @@ fn test_passthrough_status_command() -> Result<()> {
         stderr
     );
 
-    // Should show git status output
+    // Should show git stash output
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
-        stdout.contains("On branch") || stdout.contains("nothing to commit"),
-        "Expected git status output, got: {}",
+        stdout.contains("Saved working directory"),
+        "Expected git stash output, got: {}",
         stdout
     );
 
@@ -104,14 +105,14 @@ fn test_passthrough_unknown_command_shows_help() -> Result<()> {
 
 #[test]
 fn test_passthrough_git_failure_propagates() -> Result<()> {
-    // Test outside a git repo - git status should fail
+    // Test outside a git repo - git stash should fail
     let temp_dir = TempDir::new()?;
     // Don't init git repo
 
-    let output = run_dm(temp_dir.path(), &["status"])?;
+    let output = run_dm(temp_dir.path(), &["stash", "list"])?;
 
     // Should fail because not a git repo
-    assert!(!output.status.success(), "dm status outside git repo should fail");
+    assert!(!output.status.success(), "dm stash outside git repo should fail");
 
     Ok(())
 }
@@ -80,6 +80,45 @@ fn test_delete_leaf_branch() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_delete_no_reparent_requires_force_with_children() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Create stack: main -> f1 -> f2
+    for i in 1..=2 {
+        fs::write(temp_dir.path().join(format!("f{}.txt", i)), format!("{}", i))?;
+        run_dm(
+            temp_dir.path(),
+            &["create", &format!("f{}", i), "-a", "-m", &format!("F{}", i)],
+        )?;
+    }
+    run_dm(temp_dir.path(), &["checkout", "main"])?;
+
+    // The flag takes no value, so the branch name after it isn't swallowed
+    let output = run_dm(temp_dir.path(), &["delete", "--no-reparent", "f1"])?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("would orphan 1 child: f2"), "stderr: {}", stderr);
+
+    let output = run_dm(temp_dir.path(), &["delete", "--no-reparent", "-f", "f1"])?;
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // f2 keeps its commits on top of the deleted f1 instead of being rebased onto main
+    let output = Command::new("git")
+        .args(["log", "--format=%s", "main..f2"])
+        .current_dir(temp_dir.path())
+        .output()?;
+    let log = String::from_utf8_lossy(&output.stdout);
+    assert!(log.contains("F1") && log.contains("F2"), "log: {}", log);
+
+    Ok(())
+}
+
 #[test]
 fn test_delete_current_branch_switches_to_parent() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -254,6 +293,71 @@ fn test_pop_with_children_fails() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_pop_to_sibling_applies_changes_uncommitted() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Create main -> sibling and main -> wrong
+    fs::write(temp_dir.path().join("sibling.txt"), "sibling")?;
+    run_dm_success(temp_dir.path(), &["create", "sibling", "-a", "-m", "Sibling"])?;
+    run_dm_success(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("moved.txt"), "committed")?;
+    run_dm_success(temp_dir.path(), &["create", "wrong", "-a", "-m", "Wrong place"])?;
+    let sibling_sha = get_commit_hash(temp_dir.path(), "sibling")?;
+
+    // Plus an uncommitted edit on top
+    fs::write(temp_dir.path().join("extra.txt"), "uncommitted")?;
+
+    let output = run_dm(temp_dir.path(), &["pop", "--to", "sibling"])?;
+    assert!(
+        output.status.success(),
+        "pop --to failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(get_current_branch(temp_dir.path())?, "sibling");
+    assert!(!git_branch_exists(temp_dir.path(), "wrong")?);
+    assert!(!is_branch_tracked_in_refs(temp_dir.path(), "wrong")?);
+
+    // Changes landed on sibling's working tree without new commits
+    assert_eq!(fs::read_to_string(temp_dir.path().join("moved.txt"))?, "committed");
+    assert_eq!(fs::read_to_string(temp_dir.path().join("extra.txt"))?, "uncommitted");
+    assert_eq!(fs::read_to_string(temp_dir.path().join("sibling.txt"))?, "sibling");
+    assert_eq!(get_commit_hash(temp_dir.path(), "sibling")?, sibling_sha);
+    let status = run_git(temp_dir.path(), &["status", "--porcelain"])?;
+    let status = String::from_utf8_lossy(&status.stdout);
+    assert!(status.contains("moved.txt"), "status: {}", status);
+    assert!(status.contains("extra.txt"), "status: {}", status);
+
+    Ok(())
+}
+
+#[test]
+fn test_pop_to_conflict_leaves_markers_on_target() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("shared.txt"), "sibling version\n")?;
+    run_dm_success(temp_dir.path(), &["create", "sibling", "-a", "-m", "Sibling"])?;
+    run_dm_success(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("shared.txt"), "popped version\n")?;
+    run_dm_success(temp_dir.path(), &["create", "wrong", "-a", "-m", "Wrong place"])?;
+
+    let output = run_dm(temp_dir.path(), &["pop", "--to", "sibling"])?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("with conflicts"), "stderr: {}", stderr);
+
+    assert_eq!(get_current_branch(temp_dir.path())?, "sibling");
+    assert!(!git_branch_exists(temp_dir.path(), "wrong")?);
+    let content = fs::read_to_string(temp_dir.path().join("shared.txt"))?;
+    assert!(content.contains("<<<<<<<"), "expected conflict markers: {}", content);
+    assert!(content.contains("popped version"));
+
+    Ok(())
+}
+
 // ============================================================================
 // FOLD TESTS
 // ============================================================================
@@ -141,6 +141,26 @@ fn test_submit_update_only_flag_exists() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_submit_strict_flag_works_without_check_base_protection() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("f.txt"), "feature")?;
+    run_dm(temp_dir.path(), &["create", "feature", "-a", "-m", "Feature"])?;
+
+    // submit --strict should be accepted on its own
+    let output = run_dm(temp_dir.path(), &["submit", "--strict"])?;
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("unexpected") && !stderr.contains("required arguments"),
+        "--strict should not need --check-base-protection: {}",
+        stderr
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_submit_confirm_flag_non_interactive() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -501,6 +521,46 @@ fn test_modify_frozen_branch_fails() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_freeze_stack_blocks_modify_on_every_branch() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Create a three-branch stack and freeze it from the middle
+    for name in ["s1", "s2", "s3"] {
+        fs::write(temp_dir.path().join(format!("{}.txt", name)), name)?;
+        run_dm(temp_dir.path(), &["create", name, "-a", "-m", name])?;
+    }
+    run_dm(temp_dir.path(), &["checkout", "s2"])?;
+
+    let output = run_dm(temp_dir.path(), &["freeze", "--stack"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "freeze --stack failed: {}", stdout);
+    assert!(
+        stdout.contains("Froze 3 branches"),
+        "Should freeze all three: {}",
+        stdout
+    );
+
+    for name in ["s1", "s2", "s3"] {
+        run_dm(temp_dir.path(), &["checkout", name])?;
+        fs::write(temp_dir.path().join("extra.txt"), name)?;
+        let output = run_dm(temp_dir.path(), &["modify", "-a", "-m", "Should fail"])?;
+
+        assert!(!output.status.success(), "modify on {} should be refused", name);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("frozen"),
+            "Should mention frozen for {}: {}",
+            name,
+            stderr
+        );
+        fs::remove_file(temp_dir.path().join("extra.txt"))?;
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_freeze_trunk_fails() -> Result<()> {
     let temp_dir = TempDir::new()?;
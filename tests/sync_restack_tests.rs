@@ -223,6 +223,87 @@ fn test_sync_rebases_stack_onto_updated_main() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_info_reports_sync_as_last_operation() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("f1.txt"), "f1")?;
+    run_dm(temp_dir.path(), &["create", "f1", "-a", "-m", "F1"])?;
+
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("main_update.txt"), "main update")?;
+    run_git(temp_dir.path(), &["add", "."])?;
+    run_git(temp_dir.path(), &["commit", "-m", "Main update"])?;
+    run_dm(temp_dir.path(), &["checkout", "f1"])?;
+
+    let output = run_dm(temp_dir.path(), &["sync"])?;
+    assert!(
+        output.status.success(),
+        "sync failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = run_dm(temp_dir.path(), &["info", "f1"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Last modified by: sync ("),
+        "info should report sync as the last operation: {}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_merge_strategy_keeps_original_commits() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Create a stack: main -> f1 -> f2
+    fs::write(temp_dir.path().join("f1.txt"), "f1")?;
+    run_dm(temp_dir.path(), &["create", "f1", "-a", "-m", "F1"])?;
+
+    fs::write(temp_dir.path().join("f2.txt"), "f2")?;
+    run_dm(temp_dir.path(), &["create", "f2", "-a", "-m", "F2"])?;
+
+    let original_f1 = get_commit_hash(temp_dir.path(), "f1")?;
+    let original_f2 = get_commit_hash(temp_dir.path(), "f2")?;
+
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("main_update.txt"), "main update")?;
+    run_git(temp_dir.path(), &["add", "."])?;
+    run_git(temp_dir.path(), &["commit", "-m", "Main update"])?;
+
+    run_dm(temp_dir.path(), &["checkout", "f2"])?;
+    let output = run_dm(temp_dir.path(), &["sync", "--strategy", "merge"])?;
+    assert!(
+        output.status.success(),
+        "sync --strategy merge failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    for (branch, original) in [("f1", &original_f1), ("f2", &original_f2)] {
+        // The original commit is still in the branch's history, unchanged
+        let output = run_git(temp_dir.path(), &["merge-base", "--is-ancestor", original, branch])?;
+        assert!(output.status.success(), "{} should keep its original commit", branch);
+
+        // ...and the branch tip is a merge commit bringing in the parent
+        let output = run_git(temp_dir.path(), &["rev-list", "--parents", "-n", "1", branch])?;
+        let parents = String::from_utf8_lossy(&output.stdout).split_whitespace().count() - 1;
+        assert_eq!(parents, 2, "{} should end in a merge commit", branch);
+
+        let output = run_git(temp_dir.path(), &["merge-base", "--is-ancestor", "main", branch])?;
+        assert!(output.status.success(), "{} should contain the main update", branch);
+    }
+
+    // The intra-stack update merged f1 into f2 rather than rebasing it
+    let output = run_git(temp_dir.path(), &["merge-base", "--is-ancestor", "f1", "f2"])?;
+    assert!(output.status.success(), "f2 should contain f1's merge");
+
+    Ok(())
+}
+
 #[test]
 fn test_sync_abort_stops_operation() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -287,6 +368,152 @@ fn test_sync_with_no_updates_is_noop() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_sync_summary_categorizes_rebased_and_up_to_date_branches() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // stale is created before main moves; fresh is created after
+    fs::write(temp_dir.path().join("stale.txt"), "stale")?;
+    run_dm(temp_dir.path(), &["create", "stale", "-a", "-m", "Stale"])?;
+
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("main_new.txt"), "new")?;
+    run_git(temp_dir.path(), &["add", "."])?;
+    run_git(temp_dir.path(), &["commit", "-m", "Main new"])?;
+
+    fs::write(temp_dir.path().join("fresh.txt"), "fresh")?;
+    run_dm(temp_dir.path(), &["create", "fresh", "-a", "-m", "Fresh"])?;
+
+    let output = run_dm(temp_dir.path(), &["sync", "--verbose"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "sync failed: {}", stdout);
+
+    let summary: Vec<&str> = stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("Summary:"))
+        .map(str::trim)
+        .collect();
+    assert!(
+        summary
+            .iter()
+            .any(|line| line.starts_with("Rebased") && line.ends_with("1  stale")),
+        "stale should be listed as rebased: {}",
+        stdout
+    );
+    assert!(
+        summary
+            .iter()
+            .any(|line| line.starts_with("Up to date") && line.ends_with("1  fresh")),
+        "fresh should be listed as up to date: {}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_max_depth_only_rebases_branches_nearest_trunk() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    for i in 1..=5 {
+        fs::write(temp_dir.path().join(format!("b{}.txt", i)), "content")?;
+        let name = format!("b{}", i);
+        run_dm(temp_dir.path(), &["create", &name, "-a", "-m", &name])?;
+    }
+
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("main_new.txt"), "new")?;
+    run_git(temp_dir.path(), &["add", "."])?;
+    run_git(temp_dir.path(), &["commit", "-m", "Main new"])?;
+    let main_sha = get_commit_hash(temp_dir.path(), "main")?;
+
+    let before: Vec<String> = (3..=5)
+        .map(|i| get_commit_hash(temp_dir.path(), &format!("b{}", i)))
+        .collect::<Result<_>>()?;
+
+    let output = run_dm(temp_dir.path(), &["sync", "--max-depth", "2"])?;
+    assert!(
+        output.status.success(),
+        "sync --max-depth failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The bottom two branches now sit on top of the new trunk commit
+    for name in ["b1", "b2"] {
+        let result = run_git(temp_dir.path(), &["merge-base", "--is-ancestor", &main_sha, name])?;
+        assert!(result.status.success(), "{} should be rebased onto main", name);
+    }
+
+    // Everything deeper is untouched
+    for (i, sha) in (3..=5).zip(before) {
+        assert_eq!(
+            get_commit_hash(temp_dir.path(), &format!("b{}", i))?,
+            sha,
+            "b{} should not be rebased",
+            i
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_default_flags_apply_and_cli_overrides_them() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("feature.txt"), "feature")?;
+    run_dm(temp_dir.path(), &["create", "feature", "-a", "-m", "Feature"])?;
+
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("main_new.txt"), "new")?;
+    run_git(temp_dir.path(), &["add", "."])?;
+    run_git(temp_dir.path(), &["commit", "-m", "Main new"])?;
+    let main_sha = get_commit_hash(temp_dir.path(), "main")?;
+    let feature_sha = get_commit_hash(temp_dir.path(), "feature")?;
+
+    let output = run_dm(
+        temp_dir.path(),
+        &[
+            "config",
+            "set",
+            "--local",
+            "sync.default_flags",
+            "--no-restack --no-tags",
+        ],
+    )?;
+    assert!(
+        output.status.success(),
+        "config set failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // A bare sync behaves like `sync --no-restack --no-tags`
+    let output = run_dm(temp_dir.path(), &["sync"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "sync failed: {}", stdout);
+    assert!(
+        stdout.contains("cleanup only"),
+        "default --no-restack not applied: {}",
+        stdout
+    );
+    assert_eq!(get_commit_hash(temp_dir.path(), "feature")?, feature_sha);
+
+    // An explicit opposite flag wins over the default
+    let output = run_dm(temp_dir.path(), &["sync", "--restack"])?;
+    assert!(
+        output.status.success(),
+        "sync --restack failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let result = run_git(temp_dir.path(), &["merge-base", "--is-ancestor", &main_sha, "feature"])?;
+    assert!(result.status.success(), "feature should be rebased onto main");
+
+    Ok(())
+}
+
 #[test]
 fn test_sync_multiple_independent_stacks() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -331,6 +558,152 @@ fn test_sync_multiple_independent_stacks() -> Result<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(unix)]
+fn test_sync_fetches_once_for_multiple_stacks() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = TempDir::new()?;
+    let remote_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Local bare remote whose upload-pack is wrapped to count fetches
+    run_git(remote_dir.path(), &["init", "--bare", "-q", "remote.git"])?;
+    let remote_path = remote_dir.path().join("remote.git");
+    run_git(
+        temp_dir.path(),
+        &["remote", "add", "origin", remote_path.to_str().unwrap()],
+    )?;
+    run_git(temp_dir.path(), &["push", "-q", "origin", "main"])?;
+
+    let count_file = remote_dir.path().join("fetch-count");
+    let shim = remote_dir.path().join("upload-pack-shim.sh");
+    fs::write(
+        &shim,
+        format!(
+            "#!/bin/sh\necho fetch >> '{}'\nexec git-upload-pack \"$@\"\n",
+            count_file.display()
+        ),
+    )?;
+    fs::set_permissions(&shim, fs::Permissions::from_mode(0o755))?;
+    run_git(
+        temp_dir.path(),
+        &["config", "remote.origin.uploadpack", shim.to_str().unwrap()],
+    )?;
+
+    // Two independent stacks from main
+    fs::write(temp_dir.path().join("stack1.txt"), "stack1")?;
+    run_dm(temp_dir.path(), &["create", "stack1", "-a", "-m", "Stack1"])?;
+    run_dm(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("stack2.txt"), "stack2")?;
+    run_dm(temp_dir.path(), &["create", "stack2", "-a", "-m", "Stack2"])?;
+
+    // Only count fetches made by sync itself (checkout fetches diamond refs)
+    let _ = fs::remove_file(&count_file);
+    let output = run_dm(temp_dir.path(), &["sync", "--no-cleanup"])?;
+    assert!(
+        output.status.success(),
+        "sync failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let fetches = fs::read_to_string(&count_file).unwrap_or_default().lines().count();
+    assert_eq!(fetches, 1, "sync should fetch exactly once");
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_no_tags_skips_remote_tags() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let remote_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Local bare remote holding a tag the local repo doesn't have
+    run_git(remote_dir.path(), &["init", "--bare", "-q", "remote.git"])?;
+    let remote_path = remote_dir.path().join("remote.git");
+    run_git(
+        temp_dir.path(),
+        &["remote", "add", "origin", remote_path.to_str().unwrap()],
+    )?;
+    run_git(temp_dir.path(), &["push", "-q", "origin", "main"])?;
+    run_git(temp_dir.path(), &["tag", "v1.0"])?;
+    run_git(temp_dir.path(), &["push", "-q", "origin", "v1.0"])?;
+    run_git(temp_dir.path(), &["tag", "-d", "v1.0"])?;
+
+    let has_tag = || -> Result<bool> {
+        let output = Command::new("git")
+            .args(["tag", "--list", "v1.0"])
+            .current_dir(temp_dir.path())
+            .output()?;
+        Ok(!String::from_utf8_lossy(&output.stdout).trim().is_empty())
+    };
+
+    let output = run_dm(temp_dir.path(), &["sync", "--no-cleanup", "--no-tags"])?;
+    assert!(
+        output.status.success(),
+        "sync --no-tags failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!has_tag()?, "sync --no-tags should not fetch tags");
+
+    let output = run_dm(temp_dir.path(), &["sync", "--no-cleanup"])?;
+    assert!(
+        output.status.success(),
+        "sync failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(has_tag()?, "sync should fetch tags by default");
+
+    Ok(())
+}
+
+#[test]
+fn test_sync_autostash_restores_uncommitted_changes() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("feature.txt"), "committed")?;
+    run_dm(temp_dir.path(), &["create", "feature", "-a", "-m", "Feature"])?;
+
+    // Move main forward
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("main_new.txt"), "new")?;
+    run_git(temp_dir.path(), &["add", "."])?;
+    run_git(temp_dir.path(), &["commit", "-m", "Main new"])?;
+    run_git(temp_dir.path(), &["checkout", "feature"])?;
+
+    // Dirty the tree with a tracked modification
+    fs::write(temp_dir.path().join("feature.txt"), "work in progress")?;
+
+    let output = run_dm(temp_dir.path(), &["sync", "--no-cleanup"])?;
+    assert!(
+        !output.status.success(),
+        "sync should refuse a dirty tree without --autostash"
+    );
+
+    let output = run_dm(temp_dir.path(), &["sync", "--no-cleanup", "--autostash"])?;
+    assert!(
+        output.status.success(),
+        "sync --autostash failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Branch was rebased and the working changes are back
+    assert_eq!(get_current_branch(temp_dir.path())?, "feature");
+    let log = run_git(temp_dir.path(), &["log", "--oneline"])?;
+    assert!(String::from_utf8_lossy(&log.stdout).contains("Main new"));
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("feature.txt"))?,
+        "work in progress"
+    );
+
+    let stashes = run_git(temp_dir.path(), &["stash", "list"])?;
+    assert!(String::from_utf8_lossy(&stashes.stdout).trim().is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn test_sync_then_cleanup_workflow() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -1345,6 +1718,61 @@ fn test_undo_restores_last_sync() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_restack_groups_backups_and_undoes_them_together() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Stack: main -> stack-1 -> stack-2 -> stack-3
+    for i in 1..=3 {
+        fs::write(temp_dir.path().join(format!("stack{}.txt", i)), "content")?;
+        run_dm_success(
+            temp_dir.path(),
+            &["create", &format!("stack-{}", i), "-a", "-m", &format!("Stack {}", i)],
+        )?;
+    }
+    let original: Vec<String> = (1..=3)
+        .map(|i| get_commit_hash(temp_dir.path(), &format!("stack-{}", i)))
+        .collect::<Result<_>>()?;
+
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    create_file_and_commit(temp_dir.path(), "main.txt", "main content", "Main update")?;
+    run_dm_success(temp_dir.path(), &["checkout", "stack-1"])?;
+    run_dm_success(temp_dir.path(), &["restack"])?;
+
+    // One restack -> one operation group holding a backup per branch
+    let output = run_git(
+        temp_dir.path(),
+        &["for-each-ref", "--format=%(refname)", "refs/diamond/backup/"],
+    )?;
+    let refs: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|l| l.to_string())
+        .collect();
+    assert_eq!(refs.len(), 3, "Expected one backup per branch: {:?}", refs);
+    let groups: std::collections::HashSet<&str> = refs
+        .iter()
+        .map(|r| r.trim_start_matches("refs/diamond/backup/").split('/').next().unwrap())
+        .collect();
+    assert_eq!(groups.len(), 1, "Backups should share one operation group: {:?}", refs);
+    for i in 1..=3 {
+        assert!(refs.iter().any(|r| r.ends_with(&format!("/stack-{}", i))));
+    }
+
+    // Undo restores the whole group
+    run_dm_success(temp_dir.path(), &["undo", "--force"])?;
+    for (i, hash) in original.iter().enumerate() {
+        assert_eq!(
+            &get_commit_hash(temp_dir.path(), &format!("stack-{}", i + 1))?,
+            hash,
+            "stack-{} should be restored",
+            i + 1
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_undo_chain_multiple_operations() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -1420,6 +1848,78 @@ fn test_undo_chain_multiple_operations() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_history_undo_restores_older_operation_by_id() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    // Two stacks: main -> f1, main -> g1 -> g2
+    fs::write(temp_dir.path().join("f1.txt"), "f1")?;
+    run_dm(temp_dir.path(), &["create", "f1", "-a", "-m", "F1"])?;
+    run_dm(temp_dir.path(), &["checkout", "main"])?;
+    fs::write(temp_dir.path().join("g1.txt"), "g1")?;
+    run_dm(temp_dir.path(), &["create", "g1", "-a", "-m", "G1"])?;
+    fs::write(temp_dir.path().join("g2.txt"), "g2")?;
+    run_dm(temp_dir.path(), &["create", "g2", "-a", "-m", "G2"])?;
+
+    let pre_sync_f1 = get_commit_hash(temp_dir.path(), "f1")?;
+    let pre_sync_g1 = get_commit_hash(temp_dir.path(), "g1")?;
+
+    // First operation: sync after main moves
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    create_file_and_commit(temp_dir.path(), "main.txt", "main", "Main update")?;
+    run_dm(temp_dir.path(), &["checkout", "f1"])?;
+    run_dm_success(temp_dir.path(), &["sync", "--no-cleanup"])?;
+    assert_ne!(get_commit_hash(temp_dir.path(), "f1")?, pre_sync_f1);
+
+    // Second operation: modifying g1 restacks g2
+    run_dm(temp_dir.path(), &["checkout", "g1"])?;
+    fs::write(temp_dir.path().join("g1.txt"), "g1 modified")?;
+    run_dm_success(temp_dir.path(), &["modify", "-a"])?;
+
+    // Find the sync's ID in history: "<date> <time> <id> <icon> Started sync ..."
+    let output = run_dm_success(temp_dir.path(), &["history", "--all"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let sync_id = stdout
+        .lines()
+        .find(|line| line.contains("Started sync"))
+        .and_then(|line| line.split_whitespace().nth(2))
+        .expect("history should list the sync")
+        .to_string();
+
+    // Undo the older sync, skipping the newer restack
+    let output = run_dm(temp_dir.path(), &["history", "--undo", &sync_id, "--force"])?;
+    assert!(
+        output.status.success(),
+        "history --undo failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        get_commit_hash(temp_dir.path(), "f1")?,
+        pre_sync_f1,
+        "f1 should be restored"
+    );
+    assert_eq!(
+        get_commit_hash(temp_dir.path(), "g1")?,
+        pre_sync_g1,
+        "g1 should be restored"
+    );
+
+    // Once the backups are garbage collected, the operation can't be undone
+    let refs = run_git(
+        temp_dir.path(),
+        &["for-each-ref", "--format=%(refname)", "refs/diamond/backup"],
+    )?;
+    for backup_ref in String::from_utf8_lossy(&refs.stdout).lines() {
+        run_git(temp_dir.path(), &["update-ref", "-d", backup_ref])?;
+    }
+    let output = run_dm(temp_dir.path(), &["history", "--undo", &sync_id, "--force"])?;
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no longer available"));
+
+    Ok(())
+}
+
 #[test]
 fn test_undo_without_force_requires_tty() -> Result<()> {
     let temp_dir = TempDir::new()?;
@@ -2646,3 +3146,109 @@ fn test_restack_from_trunk_stops_on_branch_conflict() -> Result<()> {
 
     Ok(())
 }
+
+/// Stack main -> feature-a -> feature-b where main conflicts with feature-b,
+/// restacked from feature-b so the restack stops on feature-b.
+/// Returns feature-b's commit before the restack.
+fn start_conflicting_restack(dir: &std::path::Path) -> Result<String> {
+    fs::write(dir.join("a.txt"), "a content")?;
+    run_dm(dir, &["create", "feature-a", "-a", "-m", "Feature A"])?;
+    fs::write(dir.join("b.txt"), "b content")?;
+    run_dm(dir, &["create", "feature-b", "-a", "-m", "Feature B"])?;
+    let original_b = get_commit_hash(dir, "feature-b")?;
+
+    run_git(dir, &["checkout", "main"])?;
+    create_file_and_commit(dir, "b.txt", "CONFLICT", "Main conflicts with b")?;
+    run_git(dir, &["checkout", "feature-b"])?;
+
+    run_dm(dir, &["restack"])?;
+    assert!(git_rebase_in_progress(dir)?, "restack should stop on the conflict");
+    Ok(original_b)
+}
+
+#[test]
+fn test_restack_continue_resumes_after_resolving() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+    start_conflicting_restack(temp_dir.path())?;
+
+    fs::write(temp_dir.path().join("b.txt"), "resolved")?;
+    run_git(temp_dir.path(), &["add", "b.txt"])?;
+
+    let output = run_dm(temp_dir.path(), &["restack", "--continue"])?;
+    assert!(
+        output.status.success(),
+        "restack --continue should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!git_rebase_in_progress(temp_dir.path())?);
+    assert!(
+        get_operation_state(temp_dir.path())?.is_none(),
+        "state should be cleared"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_restack_abort_restores_branches() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+    let original_b = start_conflicting_restack(temp_dir.path())?;
+
+    let output = run_dm(temp_dir.path(), &["restack", "--abort"])?;
+    assert!(
+        output.status.success(),
+        "restack --abort should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!git_rebase_in_progress(temp_dir.path())?);
+    assert!(
+        get_operation_state(temp_dir.path())?.is_none(),
+        "state should be cleared"
+    );
+    assert_eq!(
+        get_commit_hash(temp_dir.path(), "feature-b")?,
+        original_b,
+        "feature-b should be restored"
+    );
+
+    Ok(())
+}
+#[test]
+fn test_restack_continue_refuses_other_operations() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    init_test_repo(temp_dir.path())?;
+
+    let output = run_dm(temp_dir.path(), &["restack", "--continue"])?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No restack in progress"),
+        "unexpected error: {}",
+        stderr
+    );
+
+    // A conflicting sync is not a restack
+    fs::write(temp_dir.path().join("shared.txt"), "original")?;
+    run_dm(temp_dir.path(), &["create", "feature", "-a", "-m", "Add shared"])?;
+    run_git(temp_dir.path(), &["checkout", "main"])?;
+    create_file_and_commit(temp_dir.path(), "shared.txt", "main's version", "Main changes shared")?;
+    run_git(temp_dir.path(), &["checkout", "feature"])?;
+    run_dm(temp_dir.path(), &["sync"])?;
+    assert!(
+        git_rebase_in_progress(temp_dir.path())?,
+        "sync should stop on the conflict"
+    );
+
+    let output = run_dm(temp_dir.path(), &["restack", "--abort"])?;
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("A sync is in progress, not a restack"),
+        "unexpected error: {}",
+        stderr
+    );
+
+    Ok(())
+}